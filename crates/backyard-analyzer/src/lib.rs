@@ -0,0 +1,32 @@
+pub mod resolver;
+pub mod symbols;
+pub mod validator;
+pub mod variables;
+
+use backyard_nodes::Node;
+use resolver::{ NameResolution, NameResolver };
+use symbols::SymbolTable;
+use validator::{ Diagnostic, Validator };
+use variables::{ VariableAnalysis, VariableAnalyzer };
+
+/// Resolve every class, function and constant name in `node` against its namespace and
+/// `use` imports
+pub fn resolve_names<'b>(node: &'b Node) -> NameResolution<'b> {
+  NameResolver::resolve(node)
+}
+
+/// Collect every class, interface, trait, enum, function and constant declared in `node`
+pub fn collect_symbols(node: &Node) -> SymbolTable {
+  SymbolTable::collect(node)
+}
+
+/// Report the fatal errors PHP raises when compiling `node`, in source order
+pub fn validate(node: &Node) -> Vec<Diagnostic> {
+  Validator::validate(node)
+}
+
+/// Variables defined and read by the file and each function, method, closure and arrow
+/// function of `node`, reporting undefined variables and unused assignments
+pub fn analyze_variables(node: &Node) -> VariableAnalysis {
+  VariableAnalyzer::analyze(node)
+}
@@ -0,0 +1,506 @@
+use std::{ collections::HashMap, marker::PhantomData };
+
+use backyard_nodes::{
+  visitor::Visitor,
+  BinaryType,
+  Node,
+  NodeType,
+  NodeWrapper,
+  RangeLocation,
+  UseItemModifier,
+};
+use bstr::{ BStr, BString, ByteSlice };
+
+/// Class names that refer to the current class scope and are never imported or namespaced
+const SPECIAL_CLASSES: [&str; 3] = ["self", "parent", "static"];
+
+const BUILTIN_TYPES: [&str; 17] = [
+  "array",
+  "bool",
+  "callable",
+  "false",
+  "float",
+  "int",
+  "iterable",
+  "mixed",
+  "never",
+  "null",
+  "object",
+  "parent",
+  "self",
+  "static",
+  "string",
+  "true",
+  "void",
+];
+
+/// Language constructs parsed as calls
+const CONSTRUCTS: [&str; 3] = ["empty", "isset", "unset"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameKind {
+  Class,
+  Function,
+  Constant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedName {
+  pub kind: NameKind,
+  /// Name as written in the source
+  pub original: BString,
+  /// Fully-qualified name, without the leading backslash
+  pub name: BString,
+  /// Global name PHP falls back to at runtime when `name` does not exist, only set for
+  /// unqualified function and constant references inside a namespace
+  pub fallback: Option<BString>,
+  /// Whether this is the name of a declared class, function or constant
+  pub is_declaration: bool,
+  pub loc: Option<RangeLocation>,
+}
+
+/// Resolved names of a tree, looked up by the `Identifier`, `Type` or `AttributeItem` node
+/// carrying them. Borrows the tree so it cannot change while the resolution is in use.
+#[derive(Debug)]
+pub struct NameResolution<'b> {
+  names: Vec<ResolvedName>,
+  nodes: HashMap<usize, usize>,
+  tree: PhantomData<&'b ()>,
+}
+
+impl<'b> NameResolution<'b> {
+  pub fn get(&self, node: &Node) -> Option<&ResolvedName> {
+    self.nodes.get(&address(node)).map(|index| &self.names[*index])
+  }
+
+  /// Resolved names in source order
+  pub fn iter(&self) -> std::slice::Iter<'_, ResolvedName> {
+    self.names.iter()
+  }
+
+  pub fn len(&self) -> usize {
+    self.names.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.names.is_empty()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+  Reference(NameKind),
+  Declaration(NameKind),
+  /// Names of variables, members, parameters, labels, ... that are not resolved
+  Skip,
+}
+
+#[derive(Debug, Default)]
+struct Imports {
+  classes: HashMap<String, BString>,
+  functions: HashMap<String, BString>,
+  constants: HashMap<BString, BString>,
+}
+
+pub(crate) struct NameResolver {
+  namespace: BString,
+  imports: Imports,
+  roles: HashMap<usize, Role>,
+  names: Vec<ResolvedName>,
+  nodes: HashMap<usize, usize>,
+}
+
+fn address(node: &Node) -> usize {
+  node as *const Node as usize
+}
+
+impl NameResolver {
+  pub(crate) fn resolve<'b>(node: &'b Node) -> NameResolution<'b> {
+    let mut resolver = NameResolver {
+      namespace: BString::default(),
+      imports: Imports::default(),
+      roles: HashMap::new(),
+      names: vec![],
+      nodes: HashMap::new(),
+    };
+    node.visit(&mut resolver);
+    NameResolution { names: resolver.names, nodes: resolver.nodes, tree: PhantomData }
+  }
+
+  fn set_role(&mut self, node: &Node, role: Role) {
+    self.roles.insert(address(node), role);
+  }
+
+  fn set_roles<'n, 'a: 'n, I>(&mut self, nodes: I, role: Role)
+    where I: IntoIterator<Item = &'n Node<'a>>
+  {
+    nodes.into_iter().for_each(|node| self.set_role(node, role));
+  }
+
+  fn push(&mut self, node: &Node, kind: NameKind, original: &BStr, is_declaration: bool) {
+    let (name, fallback) = if is_declaration {
+      (self.prefixed(original.as_bytes()), None)
+    } else {
+      match self.resolve_name(kind, original.as_bytes()) {
+        Some(resolved) => resolved,
+        None => {
+          return;
+        }
+      }
+    };
+    self.nodes.insert(address(node), self.names.len());
+    self.names.push(ResolvedName {
+      kind,
+      original: original.into(),
+      name,
+      fallback,
+      is_declaration,
+      loc: node.loc.clone(),
+    });
+  }
+
+  fn prefixed(&self, name: &[u8]) -> BString {
+    if self.namespace.is_empty() {
+      BString::from(name)
+    } else {
+      let mut prefixed = self.namespace.clone();
+      prefixed.push(b'\\');
+      prefixed.extend_from_slice(name);
+      prefixed
+    }
+  }
+
+  /// Fully-qualified name and global fallback of a reference, following PHP's rules
+  fn resolve_name(&self, kind: NameKind, name: &[u8]) -> Option<(BString, Option<BString>)> {
+    if let Some(name) = name.strip_prefix(b"\\") {
+      return Some((BString::from(name), None));
+    }
+    if name.len() > 10 && name[..10].eq_ignore_ascii_case(b"namespace\\") {
+      return Some((self.prefixed(&name[10..]), None));
+    }
+    if let Some(separator) = name.find_byte(b'\\') {
+      // Qualified names always go through class imports, whatever they refer to
+      let first = name[..separator].to_str_lossy().to_lowercase();
+      return Some(match self.imports.classes.get(&first) {
+        Some(import) => {
+          let mut resolved = import.clone();
+          resolved.extend_from_slice(&name[separator..]);
+          (resolved, None)
+        }
+        None => (self.prefixed(name), None),
+      });
+    }
+    let lowercase = name.to_str_lossy().to_lowercase();
+    match kind {
+      NameKind::Class => {
+        if SPECIAL_CLASSES.contains(&lowercase.as_str()) {
+          return None;
+        }
+        Some(match self.imports.classes.get(&lowercase) {
+          Some(import) => (import.clone(), None),
+          None => (self.prefixed(name), None),
+        })
+      }
+      NameKind::Function | NameKind::Constant => {
+        let import = if kind == NameKind::Function {
+          self.imports.functions.get(&lowercase)
+        } else {
+          self.imports.constants.get(name.as_bstr())
+        };
+        Some(match import {
+          Some(import) => (import.clone(), None),
+          None if self.namespace.is_empty() => (BString::from(name), None),
+          None => (self.prefixed(name), Some(BString::from(name))),
+        })
+      }
+    }
+  }
+
+  fn import(&mut self, prefix: Option<&BStr>, item: &Node) {
+    let NodeWrapper::UseItem(item) = &item.wrapper else {
+      return;
+    };
+    let mut name = prefix.map(BString::from).unwrap_or_default();
+    name.extend_from_slice(item.name);
+    let name = match name.strip_prefix(b"\\") {
+      Some(name) => BString::from(name),
+      None => name,
+    };
+    let alias = match item.alias.as_ref().map(|alias| &alias.wrapper) {
+      Some(NodeWrapper::Identifier(alias)) => alias.name.into(),
+      _ =>
+        match name.rfind_byte(b'\\') {
+          Some(separator) => BString::from(&name[separator + 1..]),
+          None => name.clone(),
+        }
+    };
+    match item.modifier {
+      Some(UseItemModifier::Function) => {
+        self.imports.functions.insert(alias.to_str_lossy().to_lowercase(), name);
+      }
+      Some(UseItemModifier::Const) => {
+        self.imports.constants.insert(alias, name);
+      }
+      None => {
+        self.imports.classes.insert(alias.to_str_lossy().to_lowercase(), name);
+      }
+    }
+  }
+
+  /// Name of an item of `const` declarations, class constants or enum cases
+  fn item_name<'a>(item: &'a Node<'a>) -> &'a Node<'a> {
+    match &item.wrapper {
+      NodeWrapper::Assignment(assignment) => &assignment.left,
+      _ => item,
+    }
+  }
+
+  fn resolve_type(&mut self, node: &Node) {
+    if let NodeWrapper::Type(t) = &node.wrapper {
+      if !BUILTIN_TYPES.contains(&t.name.to_str_lossy().to_lowercase().as_str()) {
+        self.push(node, NameKind::Class, t.name, false);
+      }
+    }
+  }
+}
+
+impl<'arena> Visitor<'arena> for NameResolver {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    // Attributes of nodes without an attributes field, e.g. property hooks, are leadings
+    for leading in node.leadings.iter().flatten() {
+      if leading.node_type == NodeType::Attribute {
+        leading.visit(self);
+      }
+    }
+  }
+
+  fn enter_namespace(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Namespace(namespace) = &node.wrapper {
+      self.namespace = namespace.name.into();
+      self.imports = Imports::default();
+    }
+  }
+
+  fn leave_namespace(&mut self, _: &Node<'arena>) {
+    self.namespace = BString::default();
+    self.imports = Imports::default();
+  }
+
+  fn enter_use(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Use(node) = &node.wrapper {
+      for item in node.items.iter() {
+        self.import(node.name, item);
+      }
+    }
+  }
+
+  fn enter_use_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::UseItem(item) = &node.wrapper {
+      self.set_roles(item.alias.as_deref(), Role::Skip);
+    }
+  }
+
+  fn enter_identifier(&mut self, node: &Node<'arena>) {
+    let role = self.roles.remove(&address(node)).unwrap_or(Role::Reference(NameKind::Constant));
+    if let NodeWrapper::Identifier(identifier) = &node.wrapper {
+      match role {
+        Role::Reference(kind) => self.push(node, kind, identifier.name, false),
+        Role::Declaration(kind) => self.push(node, kind, identifier.name, true),
+        Role::Skip => {}
+      }
+    }
+  }
+
+  fn enter_type(&mut self, node: &Node<'arena>) {
+    self.resolve_type(node);
+  }
+
+  fn enter_attribute_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::AttributeItem(item) = &node.wrapper {
+      self.push(node, NameKind::Class, item.name, false);
+    }
+  }
+
+  fn enter_call(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Call(call) = &node.wrapper {
+      if self.roles.remove(&address(node)) == Some(Role::Reference(NameKind::Class)) {
+        self.set_role(&call.name, Role::Reference(NameKind::Class));
+      } else if let NodeWrapper::Identifier(name) = &call.name.wrapper {
+        let role = if CONSTRUCTS.contains(&name.name.to_str_lossy().to_lowercase().as_str()) {
+          Role::Skip
+        } else {
+          Role::Reference(NameKind::Function)
+        };
+        self.set_role(&call.name, role);
+      }
+    }
+  }
+
+  fn enter_new(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::New(new) = &node.wrapper {
+      self.set_role(&new.statement, Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_static_lookup(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::StaticLookup(lookup) = &node.wrapper {
+      self.set_role(&lookup.left, Role::Reference(NameKind::Class));
+      self.set_role(&lookup.right, Role::Skip);
+    }
+  }
+
+  fn enter_bin(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Bin(bin) = &node.wrapper {
+      if bin.operator == BinaryType::InstanceOf {
+        self.set_role(&bin.right, Role::Reference(NameKind::Class));
+      }
+    }
+  }
+
+  fn enter_class(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Class(class) = &node.wrapper {
+      self.set_roles(class.name.as_deref(), Role::Declaration(NameKind::Class));
+      self.set_roles(class.extends.as_deref(), Role::Reference(NameKind::Class));
+      self.set_roles(class.implements.iter(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_anonymous_class(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::AnonymousClass(class) = &node.wrapper {
+      self.set_roles(class.extends.as_deref(), Role::Reference(NameKind::Class));
+      self.set_roles(class.implements.iter(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_interface(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Interface(interface) = &node.wrapper {
+      self.set_role(&interface.name, Role::Declaration(NameKind::Class));
+      self.set_roles(interface.extends.iter(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_trait(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Trait(node) = &node.wrapper {
+      self.set_role(&node.name, Role::Declaration(NameKind::Class));
+    }
+  }
+
+  fn enter_enum(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Enum(node) = &node.wrapper {
+      self.set_role(&node.name, Role::Declaration(NameKind::Class));
+      self.set_roles(node.implements.as_deref(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_enum_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::EnumItem(item) = &node.wrapper {
+      self.set_role(Self::item_name(&item.value), Role::Skip);
+    }
+  }
+
+  fn enter_const(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Const(node) = &node.wrapper {
+      for item in node.items.iter() {
+        self.set_role(Self::item_name(item), Role::Declaration(NameKind::Constant));
+      }
+    }
+  }
+
+  fn enter_const_property(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ConstProperty(node) = &node.wrapper {
+      for item in node.items.iter() {
+        self.set_role(Self::item_name(item), Role::Skip);
+      }
+    }
+  }
+
+  fn enter_method(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Method(method) = &node.wrapper {
+      self.set_role(&method.function, Role::Skip);
+    }
+  }
+
+  fn enter_function(&mut self, node: &Node<'arena>) {
+    let is_method = self.roles.remove(&address(node)) == Some(Role::Skip);
+    if let NodeWrapper::Function(function) = &node.wrapper {
+      let role = if is_method { Role::Skip } else { Role::Declaration(NameKind::Function) };
+      self.set_role(&function.name, role);
+    }
+  }
+
+  fn enter_catch(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Catch(catch) = &node.wrapper {
+      self.set_roles(catch.types.iter(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_trait_use(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::TraitUse(node) = &node.wrapper {
+      self.set_roles(node.traits.iter(), Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_trait_use_alias(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::TraitUseAlias(node) = &node.wrapper {
+      self.set_roles(node.trait_name.as_deref(), Role::Reference(NameKind::Class));
+      self.set_role(&node.method, Role::Skip);
+      self.set_roles(node.alias.as_deref(), Role::Skip);
+    }
+  }
+
+  fn enter_trait_use_precedence(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::TraitUsePrecedence(node) = &node.wrapper {
+      self.set_roles(node.trait_name.as_deref(), Role::Reference(NameKind::Class));
+      self.set_role(&node.method, Role::Skip);
+      self.set_role(&node.instead, Role::Reference(NameKind::Class));
+    }
+  }
+
+  fn enter_variable(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Variable(variable) = &node.wrapper {
+      self.set_role(&variable.name, Role::Skip);
+    }
+  }
+
+  fn enter_parameter(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Parameter(parameter) = &node.wrapper {
+      self.set_role(&parameter.name, Role::Skip);
+    }
+  }
+
+  fn enter_property_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::PropertyItem(item) = &node.wrapper {
+      self.set_role(&item.name, Role::Skip);
+    }
+  }
+
+  fn enter_object_access(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ObjectAccess(access) = &node.wrapper {
+      self.set_role(&access.property, Role::Skip);
+    }
+  }
+
+  fn enter_argument(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Argument(argument) = &node.wrapper {
+      self.set_roles(argument.name.as_deref(), Role::Skip);
+    }
+  }
+
+  fn enter_label(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Label(label) = &node.wrapper {
+      self.set_role(&label.label, Role::Skip);
+    }
+  }
+
+  fn enter_goto(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Goto(goto) = &node.wrapper {
+      self.set_role(&goto.label, Role::Skip);
+    }
+  }
+
+  fn enter_declare_argument(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::DeclareArgument(argument) = &node.wrapper {
+      self.set_role(&argument.name, Role::Skip);
+    }
+  }
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use backyard_nodes::{
+  visitor::Visitor,
+  Inheritance,
+  Modifier,
+  Node,
+  NodeWrapper,
+  RangeLocation,
+  Visibility,
+};
+use bstr::BString;
+
+use crate::resolver::{ NameKind, NameResolution, NameResolver };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+  Class,
+  Interface,
+  Trait,
+  Enum,
+  Function,
+  Constant,
+}
+
+impl SymbolKind {
+  /// Namespace the symbol is declared in, classes, interfaces, traits and enums share one
+  pub fn name_kind(&self) -> NameKind {
+    match self {
+      SymbolKind::Function => NameKind::Function,
+      SymbolKind::Constant => NameKind::Constant,
+      _ => NameKind::Class,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberKind {
+  Method,
+  Property,
+  Constant,
+  EnumCase,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+  pub kind: MemberKind,
+  /// Property names are stored without the leading `$`
+  pub name: BString,
+  pub loc: Option<RangeLocation>,
+  pub visibilities: Vec<Visibility>,
+  pub inheritance: Option<Inheritance>,
+  pub modifiers: Vec<Modifier>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+  pub kind: SymbolKind,
+  /// Fully-qualified name, without the leading backslash
+  pub name: BString,
+  pub file: Option<String>,
+  pub loc: Option<RangeLocation>,
+  pub inheritance: Option<Inheritance>,
+  pub modifiers: Vec<Modifier>,
+  /// Parent class, or parent interfaces of an interface
+  pub extends: Vec<BString>,
+  pub implements: Vec<BString>,
+  pub traits: Vec<BString>,
+  pub members: Vec<Member>,
+}
+
+impl Symbol {
+  pub fn member(&self, kind: MemberKind, name: &str) -> Option<&Member> {
+    self.members.iter().find(|member| {
+      member.kind == kind &&
+        (if kind == MemberKind::Method {
+          member.name.eq_ignore_ascii_case(name.as_bytes())
+        } else {
+          member.name == name
+        })
+    })
+  }
+}
+
+/// Declarations of one or many files, looked up by fully-qualified name
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+  symbols: Vec<Symbol>,
+  index: HashMap<(NameKind, BString), usize>,
+}
+
+impl SymbolTable {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn collect(node: &Node) -> Self {
+    Self::collect_symbols(None, node)
+  }
+
+  /// Collect declarations of `node`, recording `file` as their origin
+  pub fn collect_file(file: &str, node: &Node) -> Self {
+    Self::collect_symbols(Some(file.to_string()), node)
+  }
+
+  fn collect_symbols(file: Option<String>, node: &Node) -> Self {
+    let names = NameResolver::resolve(node);
+    let mut collector = SymbolCollector { names: &names, file, symbols: vec![] };
+    node.visit(&mut collector);
+    let mut table = Self::new();
+    collector.symbols.into_iter().for_each(|symbol| table.push(symbol));
+    table
+  }
+
+  /// Add the declarations of `other`, a name declared in both keeps resolving to the first
+  pub fn merge(&mut self, other: SymbolTable) {
+    other.symbols.into_iter().for_each(|symbol| self.push(symbol));
+  }
+
+  pub fn push(&mut self, symbol: Symbol) {
+    let key = Self::key(symbol.kind.name_kind(), &symbol.name);
+    self.index.entry(key).or_insert(self.symbols.len());
+    self.symbols.push(symbol);
+  }
+
+  /// Class and function names are case-insensitive, constant names are not
+  fn key(kind: NameKind, name: &[u8]) -> (NameKind, BString) {
+    let name = name.strip_prefix(b"\\").unwrap_or(name);
+    match kind {
+      NameKind::Constant => (kind, BString::from(name)),
+      _ => (kind, BString::from(name.to_ascii_lowercase())),
+    }
+  }
+
+  pub fn get(&self, kind: NameKind, name: &str) -> Option<&Symbol> {
+    self.index.get(&Self::key(kind, name.as_bytes())).map(|index| &self.symbols[*index])
+  }
+
+  pub fn iter(&self) -> std::slice::Iter<'_, Symbol> {
+    self.symbols.iter()
+  }
+
+  pub fn len(&self) -> usize {
+    self.symbols.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.symbols.is_empty()
+  }
+}
+
+struct SymbolCollector<'r, 'b> {
+  names: &'r NameResolution<'b>,
+  file: Option<String>,
+  symbols: Vec<Symbol>,
+}
+
+impl<'r, 'b> SymbolCollector<'r, 'b> {
+  fn name(&self, node: &Node) -> BString {
+    match self.names.get(node) {
+      Some(name) => name.name.clone(),
+      None => Self::raw_name(node),
+    }
+  }
+
+  fn raw_name(node: &Node) -> BString {
+    match &node.wrapper {
+      NodeWrapper::Identifier(identifier) => identifier.name.into(),
+      NodeWrapper::MagicMethod(method) => BString::from(method.name.to_string()),
+      NodeWrapper::Variable(variable) => Self::raw_name(&variable.name),
+      _ => BString::default(),
+    }
+  }
+
+  fn names<'a, I>(&self, nodes: I) -> Vec<BString> where I: IntoIterator<Item = &'a Node<'a>> {
+    nodes
+      .into_iter()
+      .map(|node| self.name(node))
+      .collect()
+  }
+
+  fn is_declaration(&self, node: &Node) -> bool {
+    self.names.get(node).is_some_and(|name| name.is_declaration)
+  }
+
+  fn symbol(&self, kind: SymbolKind, name: &Node, node: &Node) -> Symbol {
+    Symbol {
+      kind,
+      name: self.name(name),
+      file: self.file.clone(),
+      loc: node.loc.clone(),
+      inheritance: None,
+      modifiers: vec![],
+      extends: vec![],
+      implements: vec![],
+      traits: vec![],
+      members: vec![],
+    }
+  }
+
+  fn members(&self, symbol: &mut Symbol, statements: &[Node]) {
+    for statement in statements {
+      match &statement.wrapper {
+        NodeWrapper::Method(method) => {
+          let NodeWrapper::Function(function) = &method.function.wrapper else {
+            continue;
+          };
+          let mut modifiers = vec![];
+          if method.is_static {
+            modifiers.push(Modifier::Static);
+          }
+          symbol.members.push(Member {
+            kind: MemberKind::Method,
+            name: Self::raw_name(&function.name),
+            loc: statement.loc.clone(),
+            visibilities: method.visibility.iter().cloned().collect(),
+            inheritance: method.inheritance.clone(),
+            modifiers,
+          });
+          // Promoted constructor parameters are properties too
+          for parameter in function.parameters.iter() {
+            if let NodeWrapper::ConstructorParameter(promoted) = &parameter.wrapper {
+              if let NodeWrapper::Parameter(parameter) = &promoted.parameter.wrapper {
+                symbol.members.push(Member {
+                  kind: MemberKind::Property,
+                  name: Self::raw_name(&parameter.name),
+                  loc: promoted.parameter.loc.clone(),
+                  visibilities: promoted.visibilities.clone(),
+                  inheritance: None,
+                  modifiers: promoted.modifier.iter().cloned().collect(),
+                });
+              }
+            }
+          }
+        }
+        NodeWrapper::Property(property) => {
+          for item in property.items.iter() {
+            if let NodeWrapper::PropertyItem(item) = &item.wrapper {
+              symbol.members.push(Member {
+                kind: MemberKind::Property,
+                name: Self::raw_name(&item.name),
+                loc: statement.loc.clone(),
+                visibilities: property.visibilities.clone(),
+                inheritance: None,
+                modifiers: property.modifier.iter().cloned().collect(),
+              });
+            }
+          }
+        }
+        NodeWrapper::ConstProperty(constant) => {
+          for item in constant.items.iter() {
+            symbol.members.push(Member {
+              kind: MemberKind::Constant,
+              name: Self::raw_name(Self::item_name(item)),
+              loc: statement.loc.clone(),
+              visibilities: constant.visibilities.clone(),
+              inheritance: None,
+              modifiers: vec![],
+            });
+          }
+        }
+        NodeWrapper::EnumItem(item) => {
+          symbol.members.push(Member {
+            kind: MemberKind::EnumCase,
+            name: Self::raw_name(Self::item_name(&item.value)),
+            loc: statement.loc.clone(),
+            visibilities: vec![],
+            inheritance: None,
+            modifiers: vec![],
+          });
+        }
+        NodeWrapper::TraitUse(trait_use) => {
+          symbol.traits.extend(self.names(trait_use.traits.iter()));
+        }
+        _ => {}
+      }
+    }
+  }
+
+  fn item_name<'a>(item: &'a Node<'a>) -> &'a Node<'a> {
+    match &item.wrapper {
+      NodeWrapper::Assignment(assignment) => &assignment.left,
+      _ => item,
+    }
+  }
+
+  fn statements<'a>(body: &'a Node<'a>) -> &'a [Node<'a>] {
+    match &body.wrapper {
+      NodeWrapper::Block(block) => &block.statements,
+      _ => &[],
+    }
+  }
+}
+
+impl<'r, 'b, 'arena> Visitor<'arena> for SymbolCollector<'r, 'b> {
+  fn enter_class(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Class(class) = &node.wrapper else {
+      return;
+    };
+    let Some(name) = class.name.as_deref().filter(|name| self.is_declaration(name)) else {
+      return;
+    };
+    let mut symbol = self.symbol(SymbolKind::Class, name, node);
+    symbol.inheritance = class.inheritance.clone();
+    if class.is_readonly {
+      symbol.modifiers.push(Modifier::Readonly);
+    }
+    symbol.extends = self.names(class.extends.as_deref());
+    symbol.implements = self.names(class.implements.iter());
+    self.members(&mut symbol, Self::statements(&class.body));
+    self.symbols.push(symbol);
+  }
+
+  fn enter_interface(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Interface(interface) = &node.wrapper else {
+      return;
+    };
+    let mut symbol = self.symbol(SymbolKind::Interface, &interface.name, node);
+    symbol.extends = self.names(interface.extends.iter());
+    self.members(&mut symbol, Self::statements(&interface.body));
+    self.symbols.push(symbol);
+  }
+
+  fn enter_trait(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Trait(declaration) = &node.wrapper else {
+      return;
+    };
+    let mut symbol = self.symbol(SymbolKind::Trait, &declaration.name, node);
+    self.members(&mut symbol, Self::statements(&declaration.body));
+    self.symbols.push(symbol);
+  }
+
+  fn enter_enum(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Enum(declaration) = &node.wrapper else {
+      return;
+    };
+    let mut symbol = self.symbol(SymbolKind::Enum, &declaration.name, node);
+    symbol.implements = self.names(declaration.implements.as_deref());
+    self.members(&mut symbol, &declaration.body);
+    self.symbols.push(symbol);
+  }
+
+  fn enter_function(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Function(function) = &node.wrapper {
+      if self.is_declaration(&function.name) {
+        let symbol = self.symbol(SymbolKind::Function, &function.name, node);
+        self.symbols.push(symbol);
+      }
+    }
+  }
+
+  fn enter_const(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Const(constant) = &node.wrapper {
+      for item in constant.items.iter() {
+        let symbol = self.symbol(SymbolKind::Constant, Self::item_name(item), node);
+        self.symbols.push(symbol);
+      }
+    }
+  }
+}
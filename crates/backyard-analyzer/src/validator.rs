@@ -0,0 +1,735 @@
+use std::{ collections::HashMap, fmt::{ self, Display, Formatter } };
+
+use backyard_nodes::{
+  number::NumberValue,
+  visitor::Visitor,
+  ConstructorParameterNode,
+  Inheritance,
+  Modifier,
+  Node,
+  NodeType,
+  NodeWrapper,
+  PreType,
+  RangeLocation,
+};
+use bstr::{ BString, ByteSlice };
+
+use crate::resolver::{ NameResolution, NameResolver };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+  /// `break` or `continue` outside a loop, or with an invalid number of levels
+  InvalidJump,
+  /// `$this` used in a static method or re-assigned
+  InvalidThis,
+  DuplicateParameter,
+  /// Variadic parameter followed by another parameter
+  VariadicParameter,
+  /// Method declared with a body where none is allowed, or without one where it is required
+  InvalidMethodBody,
+  /// Missing or unexpected `return` value for the declared return type
+  InvalidReturn,
+  InvalidReadonlyProperty,
+  InvalidEnumCase,
+  DuplicateMember,
+  /// Variable read before any assignment, see [`crate::variables`]
+  UndefinedVariable,
+  /// Variable assigned but never read, see [`crate::variables`]
+  UnusedAssignment,
+}
+
+/// Problem found in the code, fatal errors use the message PHP reports when compiling it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+  pub kind: DiagnosticKind,
+  pub message: String,
+  pub loc: Option<RangeLocation>,
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.message)?;
+    if let Some(loc) = &self.loc {
+      write!(f, " at line {}, column {}", loc.start.line, loc.start.column)?;
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReturnType {
+  None,
+  Void,
+  Never,
+  Value {
+    is_nullable: bool,
+  },
+}
+
+impl ReturnType {
+  fn of(node: Option<&Node>) -> Self {
+    let Some(node) = node else {
+      return ReturnType::None;
+    };
+    match &node.wrapper {
+      NodeWrapper::Type(t) if t.name.eq_ignore_ascii_case(b"void") => ReturnType::Void,
+      NodeWrapper::Type(t) if t.name.eq_ignore_ascii_case(b"never") => ReturnType::Never,
+      NodeWrapper::Type(t) =>
+        ReturnType::Value {
+          is_nullable: t.is_nullable ||
+          Self::is_null(node) ||
+          t.name.eq_ignore_ascii_case(b"mixed"),
+        },
+      NodeWrapper::UnionType(union) =>
+        ReturnType::Value { is_nullable: union.types.iter().any(Self::is_null) },
+      _ => ReturnType::Value { is_nullable: false },
+    }
+  }
+
+  fn is_null(node: &Node) -> bool {
+    matches!(&node.wrapper, NodeWrapper::Type(t) if t.name.eq_ignore_ascii_case(b"null"))
+  }
+}
+
+/// Body of a function, method, closure or property hook, the root frame is the file itself
+struct Frame {
+  is_static: bool,
+  return_type: ReturnType,
+  /// Loops and switches enclosing the current statement
+  loops: usize,
+  has_yield: bool,
+  /// `return;` statements, only invalid when the function is not a generator
+  empty_returns: Vec<Option<RangeLocation>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ClassKind {
+  Class,
+  Interface,
+  Trait,
+  Enum,
+}
+
+struct Scope {
+  kind: ClassKind,
+  name: BString,
+  is_abstract: bool,
+  is_readonly: bool,
+}
+
+pub(crate) struct Validator<'r, 'b> {
+  names: &'r NameResolution<'b>,
+  frames: Vec<Frame>,
+  scopes: Vec<Scope>,
+  /// Function nodes of static methods
+  static_functions: Vec<usize>,
+  diagnostics: Vec<Diagnostic>,
+}
+
+fn address(node: &Node) -> usize {
+  node as *const Node as usize
+}
+
+impl<'r, 'b> Validator<'r, 'b> {
+  pub(crate) fn validate(node: &Node) -> Vec<Diagnostic> {
+    let names = NameResolver::resolve(node);
+    let mut validator = Validator {
+      names: &names,
+      frames: vec![Frame {
+        is_static: false,
+        return_type: ReturnType::None,
+        loops: 0,
+        has_yield: false,
+        empty_returns: vec![],
+      }],
+      scopes: vec![],
+      static_functions: vec![],
+      diagnostics: vec![],
+    };
+    node.visit(&mut validator);
+    let mut diagnostics = validator.diagnostics;
+    diagnostics.sort_by_key(|x| x.loc.as_ref().map_or(0, |loc| loc.start.offset));
+    diagnostics
+  }
+
+  fn report(&mut self, kind: DiagnosticKind, message: String, node: &Node) {
+    self.diagnostics.push(Diagnostic { kind, message, loc: node.loc.clone() });
+  }
+
+  fn frame(&mut self) -> &mut Frame {
+    self.frames.last_mut().expect("root frame is never removed")
+  }
+
+  fn scope_name(&self) -> BString {
+    self.scopes.last().map_or_else(BString::default, |scope| scope.name.clone())
+  }
+
+  fn name(&self, node: &Node) -> BString {
+    match self.names.get(node) {
+      Some(name) => name.name.clone(),
+      None => Self::raw_name(node),
+    }
+  }
+
+  fn raw_name(node: &Node) -> BString {
+    match &node.wrapper {
+      NodeWrapper::Identifier(identifier) => identifier.name.into(),
+      NodeWrapper::MagicMethod(method) => BString::from(method.name.to_string()),
+      NodeWrapper::Variable(variable) => Self::raw_name(&variable.name),
+      _ => BString::default(),
+    }
+  }
+
+  fn enter_frame(&mut self, return_type: Option<&Node>, parameters: &[Node], is_static: bool) {
+    let mut seen: Vec<BString> = vec![];
+    for (index, parameter) in parameters.iter().enumerate() {
+      let parameter = match &parameter.wrapper {
+        NodeWrapper::ConstructorParameter(promoted) => &promoted.parameter,
+        _ => parameter,
+      };
+      let NodeWrapper::Parameter(declared) = &parameter.wrapper else {
+        continue;
+      };
+      let name = Self::raw_name(&declared.name);
+      if seen.contains(&name) {
+        self.report(
+          DiagnosticKind::DuplicateParameter,
+          format!("Redefinition of parameter ${}", name),
+          parameter
+        );
+      } else {
+        seen.push(name);
+      }
+      if declared.is_ellipsis && index + 1 < parameters.len() {
+        self.report(
+          DiagnosticKind::VariadicParameter,
+          "Only the last parameter can be variadic".to_string(),
+          parameter
+        );
+      }
+    }
+    self.frames.push(Frame {
+      is_static,
+      return_type: ReturnType::of(return_type),
+      loops: 0,
+      has_yield: false,
+      empty_returns: vec![],
+    });
+  }
+
+  fn leave_frame(&mut self) {
+    let Some(frame) = self.frames.pop() else {
+      return;
+    };
+    if frame.has_yield {
+      return;
+    }
+    if let ReturnType::Value { is_nullable } = frame.return_type {
+      let hint = if is_nullable {
+        " (did you mean \"return null;\" instead of \"return;\"?)"
+      } else {
+        ""
+      };
+      for loc in frame.empty_returns {
+        self.diagnostics.push(Diagnostic {
+          kind: DiagnosticKind::InvalidReturn,
+          message: format!("A function with return type must return a value{}", hint),
+          loc,
+        });
+      }
+    }
+  }
+
+  fn enter_scope(&mut self, kind: ClassKind, name: Option<&Node>, body: &[Node]) {
+    let name = name.map_or_else(|| BString::from("class@anonymous"), |name| self.name(name));
+    self.scopes.push(Scope { kind, name, is_abstract: false, is_readonly: false });
+    self.members(body);
+  }
+
+  /// Methods, properties and constants declared twice in the same class
+  fn members(&mut self, statements: &[Node]) {
+    let mut methods: Vec<String> = vec![];
+    let mut properties: Vec<BString> = vec![];
+    let mut constants: Vec<BString> = vec![];
+    for statement in statements {
+      match &statement.wrapper {
+        NodeWrapper::Method(method) => {
+          let NodeWrapper::Function(function) = &method.function.wrapper else {
+            continue;
+          };
+          let name = Self::raw_name(&function.name);
+          let key = name.to_str_lossy().to_lowercase();
+          if methods.contains(&key) {
+            let message = format!("Cannot redeclare {}::{}()", self.scope_name(), name);
+            self.report(DiagnosticKind::DuplicateMember, message, statement);
+          } else {
+            methods.push(key);
+          }
+          for parameter in function.parameters.iter() {
+            let NodeWrapper::ConstructorParameter(promoted) = &parameter.wrapper else {
+              continue;
+            };
+            if let NodeWrapper::Parameter(parameter) = &promoted.parameter.wrapper {
+              if Self::is_promoted(promoted) {
+                properties.push(Self::raw_name(&parameter.name));
+              }
+            }
+          }
+        }
+        NodeWrapper::Property(property) => {
+          for item in property.items.iter() {
+            let NodeWrapper::PropertyItem(declared) = &item.wrapper else {
+              continue;
+            };
+            let name = Self::raw_name(&declared.name);
+            if properties.contains(&name) {
+              let message = format!("Cannot redeclare {}::${}", self.scope_name(), name);
+              self.report(DiagnosticKind::DuplicateMember, message, item);
+            } else {
+              properties.push(name);
+            }
+          }
+        }
+        NodeWrapper::ConstProperty(property) => {
+          for item in property.items.iter() {
+            let name = match &item.wrapper {
+              NodeWrapper::Assignment(assignment) => Self::raw_name(&assignment.left),
+              NodeWrapper::Identifier(_) => Self::raw_name(item),
+              _ => {
+                continue;
+              }
+            };
+            if constants.contains(&name) {
+              let message = format!(
+                "Cannot redefine class constant {}::{}",
+                self.scope_name(),
+                name
+              );
+              self.report(DiagnosticKind::DuplicateMember, message, item);
+            } else {
+              constants.push(name);
+            }
+          }
+        }
+        _ => {}
+      }
+    }
+  }
+
+  /// Every parameter of a constructor is parsed as a constructor parameter, only the ones
+  /// with a visibility or modifier declare a property
+  fn is_promoted(parameter: &ConstructorParameterNode) -> bool {
+    !parameter.visibilities.is_empty() || parameter.modifier.is_some()
+  }
+
+  fn readonly_property(&mut self, name: &Node, variable_type: Option<&Node>, node: &Node) {
+    if variable_type.is_none() {
+      let message = format!(
+        "Readonly property {}::${} must have type",
+        self.scope_name(),
+        Self::raw_name(name)
+      );
+      self.report(DiagnosticKind::InvalidReadonlyProperty, message, node);
+    }
+  }
+
+  fn jump(&mut self, keyword: &str, statement: Option<&Node>, node: &Node) {
+    let depth = match statement.map(|x| &x.wrapper) {
+      None => Some(1),
+      Some(NodeWrapper::Number(number)) =>
+        match number.to_value() {
+          Some(NumberValue::Int(depth)) => Some(depth),
+          _ => None,
+        }
+      Some(_) => None,
+    };
+    let Some(depth) = depth else {
+      let message = format!(
+        "'{}' operator with non-integer operand is no longer supported",
+        keyword
+      );
+      self.report(DiagnosticKind::InvalidJump, message, node);
+      return;
+    };
+    let loops = self.frame().loops as i64;
+    let message = if depth < 1 {
+      format!("'{}' operator accepts only positive integers", keyword)
+    } else if loops == 0 {
+      format!("'{}' not in the 'loop' or 'switch' context", keyword)
+    } else if depth > loops {
+      format!("Cannot '{}' {} level{}", keyword, depth, if depth == 1 { "" } else { "s" })
+    } else {
+      return;
+    };
+    self.report(DiagnosticKind::InvalidJump, message, node);
+  }
+
+  /// Type and value of a backed enum case as a comparable key, `None` for non-literal
+  /// expressions
+  fn case_value(node: &Node) -> Option<(&'static str, BString)> {
+    let number = |number: NumberValue, sign: &str| {
+      let kind = if matches!(number, NumberValue::Int(_)) { "int" } else { "float" };
+      (kind, BString::from(format!("{}{}", sign, number)))
+    };
+    match &node.wrapper {
+      NodeWrapper::Number(x) => x.to_value().map(|x| number(x, "")),
+      NodeWrapper::String(string) => Some(("string", string.to_bytes().into())),
+      NodeWrapper::Encapsed(string) => string.to_bytes().map(|x| ("string", BString::from(x))),
+      NodeWrapper::Pre(pre) if pre.operator == PreType::Subtraction =>
+        match &pre.statement.wrapper {
+          NodeWrapper::Number(x) => x.to_value().map(|x| number(x, "-")),
+          _ => None,
+        }
+      _ => None,
+    }
+  }
+}
+
+impl<'r, 'b, 'arena> Visitor<'arena> for Validator<'r, 'b> {
+  fn enter_class(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Class(class) = &node.wrapper {
+      let body = match &class.body.wrapper {
+        NodeWrapper::Block(block) => &block.statements[..],
+        _ => &[],
+      };
+      self.enter_scope(ClassKind::Class, class.name.as_deref(), body);
+      if let Some(scope) = self.scopes.last_mut() {
+        scope.is_abstract = class.inheritance == Some(Inheritance::Abstract);
+        scope.is_readonly = class.is_readonly;
+      }
+    }
+  }
+
+  fn leave_class(&mut self, _: &Node<'arena>) {
+    self.scopes.pop();
+  }
+
+  fn enter_anonymous_class(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::AnonymousClass(class) = &node.wrapper {
+      let body = match &class.body.wrapper {
+        NodeWrapper::Block(block) => &block.statements[..],
+        _ => &[],
+      };
+      self.enter_scope(ClassKind::Class, None, body);
+    }
+  }
+
+  fn leave_anonymous_class(&mut self, _: &Node<'arena>) {
+    self.scopes.pop();
+  }
+
+  fn enter_interface(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Interface(interface) = &node.wrapper {
+      let body = match &interface.body.wrapper {
+        NodeWrapper::Block(block) => &block.statements[..],
+        _ => &[],
+      };
+      self.enter_scope(ClassKind::Interface, Some(&interface.name), body);
+    }
+  }
+
+  fn leave_interface(&mut self, _: &Node<'arena>) {
+    self.scopes.pop();
+  }
+
+  fn enter_trait(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Trait(declaration) = &node.wrapper {
+      let body = match &declaration.body.wrapper {
+        NodeWrapper::Block(block) => &block.statements[..],
+        _ => &[],
+      };
+      self.enter_scope(ClassKind::Trait, Some(&declaration.name), body);
+    }
+  }
+
+  fn leave_trait(&mut self, _: &Node<'arena>) {
+    self.scopes.pop();
+  }
+
+  fn enter_enum(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Enum(declaration) = &node.wrapper else {
+      return;
+    };
+    self.enter_scope(ClassKind::Enum, Some(&declaration.name), &declaration.body);
+    let name = self.scope_name();
+    let is_backed = declaration.enum_type.is_some();
+    let backing_type = match declaration.enum_type.as_ref().map(|x| &x.wrapper) {
+      Some(NodeWrapper::Type(backing)) => Some(backing.name.to_str_lossy().to_lowercase()),
+      _ => None,
+    };
+    let mut cases: Vec<BString> = vec![];
+    let mut values: HashMap<BString, BString> = HashMap::new();
+    for item in declaration.body.iter() {
+      let NodeWrapper::EnumItem(case) = &item.wrapper else {
+        continue;
+      };
+      let (case_name, value) = match &case.value.wrapper {
+        NodeWrapper::Assignment(assignment) => (&*assignment.left, Some(&*assignment.right)),
+        _ => (&*case.value, None),
+      };
+      let case_name = Self::raw_name(case_name);
+      if cases.contains(&case_name) {
+        let message = format!("Cannot redefine class constant {}::{}", name, case_name);
+        self.report(DiagnosticKind::InvalidEnumCase, message, item);
+        continue;
+      }
+      cases.push(case_name.clone());
+      let Some(value) = value else {
+        if is_backed {
+          let message = format!("Case {} of backed enum {} must have a value", case_name, name);
+          self.report(DiagnosticKind::InvalidEnumCase, message, item);
+        }
+        continue;
+      };
+      if !is_backed {
+        let message = format!(
+          "Case {} of non-backed enum {} must not have a value",
+          case_name,
+          name
+        );
+        self.report(DiagnosticKind::InvalidEnumCase, message, item);
+        continue;
+      }
+      let Some((case_type, key)) = Self::case_value(value) else {
+        continue;
+      };
+      if let Some(backing_type) = backing_type.as_ref().filter(|x| *x != case_type) {
+        let message = format!(
+          "Enum case type {} does not match enum backing type {}",
+          case_type,
+          backing_type
+        );
+        self.report(DiagnosticKind::InvalidEnumCase, message, item);
+        continue;
+      }
+      match values.get(&key) {
+        Some(first) => {
+          let message = format!(
+            "Duplicate value in enum {} for cases {} and {}",
+            name,
+            first,
+            case_name
+          );
+          self.report(DiagnosticKind::InvalidEnumCase, message, item);
+        }
+        None => {
+          values.insert(key, case_name);
+        }
+      }
+    }
+  }
+
+  fn leave_enum(&mut self, _: &Node<'arena>) {
+    self.scopes.pop();
+  }
+
+  fn enter_method(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Method(method) = &node.wrapper else {
+      return;
+    };
+    let NodeWrapper::Function(function) = &method.function.wrapper else {
+      return;
+    };
+    if method.is_static {
+      self.static_functions.push(address(&method.function));
+    }
+    let Some(scope) = self.scopes.last() else {
+      return;
+    };
+    let name = format!("{}::{}()", scope.name, Self::raw_name(&function.name));
+    let is_abstract = method.inheritance == Some(Inheritance::Abstract);
+    let message = match (scope.kind, is_abstract, function.body.is_some()) {
+      (ClassKind::Interface, _, true) => format!("Interface function {} cannot contain body", name),
+      (ClassKind::Interface, _, false) => {
+        return;
+      }
+      (_, true, true) => format!("Abstract function {} cannot contain body", name),
+      (_, false, false) => format!("Non-abstract method {} must contain body", name),
+      (ClassKind::Class, true, false) if !scope.is_abstract =>
+        format!(
+          "Class {} declares abstract method {}() and must therefore be declared abstract",
+          scope.name,
+          Self::raw_name(&function.name)
+        ),
+      _ => {
+        return;
+      }
+    };
+    self.report(DiagnosticKind::InvalidMethodBody, message, node);
+  }
+
+  fn enter_function(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Function(function) = &node.wrapper {
+      let is_static = match self.static_functions.iter().position(|x| *x == address(node)) {
+        Some(index) => {
+          self.static_functions.swap_remove(index);
+          true
+        }
+        None => false,
+      };
+      self.enter_frame(function.return_type.as_deref(), &function.parameters, is_static);
+    }
+  }
+
+  fn leave_function(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_anonymous_function(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::AnonymousFunction(function) = &node.wrapper {
+      // Closures have no `$this` when they are declared in a static method
+      let is_static = self.frame().is_static;
+      self.enter_frame(function.return_type.as_deref(), &function.parameters, is_static);
+    }
+  }
+
+  fn leave_anonymous_function(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_arrow_function(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::ArrowFunction(function) = &node.wrapper else {
+      return;
+    };
+    let is_static = self.frame().is_static;
+    self.enter_frame(function.return_type.as_deref(), &function.parameters, is_static);
+    // The body of an arrow function is always returned
+    let message = match self.frame().return_type {
+      ReturnType::Void => "A void function must not return a value",
+      ReturnType::Never => "A never-returning function must not return",
+      _ => {
+        return;
+      }
+    };
+    self.report(DiagnosticKind::InvalidReturn, message.to_string(), &function.body);
+  }
+
+  fn leave_arrow_function(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_property_hook(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::PropertyHook(hook) = &node.wrapper {
+      self.enter_frame(None, &hook.parameters, false);
+    }
+  }
+
+  fn leave_property_hook(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_return(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Return(statement) = &node.wrapper else {
+      return;
+    };
+    let has_value = statement.statement.is_some();
+    let message = match self.frame().return_type {
+      ReturnType::Void if has_value => "A void function must not return a value",
+      ReturnType::Never => "A never-returning function must not return",
+      ReturnType::Value { .. } if !has_value => {
+        self.frame().empty_returns.push(node.loc.clone());
+        return;
+      }
+      _ => {
+        return;
+      }
+    };
+    self.report(DiagnosticKind::InvalidReturn, message.to_string(), node);
+  }
+
+  fn enter_yield(&mut self, _: &Node<'arena>) {
+    self.frame().has_yield = true;
+  }
+
+  fn enter_yield_from(&mut self, _: &Node<'arena>) {
+    self.frame().has_yield = true;
+  }
+
+  fn enter_break(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Break(statement) = &node.wrapper {
+      self.jump("break", statement.statement.as_deref(), node);
+    }
+  }
+
+  fn enter_continue(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Continue(statement) = &node.wrapper {
+      self.jump("continue", statement.statement.as_deref(), node);
+    }
+  }
+
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    if
+      matches!(
+        node.node_type,
+        NodeType::While | NodeType::DoWhile | NodeType::For | NodeType::Foreach | NodeType::Switch
+      )
+    {
+      self.frame().loops += 1;
+    }
+  }
+
+  fn leave_node(&mut self, node: &Node<'arena>) {
+    if
+      matches!(
+        node.node_type,
+        NodeType::While | NodeType::DoWhile | NodeType::For | NodeType::Foreach | NodeType::Switch
+      )
+    {
+      self.frame().loops -= 1;
+    }
+  }
+
+  fn enter_this(&mut self, node: &Node<'arena>) {
+    if self.frame().is_static {
+      let message = "Using $this when not in object context".to_string();
+      self.report(DiagnosticKind::InvalidThis, message, node);
+    }
+  }
+
+  fn enter_assignment(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Assignment(assignment) = &node.wrapper {
+      if assignment.left.node_type == NodeType::This {
+        let message = "Cannot re-assign $this".to_string();
+        self.report(DiagnosticKind::InvalidThis, message, &assignment.left);
+      }
+    }
+  }
+
+  fn enter_property(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Property(property) = &node.wrapper else {
+      return;
+    };
+    let is_class_readonly = self.scopes.last().is_some_and(|scope| scope.is_readonly);
+    if property.modifier != Some(Modifier::Readonly) && !is_class_readonly {
+      return;
+    }
+    for item in property.items.iter() {
+      let NodeWrapper::PropertyItem(declared) = &item.wrapper else {
+        continue;
+      };
+      self.readonly_property(&declared.name, declared.variable_type.as_deref(), item);
+      if declared.value.is_some() {
+        let message = format!(
+          "Readonly property {}::${} cannot have default value",
+          self.scope_name(),
+          Self::raw_name(&declared.name)
+        );
+        self.report(DiagnosticKind::InvalidReadonlyProperty, message, item);
+      }
+    }
+  }
+
+  fn enter_constructor_parameter(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::ConstructorParameter(promoted) = &node.wrapper else {
+      return;
+    };
+    let is_class_readonly = self.scopes.last().is_some_and(|scope| scope.is_readonly);
+    let is_readonly = promoted.modifier == Some(Modifier::Readonly) || is_class_readonly;
+    if !is_readonly || !Self::is_promoted(promoted) {
+      return;
+    }
+    if let NodeWrapper::Parameter(parameter) = &promoted.parameter.wrapper {
+      self.readonly_property(&parameter.name, parameter.variable_type.as_deref(), node);
+    }
+  }
+}
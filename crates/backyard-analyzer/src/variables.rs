@@ -0,0 +1,806 @@
+//! Variables defined and read by each function, method, closure and arrow function, following
+//! PHP's scoping rules. Analysis is done in source order, a definition anywhere in an enclosing
+//! loop counts for the whole loop. Variables checked by `isset()`, `empty()` or `??` in a
+//! condition count as defined in the code the condition guards.
+
+use std::collections::HashMap;
+
+use backyard_nodes::{
+  visitor::Visitor,
+  AssignmentType,
+  BinaryType,
+  Node,
+  NodeType,
+  NodeWrapper,
+  PostType,
+  PreType,
+  RangeLocation,
+};
+use bstr::{ BStr, BString, ByteSlice };
+
+use crate::validator::{ Diagnostic, DiagnosticKind };
+
+const SUPERGLOBALS: [&str; 9] = [
+  "GLOBALS",
+  "_COOKIE",
+  "_ENV",
+  "_FILES",
+  "_GET",
+  "_POST",
+  "_REQUEST",
+  "_SERVER",
+  "_SESSION",
+];
+
+/// Functions reading or writing variables by name, scopes calling them are not reported
+const DYNAMIC_FUNCTIONS: [&str; 3] = ["compact", "extract", "get_defined_vars"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+  /// Code outside of any function
+  File,
+  Function,
+  Method,
+  Closure,
+  ArrowFunction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinitionKind {
+  Parameter,
+  /// Closure `use`
+  Use,
+  /// Variable of the parent scope read by an arrow function
+  Capture,
+  Global,
+  Static,
+  /// Assignment, including `list()` and array destructuring
+  Assignment,
+  /// Key or value of a `foreach`
+  Foreach,
+  Catch,
+  /// Variable bound by reference, e.g. `$a = &$b` or a closure `use (&$b)`
+  Reference,
+  /// Undefined variable passed to a call, which may take it by reference
+  Argument,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+  /// Name without the leading `$`
+  pub name: BString,
+  pub kind: DefinitionKind,
+  pub is_reference: bool,
+  pub loc: Option<RangeLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableRead {
+  pub name: BString,
+  pub loc: Option<RangeLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableScope {
+  pub kind: ScopeKind,
+  /// Name of functions and methods
+  pub name: Option<BString>,
+  pub loc: Option<RangeLocation>,
+  /// Definitions in source order
+  pub definitions: Vec<Definition>,
+  /// Reads in source order
+  pub reads: Vec<VariableRead>,
+  /// Whether the scope accesses variables by name, e.g. `${$name}` or `compact()`
+  pub is_dynamic: bool,
+}
+
+impl VariableScope {
+  /// Name of every defined variable, once each
+  pub fn variables(&self) -> Vec<&BStr> {
+    let mut names: Vec<&BStr> = vec![];
+    for definition in self.definitions.iter() {
+      if !names.contains(&definition.name.as_bstr()) {
+        names.push(definition.name.as_bstr());
+      }
+    }
+    names
+  }
+
+  pub fn defines(&self, name: &str) -> bool {
+    self.definitions.iter().any(|x| x.name == name)
+  }
+
+  pub fn reads(&self, name: &str) -> bool {
+    self.reads.iter().any(|x| x.name == name)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableAnalysis {
+  /// Scopes ordered by their start, the file scope first
+  pub scopes: Vec<VariableScope>,
+  /// Undefined variable reads and unused assignments, in source order
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+  Define {
+    kind: DefinitionKind,
+    /// Offset from which the variable is defined
+    position: usize,
+    is_reference: bool,
+  },
+  /// Read then written, e.g. `$a++` or `$a .= 'b'`
+  Update {
+    position: usize,
+    warns: bool,
+  },
+  /// Read that never warns, e.g. in `isset()` or on the left of `??`
+  Check,
+  Argument {
+    position: usize,
+  },
+  Skip,
+}
+
+struct PendingRead {
+  name: BString,
+  loc: Option<RangeLocation>,
+  offset: usize,
+  /// Start and end of the loops enclosing the read
+  loops: Vec<(usize, usize)>,
+  warns: bool,
+}
+
+struct Frame {
+  scope: VariableScope,
+  /// Offset from which each definition is visible
+  positions: Vec<usize>,
+  pending: Vec<PendingRead>,
+  loops: Vec<(usize, usize)>,
+  /// Variables checked by `isset()`, `empty()` or `??` in a condition, with the start and end
+  /// of the code that only runs when they are set
+  guards: Vec<(BString, usize, usize)>,
+}
+
+impl Frame {
+  fn new(kind: ScopeKind, name: Option<BString>, node: &Node) -> Self {
+    Frame {
+      scope: VariableScope {
+        kind,
+        name,
+        loc: node.loc.clone(),
+        definitions: vec![],
+        reads: vec![],
+        is_dynamic: false,
+      },
+      positions: vec![],
+      pending: vec![],
+      loops: vec![],
+      guards: vec![],
+    }
+  }
+
+  fn is_defined(&self, name: &[u8], offset: usize, loops: &[(usize, usize)]) -> bool {
+    let is_guarded = self.guards
+      .iter()
+      .any(|(guarded, start, end)| guarded == name && (*start..*end).contains(&offset));
+    is_guarded ||
+      self.scope.definitions
+      .iter()
+        .zip(self.positions.iter())
+        .filter(|(definition, _)| definition.name == name)
+        .any(|(_, position)| {
+          *position <= offset ||
+            loops.iter().any(|(start, end)| (*start..=*end).contains(position))
+        })
+  }
+}
+
+pub(crate) struct VariableAnalyzer {
+  frames: Vec<Frame>,
+  roles: HashMap<usize, Role>,
+  /// Function nodes of methods
+  methods: Vec<usize>,
+  scopes: Vec<VariableScope>,
+  diagnostics: Vec<Diagnostic>,
+}
+
+fn address(node: &Node) -> usize {
+  node as *const Node as usize
+}
+
+/// Variables inside array literals have no location of their own, their name does
+fn loc(node: &Node) -> Option<RangeLocation> {
+  match &node.wrapper {
+    NodeWrapper::Variable(variable) if node.loc.is_none() => variable.name.loc.clone(),
+    _ => node.loc.clone(),
+  }
+}
+
+fn start(node: &Node) -> usize {
+  loc(node).map_or(0, |loc| loc.start.offset as usize)
+}
+
+fn end(node: &Node) -> usize {
+  loc(node).map_or(0, |loc| loc.end.offset as usize)
+}
+
+impl VariableAnalyzer {
+  pub(crate) fn analyze(node: &Node) -> VariableAnalysis {
+    let mut analyzer = VariableAnalyzer {
+      frames: vec![Frame::new(ScopeKind::File, None, node)],
+      roles: HashMap::new(),
+      methods: vec![],
+      scopes: vec![],
+      diagnostics: vec![],
+    };
+    node.visit(&mut analyzer);
+    while !analyzer.frames.is_empty() {
+      analyzer.leave_frame();
+    }
+    let mut scopes = analyzer.scopes;
+    scopes.sort_by_key(|x| x.loc.as_ref().map_or(0, |loc| loc.start.offset));
+    let mut diagnostics = analyzer.diagnostics;
+    diagnostics.sort_by_key(|x| x.loc.as_ref().map_or(0, |loc| loc.start.offset));
+    VariableAnalysis { scopes, diagnostics }
+  }
+
+  fn frame(&mut self) -> &mut Frame {
+    self.frames.last_mut().expect("file frame is removed last")
+  }
+
+  fn name(node: &Node) -> Option<BString> {
+    match &node.wrapper {
+      NodeWrapper::Identifier(identifier) => Some(identifier.name.into()),
+      _ => None,
+    }
+  }
+
+  /// Name of a variable written as `$name`, superglobals are never tracked
+  fn variable_name(node: &Node) -> Option<BString> {
+    let NodeWrapper::Variable(variable) = &node.wrapper else {
+      return None;
+    };
+    Self::name(&variable.name).filter(|x| !SUPERGLOBALS.contains(&x.to_str_lossy().as_ref()))
+  }
+
+  fn read(&mut self, name: BString, node: &Node, warns: bool) {
+    let frame = self.frame();
+    frame.scope.reads.push(VariableRead { name: name.clone(), loc: loc(node) });
+    let loops = frame.loops.clone();
+    frame.pending.push(PendingRead { name, loc: loc(node), offset: start(node), loops, warns });
+  }
+
+  fn define(
+    &mut self,
+    name: BString,
+    kind: DefinitionKind,
+    is_reference: bool,
+    node: &Node,
+    position: usize
+  ) {
+    let frame = self.frame();
+    frame.scope.definitions.push(Definition { name, kind, is_reference, loc: loc(node) });
+    frame.positions.push(position);
+  }
+
+  fn enter_frame(&mut self, mut frame: Frame, parameters: &[Node], position: usize) {
+    for parameter in parameters {
+      let parameter = match &parameter.wrapper {
+        NodeWrapper::ConstructorParameter(promoted) => &promoted.parameter,
+        _ => parameter,
+      };
+      if let NodeWrapper::Parameter(declared) = &parameter.wrapper {
+        if let Some(name) = Self::name(&declared.name) {
+          frame.scope.definitions.push(Definition {
+            name,
+            kind: DefinitionKind::Parameter,
+            is_reference: declared.is_ref,
+            loc: parameter.loc.clone(),
+          });
+          frame.positions.push(position);
+        }
+      }
+    }
+    self.frames.push(frame);
+  }
+
+  fn leave_frame(&mut self) {
+    let Some(frame) = self.frames.pop() else {
+      return;
+    };
+    let Frame { scope, positions, pending, guards, .. } = frame;
+    if scope.kind != ScopeKind::File && !scope.is_dynamic {
+      let frame = Frame { scope, positions, pending: vec![], loops: vec![], guards };
+      for read in pending.iter().filter(|x| x.warns) {
+        if !frame.is_defined(&read.name, read.offset, &read.loops) {
+          self.diagnostics.push(Diagnostic {
+            kind: DiagnosticKind::UndefinedVariable,
+            message: format!("Undefined variable ${}", read.name),
+            loc: read.loc.clone(),
+          });
+        }
+      }
+      self.unused(&frame.scope);
+      self.scopes.push(frame.scope);
+    } else {
+      self.scopes.push(scope);
+    }
+  }
+
+  /// First assignment to each variable that is never read, variables shared with other scopes
+  /// or bound by reference are skipped
+  fn unused(&mut self, scope: &VariableScope) {
+    let mut reported: Vec<&BStr> = vec![];
+    for definition in scope.definitions.iter() {
+      let is_read = scope.reads(&definition.name.to_str_lossy());
+      if
+        definition.kind != DefinitionKind::Assignment ||
+        is_read ||
+        reported.contains(&definition.name.as_bstr())
+      {
+        continue;
+      }
+      reported.push(definition.name.as_bstr());
+      let is_shared = scope.definitions
+        .iter()
+        .filter(|x| x.name == definition.name)
+        .any(|x| {
+          x.is_reference ||
+            matches!(
+              x.kind,
+              DefinitionKind::Global | DefinitionKind::Static | DefinitionKind::Use
+            )
+        });
+      if !is_shared {
+        self.diagnostics.push(Diagnostic {
+          kind: DiagnosticKind::UnusedAssignment,
+          message: format!("Variable ${} is assigned but never read", definition.name),
+          loc: definition.loc.clone(),
+        });
+      }
+    }
+  }
+
+  fn set_role(&mut self, node: &Node, role: Role) {
+    self.roles.insert(address(node), role);
+  }
+
+  /// Variable whose value is read or written when accessing `node`, e.g. `$a` of `$a['b']->c`
+  fn base<'n, 'a>(node: &'n Node<'a>) -> Option<&'n Node<'a>> {
+    match &node.wrapper {
+      NodeWrapper::Variable(_) => Some(node),
+      NodeWrapper::ArrayLookup(lookup) => Self::base(&lookup.left),
+      NodeWrapper::ObjectAccess(access) => Self::base(&access.object),
+      _ => None,
+    }
+  }
+
+  /// Variables known to be set when `condition` evaluates to `is_true`, e.g. `$a` after
+  /// `isset($a['b'])` is true or after `empty($a)` is false
+  fn checked(condition: &Node, is_true: bool, names: &mut Vec<BString>) {
+    match &condition.wrapper {
+      NodeWrapper::Parenthesis(parenthesis) => Self::checked(&parenthesis.statement, is_true, names),
+      NodeWrapper::Negate(negate) => Self::checked(&negate.statement, !is_true, names),
+      NodeWrapper::Bin(bin) => {
+        match bin.operator {
+          BinaryType::BooleanAnd | BinaryType::LogicalAnd if is_true => {
+            Self::checked(&bin.left, is_true, names);
+            Self::checked(&bin.right, is_true, names);
+          }
+          BinaryType::BooleanOr | BinaryType::LogicalOr if !is_true => {
+            Self::checked(&bin.left, is_true, names);
+            Self::checked(&bin.right, is_true, names);
+          }
+          BinaryType::Coalesce if is_true => {
+            names.extend(Self::base(&bin.left).and_then(Self::variable_name));
+          }
+          _ => {}
+        }
+      }
+      NodeWrapper::Call(call) => {
+        let NodeWrapper::Identifier(identifier) = &call.name.wrapper else {
+          return;
+        };
+        let name = identifier.name.to_str_lossy().to_lowercase();
+        let is_set = match name.strip_prefix('\\').unwrap_or(&name) {
+          "isset" => is_true,
+          "empty" => !is_true,
+          _ => false,
+        };
+        if !is_set {
+          return;
+        }
+        for argument in call.arguments.iter() {
+          if let NodeWrapper::Argument(argument) = &argument.wrapper {
+            names.extend(Self::base(&argument.value).and_then(Self::variable_name));
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
+  /// Variables checked by `condition` are defined in `branch` when it runs for `is_true`
+  fn guard(&mut self, condition: &Node, is_true: bool, branch: &Node) {
+    let mut names = vec![];
+    Self::checked(condition, is_true, &mut names);
+    let (start, end) = (start(branch), end(branch));
+    let frame = self.frame();
+    frame.guards.extend(names.into_iter().map(|name| (name, start, end)));
+  }
+
+  /// Assign `role` to every variable written by `node`, destructuring `list()` and arrays
+  fn target(&mut self, node: &Node, role: Role) {
+    match &node.wrapper {
+      NodeWrapper::Variable(_) => self.set_role(node, role),
+      NodeWrapper::ArrayLookup(lookup) => self.target(&lookup.left, role),
+      NodeWrapper::List(list) => list.items.iter().for_each(|x| self.target(x, role)),
+      NodeWrapper::Array(array) => array.items.iter().for_each(|x| self.target(x, role)),
+      NodeWrapper::ArrayItem(item) => self.target(&item.value, role),
+      NodeWrapper::Reference(reference) => {
+        let role = match role {
+          Role::Define { kind, position, .. } => {
+            Role::Define { kind, position, is_reference: true }
+          }
+          _ => role,
+        };
+        self.target(&reference.statement, role);
+      }
+      _ => {}
+    }
+  }
+}
+
+impl<'arena> Visitor<'arena> for VariableAnalyzer {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    if
+      matches!(
+        node.node_type,
+        NodeType::While | NodeType::DoWhile | NodeType::For | NodeType::Foreach
+      )
+    {
+      self.frame().loops.push((start(node), end(node)));
+    }
+  }
+
+  fn leave_node(&mut self, node: &Node<'arena>) {
+    if
+      matches!(
+        node.node_type,
+        NodeType::While | NodeType::DoWhile | NodeType::For | NodeType::Foreach
+      )
+    {
+      self.frame().loops.pop();
+    }
+  }
+
+  fn enter_method(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Method(method) = &node.wrapper {
+      self.methods.push(address(&method.function));
+    }
+  }
+
+  fn enter_function(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Function(function) = &node.wrapper else {
+      return;
+    };
+    let kind = match self.methods.iter().position(|x| *x == address(node)) {
+      Some(index) => {
+        self.methods.swap_remove(index);
+        ScopeKind::Method
+      }
+      None => ScopeKind::Function,
+    };
+    let name = match &function.name.wrapper {
+      NodeWrapper::MagicMethod(method) => Some(BString::from(method.name.to_string())),
+      _ => Self::name(&function.name),
+    };
+    let frame = Frame::new(kind, name, node);
+    self.enter_frame(frame, &function.parameters, start(node));
+  }
+
+  fn leave_function(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_anonymous_function(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::AnonymousFunction(function) = &node.wrapper else {
+      return;
+    };
+    let mut frame = Frame::new(ScopeKind::Closure, None, node);
+    for item in function.uses.iter() {
+      let (variable, is_reference) = match &item.wrapper {
+        NodeWrapper::Reference(reference) => (&*reference.statement, true),
+        _ => (item, false),
+      };
+      let Some(name) = Self::variable_name(variable) else {
+        continue;
+      };
+      self.set_role(variable, Role::Skip);
+      // By-value uses are read when the closure is created, by-reference ones are bound
+      if is_reference {
+        self.define(name.clone(), DefinitionKind::Reference, true, variable, end(variable));
+      } else {
+        self.read(name.clone(), variable, true);
+      }
+      frame.scope.definitions.push(Definition {
+        name,
+        kind: DefinitionKind::Use,
+        is_reference,
+        loc: loc(variable),
+      });
+      frame.positions.push(start(node));
+    }
+    self.enter_frame(frame, &function.parameters, start(node));
+  }
+
+  fn leave_anonymous_function(&mut self, _: &Node<'arena>) {
+    self.leave_frame();
+  }
+
+  fn enter_arrow_function(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ArrowFunction(function) = &node.wrapper {
+      let frame = Frame::new(ScopeKind::ArrowFunction, None, node);
+      self.enter_frame(frame, &function.parameters, start(node));
+    }
+  }
+
+  fn leave_arrow_function(&mut self, node: &Node<'arena>) {
+    let Some(mut frame) = self.frames.pop() else {
+      return;
+    };
+    // Variables not defined by the arrow function are captured by value from the parent
+    let pending = std::mem::take(&mut frame.pending);
+    let mut captures: Vec<BString> = vec![];
+    for read in pending {
+      if frame.scope.defines(&read.name.to_str_lossy()) {
+        frame.pending.push(read);
+        continue;
+      }
+      if !captures.contains(&read.name) {
+        captures.push(read.name.clone());
+      }
+      let parent = self.frame();
+      let loops = parent.loops.clone();
+      parent.pending.push(PendingRead { offset: start(node), loops, ..read });
+    }
+    for name in captures {
+      let parent = self.frame();
+      parent.scope.reads.push(VariableRead { name: name.clone(), loc: node.loc.clone() });
+      frame.scope.definitions.push(Definition {
+        name,
+        kind: DefinitionKind::Capture,
+        is_reference: false,
+        loc: node.loc.clone(),
+      });
+      frame.positions.push(start(node));
+    }
+    self.frames.push(frame);
+    self.leave_frame();
+  }
+
+  fn enter_variable(&mut self, node: &Node<'arena>) {
+    let role = self.roles.remove(&address(node));
+    if role == Some(Role::Skip) {
+      return;
+    }
+    let Some(name) = Self::variable_name(node) else {
+      if let NodeWrapper::Variable(variable) = &node.wrapper {
+        if variable.name.node_type != NodeType::Identifier {
+          self.frame().scope.is_dynamic = true;
+        }
+      }
+      return;
+    };
+    match role {
+      None => self.read(name, node, true),
+      Some(Role::Check) => self.read(name, node, false),
+      Some(Role::Skip) => {}
+      Some(Role::Define { kind, position, is_reference }) => {
+        self.define(name, kind, is_reference, node, position);
+      }
+      Some(Role::Update { position, warns }) => {
+        self.read(name.clone(), node, warns);
+        self.define(name, DefinitionKind::Assignment, false, node, position);
+      }
+      Some(Role::Argument { position }) => {
+        let frame = self.frame();
+        let is_defined = frame.is_defined(&name, start(node), &frame.loops);
+        self.read(name.clone(), node, false);
+        if !is_defined {
+          self.define(name, DefinitionKind::Argument, false, node, position);
+        }
+      }
+    }
+  }
+
+  /// `A::$b` names a static property, only the class side and a variable property name, as in
+  /// `A::$$b`, are read
+  fn enter_static_lookup(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::StaticLookup(lookup) = &node.wrapper {
+      if lookup.right.node_type == NodeType::Variable {
+        self.set_role(&lookup.right, Role::Skip);
+      }
+    }
+  }
+
+  fn enter_assignment(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Assignment(assignment) = &node.wrapper else {
+      return;
+    };
+    let position = end(node);
+    let bound = match &assignment.right.wrapper {
+      NodeWrapper::Reference(reference) => Some(&*reference.statement),
+      _ if assignment.operator == AssignmentType::Reference => Some(&*assignment.right),
+      _ => None,
+    };
+    match assignment.operator {
+      AssignmentType::Default | AssignmentType::Reference => {
+        let is_reference = bound.is_some();
+        let kind = DefinitionKind::Assignment;
+        self.target(&assignment.left, Role::Define { kind, position, is_reference });
+      }
+      AssignmentType::Coalesce => {
+        self.target(&assignment.left, Role::Update { position, warns: false });
+      }
+      _ => {
+        self.target(&assignment.left, Role::Update { position, warns: true });
+      }
+    }
+    if let Some(bound) = bound.filter(|x| x.node_type == NodeType::Variable) {
+      let kind = DefinitionKind::Reference;
+      self.set_role(bound, Role::Define { kind, position, is_reference: true });
+    }
+  }
+
+  fn enter_pre(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Pre(pre) = &node.wrapper {
+      if matches!(pre.operator, PreType::Increment | PreType::Decrement) {
+        self.target(&pre.statement, Role::Update { position: end(node), warns: true });
+      }
+    }
+  }
+
+  fn enter_post(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Post(post) = &node.wrapper {
+      if matches!(post.operator, PostType::Increment | PostType::Decrement) {
+        self.target(&post.statement, Role::Update { position: end(node), warns: true });
+      }
+    }
+  }
+
+  fn enter_bin(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Bin(bin) = &node.wrapper else {
+      return;
+    };
+    match bin.operator {
+      BinaryType::Coalesce => {
+        if let Some(base) = Self::base(&bin.left) {
+          self.set_role(base, Role::Check);
+        }
+      }
+      BinaryType::BooleanAnd | BinaryType::LogicalAnd => self.guard(&bin.left, true, &bin.right),
+      BinaryType::BooleanOr | BinaryType::LogicalOr => self.guard(&bin.left, false, &bin.right),
+      _ => {}
+    }
+  }
+
+  fn enter_if(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::If(statement) = &node.wrapper {
+      self.guard(&statement.condition, true, &statement.valid);
+      if let Some(invalid) = &statement.invalid {
+        self.guard(&statement.condition, false, invalid);
+      }
+    }
+  }
+
+  fn enter_ternary(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Ternary(ternary) = &node.wrapper {
+      self.guard(&ternary.condition, true, &ternary.valid);
+      self.guard(&ternary.condition, false, &ternary.invalid);
+    }
+  }
+
+  fn enter_while(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::While(statement) = &node.wrapper {
+      self.guard(&statement.condition, true, &statement.body);
+    }
+  }
+
+  fn enter_call(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Call(call) = &node.wrapper else {
+      return;
+    };
+    let name = match &call.name.wrapper {
+      NodeWrapper::Identifier(identifier) => identifier.name.to_str_lossy().to_lowercase(),
+      _ => {
+        return;
+      }
+    };
+    let name = name.strip_prefix('\\').unwrap_or(&name);
+    if DYNAMIC_FUNCTIONS.contains(&name) {
+      self.frame().scope.is_dynamic = true;
+    }
+    let role = match name {
+      "isset" | "empty" => Role::Check,
+      "unset" => Role::Skip,
+      _ => {
+        return;
+      }
+    };
+    for argument in call.arguments.iter() {
+      if let NodeWrapper::Argument(argument) = &argument.wrapper {
+        if let Some(base) = Self::base(&argument.value) {
+          self.set_role(base, role);
+        }
+      }
+    }
+  }
+
+  fn enter_argument(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Argument(argument) = &node.wrapper {
+      if argument.value.node_type == NodeType::Variable {
+        let role = Role::Argument { position: end(&argument.value) };
+        self.roles.entry(address(&argument.value)).or_insert(role);
+      }
+    }
+  }
+
+  fn enter_eval(&mut self, _: &Node<'arena>) {
+    self.frame().scope.is_dynamic = true;
+  }
+
+  fn enter_include(&mut self, _: &Node<'arena>) {
+    self.frame().scope.is_dynamic = true;
+  }
+
+  fn enter_global(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Global(global) = &node.wrapper {
+      for item in global.items.iter() {
+        let kind = DefinitionKind::Global;
+        self.set_role(item, Role::Define { kind, position: end(item), is_reference: true });
+      }
+    }
+  }
+
+  fn enter_static(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Static(declaration) = &node.wrapper else {
+      return;
+    };
+    for item in declaration.items.iter() {
+      if let NodeWrapper::PropertyItem(declared) = &item.wrapper {
+        if let Some(name) = Self::name(&declared.name) {
+          self.define(name, DefinitionKind::Static, true, item, end(item));
+        }
+      }
+    }
+  }
+
+  fn enter_foreach(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Foreach(foreach) = &node.wrapper else {
+      return;
+    };
+    let position = end(&foreach.value);
+    let kind = DefinitionKind::Foreach;
+    if let Some(key) = &foreach.key {
+      self.target(key, Role::Define { kind, position, is_reference: false });
+    }
+    self.target(&foreach.value, Role::Define { kind, position, is_reference: false });
+  }
+
+  fn enter_catch(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Catch(catch) = &node.wrapper {
+      if let Some(variable) = &catch.variable {
+        let role = Role::Define {
+          kind: DefinitionKind::Catch,
+          position: end(variable),
+          is_reference: false,
+        };
+        self.set_role(variable, role);
+      }
+    }
+  }
+}
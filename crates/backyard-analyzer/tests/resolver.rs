@@ -0,0 +1,163 @@
+use backyard_analyzer::{ resolve_names, resolver::{ NameKind, NameResolution } };
+use backyard_nodes::NodeWrapper;
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+fn print(names: &NameResolution) -> String {
+  names
+    .iter()
+    .map(|name| {
+      let kind = match name.kind {
+        NameKind::Class => "class",
+        NameKind::Function => "function",
+        NameKind::Constant => "const",
+      };
+      let declaration = if name.is_declaration { " declaration" } else { "" };
+      let fallback = match &name.fallback {
+        Some(fallback) => format!(" (fallback {})", fallback),
+        None => String::new(),
+      };
+      format!("{} {}{}: {}{}", kind, name.original, declaration, name.name, fallback)
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn resolve(code: &str) -> String {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, code).unwrap();
+  print(&resolve_names(&node))
+}
+
+#[test]
+fn imports() {
+  insta::assert_snapshot!(
+    resolve(
+      "<?php
+namespace App\\Http;
+
+use Lib\\Foo as Bar, Lib\\Baz;
+use function Lib\\helper;
+use const Lib\\LIMIT;
+use Lib\\Group\\{ Item, function group_fn, const GROUP };
+
+$a = new Bar(Baz::VALUE, LIMIT, GROUP);
+helper(group_fn(), Item::make());
+Baz\\Sub::make();"
+    )
+  );
+}
+
+#[test]
+fn fallback() {
+  insta::assert_snapshot!(
+    resolve("<?php
+namespace App;
+
+strlen(PHP_EOL);
+\\strlen(\\PHP_EOL);
+Sub\\call(Sub\\VALUE);")
+  );
+}
+
+#[test]
+fn global() {
+  insta::assert_snapshot!(resolve("<?php
+strlen(PHP_EOL);
+new Foo\\Bar();"));
+}
+
+#[test]
+fn relative() {
+  insta::assert_snapshot!(
+    resolve("<?php
+namespace App;
+
+namespace\\helper();
+new namespace\\Sub\\Foo();
+namespace\\VALUE;")
+  );
+}
+
+#[test]
+fn declarations() {
+  insta::assert_snapshot!(
+    resolve(
+      "<?php
+namespace App;
+
+use Lib\\Base;
+use Lib\\Attributes\\Route;
+
+#[Route]
+class Foo extends Base implements Contract {
+  use Helpers;
+
+  const VALUE = 1;
+
+  public function run(Base $base, int $count): ?self {
+    return ($base instanceof Contract) ? static::VALUE : null;
+  }
+}
+
+interface Contract extends \\Countable {}
+trait Helpers {}
+enum Status: string implements Contract { case Active = 'active'; }
+function helper(): Base {}
+const LIMIT = 10;"
+    )
+  );
+}
+
+#[test]
+fn namespaces() {
+  insta::assert_snapshot!(
+    resolve(
+      "<?php
+namespace First {
+  use Lib\\Foo;
+  new Foo();
+}
+
+namespace Second {
+  new Foo();
+}"
+    )
+  );
+}
+
+#[test]
+fn catches() {
+  insta::assert_snapshot!(
+    resolve("<?php
+namespace App;
+
+use Lib\\Failure;
+
+try {} catch (Failure | \\Exception $e) {}")
+  );
+}
+
+#[test]
+fn lookup() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, "<?php\nnamespace App;\nuse Lib\\Foo;\nnew Foo();").unwrap();
+  let names = resolve_names(&node);
+  let NodeWrapper::Program(program) = &node.wrapper else {
+    unreachable!();
+  };
+  let NodeWrapper::Namespace(namespace) = &program.children[0].wrapper else {
+    unreachable!();
+  };
+  let NodeWrapper::Block(block) = &namespace.body.wrapper else {
+    unreachable!();
+  };
+  let NodeWrapper::New(new) = &block.statements[1].wrapper else {
+    unreachable!();
+  };
+  let NodeWrapper::Call(call) = &new.statement.wrapper else {
+    unreachable!();
+  };
+  assert_eq!(Some("Lib\\Foo"), names.get(&call.name).map(|name| name.name.to_string()).as_deref());
+  assert_eq!(None, names.get(&new.statement));
+}
@@ -0,0 +1,137 @@
+use backyard_analyzer::{
+  collect_symbols,
+  resolver::NameKind,
+  symbols::{ MemberKind, Symbol, SymbolTable },
+};
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+fn print(symbol: &Symbol) -> String {
+  let mut lines = vec![
+    format!(
+      "{:?} {}{}{}{}{}{}",
+      symbol.kind,
+      symbol.name,
+      symbol.inheritance.as_ref().map(|x| format!(" {}", x)).unwrap_or_default(),
+      symbol.modifiers
+        .iter()
+        .map(|x| format!(" {}", x))
+        .collect::<String>(),
+      symbol.extends
+        .iter()
+        .map(|x| format!(" extends {}", x))
+        .collect::<String>(),
+      symbol.implements
+        .iter()
+        .map(|x| format!(" implements {}", x))
+        .collect::<String>(),
+      symbol.traits
+        .iter()
+        .map(|x| format!(" uses {}", x))
+        .collect::<String>()
+    )
+  ];
+  for member in symbol.members.iter() {
+    lines.push(
+      format!(
+        "  {:?} {}{}{}{}",
+        member.kind,
+        member.name,
+        member.visibilities
+          .iter()
+          .map(|x| format!(" {}", x))
+          .collect::<String>(),
+        member.inheritance.as_ref().map(|x| format!(" {}", x)).unwrap_or_default(),
+        member.modifiers
+          .iter()
+          .map(|x| format!(" {}", x))
+          .collect::<String>()
+      )
+    );
+  }
+  lines.join("\n")
+}
+
+fn collect(code: &str) -> String {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, code).unwrap();
+  collect_symbols(&node).iter().map(print).collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn declarations() {
+  insta::assert_snapshot!(
+    collect(
+      "<?php
+namespace App;
+
+use Lib\\Model;
+
+abstract class User extends Model implements Contract {
+  use Helpers, \\Lib\\Timestamps;
+
+  const TABLE = 'users';
+  protected static $count = 0;
+  public readonly string $name;
+
+  public function __construct(private int $id, protected readonly string $email) {}
+
+  abstract protected function scope();
+
+  final public static function make(): static {}
+}
+
+final readonly class Point {}
+
+interface Contract extends \\Countable, Other {
+  public function run();
+}
+
+trait Helpers {
+  private $cache;
+
+  function helper() {}
+}
+
+enum Status: string implements Contract {
+  case Active = 'active';
+  case Inactive = 'inactive';
+
+  const FALLBACK = self::Active;
+}
+
+function helper() {
+  function nested() {}
+}
+
+const LIMIT = 10, OFFSET = 0;"
+    )
+  );
+}
+
+#[test]
+fn lookup() {
+  let arena = Bump::new();
+  let mut table = SymbolTable::new();
+  for (file, code) in [
+    ("src/User.php", "<?php\nnamespace App;\nclass User { public function save() {} }"),
+    ("src/helpers.php", "<?php\nnamespace App;\nfunction helper() {}\nconst LIMIT = 1;"),
+    ("src/Duplicate.php", "<?php\nnamespace App;\nclass USER {}"),
+  ] {
+    let node = parse_in(&arena, false, code).unwrap();
+    table.merge(SymbolTable::collect_file(file, &node));
+  }
+
+  assert_eq!(4, table.len());
+  let user = table.get(NameKind::Class, "\\app\\user").unwrap();
+  assert_eq!(Some("src/User.php"), user.file.as_deref());
+  assert!(user.member(MemberKind::Method, "SAVE").is_some());
+  assert!(user.member(MemberKind::Property, "save").is_none());
+  assert_eq!(
+    Some("src/helpers.php"),
+    table.get(NameKind::Function, "App\\Helper").and_then(|x| x.file.as_deref())
+  );
+  assert!(table.get(NameKind::Constant, "App\\LIMIT").is_some());
+  assert!(table.get(NameKind::Constant, "App\\limit").is_none());
+  assert!(table.get(NameKind::Function, "App\\User").is_none());
+}
@@ -0,0 +1,334 @@
+use backyard_analyzer::{ validate, validator::DiagnosticKind };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+fn check(code: &str) -> String {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, code).unwrap();
+  validate(&node)
+    .iter()
+    .map(|x| x.to_string())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+#[test]
+fn jumps() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+break;
+while (true) {
+  switch ($a) {
+    case 1:
+      break 2;
+    default:
+      continue 3;
+  }
+  break 0;
+  break $n;
+  $f = function () {
+    break;
+  };
+}
+foreach ($items as $item) {
+  for (;;) {
+    continue 2;
+  }
+}"
+    )
+  );
+}
+
+#[test]
+fn this() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+class A {
+  public static function make() {
+    $f = fn() => $this->value;
+    return new class {
+      function value() {
+        return $this;
+      }
+    };
+  }
+
+  public function reset() {
+    $this = null;
+  }
+}"
+    )
+  );
+}
+
+#[test]
+fn parameters() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+function f($a, int $b, $a) {}
+$f = fn($x, $x) => $x;
+class A {
+  public function __construct(private $id, $id) {}
+}"
+    )
+  );
+}
+
+#[test]
+fn variadic_parameters() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+function f(...$a, $b) {}
+function g($a, int ...$b) {}
+$f = fn(...$x, $y) => $x;
+class A {
+  public function __construct(private ...$ids, $id) {}
+}"
+    )
+  );
+}
+
+#[test]
+fn methods() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+namespace App;
+
+class A {
+  abstract function a() {}
+  function b();
+  function c() {}
+  function C() {}
+  abstract function d();
+}
+
+abstract class B {
+  abstract protected function a();
+  abstract protected function b() {}
+}
+
+interface I {
+  function a();
+  function b() {}
+}
+
+trait T {
+  abstract function a();
+}"
+    )
+  );
+}
+
+#[test]
+fn returns() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+function a(): void {
+  return;
+  return null;
+}
+function b(): never {
+  return;
+}
+function c(): int {
+  return;
+}
+function d(): ?int {
+  if (true) {
+    return 1;
+  }
+  return;
+}
+function e(): iterable {
+  yield 1;
+  return;
+}
+$f = fn(): void => 1;
+$g = function (): string {
+  return;
+};
+function h() {
+  return;
+}
+return 1;"
+    )
+  );
+}
+
+#[test]
+fn readonly_properties() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+class A {
+  public readonly $a;
+  public readonly int $b = 1;
+  public readonly int $c;
+  public $d;
+  public $d;
+
+  public function __construct(public readonly $e, $f) {}
+}
+
+readonly class B {
+  public $a;
+
+  public function __construct(protected int $b, $c) {}
+}"
+    )
+  );
+}
+
+#[test]
+fn constants() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+class A {
+  const X = 1;
+  const X = 2;
+  public const Y = 1, Z = 2, Y = 3;
+  const x = 4;
+}
+
+interface I {
+  const X = 1;
+}"
+    )
+  );
+}
+
+#[test]
+fn enum_cases() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+enum Suit: string {
+  case Hearts = 'H';
+  case Spades = \"S\";
+  case Clubs = 'S';
+  case Diamonds;
+}
+
+enum Level: int {
+  case Low = 1;
+  case Medium = 0x1;
+  case High = -1;
+  case Max = PHP_INT_MAX;
+  case Other = PHP_INT_MAX;
+}
+
+enum Status {
+  case Active;
+  case Inactive = 1;
+}"
+    )
+  );
+}
+
+#[test]
+fn enum_case_names_and_types() {
+  insta::assert_snapshot!(
+    check(
+      "<?php
+enum E {
+  case A;
+  case A;
+}
+
+enum F: string {
+  case A = '1';
+  case B = 1;
+}
+
+enum G: int {
+  case A = 1;
+  case B = 1.5;
+  case C = 'c';
+}"
+    )
+  );
+}
+
+#[test]
+fn magic_methods() {
+  assert_eq!(
+    "",
+    check(
+      "<?php
+class A {
+  public function __construct() {}
+  public function __clone() {}
+  public function __wakeup() {}
+  public function __toString(): string {
+    return '';
+  }
+}"
+    )
+  );
+}
+
+#[test]
+fn valid() {
+  let arena = Bump::new();
+  let node = parse_in(
+    &arena,
+    false,
+    "<?php
+abstract class A {
+  public function __construct(private readonly int $id) {}
+
+  abstract public function name(): string;
+
+  public static function make(): static {
+    foreach ([1, 2] as $i) {
+      while (true) {
+        if ($i) {
+          continue 2;
+        }
+        break 2;
+      }
+    }
+    return new static(1);
+  }
+
+  public function items(): iterable {
+    $f = function () {
+      return $this->id;
+    };
+    yield $f();
+  }
+}
+
+enum E: int {
+  case A = 1;
+  case B = 2;
+}"
+  ).unwrap();
+  assert_eq!(0, validate(&node).len());
+}
+
+#[test]
+fn kinds() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, "<?php\nfunction f($a, $a): void { return 1; }\nbreak;")
+    .unwrap();
+  let kinds: Vec<DiagnosticKind> = validate(&node)
+    .iter()
+    .map(|x| x.kind)
+    .collect();
+  assert_eq!(
+    vec![
+      DiagnosticKind::DuplicateParameter,
+      DiagnosticKind::InvalidReturn,
+      DiagnosticKind::InvalidJump
+    ],
+    kinds
+  );
+}
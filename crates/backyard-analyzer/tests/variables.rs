@@ -0,0 +1,258 @@
+use backyard_analyzer::{
+  analyze_variables,
+  variables::{ DefinitionKind, ScopeKind, VariableScope },
+};
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+fn print(scope: &VariableScope) -> String {
+  let definitions = scope.definitions
+    .iter()
+    .map(|x| format!("{}${} ({:?})", if x.is_reference { "&" } else { "" }, x.name, x.kind))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let reads = scope.reads
+    .iter()
+    .map(|x| format!("${}", x.name))
+    .collect::<Vec<_>>()
+    .join(", ");
+  format!(
+    "{:?}{}{}\n  defines: {}\n  reads: {}",
+    scope.kind,
+    scope.name.as_ref().map(|x| format!(" {}", x)).unwrap_or_default(),
+    if scope.is_dynamic { " dynamic" } else { "" },
+    definitions,
+    reads
+  )
+}
+
+fn analyze(code: &str) -> String {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, code).unwrap();
+  let analysis = analyze_variables(&node);
+  let mut lines = analysis.scopes.iter().map(print).collect::<Vec<_>>();
+  lines.extend(analysis.diagnostics.iter().map(|x| x.to_string()));
+  lines.join("\n")
+}
+
+#[test]
+fn functions() {
+  insta::assert_snapshot!(
+    analyze(
+      "<?php
+$config = load();
+
+function handle($request, &$response) {
+  $user = $request->user;
+  $unused = find($user);
+  $response = $user;
+  return $missing;
+}
+
+class Controller {
+  public function index(int $page) {
+    $items = [];
+    $items[] = $page;
+    $count = 0;
+    $count++;
+    return $items;
+  }
+}"
+    )
+  );
+}
+
+#[test]
+fn closures() {
+  insta::assert_snapshot!(
+    analyze(
+      "<?php
+function scope($a, $b) {
+  $total = 0;
+  $add = function ($x) use ($a, &$total, $undefined) {
+    $total += $x + $a + $other;
+  };
+  $double = fn($x) => $x * $b + $c;
+  $nested = fn($x) => fn($y) => $x + $y + $a;
+  return [$add, $double, $nested];
+}"
+    )
+  );
+}
+
+#[test]
+fn statements() {
+  insta::assert_snapshot!(
+    analyze(
+      "<?php
+function statements($items) {
+  global $config;
+  foreach ($items as $key => [$first, $second]) {
+    echo $first;
+  }
+  foreach ($items as &$item) {}
+  [$a, [$b]] = $items;
+  list($c, $d) = $items;
+  try {
+    echo $a, $c;
+  } catch (Exception $e) {
+    echo $e->getMessage();
+  }
+  return $config;
+}
+
+function loops() {
+  while (true) {
+    if (isset($last)) {
+      echo $current;
+    }
+    $current = next();
+    $last = $current;
+  }
+  $value = $cached ?? null;
+  $value ??= 1;
+  preg_match('/a/', 'a', $matches);
+  unset($previous);
+  return $matches;
+}
+
+function statics() {
+  static $calls = 0;
+  $calls = $calls + 1;
+}"
+    )
+  );
+}
+
+#[test]
+fn dynamic() {
+  insta::assert_snapshot!(
+    analyze(
+      "<?php
+function view($name) {
+  $title = 'Home';
+  return compact('title', 'name') + [$undefined];
+}
+
+function variable() {
+  ${'a' . 'b'} = 1;
+  return $ab;
+}
+
+function superglobals() {
+  return $_GET['id'] ?? $GLOBALS['id'];
+}"
+    )
+  );
+}
+
+#[test]
+fn scopes() {
+  let arena = Bump::new();
+  let code = "<?php\n$a = 1;\nfunction f($b) { $c = $b; return $c; }\n$f = fn() => $a;";
+  let node = parse_in(&arena, false, code).unwrap();
+  let analysis = analyze_variables(&node);
+  let kinds: Vec<ScopeKind> = analysis.scopes
+    .iter()
+    .map(|x| x.kind)
+    .collect();
+  assert_eq!(vec![ScopeKind::File, ScopeKind::Function, ScopeKind::ArrowFunction], kinds);
+  let function = &analysis.scopes[1];
+  assert_eq!(vec!["b", "c"], function.variables());
+  assert!(function.defines("c") && function.reads("c"));
+  assert!(!function.defines("a"));
+  let arrow = &analysis.scopes[2];
+  assert_eq!(DefinitionKind::Capture, arrow.definitions[0].kind);
+  assert!(analysis.scopes[0].reads("a"));
+  assert!(analysis.diagnostics.is_empty());
+}
+
+#[test]
+fn static_properties() {
+  insta::assert_snapshot!(
+    analyze(
+      "<?php
+class B extends A {
+  public function f($name) {
+    self::$x = static::$x + A::$y;
+    return parent::$z ?? A::$$name;
+  }
+}"
+    )
+  );
+}
+
+#[test]
+fn unused_once() {
+  insta::assert_snapshot!(analyze("<?php\nfunction f() {\n  $a = [];\n  $a[] = 1;\n}"));
+}
+
+fn diagnostics(code: &str) -> Vec<String> {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, code).unwrap();
+  analyze_variables(&node)
+    .diagnostics.iter()
+    .map(|x| x.to_string())
+    .collect()
+}
+
+#[test]
+fn guards() {
+  let code =
+    "<?php
+function f() {
+  if (isset($a)) { echo $a; }
+  echo isset($b) ? $b : 0;
+  if (!empty($c)) { echo $c; }
+  if (empty($d)) { return; } else { echo $d; }
+  if (isset($e['x']) && $e['x'] > 1) { echo $e; }
+  if (($f ?? false) || true) {}
+  if ($g ?? false) { echo $g; }
+  while (isset($h)) { echo $h; }
+  echo !isset($i) || $i;
+}";
+  assert!(diagnostics(code).is_empty());
+}
+
+#[test]
+fn unguarded() {
+  let code =
+    "<?php
+function f() {
+  if (isset($a)) {} else { echo $a; }
+  if (!isset($b)) { echo $b; }
+  if (isset($c) || true) { echo $c; }
+  echo isset($d) ? 0 : $d;
+  if (isset($e)) {}
+  echo $e;
+}";
+  assert_eq!(
+    vec![
+      "Undefined variable $a at line 3, column 32",
+      "Undefined variable $b at line 4, column 25",
+      "Undefined variable $c at line 5, column 32",
+      "Undefined variable $d at line 6, column 23",
+      "Undefined variable $e at line 8, column 7"
+    ],
+    diagnostics(code)
+  );
+}
+
+#[test]
+fn readme() {
+  let code = "<?php function f($a) { $b = $a + $c; $fn = fn() => $a; return $fn; }";
+  assert_eq!(
+    "File
+  defines: 
+  reads: 
+Function f
+  defines: $a (Parameter), $b (Assignment), $fn (Assignment)
+  reads: $a, $c, $a, $fn
+ArrowFunction
+  defines: $a (Capture)
+  reads: $a
+Variable $b is assigned but never read at line 1, column 23
+Undefined variable $c at line 1, column 33",
+    analyze(code)
+  );
+}
@@ -1,14 +1,25 @@
 use std::fmt::{ Display, Formatter };
 
+use backyard_nodes::{ binary::BinaryError, NodeType };
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum GeneratorError {
   NotAProgram,
+  NotAnExpression,
+  UnsupportedNode(NodeType),
+  InvalidSource,
+  InvalidNode(BinaryError),
 }
 
 impl Display for GeneratorError {
   fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     match self {
       GeneratorError::NotAProgram => write!(f, "Not a program"),
+      GeneratorError::NotAnExpression => write!(f, "Not an expression"),
+      GeneratorError::UnsupportedNode(node_type) =>
+        write!(f, "Node {:?} can not be generated on its own", node_type),
+      GeneratorError::InvalidSource => write!(f, "Source could not be parsed"),
+      GeneratorError::InvalidNode(err) => write!(f, "Node could not be decoded: {}", err),
     }
   }
 }
@@ -1,6 +1,11 @@
-use backyard_nodes::{ Node, NodeType };
+use backyard_nodes::{ precedence, Node, NodeType };
 
-use crate::internal::{ attribute::AttributeGenerator, comment::CommentGenerator };
+use crate::{
+  error::GeneratorError,
+  internal::{ attribute::AttributeGenerator, comment::CommentGenerator },
+  lossless::Original,
+  options::GeneratorOptions,
+};
 
 pub type InternalGenerator = for<'arena, 'a> fn(
   &mut Generator<'arena, 'a>,
@@ -8,7 +13,7 @@ pub type InternalGenerator = for<'arena, 'a> fn(
   &Node<'arena>
 );
 
-pub const DEFAULT_GENERATORS: [(NodeType, InternalGenerator); 80] = [
+pub const DEFAULT_GENERATORS: [(NodeType, InternalGenerator); 81] = [
   (NodeType::AnonymousClass, super::internal::class::ClassGenerator::generate_anonymous),
   (NodeType::AnonymousFunction, super::internal::function::FunctionGenerator::generate_anonymous),
   // (NodeType::Argument, super::internal::call::CallGenerator::generate_argument),
@@ -44,6 +49,7 @@ pub const DEFAULT_GENERATORS: [(NodeType, InternalGenerator); 80] = [
   // (NodeType::EncapsedPart, StringGenerator::generate_encapsed_part),
   (NodeType::Enum, super::internal::enums::EnumGenerator::generate),
   // (NodeType::EnumItem, EnumItemGenerator::generate),
+  (NodeType::Error, super::internal::error::ErrorGenerator::generate),
   (NodeType::Eval, super::internal::eval::EvalGenerator::generate),
   (NodeType::Exit, super::internal::exit::ExitGenerator::generate),
   (NodeType::For, super::internal::fors::ForGenerator::generate),
@@ -107,6 +113,117 @@ pub const DEFAULT_GENERATORS: [(NodeType, InternalGenerator); 80] = [
   (NodeType::YieldFrom, super::internal::yields::YieldGenerator::generate_from),
 ];
 
+/// Nodes only found inside another node, which generates them with its own generators
+pub const FRAGMENT_GENERATORS: [(NodeType, InternalGenerator); 21] = [
+  (NodeType::Argument, super::internal::call::CallGenerator::generate_argument),
+  (NodeType::ArrayItem, super::internal::array::ArrayGenerator::generate_item),
+  (NodeType::AttributeItem, super::internal::attribute::AttributeGenerator::generate_item),
+  (NodeType::Case, super::internal::switch::SwitchGenerator::generate_case),
+  (NodeType::Catch, super::internal::tries::TryGenerator::generate_catch),
+  (NodeType::ConstProperty, super::internal::consts::ConstGenerator::generate_property),
+  (
+    NodeType::ConstructorParameter,
+    super::internal::function::FunctionGenerator::generate_constructor_parameter,
+  ),
+  (NodeType::DeclareArgument, super::internal::declare::DeclareGenerator::generate_argument),
+  (NodeType::EncapsedPart, super::internal::string::StringGenerator::generate_encapsed_part),
+  (NodeType::EnumItem, super::internal::enums::EnumGenerator::generate_item),
+  (NodeType::Finally, super::internal::tries::TryGenerator::generate_finally),
+  (NodeType::MatchArm, super::internal::matchs::MatchGenerator::generate_arm),
+  (NodeType::Method, super::internal::method::MethodGenerator::generate),
+  (NodeType::Parameter, super::internal::function::FunctionGenerator::generate_parameter),
+  (NodeType::Property, super::internal::property::PropertyGenerator::generate),
+  (NodeType::PropertyHook, super::internal::property::PropertyGenerator::generate_hook),
+  (NodeType::PropertyItem, super::internal::property::PropertyGenerator::generate_item),
+  (NodeType::TraitUse, super::internal::traituse::TraitUseGenerator::generate),
+  (NodeType::TraitUseAlias, super::internal::traituse::TraitUseGenerator::generate_alias),
+  (
+    NodeType::TraitUsePrecedence,
+    super::internal::traituse::TraitUseGenerator::generate_precedence,
+  ),
+  (NodeType::UseItem, super::internal::uses::UseGenerator::generate_item),
+];
+
+/// Class members, generated with the terminator they have in a class body
+const MEMBERS: [NodeType; 5] = [
+  NodeType::ConstProperty,
+  NodeType::EnumItem,
+  NodeType::Method,
+  NodeType::Property,
+  NodeType::TraitUse,
+];
+
+/// Nodes that can be used as a value, generated without a terminator on their own
+pub(crate) const EXPRESSIONS: [NodeType; 46] = [
+  NodeType::AnonymousClass,
+  NodeType::AnonymousFunction,
+  NodeType::Array,
+  NodeType::ArrayLookup,
+  NodeType::ArrowFunction,
+  NodeType::Assignment,
+  NodeType::Bin,
+  NodeType::Boolean,
+  NodeType::Call,
+  NodeType::Cast,
+  NodeType::ClassKeyword,
+  NodeType::Clone,
+  NodeType::Encapsed,
+  NodeType::Eval,
+  NodeType::Exit,
+  NodeType::HereDoc,
+  NodeType::Identifier,
+  NodeType::Include,
+  NodeType::List,
+  NodeType::Magic,
+  NodeType::MagicMethod,
+  NodeType::Match,
+  NodeType::Negate,
+  NodeType::New,
+  NodeType::NowDoc,
+  NodeType::Null,
+  NodeType::Number,
+  NodeType::ObjectAccess,
+  NodeType::Parent,
+  NodeType::Parenthesis,
+  NodeType::Post,
+  NodeType::Pre,
+  NodeType::Print,
+  NodeType::Reference,
+  NodeType::SelfKeyword,
+  NodeType::Silent,
+  NodeType::StaticKeyword,
+  NodeType::StaticLookup,
+  NodeType::String,
+  NodeType::Ternary,
+  NodeType::This,
+  NodeType::Throw,
+  NodeType::Variable,
+  NodeType::Variadic,
+  NodeType::Yield,
+  NodeType::YieldFrom,
+];
+
+/// Declarations written inside an expression or a parameter list, their attributes are
+/// printed on the same line
+const INLINE_ATTRIBUTES: [NodeType; 5] = [
+  NodeType::AnonymousClass,
+  NodeType::AnonymousFunction,
+  NodeType::ArrowFunction,
+  NodeType::ConstructorParameter,
+  NodeType::Parameter,
+];
+
+/// Parts of other nodes that are printed from the default generators without a terminator
+const PARTS: [NodeType; 7] = [
+  NodeType::Attribute,
+  NodeType::Block,
+  NodeType::DoWhileCondition,
+  NodeType::Else,
+  NodeType::IntersectionType,
+  NodeType::Type,
+  NodeType::UnionType,
+];
+
 #[derive(Debug, Clone)]
 pub struct Line {
   pub line: String,
@@ -128,17 +245,8 @@ impl Line {
     self.line.push_str(line);
   }
 
-  pub fn print(&self) -> String {
-    let indent = Self::get_indent(self.indent);
-    format!("{indent}{}", self.line)
-  }
-
-  fn get_indent(i: usize) -> String {
-    let mut indent = String::new();
-    for _ in 0..i {
-      indent.push_str("  ");
-    }
-    indent
+  pub fn print(&self, indent: &str) -> String {
+    format!("{}{}", indent.repeat(self.indent), self.line)
   }
 }
 
@@ -210,17 +318,46 @@ impl Builder {
     self.lines.extend(builder.lines);
   }
 
-  pub fn print(&self, separator: &str) -> String {
+  pub fn print(&self, separator: &str, indent: &str) -> String {
     self.lines
       .iter()
       .filter_map(|x| {
-        if x.line.is_empty() { None } else { Some(x.print()) }
+        if x.line.is_empty() { None } else { Some(x.print(indent)) }
       })
       .collect::<Vec<String>>()
       .join(separator)
   }
 }
 
+/// Statements that never get a semicolon appended, they print their own terminator if any
+pub(crate) const SELF_TERMINATED: [NodeType; 25] = [
+  NodeType::Function,
+  NodeType::Program,
+  NodeType::Class,
+  NodeType::Interface,
+  NodeType::Trait,
+  NodeType::Enum,
+  NodeType::Method,
+  NodeType::TraitUse,
+  NodeType::Declare,
+  NodeType::Namespace,
+  NodeType::If,
+  NodeType::Switch,
+  NodeType::Case,
+  NodeType::Foreach,
+  NodeType::For,
+  NodeType::While,
+  NodeType::DoWhile,
+  NodeType::Try,
+  NodeType::Label,
+  NodeType::CommentBlock,
+  NodeType::CommentDoc,
+  NodeType::CommentLine,
+  NodeType::Inline,
+  NodeType::PropertyHook,
+  NodeType::Error,
+];
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum EndMode {
   CommaWithoutEnd,
@@ -262,34 +399,7 @@ impl<'a> GeneratorArgument<'a> {
 
   fn get_end_statement(&mut self, node_type: &NodeType) -> Option<&str> {
     if self.end == EndMode::SemicolonDynamic {
-      if
-        ![
-          NodeType::Function,
-          NodeType::Program,
-          NodeType::Class,
-          NodeType::Interface,
-          NodeType::Trait,
-          NodeType::Enum,
-          NodeType::Method,
-          NodeType::TraitUse,
-          NodeType::Declare,
-          NodeType::Namespace,
-          NodeType::If,
-          NodeType::Switch,
-          NodeType::Case,
-          NodeType::Foreach,
-          NodeType::For,
-          NodeType::While,
-          NodeType::DoWhile,
-          NodeType::Try,
-          NodeType::Label,
-          NodeType::CommentBlock,
-          NodeType::CommentDoc,
-          NodeType::CommentLine,
-          NodeType::Inline,
-          NodeType::PropertyHook,
-        ].contains(node_type)
-      {
+      if !SELF_TERMINATED.contains(node_type) {
         return Some(";");
       }
     } else if self.end == EndMode::CommaWithoutEnd && !self.is_last {
@@ -300,19 +410,30 @@ impl<'a> GeneratorArgument<'a> {
 }
 
 pub struct Generator<'arena, 'a> {
-  pub max_length: usize,
+  pub options: &'a GeneratorOptions,
+  /// Number of blocks the node being generated is nested in
+  pub depth: usize,
   nodes: &'a [Node<'arena>],
+  original: Option<Original<'a>>,
 }
 
 impl<'arena, 'a> Generator<'arena, 'a> {
-  pub fn new(nodes: &'a [Node<'arena>]) -> Self {
-    Self { nodes, max_length: 100 }
+  pub fn new(nodes: &'a [Node<'arena>], options: &'a GeneratorOptions) -> Self {
+    Self { nodes, options, depth: 0, original: None }
+  }
+
+  pub fn new_lossless(
+    nodes: &'a [Node<'arena>],
+    options: &'a GeneratorOptions,
+    original: Original<'a>
+  ) -> Self {
+    Self { nodes, options, depth: 0, original: Some(original) }
   }
 
   pub fn start(&mut self) -> String {
     let mut result = self
       .generate_nodes_new(self.nodes, &mut GeneratorArgument::for_block())
-      .print("\n");
+      .print(&self.options.newline, &self.options.indent);
     if result.ends_with("<?php ") {
       result = result[..result.len() - 6].to_string();
     }
@@ -322,6 +443,34 @@ impl<'arena, 'a> Generator<'arena, 'a> {
     result
   }
 
+  /// Generate a single node in the context it is written in. Statements and class members end
+  /// with their terminator, expressions and other parts of a statement do not, unless
+  /// `as_statement` is set for an expression used as a statement.
+  pub fn start_node(
+    &mut self,
+    node: &Node<'arena>,
+    as_statement: bool
+  ) -> Result<String, GeneratorError> {
+    let is_default = DEFAULT_GENERATORS.iter().any(|(x, _)| *x == node.node_type);
+    let generators: &[(NodeType, InternalGenerator)] = if is_default {
+      &DEFAULT_GENERATORS
+    } else if FRAGMENT_GENERATORS.iter().any(|(x, _)| *x == node.node_type) {
+      &FRAGMENT_GENERATORS
+    } else {
+      return Err(GeneratorError::UnsupportedNode(node.node_type.clone()));
+    };
+    let is_statement = if is_default {
+      (as_statement || !EXPRESSIONS.contains(&node.node_type)) && !PARTS.contains(&node.node_type)
+    } else {
+      MEMBERS.contains(&node.node_type)
+    };
+    let end = if is_statement { EndMode::SemicolonDynamic } else { EndMode::None };
+    let mut builder = Builder::new();
+    builder.new_line();
+    self.generate_node(&mut builder, node, &mut GeneratorArgument::new(end, generators));
+    Ok(builder.print(&self.options.newline, &self.options.indent))
+  }
+
   pub fn generate_nodes_new(
     &mut self,
     nodes: &[Node<'arena>],
@@ -344,6 +493,9 @@ impl<'arena, 'a> Generator<'arena, 'a> {
         ![NodeType::Inline, NodeType::Program].contains(&node.node_type) ||
         builder.lines.is_empty()
       {
+        if args.end == EndMode::SemicolonDynamic && i > 0 {
+          self.keep_blank_lines(builder, &nodes[i - 1]);
+        }
         builder.new_line();
       }
       self.generate_node(builder, node, args);
@@ -357,6 +509,24 @@ impl<'arena, 'a> Generator<'arena, 'a> {
     builder
   }
 
+  /// Generate an operand, wrapped in parentheses when it binds looser than `precedence`
+  pub fn generate_operand(&mut self, builder: &mut Builder, node: &Node<'arena>, precedence: u8) {
+    if precedence::of(node).is_some_and(|x| x < precedence) {
+      builder.push("(");
+      self.generate_node(builder, node, &mut GeneratorArgument::default());
+      builder.push(")");
+    } else {
+      self.generate_node(builder, node, &mut GeneratorArgument::default());
+    }
+  }
+
+  pub fn generate_operand_new(&mut self, node: &Node<'arena>, precedence: u8) -> Builder {
+    let mut builder = Builder::new();
+    builder.new_line();
+    self.generate_operand(&mut builder, node, precedence);
+    builder
+  }
+
   pub fn generate_node(
     &mut self,
     builder: &mut Builder,
@@ -374,11 +544,14 @@ impl<'arena, 'a> Generator<'arena, 'a> {
           let mut scoped_builder = Builder::new();
           if let Some(leadings) = leadings {
             self.handle_comments(&mut scoped_builder, leadings);
+            if let Some(last) = leadings.last() {
+              self.keep_blank_lines(&mut scoped_builder, last);
+            }
           }
           if scoped_builder.total_len() == 0 {
             scoped_builder.new_line();
           }
-          generator(self, &mut scoped_builder, node);
+          self.generate_or_reprint(&mut scoped_builder, node, *generator);
           if let Some(end) = args.get_end_statement(&node.node_type) {
             scoped_builder.push(end);
           }
@@ -394,7 +567,7 @@ impl<'arena, 'a> Generator<'arena, 'a> {
             builder.extend(scoped_builder);
           }
         } else {
-          generator(self, builder, node);
+          self.generate_or_reprint(builder, node, *generator);
           if node.node_type != NodeType::Inline {
             if let Some(end) = args.get_end_statement(&node.node_type) {
               builder.push(end);
@@ -404,7 +577,138 @@ impl<'arena, 'a> Generator<'arena, 'a> {
         return;
       }
     }
-    println!("No generator for node: {:?}, {:?}", node.node_type, args.generators);
+  }
+
+  /// Source text of `node` when generating losslessly and it is unchanged
+  pub(crate) fn original_source(&self, node: &Node) -> Option<&'a str> {
+    self.original.as_ref().and_then(|x| x.get(node))
+  }
+
+  fn generate_or_reprint(
+    &mut self,
+    builder: &mut Builder,
+    node: &Node<'arena>,
+    generator: InternalGenerator
+  ) {
+    if let Some(source) = self.original.as_ref().and_then(|x| x.get(node)) {
+      let mut generated = Builder::new();
+      generated.new_line();
+      Generator::new(&[], self.options).generate_declaration(&mut generated, node, generator);
+      if Original::is_equivalent(node, source, &generated.print("\n", &self.options.indent)) {
+        builder.push(source);
+        return;
+      }
+    }
+    if let Some(spliced) = self.splice(node) {
+      let mut generated = Builder::new();
+      generated.new_line();
+      Generator::new(&[], self.options).generate_declaration(&mut generated, node, generator);
+      if Original::is_spliced(&spliced, &generated.print("\n", &self.options.indent)) {
+        builder.push(&spliced);
+        return;
+      }
+    }
+    self.generate_declaration(builder, node, generator);
+  }
+
+  /// Source text of a modified `node` where only its modified children are generated again,
+  /// keeping the code around them as it was written. `None` when children were added, removed
+  /// or replaced, or when none changed, so the node itself was modified.
+  fn splice(&mut self, node: &Node<'arena>) -> Option<String> {
+    let original = self.original.as_ref()?;
+    let (origin, start, end) = original.find(node)?;
+    let source = original.source;
+    let children = node.children();
+    let original_children = origin.children();
+    if children.len() != original_children.len() {
+      return None;
+    }
+    let mut edits = vec![];
+    for (child, original_child) in children.into_iter().zip(original_children) {
+      if child.loc.is_none() || child.loc != original_child.loc {
+        return None;
+      }
+      if child == original_child {
+        continue;
+      }
+      if child.leadings != original_child.leadings || child.trailings != original_child.trailings {
+        return None;
+      }
+      let (_, child_start, child_end) = self.original.as_ref()?.find(original_child)?;
+      let text = match self.splice(child) {
+        Some(text) => text,
+        None => self.regenerate(child, Original::indent_at(source, child_start))?,
+      };
+      edits.push((child_start, child_end, text));
+    }
+    if edits.is_empty() {
+      return None;
+    }
+    edits.sort_by_key(|(start, _, _)| *start);
+    let mut spliced = String::new();
+    let mut position = start;
+    for (child_start, child_end, text) in edits {
+      if child_start < position || child_end > end {
+        return None;
+      }
+      spliced.push_str(&source[position..child_start]);
+      spliced.push_str(&text);
+      position = child_end;
+    }
+    spliced.push_str(&source[position..end]);
+    Some(spliced)
+  }
+
+  /// Generate a modified child on its own, its lines after the first are indented with
+  /// `indent`, the indentation of the line it starts on. Code reprinted inside them keeps its
+  /// own indentation.
+  fn regenerate(&mut self, node: &Node<'arena>, indent: &str) -> Option<String> {
+    let generator = DEFAULT_GENERATORS.iter()
+      .chain(FRAGMENT_GENERATORS.iter())
+      .find(|(node_type, _)| *node_type == node.node_type)?.1;
+    let mut builder = Builder::new();
+    builder.new_line();
+    self.generate_or_reprint(&mut builder, node, generator);
+    let text = builder.lines
+      .iter()
+      .filter(|x| !x.line.is_empty())
+      .enumerate()
+      .map(|(i, line)| {
+        let line = line.print(&self.options.indent);
+        if i == 0 { line } else { format!("{indent}{line}") }
+      })
+      .collect::<Vec<_>>()
+      .join(&self.options.newline);
+    Some(text)
+  }
+
+  /// Prints the attributes of `node` before it, parameters and closures keep them on the same
+  /// line while other declarations put each group on its own line
+  fn generate_declaration(
+    &mut self,
+    builder: &mut Builder,
+    node: &Node<'arena>,
+    generator: InternalGenerator
+  ) {
+    let is_inline = INLINE_ATTRIBUTES.contains(&node.node_type);
+    for attribute in node.attributes().into_iter().flatten() {
+      AttributeGenerator::generate(self, builder, attribute);
+      if is_inline {
+        builder.push(" ");
+      } else {
+        builder.new_line();
+      }
+    }
+    generator(self, builder, node);
+  }
+
+  fn keep_blank_lines(&self, builder: &mut Builder, previous: &Node<'arena>) {
+    let count = self.original.as_ref().map_or(0, |x| x.blank_lines_after(previous));
+    if count > 0 {
+      if let Some(line) = builder.lines.iter_mut().rev().find(|x| !x.line.is_empty()) {
+        line.push(&self.options.newline.repeat(count));
+      }
+    }
   }
 
   pub fn handle_comments(&mut self, builder: &mut Builder, nodes: &[Node<'arena>]) {
@@ -413,15 +717,15 @@ impl<'arena, 'a> Generator<'arena, 'a> {
         match &node.node_type {
           NodeType::CommentBlock => {
             builder.new_line();
-            CommentGenerator::generate_block(self, builder, node);
+            self.generate_or_reprint(builder, node, CommentGenerator::generate_block);
           }
           NodeType::CommentDoc => {
             builder.new_line();
-            CommentGenerator::generate_doc(self, builder, node);
+            self.generate_or_reprint(builder, node, CommentGenerator::generate_doc);
           }
           NodeType::CommentLine => {
             builder.new_line();
-            CommentGenerator::generate(self, builder, node);
+            self.generate_or_reprint(builder, node, CommentGenerator::generate);
           }
           NodeType::Attribute => {
             builder.new_line();
@@ -436,6 +740,24 @@ impl<'arena, 'a> Generator<'arena, 'a> {
     }
   }
 
+  /// Width of the last line of `builder` once printed, including its indentation
+  pub fn line_len(&self, builder: &Builder) -> usize {
+    let depth = self.depth + builder.lines.last().map_or(0, |x| x.indent);
+    depth * self.options.indent.len() + builder.last_len()
+  }
+
+  pub fn extend_list(&self, builder: &mut Builder, mut items: Builder, nodes: &[Node<'arena>]) {
+    if
+      self.options.trailing_comma &&
+      nodes.last().is_some_and(|i| i.trailings.as_ref().is_none_or(|t| t.is_empty()))
+    {
+      items.push(",");
+    }
+    items.indent();
+    builder.extend(items);
+    builder.new_line();
+  }
+
   pub fn check_nodes_has_comments(nodes: &[Node<'arena>]) -> bool {
     nodes
       .iter()
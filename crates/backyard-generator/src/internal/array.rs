@@ -29,18 +29,16 @@ impl ArrayGenerator {
   fn print_values(
     generator: &mut Generator,
     builder: &mut Builder,
-    mut items: Builder,
+    items: Builder,
     node: &ArrayNode
   ) {
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + items.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + items.total_len_with_separator(" ") > generator.options.max_length
     {
-      items.indent();
-      builder.extend(items);
-      builder.new_line();
+      generator.extend_list(builder, items, &node.items);
     } else {
-      builder.push(&items.print(" "));
+      builder.push(&items.print(" ", &generator.options.indent));
     }
   }
 
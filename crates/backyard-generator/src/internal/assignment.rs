@@ -1,4 +1,4 @@
-use backyard_nodes::{ cast_node, Node, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeWrapper };
 
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
@@ -13,8 +13,8 @@ impl AssignmentGenerator {
     let node = cast_node!(Assignment, &node.wrapper);
     generator.generate_node(builder, &node.left, &mut GeneratorArgument::default());
     builder.push(format!(" {} ", node.operator).as_str());
-    let mut right = generator.generate_node_new(&node.right);
-    if builder.last_len() + right.total_len_with_separator(" ") > generator.max_length {
+    let mut right = generator.generate_operand_new(&node.right, precedence::ASSIGNMENT);
+    if generator.line_len(builder) + right.total_len_with_separator(" ") > generator.options.max_length {
       right.indent();
       builder.extend(right);
     } else {
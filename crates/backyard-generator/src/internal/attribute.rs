@@ -17,7 +17,7 @@ impl AttributeGenerator {
       &node.items,
       &mut GeneratorArgument::for_parameter(&[(NodeType::AttributeItem, Self::generate_item)])
     );
-    builder.push(&items.print(" "));
+    builder.push(&items.print(" ", &generator.options.indent));
     builder.push("]");
   }
 
@@ -36,7 +36,7 @@ impl AttributeGenerator {
           &[(NodeType::Argument, CallGenerator::generate_argument)]
         )
       );
-      builder.push(&arguments.print(" "));
+      builder.push(&arguments.print(" ", &generator.options.indent));
       builder.push(")");
     }
   }
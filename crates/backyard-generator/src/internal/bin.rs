@@ -1,6 +1,6 @@
 use backyard_nodes::{ cast_node, Node, NodeWrapper };
 
-use crate::generator::{ Builder, Generator, GeneratorArgument };
+use crate::generator::{ Builder, Generator };
 
 pub struct BinGenerator;
 
@@ -12,9 +12,17 @@ impl BinGenerator {
   ) {
     let node = cast_node!(Bin, &node.wrapper);
     let operator = format!("{} ", node.operator);
-    generator.generate_node(builder, &node.left, &mut GeneratorArgument::default());
-    let mut expr = generator.generate_node_new(&node.right);
-    if builder.last_len() + expr.first_len() + operator.len() > generator.max_length {
+    let precedence = node.operator.precedence();
+    let (left, right) = if node.operator.is_right_associative() {
+      (precedence + 1, precedence)
+    } else if node.operator.is_non_associative() {
+      (precedence + 1, precedence + 1)
+    } else {
+      (precedence, precedence + 1)
+    };
+    generator.generate_operand(builder, &node.left, left);
+    let mut expr = generator.generate_operand_new(&node.right, right);
+    if generator.line_len(builder) + expr.first_len() + operator.len() > generator.options.max_length {
       let mut t = Builder::new();
       t.new_line();
       t.push(&operator);
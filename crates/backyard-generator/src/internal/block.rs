@@ -1,12 +1,15 @@
 use backyard_nodes::{ Node, NodeType, NodeWrapper };
 
-use crate::generator::{
-  Builder,
-  EndMode,
-  Generator,
-  GeneratorArgument,
-  InternalGenerator,
-  DEFAULT_GENERATORS,
+use crate::{
+  generator::{
+    Builder,
+    EndMode,
+    Generator,
+    GeneratorArgument,
+    InternalGenerator,
+    DEFAULT_GENERATORS,
+  },
+  options::BraceStyle,
 };
 
 pub struct BlockGenerator;
@@ -18,8 +21,7 @@ impl BlockGenerator {
     node: &Node<'arena>
   ) {
     builder.push("{");
-    let mut block = Self::generate_base(generator, node, &DEFAULT_GENERATORS);
-    block.indent();
+    let block = Self::generate_indented(generator, node, &DEFAULT_GENERATORS);
     builder.extend(block);
     builder.new_line();
     builder.push("}");
@@ -31,7 +33,8 @@ impl BlockGenerator {
     node: &Node<'arena>,
     short_close: Option<&str>
   ) {
-    Self::generate_specific(generator, builder, node, short_close, &DEFAULT_GENERATORS)
+    let brace = generator.options.braces.control;
+    Self::generate_specific(generator, builder, node, short_close, brace, &DEFAULT_GENERATORS)
   }
 
   pub fn generate_specific<'arena>(
@@ -39,6 +42,7 @@ impl BlockGenerator {
     builder: &mut Builder,
     node: &Node<'arena>,
     short_close: Option<&str>,
+    brace: BraceStyle,
     generators: &[(NodeType, InternalGenerator)]
   ) {
     let leadings = &node.leadings;
@@ -54,13 +58,13 @@ impl BlockGenerator {
       if scoped_builder.total_len() == 0 {
         scoped_builder.new_line();
       }
-      Self::print_block(generator, &mut scoped_builder, node, short_close, generators);
+      Self::print_block(generator, &mut scoped_builder, node, short_close, brace, generators);
       if let Some(trailings) = trailings {
         generator.handle_comments(&mut scoped_builder, trailings);
       }
       builder.extend_first_line(scoped_builder);
     } else {
-      Self::print_block(generator, builder, node, short_close, generators);
+      Self::print_block(generator, builder, node, short_close, brace, generators);
     }
   }
 
@@ -69,10 +73,10 @@ impl BlockGenerator {
     builder: &mut Builder,
     node: &Node<'arena>,
     short_close: Option<&str>,
+    brace: BraceStyle,
     generators: &[(NodeType, InternalGenerator)]
   ) {
-    let mut block = Self::generate_base(generator, node, generators);
-    block.indent();
+    let block = Self::generate_indented(generator, node, generators);
     if let Some(close) = short_close {
       builder.push(":");
       builder.extend(block);
@@ -80,15 +84,39 @@ impl BlockGenerator {
       builder.push(close);
     } else {
       if node.leadings.is_none() || node.leadings.as_ref().unwrap().is_empty() {
-        builder.push(" ");
+        Self::open_brace(builder, brace);
+      } else {
+        builder.push("{");
       }
-      builder.push("{");
       builder.extend(block);
       builder.new_line();
       builder.push("}");
     }
   }
 
+  pub fn open_brace(builder: &mut Builder, brace: BraceStyle) {
+    let after_parameters = builder.lines.last().is_some_and(|i| i.line.starts_with(')'));
+    if brace == BraceStyle::NextLine && !after_parameters {
+      builder.new_line();
+      builder.push("{");
+    } else {
+      builder.push(" {");
+    }
+  }
+
+  /// Generate the statements of a block one level deeper than the code around it
+  pub fn generate_indented<'arena>(
+    generator: &mut Generator<'arena, '_>,
+    node: &Node<'arena>,
+    generators: &[(NodeType, InternalGenerator)]
+  ) -> Builder {
+    generator.depth += 1;
+    let mut block = Self::generate_base(generator, node, generators);
+    generator.depth -= 1;
+    block.indent();
+    block
+  }
+
   pub fn generate_base<'arena>(
     generator: &mut Generator<'arena, '_>,
     node: &Node<'arena>,
@@ -12,20 +12,19 @@ impl CallGenerator {
   ) {
     let node = cast_node!(Call, &node.wrapper);
     generator.generate_node(builder, &node.name, &mut GeneratorArgument::default());
-    let mut arguments = generator.generate_nodes_new(
+    let arguments = generator.generate_nodes_new(
       &node.arguments,
       &mut GeneratorArgument::for_parameter(&[(NodeType::Argument, Self::generate_argument)])
     );
     builder.push("(");
     if
       Generator::check_nodes_has_comments(&node.arguments) ||
-      2 + builder.last_len() + arguments.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + arguments.total_len_with_separator(" ") >
+        generator.options.max_length
     {
-      arguments.indent();
-      builder.extend(arguments);
-      builder.new_line();
+      generator.extend_list(builder, arguments, &node.arguments);
     } else {
-      builder.push(&arguments.print(" "));
+      builder.push(&arguments.print(" ", &generator.options.indent));
     }
     builder.push(")");
   }
@@ -43,13 +43,15 @@ impl ClassGenerator {
           &[(NodeType::Identifier, IdentifierGenerator::generate)]
         )
       );
-      builder.push(&implements.print(" "));
+      builder.push(&implements.print(" ", &generator.options.indent));
     }
+    let brace = generator.options.braces.class;
     BlockGenerator::generate_specific(
       generator,
       builder,
       &node.body,
       None,
+      brace,
       &[
         (NodeType::TraitUse, TraitUseGenerator::generate),
         (NodeType::ConstProperty, ConstGenerator::generate_property),
@@ -72,7 +74,7 @@ impl ClassGenerator {
         &node.parameters,
         &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
       );
-      builder.push(&parameters.print(" "));
+      builder.push(&parameters.print(" ", &generator.options.indent));
       builder.push(")");
     }
     if let Some(n) = &node.extends {
@@ -87,13 +89,15 @@ impl ClassGenerator {
           &[(NodeType::Identifier, IdentifierGenerator::generate)]
         )
       );
-      builder.push(&implements.print(" "));
+      builder.push(&implements.print(" ", &generator.options.indent));
     }
+    let brace = generator.options.braces.closure;
     BlockGenerator::generate_specific(
       generator,
       builder,
       &node.body,
       None,
+      brace,
       &[
         (NodeType::TraitUse, TraitUseGenerator::generate),
         (NodeType::ConstProperty, ConstGenerator::generate_property),
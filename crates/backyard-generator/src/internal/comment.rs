@@ -47,11 +47,17 @@ impl CommentGenerator {
       .iter()
       .enumerate()
       .filter_map(|(index, i)| {
+        // Only the `*` gutter is removed, indentation after it is kept
         let trimmed = i.trim_start();
-        if (index == 0 || index == comments_last_index) && trimmed.is_empty() {
+        let text = trimmed.strip_prefix('*').unwrap_or(trimmed).trim_end();
+        if (index == 0 || index == comments_last_index) && text.is_empty() {
           None
+        } else if text.is_empty() {
+          Some(" *".to_string())
+        } else if text.starts_with(char::is_whitespace) {
+          Some(format!(" *{}", text))
         } else {
-          Some(format!(" {}", trimmed))
+          Some(format!(" * {}", text))
         }
       });
     for i in comments {
@@ -19,12 +19,12 @@ impl ConstGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + consts.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + consts.total_len_with_separator(" ") > generator.options.max_length
     {
       consts.indent();
       builder.extend_first_line(consts);
     } else {
-      builder.push(&consts.print(" "));
+      builder.push(&consts.print(" ", &generator.options.indent));
     }
   }
 
@@ -49,12 +49,12 @@ impl ConstGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + consts.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + consts.total_len_with_separator(" ") > generator.options.max_length
     {
       consts.indent();
       builder.extend_first_line(consts);
     } else {
-      builder.push(&consts.print(" "));
+      builder.push(&consts.print(" ", &generator.options.indent));
     }
   }
 }
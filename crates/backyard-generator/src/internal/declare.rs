@@ -21,13 +21,14 @@ impl DeclareGenerator {
     builder.push("(");
     if
       Generator::check_nodes_has_comments(&node.arguments) ||
-      2 + builder.last_len() + arguments.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + arguments.total_len_with_separator(" ") >
+        generator.options.max_length
     {
       arguments.indent();
       builder.extend(arguments);
       builder.new_line();
     } else {
-      builder.push(&arguments.print(" "));
+      builder.push(&arguments.print(" ", &generator.options.indent));
     }
     builder.push(")");
     match node.body_type {
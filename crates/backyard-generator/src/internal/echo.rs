@@ -19,12 +19,12 @@ impl EchoGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + items.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + items.total_len_with_separator(" ") > generator.options.max_length
     {
       items.indent();
       builder.extend_first_line(items);
     } else {
-      builder.push(&items.print(" "));
+      builder.push(&items.print(" ", &generator.options.indent));
     }
   }
 }
@@ -3,6 +3,7 @@ use backyard_nodes::{ cast_node, Node, NodeType, NodeWrapper };
 use crate::generator::{ Builder, EndMode, Generator, GeneratorArgument };
 
 use super::{
+  block::BlockGenerator,
   consts::ConstGenerator,
   identifier::IdentifierGenerator,
   method::MethodGenerator,
@@ -39,7 +40,7 @@ impl EnumGenerator {
         ]
       )
     );
-    builder.push(" {");
+    BlockGenerator::open_brace(builder, generator.options.braces.class);
     body.indent();
     builder.extend(body);
     builder.new_line();
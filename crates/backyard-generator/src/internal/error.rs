@@ -0,0 +1,15 @@
+use backyard_nodes::Node;
+
+use crate::generator::{ Builder, Generator };
+
+pub struct ErrorGenerator;
+
+impl ErrorGenerator {
+  /// Statements skipped by the recovering parser have nothing to generate, only lossless
+  /// generation can reprint their source
+  pub fn generate(generator: &mut Generator, builder: &mut Builder, node: &Node) {
+    if let Some(source) = generator.original_source(node) {
+      builder.push(source);
+    }
+  }
+}
@@ -23,7 +23,7 @@ impl ForGenerator {
         &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
       );
     }
-    builder.push(&inits.print(" "));
+    builder.push(&inits.print(" ", &generator.options.indent));
     builder.push("; ");
     let mut tests = Builder::new();
     if !node.tests.is_empty() {
@@ -33,7 +33,7 @@ impl ForGenerator {
         &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
       );
     }
-    builder.push(&tests.print(" "));
+    builder.push(&tests.print(" ", &generator.options.indent));
     builder.push("; ");
     let mut increments = Builder::new();
     if !node.increments.is_empty() {
@@ -43,7 +43,7 @@ impl ForGenerator {
         &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
       );
     }
-    builder.push(&increments.print(" "));
+    builder.push(&increments.print(" ", &generator.options.indent));
     builder.push(")");
     match node.body_type {
       BodyType::Basic => {
@@ -1,6 +1,9 @@
 use backyard_nodes::{ cast_node, MagicMethodName, Node, NodeType, NodeWrapper };
 
-use crate::generator::{ Builder, Generator, GeneratorArgument, DEFAULT_GENERATORS };
+use crate::{
+  generator::{ Builder, Generator, GeneratorArgument, DEFAULT_GENERATORS },
+  options::BraceStyle,
+};
 
 use super::{ block::BlockGenerator, identifier::IdentifierGenerator };
 
@@ -34,13 +37,23 @@ impl FunctionGenerator {
     generator: &mut Generator<'arena, '_>,
     builder: &mut Builder,
     node: &Node<'arena>
+  ) {
+    let brace = generator.options.braces.function;
+    Self::generate_with_brace(generator, builder, node, brace);
+  }
+
+  pub fn generate_with_brace<'arena>(
+    generator: &mut Generator<'arena, '_>,
+    builder: &mut Builder,
+    node: &Node<'arena>,
+    brace: BraceStyle
   ) {
     let node = cast_node!(Function, &node.wrapper);
     builder.push("function ");
     if node.is_ref {
       builder.push("&");
     }
-    let mut parameters = if node.name.node_type == NodeType::MagicMethod {
+    let parameters = if node.name.node_type == NodeType::MagicMethod {
       let name = cast_node!(MagicMethod, &node.name.wrapper);
       builder.push(&name.name.to_string());
       if name.name == MagicMethodName::Construct {
@@ -66,14 +79,12 @@ impl FunctionGenerator {
     builder.push("(");
     if
       Generator::check_nodes_has_comments(&node.parameters) ||
-      3 + builder.last_len() + parameters.total_len_with_separator(" ") + return_type_len >
-        generator.max_length
+      3 + generator.line_len(builder) + parameters.total_len_with_separator(" ") + return_type_len >
+        generator.options.max_length
     {
-      parameters.indent();
-      builder.extend(parameters);
-      builder.new_line();
+      generator.extend_list(builder, parameters, &node.parameters);
     } else {
-      builder.push(&parameters.print(" "));
+      builder.push(&parameters.print(" ", &generator.options.indent));
     }
     builder.push(")");
 
@@ -82,7 +93,7 @@ impl FunctionGenerator {
       builder.extend_first_line(n);
     }
     if let Some(n) = &node.body {
-      BlockGenerator::generate(generator, builder, n, None);
+      BlockGenerator::generate_specific(generator, builder, n, None, brace, &DEFAULT_GENERATORS);
     } else {
       builder.push(";");
     }
@@ -98,8 +109,8 @@ impl FunctionGenerator {
     if node.is_ref {
       builder.push("&");
     }
-    let mut parameters = Self::get_parameters(generator, &node.parameters);
-    let mut uses = generator.generate_nodes_new(
+    let parameters = Self::get_parameters(generator, &node.parameters);
+    let uses = generator.generate_nodes_new(
       &node.uses,
       &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
     );
@@ -113,16 +124,14 @@ impl FunctionGenerator {
     if
       Generator::check_nodes_has_comments(&node.parameters) ||
       3 +
-        builder.last_len() +
+        generator.line_len(builder) +
         parameters.total_len_with_separator(" ") +
         uses_len +
-        return_type_len > generator.max_length
+        return_type_len > generator.options.max_length
     {
-      parameters.indent();
-      builder.extend(parameters);
-      builder.new_line();
+      generator.extend_list(builder, parameters, &node.parameters);
     } else {
-      builder.push(&parameters.print(" "));
+      builder.push(&parameters.print(" ", &generator.options.indent));
     }
     builder.push(")");
 
@@ -130,13 +139,11 @@ impl FunctionGenerator {
       builder.push(" use (");
       if
         Generator::check_nodes_has_comments(&node.uses) ||
-        builder.last_len() + uses_len + return_type_len > generator.max_length
+        generator.line_len(builder) + uses_len + return_type_len > generator.options.max_length
       {
-        uses.indent();
-        builder.extend(uses);
-        builder.new_line();
+        generator.extend_list(builder, uses, &node.uses);
       } else {
-        builder.push(&uses.print(" "));
+        builder.push(&uses.print(" ", &generator.options.indent));
       }
       builder.push(")");
     }
@@ -146,7 +153,15 @@ impl FunctionGenerator {
       builder.extend_first_line(n);
     }
 
-    BlockGenerator::generate(generator, builder, &node.body, None);
+    let brace = generator.options.braces.closure;
+    BlockGenerator::generate_specific(
+      generator,
+      builder,
+      &node.body,
+      None,
+      brace,
+      &DEFAULT_GENERATORS
+    );
   }
 
   pub fn generate_arrow<'arena>(
@@ -159,7 +174,7 @@ impl FunctionGenerator {
     if node.is_ref {
       builder.push("&");
     }
-    let mut parameters = Self::get_parameters(generator, &node.parameters);
+    let parameters = Self::get_parameters(generator, &node.parameters);
     let (return_type, return_type_len) = Self::get_return_type(
       generator,
       &node.return_type.as_deref()
@@ -168,14 +183,12 @@ impl FunctionGenerator {
     builder.push("(");
     if
       Generator::check_nodes_has_comments(&node.parameters) ||
-      3 + builder.last_len() + parameters.total_len_with_separator(" ") + return_type_len >
-        generator.max_length
+      3 + generator.line_len(builder) + parameters.total_len_with_separator(" ") + return_type_len >
+        generator.options.max_length
     {
-      parameters.indent();
-      builder.extend(parameters);
-      builder.new_line();
+      generator.extend_list(builder, parameters, &node.parameters);
     } else {
-      builder.push(&parameters.print(" "));
+      builder.push(&parameters.print(" ", &generator.options.indent));
     }
     builder.push(")");
 
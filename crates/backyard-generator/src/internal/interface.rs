@@ -28,13 +28,15 @@ impl InterfaceGenerator {
           &[(NodeType::Identifier, IdentifierGenerator::generate)]
         )
       );
-      builder.push(&implements.print(" "));
+      builder.push(&implements.print(" ", &generator.options.indent));
     }
+    let brace = generator.options.braces.class;
     BlockGenerator::generate_specific(
       generator,
       builder,
       &node.body,
       None,
+      brace,
       &[
         (NodeType::ConstProperty, ConstGenerator::generate_property),
         (NodeType::Method, MethodGenerator::generate),
@@ -18,13 +18,13 @@ impl ListGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + values.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + values.total_len_with_separator(" ") > generator.options.max_length
     {
       values.indent();
       builder.extend(values);
       builder.new_line();
     } else {
-      builder.push(&values.print(" "));
+      builder.push(&values.print(" ", &generator.options.indent));
     }
     builder.push(")");
   }
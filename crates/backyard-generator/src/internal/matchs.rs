@@ -38,7 +38,7 @@ impl MatchGenerator {
         &node.conditions,
         &mut GeneratorArgument::for_parameter(&DEFAULT_GENERATORS)
       );
-      builder.push(&conditions.print(" "));
+      builder.push(&conditions.print(" ", &generator.options.indent));
     } else {
       builder.push("default");
     }
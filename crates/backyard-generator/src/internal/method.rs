@@ -22,6 +22,7 @@ impl MethodGenerator {
     if node.is_static {
       builder.push("static ");
     }
-    FunctionGenerator::generate(generator, builder, &node.function);
+    let brace = generator.options.braces.method;
+    FunctionGenerator::generate_with_brace(generator, builder, &node.function, brace);
   }
 }
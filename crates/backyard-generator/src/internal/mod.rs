@@ -12,6 +12,7 @@ pub mod declare;
 pub mod dowhile;
 pub mod echo;
 pub mod enums;
+pub mod error;
 pub mod eval;
 pub mod exit;
 pub mod foreach;
@@ -1,4 +1,4 @@
-use backyard_nodes::{ cast_node, Node, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeWrapper };
 
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
@@ -25,6 +25,6 @@ impl ParenthesisGenerator {
     builder.push("(");
     builder.push(&node.cast_type.to_string());
     builder.push(") ");
-    generator.generate_node(builder, &node.expression, &mut GeneratorArgument::default());
+    generator.generate_operand(builder, &node.expression, precedence::UNARY);
   }
 }
@@ -1,6 +1,6 @@
-use backyard_nodes::{ cast_node, Node, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeWrapper };
 
-use crate::generator::{ Builder, Generator, GeneratorArgument };
+use crate::generator::{ Builder, Generator };
 
 pub struct PostGenerator;
 
@@ -11,7 +11,7 @@ impl PostGenerator {
     node: &Node<'arena>
   ) {
     let node = cast_node!(Post, &node.wrapper);
-    generator.generate_node(builder, &node.statement, &mut GeneratorArgument::default());
+    generator.generate_operand(builder, &node.statement, precedence::NEW);
     builder.push(&node.operator.to_string());
   }
 }
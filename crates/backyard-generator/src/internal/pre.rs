@@ -1,4 +1,4 @@
-use backyard_nodes::{ cast_node, Node, NodeType, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeType, NodeWrapper };
 
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
@@ -21,12 +21,12 @@ impl PreGenerator {
       NodeType::Negate => {
         let node = cast_node!(Negate, &node.wrapper);
         builder.push("!");
-        generator.generate_node(builder, &node.statement, &mut GeneratorArgument::default());
+        generator.generate_operand(builder, &node.statement, precedence::NOT);
       }
       NodeType::Silent => {
         let node = cast_node!(Silent, &node.wrapper);
         builder.push("@");
-        generator.generate_node(builder, &node.statement, &mut GeneratorArgument::default());
+        generator.generate_operand(builder, &node.statement, precedence::UNARY);
       }
       NodeType::Reference => {
         let node = cast_node!(Reference, &node.wrapper);
@@ -35,8 +35,16 @@ impl PreGenerator {
       }
       NodeType::Pre => {
         let node = cast_node!(Pre, &node.wrapper);
-        builder.push(&node.operator.to_string());
-        generator.generate_node(builder, &node.statement, &mut GeneratorArgument::default());
+        let operator = node.operator.to_string();
+        builder.push(&operator);
+        // `-(-$a)` and `-(--$a)` would print as a decrement without the parentheses
+        let precedence = match &node.statement.wrapper {
+          NodeWrapper::Pre(inner) if inner.operator.to_string().starts_with(&operator) => {
+            precedence::NEW + 1
+          }
+          _ => precedence::UNARY,
+        };
+        generator.generate_operand(builder, &node.statement, precedence);
       }
       _ => {}
     }
@@ -26,12 +26,12 @@ impl PropertyGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.items) ||
-      2 + builder.last_len() + items.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + items.total_len_with_separator(" ") > generator.options.max_length
     {
       items.indent();
       builder.extend_first_line(items);
     } else {
-      builder.push(&items.print(" "));
+      builder.push(&items.print(" ", &generator.options.indent));
     }
     if !node.hooks.is_empty() {
       let mut hooks = generator.generate_nodes_new(
@@ -79,17 +79,16 @@ impl PropertyGenerator {
       builder.push("set");
     }
     if !node.parameters.is_empty() {
-      let mut parameters = FunctionGenerator::get_parameters(generator, &node.parameters);
+      let parameters = FunctionGenerator::get_parameters(generator, &node.parameters);
       builder.push("(");
       if
         Generator::check_nodes_has_comments(&node.parameters) ||
-        3 + builder.last_len() + parameters.total_len_with_separator(" ") > generator.max_length
+        3 + generator.line_len(builder) + parameters.total_len_with_separator(" ") >
+          generator.options.max_length
       {
-        parameters.indent();
-        builder.extend(parameters);
-        builder.new_line();
+        generator.extend_list(builder, parameters, &node.parameters);
       } else {
-        builder.push(&parameters.print(" "));
+        builder.push(&parameters.print(" ", &generator.options.indent));
       }
       builder.push(")");
     }
@@ -1,4 +1,4 @@
-use backyard_nodes::{ cast_node, Node, NodeType, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeType, NodeWrapper };
 
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
@@ -34,18 +34,18 @@ impl SinglesGenerator {
       }
       NodeType::Clone => {
         builder.push("clone ");
-        generator.generate_node(
+        generator.generate_operand(
           builder,
           &cast_node!(Clone, &node.wrapper).statement,
-          &mut GeneratorArgument::default()
+          precedence::NEW
         );
       }
       NodeType::New => {
         builder.push("new ");
-        generator.generate_node(
+        generator.generate_operand(
           builder,
           &cast_node!(New, &node.wrapper).statement,
-          &mut GeneratorArgument::default()
+          precedence::NEW
         );
       }
       NodeType::Print => {
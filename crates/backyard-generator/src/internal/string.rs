@@ -1,10 +1,30 @@
-use backyard_nodes::{ cast_node, Node, NodeType, NodeWrapper };
+use backyard_nodes::{
+  cast_node,
+  string::{ escape_double, escape_single },
+  Node,
+  NodeType,
+  NodeWrapper,
+};
 
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
 pub struct StringGenerator;
 
 impl StringGenerator {
+  /// Writes `value` as a PHP string literal, single quoted unless it needs escape sequences or
+  /// double quotes are shorter
+  pub fn quote(value: &[u8]) -> String {
+    let double = escape_double(value, Some(b'"'));
+    let is_plain = std::str::from_utf8(value).is_ok_and(|x| !x.chars().any(char::is_control));
+    if is_plain {
+      let single = escape_single(value);
+      if single.len() <= double.len() {
+        return format!("'{}'", String::from_utf8_lossy(&single));
+      }
+    }
+    format!("\"{}\"", String::from_utf8_lossy(&double))
+  }
+
   pub fn generate(_: &mut Generator, builder: &mut Builder, node: &Node) {
     let node = cast_node!(String, &node.wrapper);
     builder.push(&format!("{}{}{}", node.quote, node.value, node.quote));
@@ -23,7 +43,7 @@ impl StringGenerator {
         &node.values,
         &mut GeneratorArgument::generator(&[(NodeType::EncapsedPart, Self::generate_encapsed_part)])
       )
-      .print("");
+      .print("", &generator.options.indent);
     builder.push(&parts);
     builder.push(&quote);
   }
@@ -39,7 +59,7 @@ impl StringGenerator {
       builder.push(&value.value.to_string());
       return;
     }
-    let expr = generator.generate_node_new(&node.value).print("");
+    let expr = generator.generate_node_new(&node.value).print("", &generator.options.indent);
     if node.is_advanced {
       builder.push(format!("{{{}}}", expr).as_str());
     } else {
@@ -71,7 +91,7 @@ impl StringGenerator {
       &node.values,
       &mut GeneratorArgument::generator(&[(NodeType::EncapsedPart, Self::generate_encapsed_part)])
     );
-    builder.push(&parts.print(""));
+    builder.push(&parts.print("", &generator.options.indent));
     if let Some(last) = parts.lines.last() {
       if let Some(last) = last.line.split('\n').last() {
         if !last.chars().all(|x| x.is_whitespace()) {
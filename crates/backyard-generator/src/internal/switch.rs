@@ -18,11 +18,13 @@ impl SwitchGenerator {
     generator.generate_node(builder, &node.condition, &mut GeneratorArgument::default());
     builder.push(")");
     let end = if node.is_short { Some("endswitch;") } else { None };
+    let brace = generator.options.braces.control;
     BlockGenerator::generate_specific(
       generator,
       builder,
       &node.body,
       end,
+      brace,
       &[(NodeType::Case, Self::generate_case)]
     );
   }
@@ -41,8 +43,7 @@ impl SwitchGenerator {
     } else {
       builder.push("default:");
     }
-    let mut body = BlockGenerator::generate_base(generator, &node.body, &DEFAULT_GENERATORS);
-    body.indent();
+    let body = BlockGenerator::generate_indented(generator, &node.body, &DEFAULT_GENERATORS);
     builder.extend(body);
   }
 }
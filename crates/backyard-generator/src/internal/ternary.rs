@@ -1,4 +1,4 @@
-use backyard_nodes::{ cast_node, Node, NodeWrapper };
+use backyard_nodes::{ cast_node, precedence, Node, NodeWrapper };
 use crate::generator::{ Builder, Generator, GeneratorArgument };
 
 pub struct TernaryGenerator;
@@ -11,10 +11,11 @@ impl TernaryGenerator {
   ) {
     let node = cast_node!(Ternary, &node.wrapper);
 
-    generator.generate_node(builder, &node.condition, &mut GeneratorArgument::default());
+    // Nested ternaries without parentheses are a syntax error since PHP 8
+    generator.generate_operand(builder, &node.condition, precedence::TERNARY + 1);
     builder.push(" ? ");
     generator.generate_node(builder, &node.valid, &mut GeneratorArgument::default());
     builder.push(" : ");
-    generator.generate_node(builder, &node.invalid, &mut GeneratorArgument::default());
+    generator.generate_operand(builder, &node.invalid, precedence::TERNARY + 1);
   }
 }
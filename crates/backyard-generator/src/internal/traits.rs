@@ -22,11 +22,13 @@ impl TraitGenerator {
     let node = cast_node!(Trait, &node.wrapper);
     builder.push("trait ");
     IdentifierGenerator::generate(generator, builder, &node.name);
+    let brace = generator.options.braces.class;
     BlockGenerator::generate_specific(
       generator,
       builder,
       &node.body,
       None,
+      brace,
       &[
         (NodeType::TraitUse, TraitUseGenerator::generate),
         (NodeType::ConstProperty, ConstGenerator::generate_property),
@@ -22,12 +22,12 @@ impl TraitUseGenerator {
     );
     if
       Generator::check_nodes_has_comments(&node.traits) ||
-      2 + builder.last_len() + traits.total_len_with_separator(" ") > generator.max_length
+      2 + generator.line_len(builder) + traits.total_len_with_separator(" ") > generator.options.max_length
     {
       traits.indent();
       builder.extend_first_line(traits);
     } else {
-      builder.push(&traits.print(" "));
+      builder.push(&traits.print(" ", &generator.options.indent));
     }
     if !node.adaptations.is_empty() {
       let mut adaptations_builder = generator.generate_nodes_new(
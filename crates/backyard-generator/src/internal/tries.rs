@@ -16,10 +16,9 @@ impl TryGenerator {
     builder.push("try");
     BlockGenerator::generate(generator, builder, &node.body, None);
     for catch in &node.catches {
+      builder.push(" ");
       if catch.node_type == NodeType::Finally {
-        let node = cast_node!(Finally, &catch.wrapper);
-        builder.push(" finally");
-        BlockGenerator::generate(generator, builder, &node.body, None);
+        Self::generate_finally(generator, builder, catch);
       } else {
         Self::generate_catch(generator, builder, catch);
       }
@@ -32,9 +31,9 @@ impl TryGenerator {
     node: &Node<'arena>
   ) {
     let node = cast_node!(Catch, &node.wrapper);
-    builder.push(" catch (");
+    builder.push("catch (");
     let types = generator.generate_nodes_new(&node.types, &mut GeneratorArgument::default());
-    builder.push(&types.print(" | "));
+    builder.push(&types.print(" | ", &generator.options.indent));
     if let Some(variable) = &node.variable {
       builder.push(" ");
       generator.generate_node(builder, variable, &mut GeneratorArgument::default());
@@ -42,4 +41,14 @@ impl TryGenerator {
     builder.push(")");
     BlockGenerator::generate(generator, builder, &node.body, None);
   }
+
+  pub fn generate_finally<'arena>(
+    generator: &mut Generator<'arena, '_>,
+    builder: &mut Builder,
+    node: &Node<'arena>
+  ) {
+    let node = cast_node!(Finally, &node.wrapper);
+    builder.push("finally");
+    BlockGenerator::generate(generator, builder, &node.body, None);
+  }
 }
@@ -72,7 +72,7 @@ impl TypeGenerator {
             panic!("TypeGenerator::generate_union: failed to get type");
           }
         }
-        scoped_builder.print("")
+        scoped_builder.print("", &generator.options.indent)
       })
       .collect::<Vec<String>>()
   }
@@ -26,23 +26,23 @@ impl UseGenerator {
       builder.push("{");
       if
         Generator::check_nodes_has_comments(&node.items) ||
-        1 + builder.last_len() + items.total_len_with_separator(" ") > generator.max_length
+        1 + generator.line_len(builder) + items.total_len_with_separator(" ") > generator.options.max_length
       {
         items.indent();
         builder.extend(items);
         builder.new_line();
       } else {
-        builder.push(&items.print(" "));
+        builder.push(&items.print(" ", &generator.options.indent));
       }
       builder.push("}");
     } else if
       Generator::check_nodes_has_comments(&node.items) ||
-      1 + builder.last_len() + items.total_len_with_separator(" ") > generator.max_length
+      1 + generator.line_len(builder) + items.total_len_with_separator(" ") > generator.options.max_length
     {
       items.indent();
       builder.extend_first_line(items);
     } else {
-      builder.push(&items.print(" "));
+      builder.push(&items.print(" ", &generator.options.indent));
     }
   }
 
@@ -19,7 +19,7 @@ impl VariableGenerator {
     } else {
       builder.push("{");
       let mut expr = generator.generate_node_new(&node.name);
-      if 1 + builder.last_len() + expr.first_len() > generator.max_length {
+      if 1 + generator.line_len(builder) + expr.first_len() > generator.options.max_length {
         expr.indent();
         builder.extend(expr);
         builder.new_line();
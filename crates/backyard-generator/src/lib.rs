@@ -1,31 +1,137 @@
 pub mod error;
 mod generator;
 mod internal;
+mod lossless;
+pub mod options;
 
-use backyard_nodes::{
-  serde::node::{ SerializableNode, SerializableNodeWrapper },
-  Node,
-  NodeWrapper,
-};
+use backyard_nodes::{ serde::node::SerializableNode, Node, NodeType, NodeWrapper };
+use backyard_lexer::lex_lossless_in;
+use backyard_parser::parse_tokens_recovering_in;
 use bumpalo::Bump;
 use error::GeneratorError;
-use generator::Generator;
+use generator::{ Generator, EXPRESSIONS };
+use internal::string::StringGenerator;
+use lossless::Original;
+use options::GeneratorOptions;
 
 pub fn generate_serializable_node(node: &SerializableNode) -> Result<String, GeneratorError> {
-  if let SerializableNodeWrapper::Program(_) = &node.wrapper {
-    let arena = Bump::new();
-    let node = node.deserialize_in(&arena);
-    generate(&node)
-  } else {
-    Err(GeneratorError::NotAProgram)
-  }
+  generate_serializable_node_with_options(node, &GeneratorOptions::default())
+}
+
+pub fn generate_serializable_node_with_options(
+  node: &SerializableNode,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
+  let arena = Bump::new();
+  let node = node.deserialize_in(&arena).map_err(GeneratorError::InvalidNode)?;
+  generate_with_options(&node, options)
 }
 
 pub fn generate(node: &Node<'_>) -> Result<String, GeneratorError> {
+  generate_with_options(node, &GeneratorOptions::default())
+}
+
+pub fn generate_with_options(
+  node: &Node<'_>,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
   if let NodeWrapper::Program(program) = &node.wrapper {
-    let mut generator = Generator::new(&program.children);
+    let mut generator = Generator::new(&program.children, options);
     Ok(generator.start())
   } else {
     Err(GeneratorError::NotAProgram)
   }
 }
+
+/// Generate any node on its own, statements end with a semicolon while expressions do not
+pub fn generate_node(node: &Node<'_>) -> Result<String, GeneratorError> {
+  generate_node_with_options(node, &GeneratorOptions::default())
+}
+
+pub fn generate_node_with_options(
+  node: &Node<'_>,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
+  if node.node_type == NodeType::Program {
+    return generate_with_options(node, options);
+  }
+  Generator::new(&[], options).start_node(node, false)
+}
+
+/// Generate a node as a statement, expressions are ended with a semicolon too
+pub fn generate_statement(node: &Node<'_>) -> Result<String, GeneratorError> {
+  generate_statement_with_options(node, &GeneratorOptions::default())
+}
+
+pub fn generate_statement_with_options(
+  node: &Node<'_>,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
+  if node.node_type == NodeType::Program {
+    return generate_with_options(node, options);
+  }
+  Generator::new(&[], options).start_node(node, true)
+}
+
+/// Generate a node used as a value, without a terminator
+pub fn generate_expression(node: &Node<'_>) -> Result<String, GeneratorError> {
+  generate_expression_with_options(node, &GeneratorOptions::default())
+}
+
+pub fn generate_expression_with_options(
+  node: &Node<'_>,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
+  if !EXPRESSIONS.contains(&node.node_type) {
+    return Err(GeneratorError::NotAnExpression);
+  }
+  Generator::new(&[], options).start_node(node, false)
+}
+
+/// Writes arbitrary bytes as a PHP string literal, escaping them with the shortest quoting style
+pub fn quote_string(value: &[u8]) -> String {
+  StringGenerator::quote(value)
+}
+
+pub fn generate_lossless(
+  node: &Node<'_>,
+  is_eval: bool,
+  source: &str
+) -> Result<String, GeneratorError> {
+  generate_lossless_with_options(node, is_eval, source, &GeneratorOptions::default())
+}
+
+/// Reprints every node that is unchanged since `source` was parsed exactly as it was
+/// written, only generating code for modified and new nodes. The code around a modified node,
+/// including blank lines and comments between its siblings, is kept as written.
+pub fn generate_lossless_with_options(
+  node: &Node<'_>,
+  is_eval: bool,
+  source: &str,
+  options: &GeneratorOptions
+) -> Result<String, GeneratorError> {
+  let program = if let NodeWrapper::Program(program) = &node.wrapper {
+    program
+  } else {
+    return Err(GeneratorError::NotAProgram);
+  };
+  let arena = Bump::new();
+  let tokens = lex_lossless_in(&arena, is_eval, source).map_err(
+    |_| GeneratorError::InvalidSource
+  )?;
+  // Statements that fail to parse are kept as error nodes, reprinted from the source
+  let (original, _) = parse_tokens_recovering_in(&arena, &tokens);
+  if *node == original {
+    return Ok(source.to_string());
+  }
+  // Opening tag and whitespace around the tokens are not part of any node
+  let prefix = tokens.first().map_or("", |x| &source[..x.offset as usize]);
+  let suffix = tokens
+    .last()
+    .and_then(|x| x.end_offset())
+    .map_or("", |x| &source[x as usize..]);
+  let suffix = if suffix.trim().is_empty() { suffix } else { "" };
+  let original = Original::new(source, &original);
+  let mut generator = Generator::new_lossless(&program.children, options, original);
+  Ok(format!("{prefix}{}{suffix}", generator.start()))
+}
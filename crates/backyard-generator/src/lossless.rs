@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use backyard_lexer::{ lex, token::{ Token, TokenType } };
+use backyard_nodes::{ Node, NodeType, RangeLocation };
+
+use crate::generator::SELF_TERMINATED;
+
+const COMMENTS: [NodeType; 3] = [
+  NodeType::CommentBlock,
+  NodeType::CommentDoc,
+  NodeType::CommentLine,
+];
+
+struct OriginalNode<'a> {
+  node: &'a Node<'a>,
+  start: usize,
+  end: usize,
+}
+
+/// Nodes of the untouched source, used to reprint nodes that were not modified
+/// exactly as they were written.
+pub(crate) struct Original<'a> {
+  pub(crate) source: &'a str,
+  nodes: HashMap<(u32, u32), Vec<OriginalNode<'a>>>,
+}
+
+impl<'a> Original<'a> {
+  pub(crate) fn new(source: &'a str, program: &'a Node<'a>) -> Self {
+    let mut nodes: HashMap<(u32, u32), Vec<OriginalNode<'a>>> = HashMap::new();
+    for (_, node) in program.walk() {
+      for node in std::iter::once(node)
+        .chain(node.leadings.iter().flatten())
+        .chain(node.trailings.iter().flatten()) {
+        let Some(loc) = &node.loc else {
+          continue;
+        };
+        if let Some((start, end)) = Self::span(source, node) {
+          nodes
+            .entry(Self::key(loc))
+            .or_default()
+            .push(OriginalNode { node, start, end });
+        }
+      }
+    }
+    Self { source, nodes }
+  }
+
+  fn key(loc: &RangeLocation) -> (u32, u32) {
+    (loc.start.offset, loc.end.offset)
+  }
+
+  /// Node locations start at the token the parser matched and end after the last consumed
+  /// token, the real extent also covers every descendant.
+  fn span(source: &str, node: &Node) -> Option<(usize, usize)> {
+    let mut span: Option<(u32, u32)> = None;
+    for (_, node) in node.walk() {
+      if let Some(loc) = &node.loc {
+        span = Some(match span {
+          Some((start, end)) => (start.min(loc.start.offset), end.max(loc.end.offset)),
+          None => (loc.start.offset, loc.end.offset),
+        });
+      }
+    }
+    let (start, mut end) = span.map(|(start, end)| (start as usize, end as usize))?;
+    if node.node_type == NodeType::HaltCompiler {
+      end = source.len();
+    } else if SELF_TERMINATED.contains(&node.node_type) && node.node_type != NodeType::Error {
+      // The terminating semicolon is printed by these nodes but is not part of their location,
+      // statements skipped by the recovering parser already end at theirs
+      let rest = source.get(end..).unwrap_or_default();
+      let whitespace = rest.len() - rest.trim_start().len();
+      if rest[whitespace..].starts_with(';') {
+        end += whitespace + 1;
+      }
+    }
+    Some((start, end))
+  }
+
+  /// Source text of `node` when it is identical to the node parsed at the same location
+  pub(crate) fn get(&self, node: &Node) -> Option<&'a str> {
+    let loc = node.loc.as_ref()?;
+    self.nodes
+      .get(&Self::key(loc))?
+      .iter()
+      .find(|original| original.node == node)
+      .and_then(|original| self.source.get(original.start..original.end))
+  }
+
+  /// Original node at the location of `node`, which may have been modified since, with the
+  /// range of its source text
+  pub(crate) fn find(&self, node: &Node) -> Option<(&'a Node<'a>, usize, usize)> {
+    let loc = node.loc.as_ref()?;
+    self.nodes
+      .get(&Self::key(loc))?
+      .iter()
+      .find(|original| original.node.node_type == node.node_type)
+      .map(|original| (original.node, original.start, original.end))
+  }
+
+  /// Whitespace indenting the line of `source` that contains `offset`
+  pub(crate) fn indent_at(source: &str, offset: usize) -> &str {
+    let line_start = source[..offset].rfind('\n').map_or(0, |x| x + 1);
+    let line = &source[line_start..offset];
+    &line[..line.len() - line.trim_start().len()]
+  }
+
+  /// Whether the original text lexes to the same tokens as freshly generated code. Node
+  /// locations are not always accurate, so this keeps a wrong span from being reprinted.
+  /// Comments inside a node are ignored, the parser does not keep all of them.
+  pub(crate) fn is_equivalent(node: &Node, source: &str, generated: &str) -> bool {
+    let is_comment = COMMENTS.contains(&node.node_type);
+    let (Some(source), Some(generated)) = (
+      Self::significant_tokens(source, is_comment),
+      Self::significant_tokens(generated, is_comment),
+    ) else {
+      return false;
+    };
+    source == generated
+  }
+
+  /// Whether spliced source text lexes to the same tokens as freshly generated code, comments
+  /// included as the spliced text keeps every comment of the source
+  pub(crate) fn is_spliced(source: &str, generated: &str) -> bool {
+    let (Some(source), Some(generated)) = (
+      Self::significant_tokens(source, true),
+      Self::significant_tokens(generated, true),
+    ) else {
+      return false;
+    };
+    source == generated
+  }
+
+  /// Tokens of `code` with doc comments collapsed into one token. Returns `None` when
+  /// `code` cannot be lexed or, unless comments are kept, when it starts or ends with one.
+  fn significant_tokens(code: &str, keep_comments: bool) -> Option<Vec<(TokenType, String)>> {
+    let tokens = lex(true, code).ok()?;
+    let is_comment = |token: &Token| {
+      [
+        TokenType::CommentLine,
+        TokenType::CommentBlock,
+        TokenType::CommentDocOpen,
+        TokenType::CommentDocClose,
+      ].contains(&token.token_type)
+    };
+    if
+      !keep_comments &&
+      (tokens.first().is_some_and(is_comment) || tokens.last().is_some_and(is_comment))
+    {
+      return None;
+    }
+    let mut result = vec![];
+    let mut in_doc = false;
+    for token in tokens.iter() {
+      match token.token_type {
+        TokenType::CommentDocOpen => {
+          in_doc = true;
+          if keep_comments {
+            result.push((token.token_type, String::new()));
+          }
+        }
+        TokenType::CommentDocClose => {
+          in_doc = false;
+        }
+        _ if in_doc => {}
+        TokenType::CommentLine | TokenType::CommentBlock if !keep_comments => {}
+        _ => result.push((token.token_type, token.value.to_string())),
+      }
+    }
+    Some(result)
+  }
+
+  /// Number of blank lines in the source right after `node`, which must be an original node
+  pub(crate) fn blank_lines_after(&self, node: &Node) -> usize {
+    let Some(end) = self.end_of(node) else {
+      return 0;
+    };
+    self.source[end..]
+      .bytes()
+      .take_while(|ch| ch.is_ascii_whitespace())
+      .filter(|ch| *ch == b'\n')
+      .count()
+      .saturating_sub(1)
+  }
+
+  fn end_of(&self, node: &Node) -> Option<usize> {
+    let last = node.trailings
+      .as_ref()
+      .and_then(|trailings| trailings.last())
+      .unwrap_or(node);
+    let loc = last.loc.as_ref()?;
+    self.nodes
+      .get(&Self::key(loc))?
+      .iter()
+      .find(|original| original.node == last)
+      .map(|original| original.end)
+  }
+}
@@ -0,0 +1,82 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BraceStyle {
+  /// `function a() {`
+  SameLine,
+  /// `function a()` followed by `{` on its own line
+  NextLine,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BracePlacement {
+  /// Classes, interfaces, traits and enums
+  pub class: BraceStyle,
+  pub function: BraceStyle,
+  pub method: BraceStyle,
+  /// Closures and anonymous classes
+  pub closure: BraceStyle,
+  /// Control structures: if, for, foreach, while, switch, try, declare, namespace, ...
+  pub control: BraceStyle,
+}
+
+impl BracePlacement {
+  pub fn same_line() -> Self {
+    Self {
+      class: BraceStyle::SameLine,
+      function: BraceStyle::SameLine,
+      method: BraceStyle::SameLine,
+      closure: BraceStyle::SameLine,
+      control: BraceStyle::SameLine,
+    }
+  }
+}
+
+impl Default for BracePlacement {
+  fn default() -> Self {
+    Self::same_line()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratorOptions {
+  /// Inserted once per indentation level
+  pub indent: String,
+  /// Lists and expressions longer than this are split across lines
+  pub max_length: usize,
+  /// Separator between generated lines
+  pub newline: String,
+  pub braces: BracePlacement,
+  /// Add a trailing comma to arguments, arrays, parameters and closure uses
+  /// when they are split across lines
+  pub trailing_comma: bool,
+}
+
+impl Default for GeneratorOptions {
+  fn default() -> Self {
+    Self {
+      indent: "  ".to_string(),
+      max_length: 100,
+      newline: "\n".to_string(),
+      braces: BracePlacement::default(),
+      trailing_comma: false,
+    }
+  }
+}
+
+impl GeneratorOptions {
+  /// PSR-12: four spaces, 120 columns, class and function braces on the next line
+  pub fn psr12() -> Self {
+    Self {
+      indent: "    ".to_string(),
+      max_length: 120,
+      newline: "\n".to_string(),
+      braces: BracePlacement {
+        class: BraceStyle::NextLine,
+        function: BraceStyle::NextLine,
+        method: BraceStyle::NextLine,
+        closure: BraceStyle::SameLine,
+        control: BraceStyle::SameLine,
+      },
+      trailing_comma: false,
+    }
+  }
+}
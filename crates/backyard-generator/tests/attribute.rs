@@ -41,3 +41,34 @@ class A {
 }").unwrap();
   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
 }
+
+#[test]
+fn inline() {
+  let asts = parse(true, "$a = #[Attr] fn(#[Attr] $a) => 1;
+function a(#[Attr] int $a, #[Attr(1)] ...$b) {
+}
+$b = new #[Attr] class {
+};").unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
+
+#[test]
+fn members() {
+  let asts = parse(true, "enum A {
+  #[Attr]
+  case B;
+}
+class A {
+  #[Attr]
+  const B = 1;
+  /**
+   * doc
+   */
+  #[Attr]
+  public int $b;
+  #[Attr]
+  public function __construct(#[Attr] private int $a) {
+  }
+}").unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
@@ -19,11 +19,32 @@ fn block() {
   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
 }
 
-// #[test]
-// fn doc() {
-//   let asts = parse(true, "/**\n * test\n */").unwrap();
-//   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
-// }
+#[test]
+fn doc() {
+  let asts = parse(true, "/**\n * test\n */").unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
+
+#[test]
+fn doc_tags() {
+  let asts = parse(
+    true,
+    "/**
+ * Find a user by name.
+ *
+ * Looks up the repository and
+ * falls back to the cache.
+ *
+ * @param non-empty-string $name User name.
+ * @param array<string, int> ...$options
+ * @return ?User the user,
+ *   or null when missing
+ * @deprecated
+ */
+function find($name, ...$options) {}"
+  ).unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
 
 #[test]
 fn before_block() {
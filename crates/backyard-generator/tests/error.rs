@@ -0,0 +1,8 @@
+use backyard_generator::generate_serializable_node;
+use backyard_parser::parse_recovering;
+
+#[test]
+fn skipped() {
+  let (asts, _) = parse_recovering(true, "$a = 1;\n$b = );\nif ($c { foo(); }\nbar();");
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
@@ -0,0 +1,147 @@
+use backyard_generator::{ generate_lossless, generate_lossless_with_options, options::GeneratorOptions };
+use backyard_nodes::{ visitor::{ VisitContext, VisitorMut }, Node, NodeWrapper };
+use backyard_parser::{ parse_in, parse_recovering_in };
+use bumpalo::Bump;
+
+const SOURCE: &str =
+  "<?php
+
+function  Foo(INT $a)
+{
+    return $a  *  2; // double
+}
+
+$b = Foo( 1 );
+$c   =   3;
+";
+
+fn statements<'a, 'arena>(node: &'a mut Node<'arena>) -> &'a mut [Node<'arena>] {
+  match &mut node.wrapper {
+    NodeWrapper::Program(program) => &mut program.children,
+    _ => unreachable!(),
+  }
+}
+
+#[test]
+fn unchanged() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, SOURCE).unwrap();
+  assert_eq!(SOURCE, generate_lossless(&node, false, SOURCE).unwrap());
+}
+
+#[test]
+fn modified() {
+  let arena = Bump::new();
+  let mut node = parse_in(&arena, false, SOURCE).unwrap();
+  if let NodeWrapper::Assignment(assignment) = &mut statements(&mut node)[2].wrapper {
+    if let NodeWrapper::Number(number) = &mut assignment.right.wrapper {
+      number.value = "4".into();
+    }
+  }
+  insta::assert_yaml_snapshot!(generate_lossless(&node, false, SOURCE).unwrap());
+}
+
+#[test]
+fn removed() {
+  let arena = Bump::new();
+  let mut node = parse_in(&arena, false, SOURCE).unwrap();
+  if let NodeWrapper::Program(program) = &mut node.wrapper {
+    program.children.remove(1);
+  }
+  insta::assert_yaml_snapshot!(generate_lossless(&node, false, SOURCE).unwrap());
+}
+
+#[test]
+fn eval() {
+  let source = "if ($a)   {\n\techo   $a;\n}\n\n\n$B = ARRAY(1,2);";
+  let arena = Bump::new();
+  let mut node = parse_in(&arena, true, source).unwrap();
+  assert_eq!(source, generate_lossless(&node, true, source).unwrap());
+  statements(&mut node)[1].loc = None;
+  insta::assert_yaml_snapshot!(generate_lossless(&node, true, source).unwrap());
+}
+
+#[test]
+fn recovered() {
+  let source = "<?php\n$a  =  1;\n$b = );\n\nfoo( $a );\n";
+  let arena = Bump::new();
+  let (mut node, errors) = parse_recovering_in(&arena, false, source);
+  assert_eq!(1, errors.len());
+  assert_eq!(source, generate_lossless(&node, false, source).unwrap());
+  statements(&mut node)[0].loc = None;
+  insta::assert_yaml_snapshot!(generate_lossless(&node, false, source).unwrap());
+}
+
+const NESTED: &str =
+  "<?php
+/**
+ * Copyright
+ */
+
+namespace App\\Util;
+
+final class StringUtil {
+
+    const  LIMIT = 10;
+
+    /**
+     * Ends with.
+     */
+    public static function endsWith($input, $end) {
+        $length = strlen($end);
+
+        if ($length == 0) {
+            return true;
+        }
+        // compare
+        return substr($input, -$length) === $end;
+    }
+
+    private function  __construct() {
+        $x = array( 1,2 );
+    }
+}
+";
+
+struct ReplaceNumber(&'static str, &'static str);
+
+impl<'arena> VisitorMut<'arena> for ReplaceNumber {
+  fn enter_number(&mut self, node: &mut Node<'arena>, _: &mut VisitContext<'arena>) {
+    if let NodeWrapper::Number(number) = &mut node.wrapper {
+      if number.value == self.0 {
+        number.value = self.1.into();
+      }
+    }
+  }
+}
+
+#[test]
+fn nested() {
+  let arena = Bump::new();
+  let mut node = parse_in(&arena, false, NESTED).unwrap();
+  node.visit_mut(&mut ReplaceNumber("0", "1"));
+  let generated = generate_lossless(&node, false, NESTED).unwrap();
+  let changed: Vec<(&str, &str)> = NESTED.lines()
+    .zip(generated.lines())
+    .filter(|(original, generated)| original != generated)
+    .collect();
+  assert_eq!(NESTED.lines().count(), generated.lines().count());
+  assert_eq!(vec![("        if ($length == 0) {", "        if ($length == 1) {")], changed);
+}
+
+#[test]
+fn crlf() {
+  let source = NESTED.replace('\n', "\r\n");
+  let options = GeneratorOptions { newline: "\r\n".into(), ..Default::default() };
+  let arena = Bump::new();
+  let mut node = parse_in(&arena, false, arena.alloc_str(&source)).unwrap();
+  assert_eq!(source, generate_lossless_with_options(&node, false, &source, &options).unwrap());
+  node.visit_mut(&mut ReplaceNumber("0", "1"));
+  let generated = generate_lossless_with_options(&node, false, &source, &options).unwrap();
+  assert_eq!(generated.matches('\n').count(), generated.matches("\r\n").count());
+
+  let mut node = parse_in(&arena, false, NESTED).unwrap();
+  node.visit_mut(&mut ReplaceNumber("0", "1"));
+  let expected = generate_lossless(&node, false, NESTED).unwrap().replace('\n', "\r\n");
+  assert_eq!(expected, generated);
+}
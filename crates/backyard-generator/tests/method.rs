@@ -21,3 +21,19 @@ fn shuffle_modifier() {
 }").unwrap();
   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
 }
+
+#[test]
+fn magic() {
+  let asts = parse(
+    true,
+    "class A {
+  public function __construct() {
+  }
+  public function __clone() {
+  }
+  public function __wakeup() {
+  }
+}"
+  ).unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
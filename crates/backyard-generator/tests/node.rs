@@ -0,0 +1,126 @@
+use backyard_generator::{
+  error::GeneratorError,
+  generate_expression,
+  generate_node,
+  generate_statement,
+};
+use backyard_nodes::{ ClassKeywordNode, Node, NodeType, NodeWrapper };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+const SOURCE: &str =
+  "<?php
+$a = foo(1, b: $c) + 2;
+return $a;
+if ($a) {
+  echo $a;
+}
+class A {
+  public const B = 1;
+  private int $c = 2;
+  public function d(int $e = 3): void {
+    // comment
+    $this->c = match ($e) { 1 => 'a', default => 'b' };
+  }
+}
+";
+
+macro_rules! unwrap_node {
+  ($node_type:ident, $node:expr) => {
+    match &$node.wrapper {
+      NodeWrapper::$node_type(node) => node,
+      _ => unreachable!(),
+    }
+  };
+}
+
+fn statements<'a, 'arena>(node: &'a Node<'arena>) -> &'a [Node<'arena>] {
+  &unwrap_node!(Program, node).children
+}
+
+fn members<'a, 'arena>(node: &'a Node<'arena>) -> &'a [Node<'arena>] {
+  let class = unwrap_node!(Class, node);
+  &unwrap_node!(Block, class.body).statements
+}
+
+#[test]
+fn statement() {
+  let arena = Bump::new();
+  let program = parse_in(&arena, false, SOURCE).unwrap();
+  let statements = statements(&program);
+  assert_eq!("$a = foo(1, b: $c) + 2;", generate_statement(&statements[0]).unwrap());
+  assert_eq!("return $a;", generate_node(&statements[1]).unwrap());
+  assert_eq!("if ($a) {\n  echo $a;\n}", generate_node(&statements[2]).unwrap());
+  assert_eq!(generate_node(&program), generate_statement(&program));
+}
+
+#[test]
+fn expression() {
+  let arena = Bump::new();
+  let program = parse_in(&arena, false, SOURCE).unwrap();
+  let statements = statements(&program);
+  assert_eq!("$a = foo(1, b: $c) + 2", generate_node(&statements[0]).unwrap());
+  assert_eq!("$a = foo(1, b: $c) + 2", generate_expression(&statements[0]).unwrap());
+  let assignment = unwrap_node!(Assignment, statements[0]);
+  assert_eq!("foo(1, b: $c) + 2", generate_expression(&assignment.right).unwrap());
+  assert_eq!(Err(GeneratorError::NotAnExpression), generate_expression(&statements[1]));
+}
+
+#[test]
+fn member() {
+  let arena = Bump::new();
+  let program = parse_in(&arena, false, SOURCE).unwrap();
+  let members = members(&statements(&program)[3]);
+  assert_eq!("public const B = 1;", generate_node(&members[0]).unwrap());
+  assert_eq!("private int $c = 2;", generate_node(&members[1]).unwrap());
+  assert_eq!(
+    concat!(
+      "public function d(int $e = 3): void {\n",
+      "  // comment\n",
+      "  $this->c = match($e) {\n",
+      "    1 => 'a',\n",
+      "    default => 'b'\n",
+      "  };\n",
+      "}"
+    ),
+    generate_node(&members[2]).unwrap()
+  );
+}
+
+#[test]
+fn fragment() {
+  let arena = Bump::new();
+  let program = parse_in(&arena, false, SOURCE).unwrap();
+  let statements = statements(&program);
+  let assignment = unwrap_node!(Assignment, statements[0]);
+  let bin = unwrap_node!(Bin, assignment.right);
+  let call = unwrap_node!(Call, bin.left);
+  assert_eq!("b: $c", generate_node(&call.arguments[1]).unwrap());
+  let method = unwrap_node!(Method, members(&statements[3])[2]);
+  let function = unwrap_node!(Function, method.function);
+  assert_eq!("int $e = 3", generate_node(&function.parameters[0]).unwrap());
+  assert_eq!("void", generate_node(function.return_type.as_ref().unwrap()).unwrap());
+}
+
+#[test]
+fn try_fragment() {
+  let arena = Bump::new();
+  let program = parse_in(&arena, false, "<?php try {} catch (E $e) {} finally {}").unwrap();
+  let tries = unwrap_node!(Try, statements(&program)[0]);
+  assert_eq!("catch (E $e) {\n}", generate_node(&tries.catches[0]).unwrap());
+  assert_eq!("finally {\n}", generate_node(&tries.catches[1]).unwrap());
+  assert_eq!(
+    "try {\n} catch (E $e) {\n} finally {\n}",
+    generate_node(&statements(&program)[0]).unwrap()
+  );
+}
+
+#[test]
+fn unsupported() {
+  let node = Node::new(
+    NodeType::ClassKeyword,
+    NodeWrapper::ClassKeyword(ClassKeywordNode {}),
+    None
+  );
+  assert_eq!(Err(GeneratorError::UnsupportedNode(NodeType::ClassKeyword)), generate_node(&node));
+}
@@ -0,0 +1,119 @@
+use backyard_generator::{
+  generate_serializable_node_with_options,
+  options::{ BraceStyle, GeneratorOptions },
+};
+use backyard_parser::parse;
+
+#[test]
+fn psr12() {
+  let asts = parse(
+    false,
+    "<?php
+namespace App;
+
+class A extends B {
+  public function a($a, $b) {
+    if ($a) {
+      return function () use ($b) {
+        return $b;
+      };
+    }
+  }
+}
+
+function b() {
+  return new class {
+    public function c() {
+    }
+  };
+}
+
+enum C {
+  case D;
+}"
+  ).unwrap();
+  insta::assert_yaml_snapshot!(
+    generate_serializable_node_with_options(&asts, &GeneratorOptions::psr12()).unwrap()
+  );
+}
+
+#[test]
+fn psr12_multiline_parameters() {
+  let asts = parse(
+    true,
+    "class A {
+  public function a(int $first_parameter, int $second_parameter, int $third_parameter, int $fourth_parameter, int $fifth_parameter): void {
+  }
+}"
+  ).unwrap();
+  insta::assert_yaml_snapshot!(
+    generate_serializable_node_with_options(&asts, &GeneratorOptions::psr12()).unwrap()
+  );
+}
+
+#[test]
+fn indent_and_newline() {
+  let asts = parse(true, "while (true) {\n  if ($a) {\n    break;\n  }\n}").unwrap();
+  let options = GeneratorOptions {
+    indent: "\t".to_string(),
+    newline: "\r\n".to_string(),
+    ..Default::default()
+  };
+  insta::assert_yaml_snapshot!(generate_serializable_node_with_options(&asts, &options).unwrap());
+}
+
+#[test]
+fn control_next_line() {
+  let asts = parse(true, "foreach ($a as $b) {\n  echo $b;\n}").unwrap();
+  let mut options = GeneratorOptions::default();
+  options.braces.control = BraceStyle::NextLine;
+  insta::assert_yaml_snapshot!(generate_serializable_node_with_options(&asts, &options).unwrap());
+}
+
+#[test]
+fn max_length() {
+  let asts = parse(true, "$a = [1, 2, 3];\nfoo($a, $b);").unwrap();
+  let options = GeneratorOptions { max_length: 10, ..Default::default() };
+  insta::assert_yaml_snapshot!(generate_serializable_node_with_options(&asts, &options).unwrap());
+}
+
+#[test]
+fn trailing_comma() {
+  let asts = parse(
+    true,
+    "$a = [1, 2, 3];
+foo($a, $b);
+$c = function ($d, $e) use ($f, $g) {
+};"
+  ).unwrap();
+  let options = GeneratorOptions { max_length: 10, trailing_comma: true, ..Default::default() };
+  insta::assert_yaml_snapshot!(generate_serializable_node_with_options(&asts, &options).unwrap());
+}
+
+#[test]
+fn max_length_indented() {
+  let asts = parse(true, "foo($a, $b);\nif ($a) {\n  if ($b) {\n    foo($a, $b);\n  }\n}").unwrap();
+  let options = GeneratorOptions {
+    indent: "    ".to_string(),
+    max_length: 16,
+    ..Default::default()
+  };
+  insta::assert_yaml_snapshot!(generate_serializable_node_with_options(&asts, &options).unwrap());
+}
+
+#[test]
+fn anonymous_class_braces() {
+  let asts = parse(true, "class A {\n}\n$a = new class {\n};").unwrap();
+  let mut options = GeneratorOptions::default();
+  options.braces.class = BraceStyle::NextLine;
+  assert_eq!(
+    "class A\n{\n}\n$a = new class {\n};",
+    generate_serializable_node_with_options(&asts, &options).unwrap()
+  );
+  options.braces.class = BraceStyle::SameLine;
+  options.braces.closure = BraceStyle::NextLine;
+  assert_eq!(
+    "class A {\n}\n$a = new class\n{\n};",
+    generate_serializable_node_with_options(&asts, &options).unwrap()
+  );
+}
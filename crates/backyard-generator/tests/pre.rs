@@ -30,3 +30,9 @@ fn ambersand() {
   let asts = parse(true, "$a = &$b;").unwrap();
   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
 }
+
+#[test]
+fn bitwise_not() {
+  let asts = parse(true, "~$a;").unwrap();
+  insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
+}
@@ -0,0 +1,175 @@
+use backyard_generator::generate;
+use backyard_nodes::{
+  builder::{ Blueprint, BlueprintBuildable, Builder },
+  AssignmentType,
+  BinaryType,
+  CastType,
+  PostType,
+  PreType,
+};
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+fn assert_generated(expected: &str, statement: Box<Blueprint>) {
+  let arena = Bump::new();
+  let b = Builder::new();
+  let node = b.Program(&[statement]).build(&arena);
+  let code = generate(&node).unwrap();
+  assert_eq!(format!("{};", expected), code);
+  let parsed = parse_in(&arena, true, &code).unwrap();
+  assert_eq!(code, generate(&parsed).unwrap());
+}
+
+fn var(name: &str) -> Box<Blueprint<'_>> {
+  let b = Builder::new();
+  b.Variable(b.Identifier(name))
+}
+
+#[test]
+fn bin() {
+  let b = Builder::new();
+  assert_generated(
+    "($a + $b) * $c",
+    b.Bin(b.Bin(var("a"), BinaryType::Addition, var("b")), BinaryType::Multiplication, var("c"))
+  );
+  assert_generated(
+    "$a * $b + $c",
+    b.Bin(b.Bin(var("a"), BinaryType::Multiplication, var("b")), BinaryType::Addition, var("c"))
+  );
+  assert_generated(
+    "$a - ($b - $c)",
+    b.Bin(var("a"), BinaryType::Subtraction, b.Bin(var("b"), BinaryType::Subtraction, var("c")))
+  );
+  assert_generated(
+    "$a - $b - $c",
+    b.Bin(b.Bin(var("a"), BinaryType::Subtraction, var("b")), BinaryType::Subtraction, var("c"))
+  );
+  assert_generated(
+    "($a ** $b) ** $c",
+    b.Bin(
+      b.Bin(var("a"), BinaryType::Exponentiation, var("b")),
+      BinaryType::Exponentiation,
+      var("c")
+    )
+  );
+  assert_generated(
+    "$a ?? $b ?? $c",
+    b.Bin(var("a"), BinaryType::Coalesce, b.Bin(var("b"), BinaryType::Coalesce, var("c")))
+  );
+  assert_generated(
+    "($a == $b) == $c",
+    b.Bin(b.Bin(var("a"), BinaryType::IsEqual, var("b")), BinaryType::IsEqual, var("c"))
+  );
+}
+
+#[test]
+fn ternary() {
+  let b = Builder::new();
+  assert_generated(
+    "($a ? $b : $c) ? $d : $e",
+    b.Ternary(b.Ternary(var("a"), var("b"), var("c")), var("d"), var("e"))
+  );
+  assert_generated(
+    "$a ? $b : ($c ? $d : $e)",
+    b.Ternary(var("a"), var("b"), b.Ternary(var("c"), var("d"), var("e")))
+  );
+  assert_generated(
+    "$a || $b ? $c : $d",
+    b.Ternary(b.Bin(var("a"), BinaryType::BooleanOr, var("b")), var("c"), var("d"))
+  );
+  assert_generated(
+    "($a ? $b : $c) . $d",
+    b.Bin(b.Ternary(var("a"), var("b"), var("c")), BinaryType::Concatenation, var("d"))
+  );
+}
+
+#[test]
+fn assignment() {
+  let b = Builder::new();
+  assert_generated(
+    "($a = $b) && $c",
+    b.Bin(
+      b.Assignment(var("a"), AssignmentType::Default, var("b")),
+      BinaryType::BooleanAnd,
+      var("c")
+    )
+  );
+  assert_generated(
+    "$a = $b ? $c : $d",
+    b.Assignment(var("a"), AssignmentType::Default, b.Ternary(var("b"), var("c"), var("d")))
+  );
+  assert_generated(
+    "$a = $b = $c",
+    b.Assignment(
+      var("a"),
+      AssignmentType::Default,
+      b.Assignment(var("b"), AssignmentType::Default, var("c"))
+    )
+  );
+}
+
+#[test]
+fn unary() {
+  let b = Builder::new();
+  assert_generated("!($a && $b)", b.Negate(b.Bin(var("a"), BinaryType::BooleanAnd, var("b"))));
+  assert_generated(
+    "!$a instanceof $b",
+    b.Negate(b.Bin(var("a"), BinaryType::InstanceOf, var("b")))
+  );
+  assert_generated(
+    "-($a + $b)",
+    b.Pre(b.Bin(var("a"), BinaryType::Addition, var("b")), PreType::Subtraction)
+  );
+  assert_generated(
+    "-$a ** $b",
+    b.Pre(b.Bin(var("a"), BinaryType::Exponentiation, var("b")), PreType::Subtraction)
+  );
+  assert_generated(
+    "(-$a) ** $b",
+    b.Bin(b.Pre(var("a"), PreType::Subtraction), BinaryType::Exponentiation, var("b"))
+  );
+  assert_generated("-(-$a)", b.Pre(b.Pre(var("a"), PreType::Subtraction), PreType::Subtraction));
+  assert_generated("-(--$a)", b.Pre(b.Pre(var("a"), PreType::Decrement), PreType::Subtraction));
+  assert_generated("+-$a", b.Pre(b.Pre(var("a"), PreType::Subtraction), PreType::Addition));
+  assert_generated(
+    "(int) ($a . $b)",
+    b.Cast(CastType::Int, b.Bin(var("a"), BinaryType::Concatenation, var("b")))
+  );
+  assert_generated(
+    "($a ?? $b)++",
+    b.Post(b.Bin(var("a"), BinaryType::Coalesce, var("b")), PostType::Increment)
+  );
+}
+
+#[test]
+fn new_and_clone() {
+  let b = Builder::new();
+  assert_generated("clone ($a ?: $b)", b.Clone(b.Bin(var("a"), BinaryType::Elvis, var("b"))));
+  assert_generated("new ($a . $b)", b.New(b.Bin(var("a"), BinaryType::Concatenation, var("b"))));
+  assert_generated("clone $a", b.Clone(var("a")));
+  assert_generated(
+    "new A + 1",
+    b.Bin(b.New(b.Identifier("A")), BinaryType::Addition, b.Number("1"))
+  );
+}
+
+#[test]
+fn logical_keywords() {
+  let b = Builder::new();
+  assert_generated(
+    "$a = 1 and $b",
+    b.Bin(
+      b.Assignment(var("a"), AssignmentType::Default, b.Number("1")),
+      BinaryType::LogicalAnd,
+      var("b")
+    )
+  );
+  assert_generated(
+    "$a or $b && $c",
+    b.Bin(var("a"), BinaryType::LogicalOr, b.Bin(var("b"), BinaryType::BooleanAnd, var("c")))
+  );
+  assert_generated(
+    "($a or $b) && $c",
+    b.Bin(b.Bin(var("a"), BinaryType::LogicalOr, var("b")), BinaryType::BooleanAnd, var("c"))
+  );
+}
@@ -1,4 +1,4 @@
-use backyard_generator::generate_serializable_node;
+use backyard_generator::{ generate_serializable_node, quote_string };
 use backyard_parser::parse;
 
 #[test]
@@ -28,3 +28,13 @@ a {$a}
 START;;").unwrap();
   insta::assert_yaml_snapshot!(generate_serializable_node(&asts).unwrap());
 }
+
+#[test]
+fn quote() {
+  assert_eq!("'plain'", quote_string(b"plain"));
+  assert_eq!("'a $b \\\\ \"c\"'", quote_string(b"a $b \\ \"c\""));
+  assert_eq!("\"it's\"", quote_string(b"it's"));
+  assert_eq!("\"a\\nb\\t\\x00\"", quote_string(b"a\nb\t\0"));
+  assert_eq!("\"\\xFF \\$a\"", quote_string(b"\xff $a"));
+  assert_eq!("'\u{1F600}'", quote_string("\u{1F600}".as_bytes()));
+}
@@ -1,5 +1,7 @@
 use std::fmt::{ Display, Formatter };
 
+use crate::version::PhpVersion;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum LexError {
   Unrecognized {
@@ -8,6 +10,13 @@ pub enum LexError {
     column: usize,
   },
   Eof,
+  /// `token` was introduced after the targeted PHP version
+  Unsupported {
+    token: String,
+    version: PhpVersion,
+    line: usize,
+    column: usize,
+  },
 }
 
 impl Display for LexError {
@@ -16,6 +25,15 @@ impl Display for LexError {
       LexError::Unrecognized { token, line, column } =>
         write!(f, "Unrecognized character '{}' at line {}, column {}", token, line, column),
       LexError::Eof => write!(f, "End of file"),
+      LexError::Unsupported { token, version, line, column } =>
+        write!(
+          f,
+          "Unsupported syntax '{}' at line {}, column {}, requires PHP {}",
+          token,
+          line,
+          column,
+          version
+        ),
     }
   }
 }
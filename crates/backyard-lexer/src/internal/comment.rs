@@ -1,7 +1,15 @@
-use bstr::{ BString, ByteSlice };
+use bstr::ByteSlice;
 
 use crate::error::{ LexError, LexResult };
-use crate::lexer::{ ControlSnapshot, Lexer, SeriesChecker, SeriesCheckerMode };
+use crate::lexer::{
+  find_keyword,
+  to_lowercase,
+  ControlSnapshot,
+  Lexer,
+  SeriesChecker,
+  SeriesCheckerMode,
+  KEYWORD_BUFFER_LEN,
+};
 use crate::token::{ Token, TokenType };
 
 use super::number::NumberToken;
@@ -15,14 +23,14 @@ impl CommentToken {
     take_prev_len: usize,
     snapshot: &ControlSnapshot
   ) -> LexResult {
-    let againsts = [b"*/".into()];
+    let againsts: [&[u8]; 1] = [b"*/"];
     let mut checker = SeriesChecker::new(&againsts, SeriesCheckerMode::Comment);
-    let mut comment = lexer.control.next_char_until(take_prev_len, |_, ch, _| {
+    let comment = lexer.control.next_char_until(take_prev_len, |_, ch, _| {
       checker.push(ch);
       checker.check().is_some()
     });
     lexer.control.next_char();
-    comment.pop();
+    let comment = &comment[..comment.len() - 1];
     lexer.tokens.push(Token::new(TokenType::CommentBlock, comment, snapshot));
     Ok(())
   }
@@ -32,16 +40,16 @@ impl CommentToken {
     take_prev_len: usize,
     snapshot: &ControlSnapshot
   ) -> LexResult {
-    let againsts = [b"\n".into(), b"?>".into()];
+    let againsts: [&[u8]; 2] = [b"\n", b"?>"];
     let mut checker = SeriesChecker::new(&againsts, SeriesCheckerMode::Comment);
     let mut comment = lexer.control.next_char_until(take_prev_len, |_, ch, _| {
       checker.push(ch);
       checker.check().is_some()
     });
     if let Some(breaker) = checker.check() {
-      if breaker == "?>" {
+      if breaker == b"?>" {
         lexer.control.next_char();
-        comment.pop();
+        comment = &comment[..comment.len() - 1];
       }
     }
     lexer.tokens.push(Token::new(TokenType::CommentLine, comment, snapshot));
@@ -53,6 +61,7 @@ impl CommentToken {
     mut take_prev_len: usize,
     snapshot: &ControlSnapshot
   ) -> LexResult {
+    let open = lexer.tokens.len();
     lexer.tokens.push(Token::new(TokenType::CommentDocOpen, b"/**".into(), snapshot));
     lexer.control.next_char_until(0, |_, ch, _| !matches!(ch, b'\t' | b'\x0C' | b' '));
     while let Ok(_) = Self::get_next_doc_token(lexer, &mut take_prev_len) {
@@ -65,6 +74,8 @@ impl CommentToken {
         }
       }
     }
+    let end = lexer.control.get_position();
+    lexer.tokens[open].value = lexer.control.get_slice(snapshot.offset, end);
     Ok(())
   }
 
@@ -84,7 +95,7 @@ impl CommentToken {
 
     match current_char {
       b'$' => {
-        let t: BString = lexer.until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'_'));
+        let t = lexer.until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'_'));
         if t == b"$this" {
           lexer.tokens.push(Token::new(TokenType::This, t, snapshot));
           return Ok(());
@@ -97,259 +108,17 @@ impl CommentToken {
         lexer.tokens.push(Token::new(TokenType::DocUnknown, t, snapshot));
       }
       b'@' => {
-        let t: BString = lexer
-          .until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'-'))
-          .to_lowercase()
-          .into();
-        match t.as_slice() {
-          b"@param" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagParam, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-param" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanParam, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-param" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmParam, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-param" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanParam, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@param-immediately-invoked-callable" =>
-            lexer.tokens.push(
-              Token::new(TokenType::DocTagParamImmediatelyInvokedCallable, t, snapshot)
-            ),
-          b"@phpstan-param-immediately-invoked-callable" =>
-            lexer.tokens.push(
-              Token::new(TokenType::DocTagPhpstanParamImmediatelyInvokedCallable, t, snapshot)
-            ),
-          b"@param-later-invoked-callable" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagParamLaterInvokedCallable, t, snapshot)),
-          b"@phpstan-param-later-invoked-callable" =>
-            lexer.tokens.push(
-              Token::new(TokenType::DocTagPhpstanParamLaterInvokedCallable, t, snapshot)
-            ),
-          b"@param-closure-this" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagParamClosureThis, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-param-closure-this" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanParamClosureThis, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@pure-unless-callable-is-impure" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPureUnlessCallableIsImpure, t, snapshot)),
-          b"@phpstan-pure-unless-callable-is-impure" =>
-            lexer.tokens.push(
-              Token::new(TokenType::DocTagPhpstanPureUnlessCallableIsImpure, t, snapshot)
-            ),
-          b"@var" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagVar, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-var" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanVar, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-var" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmVar, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-var" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanVar, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@return" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagReturn, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-return" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanReturn, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-return" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmReturn, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-return" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanReturn, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-real-return" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanRealReturn, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@throws" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagThrows, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-throws" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanThrows, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@mixin" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagMixin, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-mixin" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanMixin, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-require-extends" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmRequireExtends, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-require-extends" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanRequireExtends, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-require-implements" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmRequireImplements, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-require-implements" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanRequireImplements, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@deprecated" => lexer.tokens.push(Token::new(TokenType::DocTagDeprecated, t, snapshot)),
-          b"@property" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagProperty, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@property-read" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPropertyRead, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@property-write" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPropertyWrite, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-property" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanProperty, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-property-read" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanPropertyRead, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phpstan-property-write" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanPropertyWrite, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-property" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmProperty, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-property-read" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmPropertyRead, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@psalm-property-write" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmPropertyWrite, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-property" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanProperty, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-property-read" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanPropertyRead, t, snapshot));
-            Self::try_parse_type(lexer)?;
-          }
-          b"@phan-property-write" => {
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanPropertyWrite, t, snapshot));
-            Self::try_parse_type(lexer)?;
+        let t = lexer.until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'-'));
+        let mut buffer = [0; KEYWORD_BUFFER_LEN];
+        let lowercase = to_lowercase(t, &mut buffer);
+        match DOC_TAGS.iter().find(|(tag, _, _)| *tag == lowercase) {
+          Some((tag, token_type, has_type)) => {
+            lexer.tokens.push(Token::new(*token_type, tag.as_bstr(), snapshot));
+            if *has_type {
+              Self::try_parse_type(lexer)?;
+            }
           }
-          b"@method" => lexer.tokens.push(Token::new(TokenType::DocTagMethod, t, snapshot)),
-          b"@phpstan-method" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanMethod, t, snapshot)),
-          b"@psalm-method" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmMethod, t, snapshot)),
-          b"@phan-method" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanMethod, t, snapshot)),
-          b"@template" => lexer.tokens.push(Token::new(TokenType::DocTagTemplate, t, snapshot)),
-          b"@phpstan-template" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanTemplate, t, snapshot)),
-          b"@psalm-template" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmTemplate, t, snapshot)),
-          b"@phan-template" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanTemplate, t, snapshot)),
-          b"@template-covariant" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagTemplateCovariant, t, snapshot)),
-          b"@phpstan-template-covariant" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanTemplateCovariant, t, snapshot)),
-          b"@psalm-template-covariant" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmTemplateCovariant, t, snapshot)),
-          b"@template-contravariant" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagTemplateContravariant, t, snapshot)),
-          b"@phpstan-template-contravariant" =>
-            lexer.tokens.push(
-              Token::new(TokenType::DocTagPhpstanTemplateContravariant, t, snapshot)
-            ),
-          b"@psalm-template-contravariant" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmTemplateContravariant, t, snapshot)),
-          b"@extends" => lexer.tokens.push(Token::new(TokenType::DocTagExtends, t, snapshot)),
-          b"@phpstan-extends" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanExtends, t, snapshot)),
-          b"@phan-extends" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanExtends, t, snapshot)),
-          b"@phan-inherits" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanInherits, t, snapshot)),
-          b"@template-extends" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagTemplateExtends, t, snapshot)),
-          b"@implements" => lexer.tokens.push(Token::new(TokenType::DocTagImplements, t, snapshot)),
-          b"@phpstan-implements" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanImplements, t, snapshot)),
-          b"@template-implements" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagTemplateImplements, t, snapshot)),
-          b"@use" => lexer.tokens.push(Token::new(TokenType::DocTagUse, t, snapshot)),
-          b"@phpstan-use" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanUse, t, snapshot)),
-          b"@template-use" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagTemplateUse, t, snapshot)),
-          b"@phpstan-type" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanType, t, snapshot)),
-          b"@psalm-type" => lexer.tokens.push(Token::new(TokenType::DocTagPsalmType, t, snapshot)),
-          b"@phan-type" => lexer.tokens.push(Token::new(TokenType::DocTagPhanType, t, snapshot)),
-          b"@phpstan-import-type" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanImportType, t, snapshot)),
-          b"@psalm-import-type" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmImportType, t, snapshot)),
-          b"@phpstan-assert" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanAssert, t, snapshot)),
-          b"@phpstan-assert-if-true" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanAssertIfTrue, t, snapshot)),
-          b"@phpstan-assert-if-false" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanAssertIfFalse, t, snapshot)),
-          b"@psalm-assert" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmAssert, t, snapshot)),
-          b"@psalm-assert-if-true" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmAssertIfTrue, t, snapshot)),
-          b"@psalm-assert-if-false" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmAssertIfFalse, t, snapshot)),
-          b"@phan-assert" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanAssert, t, snapshot)),
-          b"@phan-assert-if-true" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanAssertIfTrue, t, snapshot)),
-          b"@phan-assert-if-false" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhanAssertIfFalse, t, snapshot)),
-          b"@phpstan-this-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanThisOut, t, snapshot)),
-          b"@phpstan-self-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanSelfOut, t, snapshot)),
-          b"@psalm-this-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmThisOut, t, snapshot)),
-          b"@psalm-self-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmSelfOut, t, snapshot)),
-          b"@param-out" => lexer.tokens.push(Token::new(TokenType::DocTagParamOut, t, snapshot)),
-          b"@phpstan-param-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPhpstanParamOut, t, snapshot)),
-          b"@psalm-param-out" =>
-            lexer.tokens.push(Token::new(TokenType::DocTagPsalmParamOut, t, snapshot)),
-          _ => lexer.tokens.push(Token::new(TokenType::DocUnknown, t, snapshot)),
+          None => lexer.tokens.push(Token::new(TokenType::DocUnknown, t, snapshot)),
         }
       }
       b'*' => {
@@ -422,7 +191,7 @@ impl CommentToken {
           }
           expect_non_type = false;
         }
-        if *next_char == b'$' {
+        if [b'$', b'@'].contains(next_char) {
           break;
         }
       }
@@ -448,10 +217,9 @@ impl CommentToken {
               lexer.tokens.push(Token::new(TokenType::QualifiedName, t, snapshot));
             }
           } else {
-            let t_lowercase: BString = t.to_ascii_lowercase().into();
-            let t_lowercase_sliced = t_lowercase.as_slice();
-            if TYPE_KEYWORDS.contains(&t_lowercase_sliced) {
-              lexer.tokens.push(Token::new(TokenType::Type, t_lowercase, snapshot));
+            let mut buffer = [0; KEYWORD_BUFFER_LEN];
+            if let Some(type_name) = find_keyword(TYPE_KEYWORDS, to_lowercase(t, &mut buffer)) {
+              lexer.tokens.push(Token::new(TokenType::Type, type_name, snapshot));
             } else {
               lexer.tokens.push(Token::new(TokenType::UnqualifiedName, t, snapshot));
             }
@@ -509,6 +277,8 @@ impl CommentToken {
           }
         }
         _ => {
+          let t = lexer.until(|ch| ch.is_ascii_whitespace() || ch == b'*' || ch == b'@');
+          lexer.tokens.push(Token::new(TokenType::DocUnknown, t, snapshot));
           break;
         }
       };
@@ -536,3 +306,95 @@ const TYPE_KEYWORDS: &[&[u8]] = &[
   b"null",
   b"callable",
 ];
+
+/// Known doc tags, their token type and whether a type follows them
+const DOC_TAGS: &[(&[u8], TokenType, bool)] = &[
+  (b"@param", TokenType::DocTagParam, true),
+  (b"@phpstan-param", TokenType::DocTagPhpstanParam, true),
+  (b"@psalm-param", TokenType::DocTagPsalmParam, true),
+  (b"@phan-param", TokenType::DocTagPhanParam, true),
+  (b"@param-immediately-invoked-callable", TokenType::DocTagParamImmediatelyInvokedCallable, false),
+  (b"@phpstan-param-immediately-invoked-callable", TokenType::DocTagPhpstanParamImmediatelyInvokedCallable, false),
+  (b"@param-later-invoked-callable", TokenType::DocTagParamLaterInvokedCallable, false),
+  (b"@phpstan-param-later-invoked-callable", TokenType::DocTagPhpstanParamLaterInvokedCallable, false),
+  (b"@param-closure-this", TokenType::DocTagParamClosureThis, true),
+  (b"@phpstan-param-closure-this", TokenType::DocTagPhpstanParamClosureThis, true),
+  (b"@pure-unless-callable-is-impure", TokenType::DocTagPureUnlessCallableIsImpure, false),
+  (b"@phpstan-pure-unless-callable-is-impure", TokenType::DocTagPhpstanPureUnlessCallableIsImpure, false),
+  (b"@var", TokenType::DocTagVar, true),
+  (b"@phpstan-var", TokenType::DocTagPhpstanVar, true),
+  (b"@psalm-var", TokenType::DocTagPsalmVar, true),
+  (b"@phan-var", TokenType::DocTagPhanVar, true),
+  (b"@return", TokenType::DocTagReturn, true),
+  (b"@phpstan-return", TokenType::DocTagPhpstanReturn, true),
+  (b"@psalm-return", TokenType::DocTagPsalmReturn, true),
+  (b"@phan-return", TokenType::DocTagPhanReturn, true),
+  (b"@phan-real-return", TokenType::DocTagPhanRealReturn, true),
+  (b"@throws", TokenType::DocTagThrows, true),
+  (b"@phpstan-throws", TokenType::DocTagPhpstanThrows, true),
+  (b"@mixin", TokenType::DocTagMixin, true),
+  (b"@phan-mixin", TokenType::DocTagPhanMixin, true),
+  (b"@psalm-require-extends", TokenType::DocTagPsalmRequireExtends, true),
+  (b"@phpstan-require-extends", TokenType::DocTagPhpstanRequireExtends, true),
+  (b"@psalm-require-implements", TokenType::DocTagPsalmRequireImplements, true),
+  (b"@phpstan-require-implements", TokenType::DocTagPhpstanRequireImplements, true),
+  (b"@deprecated", TokenType::DocTagDeprecated, false),
+  (b"@property", TokenType::DocTagProperty, true),
+  (b"@property-read", TokenType::DocTagPropertyRead, true),
+  (b"@property-write", TokenType::DocTagPropertyWrite, true),
+  (b"@phpstan-property", TokenType::DocTagPhpstanProperty, true),
+  (b"@phpstan-property-read", TokenType::DocTagPhpstanPropertyRead, true),
+  (b"@phpstan-property-write", TokenType::DocTagPhpstanPropertyWrite, true),
+  (b"@psalm-property", TokenType::DocTagPsalmProperty, true),
+  (b"@psalm-property-read", TokenType::DocTagPsalmPropertyRead, true),
+  (b"@psalm-property-write", TokenType::DocTagPsalmPropertyWrite, true),
+  (b"@phan-property", TokenType::DocTagPhanProperty, true),
+  (b"@phan-property-read", TokenType::DocTagPhanPropertyRead, true),
+  (b"@phan-property-write", TokenType::DocTagPhanPropertyWrite, true),
+  (b"@method", TokenType::DocTagMethod, false),
+  (b"@phpstan-method", TokenType::DocTagPhpstanMethod, false),
+  (b"@psalm-method", TokenType::DocTagPsalmMethod, false),
+  (b"@phan-method", TokenType::DocTagPhanMethod, false),
+  (b"@template", TokenType::DocTagTemplate, false),
+  (b"@phpstan-template", TokenType::DocTagPhpstanTemplate, false),
+  (b"@psalm-template", TokenType::DocTagPsalmTemplate, false),
+  (b"@phan-template", TokenType::DocTagPhanTemplate, false),
+  (b"@template-covariant", TokenType::DocTagTemplateCovariant, false),
+  (b"@phpstan-template-covariant", TokenType::DocTagPhpstanTemplateCovariant, false),
+  (b"@psalm-template-covariant", TokenType::DocTagPsalmTemplateCovariant, false),
+  (b"@template-contravariant", TokenType::DocTagTemplateContravariant, false),
+  (b"@phpstan-template-contravariant", TokenType::DocTagPhpstanTemplateContravariant, false),
+  (b"@psalm-template-contravariant", TokenType::DocTagPsalmTemplateContravariant, false),
+  (b"@extends", TokenType::DocTagExtends, false),
+  (b"@phpstan-extends", TokenType::DocTagPhpstanExtends, false),
+  (b"@phan-extends", TokenType::DocTagPhanExtends, false),
+  (b"@phan-inherits", TokenType::DocTagPhanInherits, false),
+  (b"@template-extends", TokenType::DocTagTemplateExtends, false),
+  (b"@implements", TokenType::DocTagImplements, false),
+  (b"@phpstan-implements", TokenType::DocTagPhpstanImplements, false),
+  (b"@template-implements", TokenType::DocTagTemplateImplements, false),
+  (b"@use", TokenType::DocTagUse, false),
+  (b"@phpstan-use", TokenType::DocTagPhpstanUse, false),
+  (b"@template-use", TokenType::DocTagTemplateUse, false),
+  (b"@phpstan-type", TokenType::DocTagPhpstanType, false),
+  (b"@psalm-type", TokenType::DocTagPsalmType, false),
+  (b"@phan-type", TokenType::DocTagPhanType, false),
+  (b"@phpstan-import-type", TokenType::DocTagPhpstanImportType, false),
+  (b"@psalm-import-type", TokenType::DocTagPsalmImportType, false),
+  (b"@phpstan-assert", TokenType::DocTagPhpstanAssert, false),
+  (b"@phpstan-assert-if-true", TokenType::DocTagPhpstanAssertIfTrue, false),
+  (b"@phpstan-assert-if-false", TokenType::DocTagPhpstanAssertIfFalse, false),
+  (b"@psalm-assert", TokenType::DocTagPsalmAssert, false),
+  (b"@psalm-assert-if-true", TokenType::DocTagPsalmAssertIfTrue, false),
+  (b"@psalm-assert-if-false", TokenType::DocTagPsalmAssertIfFalse, false),
+  (b"@phan-assert", TokenType::DocTagPhanAssert, false),
+  (b"@phan-assert-if-true", TokenType::DocTagPhanAssertIfTrue, false),
+  (b"@phan-assert-if-false", TokenType::DocTagPhanAssertIfFalse, false),
+  (b"@phpstan-this-out", TokenType::DocTagPhpstanThisOut, false),
+  (b"@phpstan-self-out", TokenType::DocTagPhpstanSelfOut, false),
+  (b"@psalm-this-out", TokenType::DocTagPsalmThisOut, false),
+  (b"@psalm-self-out", TokenType::DocTagPsalmSelfOut, false),
+  (b"@param-out", TokenType::DocTagParamOut, false),
+  (b"@phpstan-param-out", TokenType::DocTagPhpstanParamOut, false),
+  (b"@psalm-param-out", TokenType::DocTagPsalmParamOut, false),
+];
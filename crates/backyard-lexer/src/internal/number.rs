@@ -2,35 +2,83 @@ use crate::error::LexResult;
 use crate::lexer::{ ControlSnapshot, Lexer };
 use crate::token::{ Token, TokenType };
 
+type IsDigit = fn(&u8) -> bool;
+
 pub struct NumberToken;
 
 impl NumberToken {
+  /// Lexes every PHP 8.1 numeric literal, `current_char` is either a digit or the `.` of a
+  /// float without integer part
   pub fn lex(lexer: &mut Lexer, current_char: &u8, snapshot: &ControlSnapshot) -> LexResult {
+    let start = lexer.control.get_position() - 1;
     if *current_char == b'0' {
-      if let Some(next) = lexer.control.peek_char(None) {
-        if *next == b'x' {
-          lexer.control.next_char();
-          let t = lexer.control.next_char_until(2, |_, ch, _| !ch.is_ascii_alphanumeric());
-          return {
-            lexer.tokens.push(Token::new(TokenType::NumberHex, t, snapshot));
-            Ok(())
-          };
-        }
-        if *next == b'b' {
-          lexer.control.next_char();
-          let t = lexer.control.next_char_until(
-            2,
-            |_, ch, _| !(ch == b'0' || ch == b'1' || ch == b'_')
-          );
-          return {
-            lexer.tokens.push(Token::new(TokenType::NumberBinary, t, snapshot));
-            Ok(())
-          };
+      let prefixed: Option<(TokenType, IsDigit)> = match lexer.control.peek_char(None) {
+        Some(b'x' | b'X') => Some((TokenType::NumberHex, u8::is_ascii_hexdigit)),
+        Some(b'b' | b'B') => Some((TokenType::NumberBinary, Self::is_binary)),
+        Some(b'o' | b'O') => Some((TokenType::NumberOctal, Self::is_octal)),
+        _ => None,
+      };
+      if let Some((token_type, is_digit)) = prefixed {
+        let end = Self::digits(lexer, start + 2, is_digit);
+        if end > start + 2 {
+          return Self::push(lexer, token_type, end, snapshot);
         }
       }
     }
-    let t = lexer.control.next_char_until(1, |_, ch, _| !(ch.is_ascii_digit() || ch == b'.'));
-    lexer.tokens.push(Token::new(TokenType::Number, t, snapshot));
+    let mut end = Self::digits(lexer, start, u8::is_ascii_digit);
+    if lexer.control.peek_char(Some(end)) == Some(&b'.') {
+      end = Self::digits(lexer, end + 1, u8::is_ascii_digit);
+    }
+    if let Some(b'e' | b'E') = lexer.control.peek_char(Some(end)) {
+      let mut position = end + 1;
+      if let Some(b'+' | b'-') = lexer.control.peek_char(Some(position)) {
+        position += 1;
+      }
+      let exponent = Self::digits(lexer, position, u8::is_ascii_digit);
+      if exponent > position {
+        end = exponent;
+      }
+    }
+    Self::push(lexer, TokenType::Number, end, snapshot)
+  }
+
+  fn push(
+    lexer: &mut Lexer,
+    token_type: TokenType,
+    end: usize,
+    snapshot: &ControlSnapshot
+  ) -> LexResult {
+    let t = lexer.control.next_char_until(1, |_, _, position| *position >= end);
+    lexer.tokens.push(Token::new(token_type, t, snapshot));
     Ok(())
   }
+
+  /// End of the digits starting at `start`, single underscores are allowed between digits
+  fn digits(lexer: &mut Lexer, start: usize, is_digit: IsDigit) -> usize {
+    let mut position = start;
+    loop {
+      match lexer.control.peek_char(Some(position)).copied() {
+        Some(ch) if is_digit(&ch) => {
+          position += 1;
+        }
+        Some(b'_') if
+          position > start &&
+          lexer.control.peek_char(Some(position + 1)).is_some_and(is_digit)
+        => {
+          position += 2;
+        }
+        _ => {
+          return position;
+        }
+      }
+    }
+  }
+
+  fn is_binary(ch: &u8) -> bool {
+    *ch == b'0' || *ch == b'1'
+  }
+
+  fn is_octal(ch: &u8) -> bool {
+    (b'0'..=b'7').contains(ch)
+  }
 }
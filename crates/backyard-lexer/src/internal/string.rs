@@ -1,4 +1,4 @@
-use bstr::{ BString, ByteSlice, ByteVec };
+use bstr::ByteSlice;
 
 use crate::error::{ LexError, LexResult };
 use crate::internal::variable::VariableToken;
@@ -10,7 +10,7 @@ pub struct StringToken;
 impl StringToken {
   fn get_parts(
     lexer: &mut Lexer,
-    breaker: BString,
+    breaker: &[u8],
     mode: SeriesCheckerMode
   ) -> Result<bool, LexError> {
     let mut token_count = 0;
@@ -20,7 +20,7 @@ impl StringToken {
     let mut checker = SeriesChecker::new(&againsts, mode);
     loop {
       let snapshot = lexer.control.get_snapshot();
-      let mut t = lexer.control.next_char_until(0, |control, ch, end_position| {
+      let t = lexer.control.next_char_until(0, |control, ch, end_position| {
         checker.push(ch);
         if need_check_condition.contains(&ch) {
           if checker.check().is_some() {
@@ -58,8 +58,8 @@ impl StringToken {
         break;
       };
       if checker.check().is_some() {
-        t.push(current);
-        t = t[..t.len() - breaker_len].into();
+        let end = snapshot.offset + t.len() + 1 - breaker_len;
+        let t = lexer.control.get_slice(snapshot.offset, end);
         lexer.tokens.push(Token::new(TokenType::EncapsedString, t, &snapshot));
         lexer.control.next_char();
         break;
@@ -93,44 +93,42 @@ impl StringToken {
   }
 
   pub fn lex_basic(lexer: &mut Lexer, snapshot: &ControlSnapshot) -> LexResult {
-    let breaker: BString = [b'\''].into();
-    let checker_breaker = [breaker.clone()];
+    let checker_breaker: [&[u8]; 1] = [b"'"];
     let mut checker = SeriesChecker::new(&checker_breaker, SeriesCheckerMode::String);
-    let mut text = lexer.control.next_char_until(1, |_, i, _| {
+    lexer.control.next_char_until(1, |_, i, _| {
       checker.push(i);
       checker.check().is_some()
     });
     lexer.control.next_char();
-    text.push_str(breaker);
+    let text = lexer.control.get_slice(snapshot.offset, lexer.control.get_position());
     lexer.tokens.push(Token::new(TokenType::String, text, snapshot));
     Ok(())
   }
 
-  pub fn lex(lexer: &mut Lexer, breaker: BString, snapshot: &ControlSnapshot) -> LexResult {
-    lexer.tokens.push(Token::new(TokenType::EncapsedStringOpen, breaker.clone(), snapshot));
+  pub fn lex(lexer: &mut Lexer, breaker: &'static [u8], snapshot: &ControlSnapshot) -> LexResult {
+    lexer.tokens.push(Token::new(TokenType::EncapsedStringOpen, breaker.as_bstr(), snapshot));
 
-    let is_without_encapsed = Self::get_parts(lexer, breaker.clone(), SeriesCheckerMode::String)?;
-    if is_without_encapsed {
-      if let Some(string_token) = lexer.tokens.pop() {
-        lexer.tokens.pop();
-        let mut value = string_token.value;
-        value.insert_str(0, breaker.clone());
-        value.push_str(breaker);
-        lexer.tokens.push(Token::new(TokenType::String, value, snapshot));
-        return Ok(());
-      }
+    let is_without_encapsed = Self::get_parts(lexer, breaker, SeriesCheckerMode::String)?;
+    if is_without_encapsed && lexer.tokens.pop().is_some() {
+      lexer.tokens.pop();
+      let value = lexer.control.get_slice(snapshot.offset, lexer.control.get_position());
+      lexer.tokens.push(Token::new(TokenType::String, value, snapshot));
+      return Ok(());
     }
 
     lexer.tokens.push(
-      Token::new(TokenType::EncapsedStringClose, breaker, lexer.control.get_last_snapshot())
+      Token::new(
+        TokenType::EncapsedStringClose,
+        breaker.as_bstr(),
+        lexer.control.get_last_snapshot()
+      )
     );
 
     Ok(())
   }
 
   pub fn lex_doc(lexer: &mut Lexer, snapshot: &ControlSnapshot) -> LexResult {
-    let label = lexer.control.next_char_until(0, |_, i, _| i == b'\n');
-    let label = BString::new(label.trim().to_vec());
+    let label = lexer.control.next_char_until(0, |_, i, _| i == b'\n').trim().as_bstr();
     if
       !label
         .iter()
@@ -145,11 +143,8 @@ impl StringToken {
       return Err(lexer.control.error_unrecognized(label.to_string().split_off(1)));
     }
     if label.starts_with(b"\'") && label.ends_with(b"\'") {
-      let clean_label: BString = label
-        .get(1..label.len() - 1)
-        .unwrap()
-        .into();
-      let againsts = [clean_label.clone()];
+      let clean_label = label[1..label.len() - 1].as_bstr();
+      let againsts = [clean_label.as_bytes()];
       let mut checker = SeriesChecker::new(&againsts, SeriesCheckerMode::Heredoc);
       let mut should_break = false;
       let content_snapshot = lexer.control.get_snapshot();
@@ -159,16 +154,16 @@ impl StringToken {
         should_break = checker.check().is_some();
         t
       });
-      let text = text[..text.len() - clean_label.len() - 1].into();
-      lexer.tokens.push(Token::new(TokenType::NowDocOpen, clean_label.clone(), snapshot));
+      let text = &text[..text.len() - clean_label.len()];
+      lexer.tokens.push(Token::new(TokenType::NowDocOpen, clean_label, snapshot));
       lexer.tokens.push(Token::new(TokenType::EncapsedString, text, &content_snapshot));
       lexer.tokens.push(
         Token::new(TokenType::NowDocClose, clean_label, lexer.control.get_last_snapshot())
       );
       Ok(())
     } else {
-      lexer.tokens.push(Token::new(TokenType::HeredocOpen, label.clone(), snapshot));
-      Self::get_parts(lexer, label.clone(), SeriesCheckerMode::Heredoc)?;
+      lexer.tokens.push(Token::new(TokenType::HeredocOpen, label, snapshot));
+      Self::get_parts(lexer, label, SeriesCheckerMode::Heredoc)?;
       lexer.tokens.push(
         Token::new(TokenType::HeredocClose, label, lexer.control.get_last_snapshot())
       );
@@ -1,9 +1,10 @@
-use bstr::{ BString, ByteSlice };
+use bstr::{ BStr, BString, ByteSlice };
 use bumpalo::Bump;
 
 use crate::error::{ LexError, LexResult };
 use crate::internal::inline::InlineToken;
-use crate::token::{ Token, TokenType };
+use crate::token::{ Position, Token, TokenType, Trivia };
+use crate::version::PhpVersion;
 use crate::internal::{
   comment::CommentToken,
   keywords::KeywordToken,
@@ -20,16 +21,16 @@ pub(crate) struct ControlSnapshot {
 }
 
 #[derive(Debug)]
-pub(crate) struct Control {
-  chars: BString,
+pub(crate) struct Control<'a> {
+  chars: &'a [u8],
   position: usize,
   line: usize,
   column: usize,
   last_snapshot: ControlSnapshot,
 }
 
-impl Control {
-  pub(crate) fn new(chars: BString) -> Self {
+impl<'a> Control<'a> {
+  pub(crate) fn new(chars: &'a [u8]) -> Self {
     Control {
       chars,
       position: 0,
@@ -70,13 +71,20 @@ impl Control {
     self.chars.get(p)
   }
 
-  pub(crate) fn peek_char_n(&mut self, pos: Option<usize>, n: usize) -> Option<BString> {
+  pub(crate) fn peek_char_n(&mut self, pos: Option<usize>, n: usize) -> Option<&'a BStr> {
     let p = if let Some(pos) = pos { pos } else { self.position };
-    self.chars.get(p..p + n).map(|x| x.into())
+    self.chars.get(p..p + n).map(|x| x.as_bstr())
   }
 
-  pub(crate) fn next_char(&mut self) -> Option<&u8> {
-    if let Some(c) = self.chars.get(self.position) {
+  /// Input between the `start` and `end` offsets, tokens borrow their values from it
+  #[inline]
+  pub(crate) fn get_slice(&self, start: usize, end: usize) -> &'a BStr {
+    self.chars[start..end].as_bstr()
+  }
+
+  pub(crate) fn next_char(&mut self) -> Option<&'a u8> {
+    let chars = self.chars;
+    if let Some(c) = chars.get(self.position) {
       self.last_snapshot = self.get_snapshot();
       self.position += 1;
       if *c == b'\n' {
@@ -91,8 +99,8 @@ impl Control {
     }
   }
 
-  pub(crate) fn next_char_until<F>(&mut self, take_prev_len: usize, mut until: F) -> BString
-    where F: FnMut(&mut Control, u8, &usize) -> bool
+  pub(crate) fn next_char_until<F>(&mut self, take_prev_len: usize, mut until: F) -> &'a BStr
+    where F: FnMut(&mut Control<'a>, u8, &usize) -> bool
   {
     let start_position = self.position;
     let mut end_position = self.position;
@@ -123,7 +131,7 @@ impl Control {
     self.column = column;
     self.position = end_position;
     self.last_snapshot = last_snapshot;
-    self.chars[start_position - take_prev_len..end_position].into()
+    self.chars[start_position - take_prev_len..end_position].as_bstr()
   }
 
   #[inline]
@@ -134,13 +142,11 @@ impl Control {
 
 #[cfg(test)]
 mod tests {
-  use bstr::BString;
-
   use super::Control;
 
   #[test]
   fn control() {
-    let mut control = Control::new(BString::new(b"<?php\necho 'hello world';\n?>".to_vec()));
+    let mut control = Control::new(b"<?php\necho 'hello world';\n?>");
     assert_eq!(Some(&b'<'), control.next_char());
     assert_eq!(Some(&b'?'), control.next_char());
 
@@ -169,13 +175,13 @@ pub enum SeriesCheckerMode {
 #[derive(Debug)]
 pub struct SeriesChecker<'a> {
   list: BString,
-  againsts: &'a [BString],
+  againsts: &'a [&'a [u8]],
   mode: SeriesCheckerMode,
-  last_result: Option<Option<&'a BString>>,
+  last_result: Option<Option<&'a [u8]>>,
 }
 
 impl<'a> SeriesChecker<'a> {
-  pub fn new(againsts: &'a [BString], mode: SeriesCheckerMode) -> Self {
+  pub fn new(againsts: &'a [&'a [u8]], mode: SeriesCheckerMode) -> Self {
     Self { list: BString::new(vec![]), againsts, mode, last_result: None }
   }
 
@@ -204,19 +210,19 @@ impl<'a> SeriesChecker<'a> {
     }
   }
 
-  pub fn check(&mut self) -> Option<&BString> {
+  pub fn check(&mut self) -> Option<&'a [u8]> {
     if self.last_result.is_some() {
       return self.last_result.unwrap();
     }
     if self.mode == SeriesCheckerMode::Heredoc {
       if let Some(label) = self.againsts.first() {
-        let result = if self.list.trim() == *label { Some(label) } else { None };
+        let result = if self.list.trim() == *label { Some(*label) } else { None };
         self.last_result = Some(result);
         return result;
       }
     } else if let Some(valid) = self.againsts.iter().find(|i| self.list.ends_with(i)) {
       if !self.is_escaped(self.list.len() - valid.len()) {
-        let result = Some(valid);
+        let result = Some(*valid);
         self.last_result = Some(result);
         return result;
       }
@@ -241,6 +247,30 @@ impl<'a> SeriesChecker<'a> {
   }
 }
 
+/// Longest name that is matched case insensitively against the keyword tables
+pub(crate) const KEYWORD_BUFFER_LEN: usize = 64;
+
+/// Lowercase `input` into `buffer`, names too long to be a keyword give an empty slice
+pub(crate) fn to_lowercase<'b>(input: &[u8], buffer: &'b mut [u8; KEYWORD_BUFFER_LEN]) -> &'b [u8] {
+  match buffer.get_mut(..input.len()) {
+    Some(lowercase) => {
+      lowercase.copy_from_slice(input);
+      lowercase.make_ascii_lowercase();
+      lowercase
+    }
+    None => &[],
+  }
+}
+
+/// The static entry of `keywords` equal to `lowercase`, tokens borrow it instead of owning a
+/// case folded copy of the input
+pub(crate) fn find_keyword(keywords: &[&'static [u8]], lowercase: &[u8]) -> Option<&'static BStr> {
+  keywords
+    .iter()
+    .find(|keyword| **keyword == lowercase)
+    .map(|keyword| keyword.as_bstr())
+}
+
 const MAGIC_KEYWORDS: &[&[u8]] = &[
   b"__CLASS__",
   b"__DIR__",
@@ -290,21 +320,30 @@ const TYPE_KEYWORDS: &[&[u8]] = &[
 ];
 
 #[derive(Debug)]
-pub struct Lexer<'a> {
-  pub(crate) tokens: bumpalo::collections::Vec<'a, Token>,
-  pub(crate) control: Control,
+pub struct Lexer<'arena, 'a> {
+  pub(crate) tokens: bumpalo::collections::Vec<'arena, Token<'a>>,
+  pub(crate) control: Control<'a>,
   pub(crate) halt: bool,
+  /// Arena of the trivia, only set by the lossless lexer
+  pub(crate) lossless: Option<&'a Bump>,
+  pub(crate) version: PhpVersion,
 }
 
-impl<'a> Lexer<'a> {
-  pub fn new(arena: &'a Bump, input: BString) -> Self {
+impl<'arena, 'a> Lexer<'arena, 'a> {
+  pub fn new(arena: &'arena Bump, input: &'a [u8]) -> Self {
     Lexer {
       tokens: bumpalo::collections::Vec::new_in(arena),
       control: Control::new(input),
       halt: false,
+      lossless: None,
+      version: PhpVersion::latest(),
     }
   }
 
+  pub fn new_lossless(arena: &'arena Bump, input: &'a [u8]) -> Self where 'arena: 'a {
+    Lexer { lossless: Some(arena), ..Self::new(arena, input) }
+  }
+
   pub fn next_tokens_until_right_bracket(&mut self) -> LexResult {
     let mut level = 1;
     while let Some(next_token) = self.control.peek_char(None) {
@@ -329,15 +368,20 @@ impl<'a> Lexer<'a> {
   }
 
   #[inline]
-  pub(crate) fn until<F>(&mut self, mut callback: F) -> BString where F: FnMut(u8) -> bool {
+  pub(crate) fn until<F>(&mut self, mut callback: F) -> &'a BStr where F: FnMut(u8) -> bool {
     self.control.next_char_until(1, |_, ch, _| callback(ch))
   }
 
   pub fn start(&mut self, is_eval: bool) -> LexResult {
+    // Index of the first token of every top level lexing step, paired with the
+    // position where that step ended
+    let mut groups = vec![];
     if !is_eval {
       InlineToken::lex(self, &(ControlSnapshot { line: 1, column: 0, offset: 0 }))?;
+      groups.push((0, self.control.get_position()));
     }
     loop {
+      let first = self.tokens.len();
       let result = self.next_tokens(true);
       if let Err(err) = result {
         if err == LexError::Eof {
@@ -345,13 +389,125 @@ impl<'a> Lexer<'a> {
         }
         return Err(err);
       }
+      if self.lossless.is_some() && self.tokens.len() > first {
+        groups.push((first, self.control.get_position()));
+      }
       if self.halt {
         break;
       }
     }
+    self.set_ends();
+    self.check_version()?;
+    if let Some(arena) = self.lossless {
+      self.attach_trivia(arena, &groups);
+    }
     Ok(())
   }
 
+  fn check_version(&self) -> LexResult {
+    if self.version == PhpVersion::latest() {
+      return Ok(());
+    }
+    let unsupported = self.tokens.iter().find_map(|token| {
+      PhpVersion::of_token(token)
+        .filter(|version| *version > self.version)
+        .map(|version| (token, version))
+    });
+    match unsupported {
+      Some((token, version)) =>
+        Err(LexError::Unsupported {
+          token: token.value.to_string(),
+          version,
+          line: token.line as usize,
+          column: token.column as usize,
+        }),
+      None => Ok(()),
+    }
+  }
+
+  /// Locate the exclusive end of every token in the input. Values can be case folded, stripped
+  /// of their delimiters or borrowed from another token, so their length is not enough.
+  fn set_ends(&mut self) {
+    let input = self.control.chars;
+    let input_start = input.as_ptr() as usize;
+    for token in self.tokens.iter_mut() {
+      let start = token.offset as usize;
+      let value_start = token.value.as_ptr() as usize;
+      let value_end = if
+        value_start >= input_start &&
+        value_start + token.value.len() <= input_start + input.len()
+      {
+        value_start - input_start + token.value.len()
+      } else {
+        start + token.value.len()
+      };
+      let end = (
+        match token.token_type {
+          TokenType::CommentBlock => value_end + 2,
+          TokenType::NowDocOpen => value_end + 1,
+          // The value holds the whole comment, the token itself is only `/**`
+          TokenType::CommentDocOpen => start + 3,
+          // Closing labels are located at their last character
+          TokenType::HeredocClose | TokenType::NowDocClose => start + 1,
+          TokenType::CommentDocClose =>
+            input[start..].find(b"*/").map_or(input.len(), |x| start + x + 2),
+          _ => value_end,
+        }
+      ).clamp(start, input.len());
+      let mut position = Position { line: token.line, column: token.column, offset: end as u32 };
+      for ch in &input[start..end] {
+        if *ch == b'\n' {
+          position.line += 1;
+          position.column = 0;
+        } else {
+          position.column += 1;
+        }
+      }
+      token.end = position;
+    }
+  }
+
+  fn attach_trivia(&mut self, arena: &'a Bump, groups: &[(usize, usize)]) {
+    let input = self.control.chars;
+    let mut previous_end = 0;
+    // The trailing trivia of a token is only known once the next token is reached
+    let mut pending: Option<(usize, Trivia<'a>)> = None;
+    for (i, (first, end)) in groups.iter().enumerate() {
+      let last = groups.get(i + 1).map_or(self.tokens.len(), |x| x.0);
+      for index in *first..last {
+        let start = (self.tokens[index].offset as usize).max(previous_end);
+        let token_end = if index + 1 < last {
+          self.tokens[index + 1].offset as usize
+        } else {
+          *end
+        }.max(start);
+        let gap = &input[previous_end..start];
+        let split = if index == 0 {
+          0
+        } else {
+          gap.find_byte(b'\n').map_or(gap.len(), |x| x + 1)
+        };
+        if let Some((previous, mut trivia)) = pending.take() {
+          trivia.trailing = gap[..split].as_bstr();
+          self.tokens[previous].trivia = Some(arena.alloc(trivia));
+        }
+        pending = Some((
+          index,
+          Trivia {
+            leading: gap[split..].as_bstr(),
+            text: input[start..token_end].as_bstr(),
+            trailing: b"".as_bstr(),
+          },
+        ));
+        previous_end = token_end;
+      }
+    }
+    if let Some((last, mut trivia)) = pending {
+      trivia.trailing = input[previous_end..].as_bstr();
+      self.tokens[last].trivia = Some(arena.alloc(trivia));
+    }
+  }
+
   pub fn next_tokens(&mut self, skip_whitespace: bool) -> LexResult {
     if skip_whitespace {
       self.control.next_char_until(0, |_, ch, _| !ch.is_ascii_whitespace());
@@ -369,7 +525,6 @@ impl<'a> Lexer<'a> {
       c if c.is_ascii_digit() => NumberToken::lex(self, &current_char, snapshot),
       c if c.is_ascii_alphabetic() || c == b'_' => {
         let t = self.until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'_' || ch == b'\\'));
-        let t_sliced = t.as_slice();
         if t.contains(&b'\\') {
           if t.starts_with(b"namespace") {
             self.tokens.push(Token::new(TokenType::RelativeName, t, snapshot));
@@ -377,25 +532,23 @@ impl<'a> Lexer<'a> {
             self.tokens.push(Token::new(TokenType::QualifiedName, t, snapshot));
           }
           return Ok(());
-        } else if MAGIC_KEYWORDS.contains(&t_sliced) {
+        } else if MAGIC_KEYWORDS.contains(&t.as_bytes()) {
           self.tokens.push(Token::new(TokenType::Magic, t, snapshot));
           return Ok(());
         }
-        let t_lowercase: BString = t.to_ascii_lowercase().into();
-        let t_lowercase_sliced = t_lowercase.as_slice();
-        if MAGIC_METHOD_KEYWORDS.contains(&t_lowercase_sliced) {
-          self.tokens.push(Token::new(TokenType::MagicMethod, t_lowercase, snapshot));
-        } else if TYPE_KEYWORDS.contains(&t_lowercase_sliced) {
-          self.tokens.push(Token::new(TokenType::Type, t_lowercase, snapshot));
-        } else if
-          let Some(token) = KeywordToken::try_lex(self, &t_lowercase, t_lowercase_sliced, snapshot)
-        {
+        let mut buffer = [0; KEYWORD_BUFFER_LEN];
+        let lowercase = to_lowercase(t, &mut buffer);
+        if let Some(magic) = find_keyword(MAGIC_METHOD_KEYWORDS, lowercase) {
+          self.tokens.push(Token::new(TokenType::MagicMethod, magic, snapshot));
+        } else if let Some(type_name) = find_keyword(TYPE_KEYWORDS, lowercase) {
+          self.tokens.push(Token::new(TokenType::Type, type_name, snapshot));
+        } else if let Some(token) = KeywordToken::try_lex(self, lowercase, snapshot) {
           self.tokens.push(token);
         } else if
-          t_lowercase_sliced == b"__halt_compiler" &&
-          self.control.peek_char_n(None, 2).is_some_and(|x| x.as_slice() == b"()")
+          lowercase == b"__halt_compiler" &&
+          self.control.peek_char_n(None, 2).is_some_and(|x| x == "()")
         {
-          self.tokens.push(Token::new(TokenType::HaltCompiler, t_lowercase, snapshot));
+          self.tokens.push(Token::new(TokenType::HaltCompiler, "__halt_compiler".into(), snapshot));
           self.halt = true;
         } else {
           self.tokens.push(Token::new(TokenType::UnqualifiedName, t, snapshot));
@@ -404,7 +557,7 @@ impl<'a> Lexer<'a> {
       }
       b'=' => {
         let t = self.until(|ch| ![b'=', b'>', b'&'].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"===" => {
             self.tokens.push(Token::new(TokenType::IsIdentical, "===".into(), snapshot));
             Ok(())
@@ -430,7 +583,7 @@ impl<'a> Lexer<'a> {
       }
       b'&' => {
         let t = self.until(|ch| ![b'&', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"&=" => {
             self.tokens.push(Token::new(TokenType::BitwiseAndAssignment, "&=".into(), snapshot));
             Ok(())
@@ -448,7 +601,7 @@ impl<'a> Lexer<'a> {
       }
       b'#' => {
         let t = self.until(|ch| ![b'#', b'['].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"#[" => {
             self.tokens.push(Token::new(TokenType::Attribute, "#[".into(), snapshot));
             Ok(())
@@ -458,7 +611,7 @@ impl<'a> Lexer<'a> {
       }
       b'?' => {
         let t = self.until(|ch| ![b'?', b'>', b'=', b'-', b':'].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"?:" => {
             self.tokens.push(Token::new(TokenType::Elvis, "?:".into(), snapshot));
             Ok(())
@@ -490,7 +643,7 @@ impl<'a> Lexer<'a> {
       }
       b'%' => {
         let t = self.until(|ch| ![b'%', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"%=" => {
             self.tokens.push(Token::new(TokenType::ModulusAssignment, "%=".into(), snapshot));
             Ok(())
@@ -504,7 +657,7 @@ impl<'a> Lexer<'a> {
       }
       b'^' => {
         let t = self.until(|ch| ![b'^', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"^=" => {
             self.tokens.push(Token::new(TokenType::BitwiseXorAssignment, "^=".into(), snapshot));
             Ok(())
@@ -518,7 +671,7 @@ impl<'a> Lexer<'a> {
       }
       b'*' => {
         let t = self.until(|ch| ![b'*', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"**=" => {
             self.tokens.push(
               Token::new(TokenType::ExponentiationAssignment, "**=".into(), snapshot)
@@ -544,7 +697,7 @@ impl<'a> Lexer<'a> {
       }
       b'/' => {
         let t = self.until(|ch| ![b'/', b'*', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"/=" => {
             self.tokens.push(Token::new(TokenType::DivisionAssignment, "/=".into(), snapshot));
             Ok(())
@@ -561,7 +714,7 @@ impl<'a> Lexer<'a> {
       }
       b'.' => {
         let t = self.until(|ch| ![b'.', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b".=" => {
             self.tokens.push(Token::new(TokenType::ConcatenationAssignment, ".=".into(), snapshot));
             Ok(())
@@ -571,12 +724,10 @@ impl<'a> Lexer<'a> {
             Ok(())
           }
           b"." => {
-            let t = self.control.next_char_until(1, |_, ch, _| !ch.is_ascii_digit());
-            if t.len() == 1 {
-              self.tokens.push(Token::new(TokenType::Concatenation, ".".into(), snapshot));
-              Ok(())
+            if self.control.peek_char(None).is_some_and(u8::is_ascii_digit) {
+              NumberToken::lex(self, &b'.', snapshot)
             } else {
-              self.tokens.push(Token::new(TokenType::Number, t, snapshot));
+              self.tokens.push(Token::new(TokenType::Concatenation, ".".into(), snapshot));
               Ok(())
             }
           }
@@ -585,7 +736,7 @@ impl<'a> Lexer<'a> {
       }
       b'|' => {
         let t = self.until(|ch| ![b'|', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"|=" => {
             self.tokens.push(Token::new(TokenType::BitwiseOrAssignment, "|=".into(), snapshot));
             Ok(())
@@ -603,7 +754,7 @@ impl<'a> Lexer<'a> {
       }
       b'-' => {
         let t = self.until(|ch| ![b'-', b'=', b'>'].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"-=" => {
             self.tokens.push(Token::new(TokenType::SubtractionAssignment, "-=".into(), snapshot));
             Ok(())
@@ -635,7 +786,7 @@ impl<'a> Lexer<'a> {
       }
       b'>' => {
         let t = self.until(|ch| ![b'>', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b">>=" => {
             self.tokens.push(
               Token::new(TokenType::BitwiseShiftRightAssignment, ">>=".into(), snapshot)
@@ -659,7 +810,7 @@ impl<'a> Lexer<'a> {
       }
       b'<' => {
         let t = self.until(|ch| ![b'<', b'=', b'>'].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"<=>" => {
             self.tokens.push(Token::new(TokenType::Spaceship, "<=>".into(), snapshot));
             Ok(())
@@ -692,7 +843,7 @@ impl<'a> Lexer<'a> {
       }
       b':' => {
         let t = self.until(|ch| ![b':'].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"::" => {
             self.tokens.push(Token::new(TokenType::DoubleColon, "::".into(), snapshot));
             Ok(())
@@ -706,7 +857,7 @@ impl<'a> Lexer<'a> {
       }
       b'!' => {
         let t = self.until(|ch| ![b'!', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"!==" => {
             self.tokens.push(Token::new(TokenType::IsNotIdentical, "!==".into(), snapshot));
             Ok(())
@@ -727,7 +878,7 @@ impl<'a> Lexer<'a> {
       }
       b'+' => {
         let t = self.until(|ch| ![b'+', b'='].contains(&ch));
-        match t.as_slice() {
+        match t.as_bytes() {
           b"+=" => {
             self.tokens.push(Token::new(TokenType::AdditionAssignment, "+=".into(), snapshot));
             Ok(())
@@ -777,8 +928,8 @@ impl<'a> Lexer<'a> {
         self.tokens.push(Token::new(TokenType::RightSquareBracket, "]".into(), snapshot));
         Ok(())
       }
-      b'`' => StringToken::lex(self, b"`".into(), snapshot),
-      b'"' => StringToken::lex(self, b"\"".into(), snapshot),
+      b'`' => StringToken::lex(self, b"`", snapshot),
+      b'"' => StringToken::lex(self, b"\"", snapshot),
       b'\'' => StringToken::lex_basic(self, snapshot),
       b'\\' => {
         let t = self.until(|ch| !(ch.is_ascii_alphanumeric() || ch == b'_' || ch == b'\\'));
@@ -794,7 +945,7 @@ impl<'a> Lexer<'a> {
         Ok(())
       }
       b'~' => {
-        self.tokens.push(Token::new(TokenType::BooleanNegate, "~".into(), snapshot));
+        self.tokens.push(Token::new(TokenType::BitwiseNot, "~".into(), snapshot));
         Ok(())
       }
       b'@' => {
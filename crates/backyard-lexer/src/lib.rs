@@ -2,41 +2,77 @@ pub mod token;
 mod internal;
 mod lexer;
 pub mod error;
+pub mod version;
 
-use bstr::BString;
 use bumpalo::Bump;
 use error::LexError;
 use lexer::Lexer;
 use token::Token;
+use version::PhpVersion;
 
-pub fn lex(is_eval: bool, input: &str) -> Result<Vec<Token>, LexError> {
+pub fn lex(is_eval: bool, input: &str) -> Result<Vec<Token<'_>>, LexError> {
   let arena = Bump::new();
   let result = lex_in(&arena, is_eval, input)?;
   Ok(Vec::from_iter(result))
 }
 
-pub fn lex_in<'arena>(
+pub fn lex_in<'arena, 'a>(
   arena: &'arena Bump,
   is_eval: bool,
-  input: &str
-) -> Result<bumpalo::collections::Vec<'arena, Token>, LexError> {
-  let mut lexer = Lexer::new(arena, BString::new(input.as_bytes().to_vec()));
+  input: &'a str
+) -> Result<bumpalo::collections::Vec<'arena, Token<'a>>, LexError> {
+  let mut lexer = Lexer::new(arena, input.as_bytes());
   lexer.start(is_eval)?;
   Ok(lexer.tokens)
 }
 
-pub fn lex_byte(is_eval: bool, input: &[u8]) -> Result<Vec<Token>, LexError> {
+/// Lex `input` keeping the trivia of every token, allocated in `arena` along with the tokens
+pub fn lex_lossless_in<'a>(
+  arena: &'a Bump,
+  is_eval: bool,
+  input: &'a str
+) -> Result<bumpalo::collections::Vec<'a, Token<'a>>, LexError> {
+  let mut lexer = Lexer::new_lossless(arena, input.as_bytes());
+  lexer.start(is_eval)?;
+  Ok(lexer.tokens)
+}
+
+pub fn lex_byte(is_eval: bool, input: &[u8]) -> Result<Vec<Token<'_>>, LexError> {
   let arena = Bump::new();
   let result = lex_byte_in(&arena, is_eval, input)?;
   Ok(Vec::from_iter(result))
 }
 
-pub fn lex_byte_in<'arena>(
+/// Lex `input` into tokens allocated in `arena`, token values borrow from `input`
+pub fn lex_byte_in<'arena, 'a>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  input: &'a [u8]
+) -> Result<bumpalo::collections::Vec<'arena, Token<'a>>, LexError> {
+  let mut lexer = Lexer::new(arena, input);
+  lexer.start(is_eval)?;
+  Ok(lexer.tokens)
+}
+
+/// Lex `input`, rejecting syntax introduced after `version`
+pub fn lex_version(
+  is_eval: bool,
+  input: &str,
+  version: PhpVersion
+) -> Result<Vec<Token<'_>>, LexError> {
+  let arena = Bump::new();
+  let result = lex_version_in(&arena, is_eval, input.as_bytes(), version)?;
+  Ok(Vec::from_iter(result))
+}
+
+pub fn lex_version_in<'arena, 'a>(
   arena: &'arena Bump,
   is_eval: bool,
-  input: &[u8]
-) -> Result<bumpalo::collections::Vec<'arena, Token>, LexError> {
-  let mut lexer = Lexer::new(arena, BString::new(input.to_vec()));
+  input: &'a [u8],
+  version: PhpVersion
+) -> Result<bumpalo::collections::Vec<'arena, Token<'a>>, LexError> {
+  let mut lexer = Lexer::new(arena, input);
+  lexer.version = version;
   lexer.start(is_eval)?;
   Ok(lexer.tokens)
 }
@@ -1,6 +1,6 @@
 use std::fmt::{ Debug, Display };
 
-use bstr::BString;
+use bstr::{ BStr, BString };
 use serde::{ Deserialize, Serialize };
 
 use crate::lexer::ControlSnapshot;
@@ -20,6 +20,7 @@ pub enum TokenType {
   RelativeName,
 
   NumberBinary,
+  NumberOctal,
   NumberHex,
   Number,
 
@@ -51,6 +52,7 @@ pub enum TokenType {
 
   BitwiseXorAssignment,
   BitwiseXor,
+  BitwiseNot,
 
   ExponentiationAssignment,
   MultiplicationAssignment,
@@ -207,6 +209,7 @@ pub enum TokenType {
   Yield,
   Xor,
 
+  /// `/**`, its value is the whole comment as written, up to and including `*/`
   CommentDocOpen,
   CommentDocClose,
   DocHorizontalWhitespace,
@@ -301,28 +304,93 @@ pub enum TokenType {
   DocTagPsalmParamOut,
 }
 
+/// A lexed token, its value borrows from the input or, for case folded keywords, from a
+/// static keyword table, so lexing does not allocate per token
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
-pub struct Token {
+pub struct Token<'a> {
   pub token_type: TokenType,
-  pub value: BString,
+  #[serde(borrow)]
+  pub value: &'a BStr,
+  pub line: u32,
+  pub column: u32,
+  pub offset: u32,
+  /// Exclusive end of the token in the source, past its closing quote, delimiter or label
+  #[serde(default)]
+  pub end: Position,
+  /// Allocated in the lexing arena, not restored when deserializing
+  #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+  pub trivia: Option<&'a Trivia<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
   pub line: u32,
   pub column: u32,
   pub offset: u32,
 }
 
-impl Token {
-  pub(crate) fn new(token_type: TokenType, value: BString, snapshot: &ControlSnapshot) -> Self {
+/// Source text surrounding a token, only filled by the lossless lexer.
+/// Concatenating `leading`, `text` and `trailing` of every token reproduces the input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trivia<'a> {
+  /// Whitespace before the token, starting after the previous token's trailing trivia
+  #[serde(borrow)]
+  pub leading: &'a BStr,
+  /// The token exactly as written, without case folding or unescaping
+  #[serde(borrow)]
+  pub text: &'a BStr,
+  /// Whitespace after the token, up to and including the end of the line
+  #[serde(borrow)]
+  pub trailing: &'a BStr,
+}
+
+impl<'a> Token<'a> {
+  pub(crate) fn new(token_type: TokenType, value: &'a BStr, snapshot: &ControlSnapshot) -> Self {
     Token {
       token_type,
       value,
       line: snapshot.line as u32,
       column: snapshot.column as u32,
       offset: snapshot.offset as u32,
+      end: Position {
+        line: snapshot.line as u32,
+        column: snapshot.column as u32,
+        offset: snapshot.offset as u32,
+      },
+      trivia: None,
+    }
+  }
+
+  /// Exclusive end offset of the token in the source, only known for lossless tokens
+  pub fn end_offset(&self) -> Option<u32> {
+    self.trivia.map(|trivia| self.offset + (trivia.text.len() as u32))
+  }
+}
+
+/// A token owning its value, for tokens outliving the input they were lexed from such as the
+/// ones carried by errors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OwnedToken {
+  pub token_type: TokenType,
+  pub value: BString,
+  pub line: u32,
+  pub column: u32,
+  pub offset: u32,
+}
+
+impl From<&Token<'_>> for OwnedToken {
+  fn from(token: &Token<'_>) -> Self {
+    OwnedToken {
+      token_type: token.token_type,
+      value: token.value.into(),
+      line: token.line,
+      column: token.column,
+      offset: token.offset,
     }
   }
 }
 
-impl Debug for Token {
+impl Debug for Token<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(
       f,
@@ -336,7 +404,7 @@ impl Debug for Token {
   }
 }
 
-impl Display for Token {
+impl Display for Token<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(
       f,
@@ -0,0 +1,94 @@
+use std::{ fmt::{ Display, Formatter }, str::FromStr };
+
+use serde::{ Deserialize, Serialize };
+
+use crate::token::{ Token, TokenType };
+
+/// PHP release targeted by the lexer and the parser, syntax introduced after it is rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum PhpVersion {
+  Php70,
+  Php71,
+  Php72,
+  Php73,
+  Php74,
+  Php80,
+  Php81,
+  Php82,
+  Php83,
+  #[default]
+  Php84,
+}
+
+impl PhpVersion {
+  pub fn latest() -> Self {
+    PhpVersion::Php84
+  }
+
+  /// Version that introduced the syntax of `token`, when it is newer than PHP 7.0
+  pub fn of_token(token: &Token) -> Option<Self> {
+    match token.token_type {
+      TokenType::CoalesceAssignment => Some(PhpVersion::Php74),
+      | TokenType::Number
+      | TokenType::NumberHex
+      | TokenType::NumberBinary if token.value.contains(&b'_') => Some(PhpVersion::Php74),
+      TokenType::NumberOctal => Some(PhpVersion::Php81),
+      TokenType::Attribute | TokenType::NullsafeObjectAccess => Some(PhpVersion::Php80),
+      | TokenType::PublicGet
+      | TokenType::PublicSet
+      | TokenType::ProtectedGet
+      | TokenType::ProtectedSet
+      | TokenType::PrivateGet
+      | TokenType::PrivateSet => Some(PhpVersion::Php84),
+      _ => None,
+    }
+  }
+}
+
+impl Display for PhpVersion {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let version = match self {
+      PhpVersion::Php70 => "7.0",
+      PhpVersion::Php71 => "7.1",
+      PhpVersion::Php72 => "7.2",
+      PhpVersion::Php73 => "7.3",
+      PhpVersion::Php74 => "7.4",
+      PhpVersion::Php80 => "8.0",
+      PhpVersion::Php81 => "8.1",
+      PhpVersion::Php82 => "8.2",
+      PhpVersion::Php83 => "8.3",
+      PhpVersion::Php84 => "8.4",
+    };
+    write!(f, "{}", version)
+  }
+}
+
+impl FromStr for PhpVersion {
+  type Err = String;
+
+  /// Accepts `major.minor`, optionally followed by a patch number, e.g. `7.4` or `8.1.2`
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let mut parts = s.trim().splitn(3, '.');
+    let version = match (parts.next(), parts.next()) {
+      (Some("7"), Some("0")) => PhpVersion::Php70,
+      (Some("7"), Some("1")) => PhpVersion::Php71,
+      (Some("7"), Some("2")) => PhpVersion::Php72,
+      (Some("7"), Some("3")) => PhpVersion::Php73,
+      (Some("7"), Some("4")) => PhpVersion::Php74,
+      (Some("8"), Some("0")) => PhpVersion::Php80,
+      (Some("8"), Some("1")) => PhpVersion::Php81,
+      (Some("8"), Some("2")) => PhpVersion::Php82,
+      (Some("8"), Some("3")) => PhpVersion::Php83,
+      (Some("8"), Some("4")) => PhpVersion::Php84,
+      _ => {
+        return Err(format!("Unsupported PHP version '{}'", s));
+      }
+    };
+    match parts.next() {
+      Some(patch) if patch.is_empty() || !patch.bytes().all(|x| x.is_ascii_digit()) => {
+        Err(format!("Unsupported PHP version '{}'", s))
+      }
+      _ => Ok(version),
+    }
+  }
+}
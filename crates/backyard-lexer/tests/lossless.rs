@@ -0,0 +1,40 @@
+use backyard_lexer::lex_lossless_in;
+use bumpalo::Bump;
+
+fn reprint(is_eval: bool, input: &str) -> String {
+  let arena = Bump::new();
+  let tokens = lex_lossless_in(&arena, is_eval, input).unwrap();
+  tokens
+    .iter()
+    .map(|token| {
+      let trivia = token.trivia.unwrap();
+      format!("{}{}{}", trivia.leading, trivia.text, trivia.trailing)
+    })
+    .collect()
+}
+
+#[test]
+fn trivia() {
+  let arena = Bump::new();
+  let tokens = lex_lossless_in(&arena, true, "  ECHO  $a ;\n\n  // b\n").unwrap();
+  insta::assert_yaml_snapshot!(tokens.as_slice());
+}
+
+#[test]
+fn round_trip() {
+  let input =
+    "<html>\n<?php\n\n  // hi\n  ECHO \"a $b {$c->d} e\";  \n  $x = <<<EOT\n    foo $bar\n    EOT;\n/** doc\n * @param int $a\n */\nfunction  Foo(INT $a): VOID {}\n?>\n<b>x</b>\n<?php __halt_compiler(); raw data";
+  assert_eq!(input, reprint(false, input));
+  let input = "<?php\r\n$a = 1 + .5;\t# c\n  ";
+  assert_eq!(input, reprint(false, input));
+}
+
+#[test]
+fn end_offset() {
+  let arena = Bump::new();
+  let tokens = lex_lossless_in(&arena, true, "function __CONSTRUCT() {}").unwrap();
+  assert_eq!(Some(8), tokens[0].end_offset());
+  assert_eq!(Some(20), tokens[1].end_offset());
+  assert_eq!("__CONSTRUCT", tokens[1].trivia.unwrap().text);
+  assert_eq!("__construct", tokens[1].value);
+}
@@ -29,3 +29,27 @@ fn binary() {
   let tokens = lex(true, "0b1010_1010").unwrap();
   insta::assert_yaml_snapshot!(tokens);
 }
+
+#[test]
+fn octal() {
+  let tokens = lex(true, "0o777 0O1_7 0777").unwrap();
+  insta::assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn underscore() {
+  let tokens = lex(true, "1_000_000 0xFF_FF 1_0.5_5").unwrap();
+  insta::assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn exponent() {
+  let tokens = lex(true, "1.5e-3 1E10 2e+5 1.e3 .5e3").unwrap();
+  insta::assert_yaml_snapshot!(tokens);
+}
+
+#[test]
+fn concatenation() {
+  let tokens = lex(true, "1 . 2 . $a").unwrap();
+  insta::assert_yaml_snapshot!(tokens);
+}
@@ -0,0 +1,40 @@
+use backyard_lexer::{ error::LexError, lex, lex_version, version::PhpVersion };
+
+#[test]
+fn unsupported() {
+  for (input, token, previous, version) in [
+    ("$a ??= 1;", "??=", PhpVersion::Php73, PhpVersion::Php74),
+    ("$a = 0b1_0;", "0b1_0", PhpVersion::Php73, PhpVersion::Php74),
+    ("$a?->b;", "?->", PhpVersion::Php74, PhpVersion::Php80),
+    ("$a = 0o17;", "0o17", PhpVersion::Php80, PhpVersion::Php81),
+    ("#[Attribute]\nclass A {}", "#[", PhpVersion::Php74, PhpVersion::Php80),
+    ("class A { private(set) int $a; }", "private(set)", PhpVersion::Php83, PhpVersion::Php84),
+  ] {
+    let error = lex_version(true, input, previous).unwrap_err();
+    let LexError::Unsupported { token: found, version: required, .. } = error else {
+      panic!("unexpected error {:?}", error);
+    };
+    assert_eq!((token, version), (found.as_str(), required));
+    assert_eq!(lex(true, input).unwrap(), lex_version(true, input, version).unwrap());
+  }
+}
+
+#[test]
+fn error() {
+  let error = lex_version(true, "\n$a ??= 1;", PhpVersion::Php73).unwrap_err();
+  assert_eq!(
+    "Unsupported syntax '??=' at line 2, column 3, requires PHP 7.4",
+    format!("{}", error)
+  );
+}
+
+#[test]
+fn parse_version() {
+  assert_eq!(Ok(PhpVersion::Php74), "7.4".parse());
+  assert_eq!(Ok(PhpVersion::Php81), "8.1.27".parse());
+  assert!("8".parse::<PhpVersion>().is_err());
+  assert!("5.6".parse::<PhpVersion>().is_err());
+  assert!("8.1.x".parse::<PhpVersion>().is_err());
+  assert_eq!("8.4", PhpVersion::default().to_string());
+  assert!(PhpVersion::Php74 < PhpVersion::Php80);
+}
@@ -0,0 +1,524 @@
+use std::{ borrow::Cow, collections::HashMap, fmt::{ self, Display, Formatter } };
+
+use bstr::{ BStr, ByteSlice };
+use bumpalo::Bump;
+
+use crate::{
+  macros::node_types::with_node_types,
+  AssignmentType,
+  BinaryType,
+  BodyType,
+  CastType,
+  Inheritance,
+  Location,
+  MagicMethodName,
+  MagicName,
+  Modifier,
+  Node,
+  NodeType,
+  NodeWrapper,
+  PostType,
+  PreType,
+  Quote,
+  RangeLocation,
+  UseItemModifier,
+  Visibility,
+};
+
+const MAGIC: &[u8] = b"BYAST";
+
+/// Bumped whenever the encoding or any node changes, older data is rejected instead of misread.
+pub const FORMAT_VERSION: u32 = 2;
+
+const HAS_LOC: u8 = 1;
+const HAS_LEADINGS: u8 = 2;
+const HAS_TRAILINGS: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryError {
+  InvalidHeader,
+  UnsupportedVersion(u32),
+  UnexpectedEnd,
+  InvalidNodeType(u32),
+  InvalidString(u32),
+  InvalidValue(String),
+  TrailingBytes,
+}
+
+impl Display for BinaryError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      BinaryError::InvalidHeader => write!(f, "Not an encoded node"),
+      BinaryError::UnsupportedVersion(version) =>
+        write!(f, "Unsupported format version {}, expected {}", version, FORMAT_VERSION),
+      BinaryError::UnexpectedEnd => write!(f, "Unexpected end of data"),
+      BinaryError::InvalidNodeType(tag) => write!(f, "Invalid node type: {}", tag),
+      BinaryError::InvalidString(index) => write!(f, "Invalid string index: {}", index),
+      BinaryError::InvalidValue(message) => write!(f, "{}", message),
+      BinaryError::TrailingBytes => write!(f, "Unexpected data after the node"),
+    }
+  }
+}
+
+impl<'a> Node<'a> {
+  /// Encode the tree into a compact binary format, which is loaded back with `from_binary_in`.
+  pub fn to_binary(&self) -> Vec<u8> {
+    let mut encoder = Encoder::default();
+    self.encode(&mut encoder);
+    let mut bytes = MAGIC.to_vec();
+    write_varint(&mut bytes, FORMAT_VERSION as u64);
+    write_varint(&mut bytes, encoder.strings.len() as u64);
+    for string in encoder.strings.iter() {
+      write_varint(&mut bytes, string.len() as u64);
+      bytes.extend_from_slice(string);
+    }
+    bytes.extend_from_slice(&encoder.bytes);
+    bytes
+  }
+}
+
+/// Decode a tree encoded with `Node::to_binary`, allocating it in `arena`.
+pub fn from_binary_in<'arena>(
+  arena: &'arena Bump,
+  bytes: &[u8]
+) -> Result<Node<'arena>, BinaryError> {
+  let bytes = bytes.strip_prefix(MAGIC).ok_or(BinaryError::InvalidHeader)?;
+  let mut decoder = Decoder { arena, bytes, position: 0, strings: vec![], last_loc: (0, 0) };
+  let version = decoder.read_u32()?;
+  if version != FORMAT_VERSION {
+    return Err(BinaryError::UnsupportedVersion(version));
+  }
+  let count = decoder.read_u32()?;
+  for _ in 0..count {
+    let len = decoder.read_u32()? as usize;
+    let string = arena.alloc_slice_copy(decoder.read_bytes(len)?).as_bstr();
+    decoder.strings.push(string);
+  }
+  let node = Node::decode(&mut decoder)?;
+  if decoder.position != decoder.bytes.len() {
+    return Err(BinaryError::TrailingBytes);
+  }
+  Ok(node)
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+  while value >= 0x80 {
+    bytes.push((value as u8) | 0x80);
+    value >>= 7;
+  }
+  bytes.push(value as u8);
+}
+
+#[derive(Default)]
+pub struct Encoder<'a> {
+  bytes: Vec<u8>,
+  strings: Vec<Cow<'a, [u8]>>,
+  indexes: HashMap<Cow<'a, [u8]>, u32>,
+  last_loc: (u32, u32),
+}
+
+impl<'a> Encoder<'a> {
+  fn write_u8(&mut self, value: u8) {
+    self.bytes.push(value);
+  }
+
+  fn write_u32(&mut self, value: u32) {
+    write_varint(&mut self.bytes, value as u64);
+  }
+
+  fn write_i64(&mut self, value: i64) {
+    write_varint(&mut self.bytes, ((value << 1) ^ (value >> 63)) as u64);
+  }
+
+  fn write_string(&mut self, value: Cow<'a, [u8]>) {
+    let index = match self.indexes.get(&value) {
+      Some(index) => *index,
+      None => {
+        let index = self.strings.len() as u32;
+        self.strings.push(value.clone());
+        self.indexes.insert(value, index);
+        index
+      }
+    };
+    self.write_u32(index);
+  }
+}
+
+pub struct Decoder<'arena, 'b> {
+  arena: &'arena Bump,
+  bytes: &'b [u8],
+  position: usize,
+  strings: Vec<&'arena BStr>,
+  last_loc: (u32, u32),
+}
+
+impl<'arena> Decoder<'arena, '_> {
+  fn read_u8(&mut self) -> Result<u8, BinaryError> {
+    let value = *self.bytes.get(self.position).ok_or(BinaryError::UnexpectedEnd)?;
+    self.position += 1;
+    Ok(value)
+  }
+
+  fn read_varint(&mut self) -> Result<u64, BinaryError> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+      let byte = self.read_u8()?;
+      if shift >= 64 {
+        return Err(BinaryError::InvalidValue("Invalid varint".to_string()));
+      }
+      value |= ((byte & 0x7f) as u64) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(value);
+      }
+      shift += 7;
+    }
+  }
+
+  fn read_u32(&mut self) -> Result<u32, BinaryError> {
+    u32
+      ::try_from(self.read_varint()?)
+      .map_err(|_| BinaryError::InvalidValue("Invalid varint".to_string()))
+  }
+
+  fn read_delta(&mut self, base: u32) -> Result<u32, BinaryError> {
+    let value = self.read_varint()?;
+    let delta = ((value >> 1) as i64) ^ -((value & 1) as i64);
+    u32
+      ::try_from((base as i64) + delta)
+      .map_err(|_| BinaryError::InvalidValue("Invalid location".to_string()))
+  }
+
+  fn read_bytes(&mut self, len: usize) -> Result<&[u8], BinaryError> {
+    let end = self.position.checked_add(len).ok_or(BinaryError::UnexpectedEnd)?;
+    let bytes = self.bytes.get(self.position..end).ok_or(BinaryError::UnexpectedEnd)?;
+    self.position = end;
+    Ok(bytes)
+  }
+
+  fn read_string(&mut self) -> Result<&'arena BStr, BinaryError> {
+    let index = self.read_u32()?;
+    self.strings
+      .get(index as usize)
+      .copied()
+      .ok_or(BinaryError::InvalidString(index))
+  }
+
+  fn read_enum<T: for<'x> TryFrom<&'x BStr, Error = String>>(&mut self) -> Result<T, BinaryError> {
+    T::try_from(self.read_string()?).map_err(BinaryError::InvalidValue)
+  }
+}
+
+pub trait Encode<'a> {
+  fn encode(&self, encoder: &mut Encoder<'a>);
+}
+
+pub trait Decode<'arena>: Sized {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError>;
+}
+
+impl<'a> Encode<'a> for Node<'a> {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_u32(self.node_type.clone() as u32);
+    let mut flags = 0;
+    if self.loc.is_some() {
+      flags |= HAS_LOC;
+    }
+    if self.leadings.is_some() {
+      flags |= HAS_LEADINGS;
+    }
+    if self.trailings.is_some() {
+      flags |= HAS_TRAILINGS;
+    }
+    encoder.write_u8(flags);
+    if let Some(loc) = &self.loc {
+      loc.encode(encoder);
+    }
+    encode_wrapper(&self.wrapper, encoder);
+    if let Some(leadings) = &self.leadings {
+      leadings.encode(encoder);
+    }
+    if let Some(trailings) = &self.trailings {
+      trailings.encode(encoder);
+    }
+  }
+}
+
+impl<'arena> Decode<'arena> for Node<'arena> {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    let tag = decoder.read_u32()?;
+    let node_type = NODE_TYPES.get(tag as usize).ok_or(BinaryError::InvalidNodeType(tag))?;
+    let flags = decoder.read_u8()?;
+    let loc = if flags & HAS_LOC != 0 { Some(RangeLocation::decode(decoder)?) } else { None };
+    let wrapper = decode_wrapper(node_type, decoder)?;
+    let mut node = Node::new(node_type.clone(), wrapper, loc);
+    if flags & HAS_LEADINGS != 0 {
+      node.leadings = Some(Decode::decode(decoder)?);
+    }
+    if flags & HAS_TRAILINGS != 0 {
+      node.trailings = Some(Decode::decode(decoder)?);
+    }
+    Ok(node)
+  }
+}
+
+// A range starts relative to the previous one and ends relative to its start, so most of its
+// numbers take a single byte
+impl<'a> Encode<'a> for RangeLocation {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    let (line, offset) = encoder.last_loc;
+    encoder.write_i64((self.start.line as i64) - (line as i64));
+    encoder.write_u32(self.start.column);
+    encoder.write_i64((self.start.offset as i64) - (offset as i64));
+    encoder.write_i64((self.end.line as i64) - (self.start.line as i64));
+    encoder.write_u32(self.end.column);
+    encoder.write_i64((self.end.offset as i64) - (self.start.offset as i64));
+    encoder.last_loc = (self.start.line, self.start.offset);
+  }
+}
+
+impl<'arena> Decode<'arena> for RangeLocation {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    let (line, offset) = decoder.last_loc;
+    let start = Location {
+      line: decoder.read_delta(line)?,
+      column: decoder.read_u32()?,
+      offset: decoder.read_delta(offset)?,
+    };
+    let end = Location {
+      line: decoder.read_delta(start.line)?,
+      column: decoder.read_u32()?,
+      offset: decoder.read_delta(start.offset)?,
+    };
+    decoder.last_loc = (start.line, start.offset);
+    Ok(RangeLocation { start, end })
+  }
+}
+
+impl<'a> Encode<'a> for &'a BStr {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_string(Cow::Borrowed(self.as_bytes()));
+  }
+}
+
+impl<'arena> Decode<'arena> for &'arena BStr {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    decoder.read_string()
+  }
+}
+
+impl<'a> Encode<'a> for bool {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_u8(*self as u8);
+  }
+}
+
+impl<'arena> Decode<'arena> for bool {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    match decoder.read_u8()? {
+      0 => Ok(false),
+      1 => Ok(true),
+      value => Err(BinaryError::InvalidValue(format!("Invalid bool: {}", value))),
+    }
+  }
+}
+
+impl<'a, T: Encode<'a>> Encode<'a> for Option<T> {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    match self {
+      Some(value) => {
+        encoder.write_u8(1);
+        value.encode(encoder);
+      }
+      None => encoder.write_u8(0),
+    }
+  }
+}
+
+impl<'arena, T: Decode<'arena>> Decode<'arena> for Option<T> {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    if bool::decode(decoder)? { Ok(Some(T::decode(decoder)?)) } else { Ok(None) }
+  }
+}
+
+impl<'a, T: Encode<'a>> Encode<'a> for bumpalo::boxed::Box<'a, T> {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    self.as_ref().encode(encoder);
+  }
+}
+
+impl<'arena, T: Decode<'arena>> Decode<'arena> for bumpalo::boxed::Box<'arena, T> {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    let value = T::decode(decoder)?;
+    Ok(bumpalo::boxed::Box::new_in(value, decoder.arena))
+  }
+}
+
+impl<'a, T: Encode<'a>> Encode<'a> for bumpalo::collections::Vec<'a, T> {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_u32(self.len() as u32);
+    self.iter().for_each(|x| x.encode(encoder));
+  }
+}
+
+impl<'arena, T: Decode<'arena>> Decode<'arena> for bumpalo::collections::Vec<'arena, T> {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    let len = decoder.read_u32()? as usize;
+    // Every item takes at least a byte, so a corrupted length cannot allocate past the input
+    let mut items = bumpalo::collections::Vec::with_capacity_in(
+      len.min(decoder.bytes.len() - decoder.position),
+      decoder.arena
+    );
+    for _ in 0..len {
+      items.push(T::decode(decoder)?);
+    }
+    Ok(items)
+  }
+}
+
+impl<'a, T: Encode<'a>> Encode<'a> for Vec<T> {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_u32(self.len() as u32);
+    self.iter().for_each(|x| x.encode(encoder));
+  }
+}
+
+impl<'arena, T: Decode<'arena>> Decode<'arena> for Vec<T> {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    let len = decoder.read_u32()? as usize;
+    let mut items = Vec::with_capacity(len.min(decoder.bytes.len() - decoder.position));
+    for _ in 0..len {
+      items.push(T::decode(decoder)?);
+    }
+    Ok(items)
+  }
+}
+
+impl<'a> Encode<'a> for BodyType {
+  fn encode(&self, encoder: &mut Encoder<'a>) {
+    encoder.write_u8(match self {
+      BodyType::Basic => 0,
+      BodyType::Short => 1,
+      BodyType::Empty => 2,
+    });
+  }
+}
+
+impl<'arena> Decode<'arena> for BodyType {
+  fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+    match decoder.read_u8()? {
+      0 => Ok(BodyType::Basic),
+      1 => Ok(BodyType::Short),
+      2 => Ok(BodyType::Empty),
+      value => Err(BinaryError::InvalidValue(format!("Invalid body type: {}", value))),
+    }
+  }
+}
+
+// Keyword enums are stored by name, so reordering their variants keeps older data readable
+macro_rules! impl_keyword {
+  ($($ty:ty),*) => {
+    $(
+      impl<'a> Encode<'a> for $ty {
+        fn encode(&self, encoder: &mut Encoder<'a>) {
+          encoder.write_string(Cow::Owned(self.to_string().into_bytes()));
+        }
+      }
+
+      impl<'arena> Decode<'arena> for $ty {
+        fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+          decoder.read_enum()
+        }
+      }
+    )*
+  };
+}
+
+impl_keyword!(
+  AssignmentType,
+  BinaryType,
+  CastType,
+  Inheritance,
+  MagicMethodName,
+  MagicName,
+  Modifier,
+  PostType,
+  PreType,
+  Quote,
+  UseItemModifier,
+  Visibility
+);
+
+// The position of a node type in `with_node_types!` is its tag
+macro_rules! node_types {
+  ($($node_type:ident),* $(,)?) => {
+    const NODE_TYPES: &[NodeType] = &[$(NodeType::$node_type),*];
+
+    fn encode_wrapper<'a>(wrapper: &NodeWrapper<'a>, encoder: &mut Encoder<'a>) {
+      match wrapper {
+        $(NodeWrapper::$node_type(node) => node.encode(encoder)),*
+      }
+    }
+
+    fn decode_wrapper<'arena>(
+      node_type: &NodeType,
+      decoder: &mut Decoder<'arena, '_>
+    ) -> Result<NodeWrapper<'arena>, BinaryError> {
+      Ok(match node_type {
+        $(NodeType::$node_type => NodeWrapper::$node_type(Decode::decode(decoder)?)),*
+      })
+    }
+  };
+}
+
+with_node_types!(node_types);
+
+#[cfg(test)]
+mod tests {
+  use crate::{ builder::{ BlueprintBuildable, BoxBlueprint, Builder }, AssignmentType, Visibility };
+
+  use super::{ from_binary_in, BinaryError, FORMAT_VERSION, NODE_TYPES };
+
+  #[test]
+  fn node_type_tags() {
+    for (index, node_type) in NODE_TYPES.iter().enumerate() {
+      assert_eq!(node_type.clone() as usize, index);
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let node = b
+      .Program(
+        &[
+          b
+            .Assignment(b.Variable(b.Identifier("a")), AssignmentType::Coalesce, b.Number("21"))
+            .add_leading(b.CommentLine("Test leading")),
+          b.Property(
+            &[b.Attribute(&[b.AttributeItem("Deprecated", &[])])],
+            vec![Visibility::Public, Visibility::PrivateSet],
+            None,
+            &[],
+            &[b.PropertyItem(b.Identifier("a"), None, Some(b.String(crate::Quote::Single, "a")))]
+          ),
+        ]
+      )
+      .build(&arena);
+    let bytes = node.to_binary();
+    assert_eq!(node.as_ref(), &from_binary_in(&arena, &bytes).unwrap());
+
+    let mut outdated = bytes.clone();
+    outdated[5] = (FORMAT_VERSION + 1) as u8;
+    assert_eq!(
+      from_binary_in(&arena, &outdated),
+      Err(BinaryError::UnsupportedVersion(FORMAT_VERSION + 1))
+    );
+    assert_eq!(from_binary_in(&arena, &bytes[1..]), Err(BinaryError::InvalidHeader));
+    assert_eq!(
+      from_binary_in(&arena, &bytes[..bytes.len() - 1]),
+      Err(BinaryError::UnexpectedEnd)
+    );
+  }
+}
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use bstr::BString;
+use bstr::{ BStr, ByteSlice };
 use bumpalo::Bump;
 
 use crate::{
@@ -99,6 +99,7 @@ impl<'arena, 'a> BlueprintBuildable<'arena> for Blueprint<'a> {
       BlueprintWrapper::Clone(bp) => bp.build(arena),
       BlueprintWrapper::CommentBlock(bp) => bp.build(arena),
       BlueprintWrapper::CommentDoc(bp) => bp.build(arena),
+      BlueprintWrapper::CommentDocTag(bp) => bp.build(arena),
       BlueprintWrapper::CommentLine(bp) => bp.build(arena),
       BlueprintWrapper::Const(bp) => bp.build(arena),
       BlueprintWrapper::ConstProperty(bp) => bp.build(arena),
@@ -114,6 +115,7 @@ impl<'arena, 'a> BlueprintBuildable<'arena> for Blueprint<'a> {
       BlueprintWrapper::EncapsedPart(bp) => bp.build(arena),
       BlueprintWrapper::Enum(bp) => bp.build(arena),
       BlueprintWrapper::EnumItem(bp) => bp.build(arena),
+      BlueprintWrapper::Error(bp) => bp.build(arena),
       BlueprintWrapper::Eval(bp) => bp.build(arena),
       BlueprintWrapper::Exit(bp) => bp.build(arena),
       BlueprintWrapper::Finally(bp) => bp.build(arena),
@@ -216,6 +218,7 @@ pub enum BlueprintWrapper<'a> {
   Clone(crate::CloneBlueprint<'a>),
   CommentBlock(crate::CommentBlockBlueprint<'a>),
   CommentDoc(crate::CommentDocBlueprint<'a>),
+  CommentDocTag(crate::CommentDocTagBlueprint<'a>),
   CommentLine(crate::CommentLineBlueprint<'a>),
   Const(crate::ConstBlueprint<'a>),
   ConstProperty(crate::ConstPropertyBlueprint<'a>),
@@ -231,6 +234,7 @@ pub enum BlueprintWrapper<'a> {
   EncapsedPart(crate::EncapsedPartBlueprint<'a>),
   Enum(crate::EnumBlueprint<'a>),
   EnumItem(crate::EnumItemBlueprint<'a>),
+  Error(crate::ErrorBlueprint),
   Eval(crate::EvalBlueprint<'a>),
   Exit(crate::ExitBlueprint<'a>),
   Finally(crate::FinallyBlueprint<'a>),
@@ -327,10 +331,10 @@ impl<'arena, 'a> BlueprintBuildable<'arena> for &'a [Box<Blueprint<'a>>] {
 }
 
 impl<'arena, 'a> BlueprintBuildable<'arena> for &'a str {
-  type Result = BString;
+  type Result = &'arena BStr;
 
-  fn build(&self, _: &'arena Bump) -> Self::Result {
-    BString::new(self.as_bytes().to_vec())
+  fn build(&self, arena: &'arena Bump) -> Self::Result {
+    arena.alloc_str(self).as_bytes().as_bstr()
   }
 }
 
@@ -1,5 +1,8 @@
 pub mod macros;
 pub mod utils;
+pub mod precedence;
+pub mod number;
+pub mod string;
 
 #[cfg(feature = "serde")]
 pub mod serde;
@@ -7,19 +10,28 @@ pub mod serde;
 #[cfg(feature = "walker")]
 pub mod walker;
 
+#[cfg(feature = "visitor")]
+pub mod visitor;
+
 #[cfg(feature = "builder")]
 pub mod builder;
 
 #[cfg(feature = "printer")]
 pub mod printer;
 
+#[cfg(feature = "binary")]
+pub mod binary;
+
 use std::fmt::{ self, Display, Formatter };
 
-use bstr::BString;
+use bstr::{ BStr, ByteSlice };
 use bumpalo::Bump;
 
 #[cfg(feature = "serde")]
-use ::serde::{ Serialize, Deserialize };
+use ::serde::{ de::MapAccess, Serialize, Deserialize };
+
+#[cfg(feature = "serde")]
+use crate::serde::seed::{ DeserializeFields, NodeFields };
 
 use crate::utils::CloneIn;
 
@@ -29,9 +41,15 @@ use crate::builder::{ Blueprint, BlueprintBuildable, BlueprintWrapper, Builder }
 #[cfg(feature = "walker")]
 use crate::walker::{ Walkable, WalkerItem };
 
+#[cfg(feature = "visitor")]
+use crate::visitor::{ Visitable, Visitor, VisitorMut };
+
 #[cfg(feature = "printer")]
 use crate::printer::{ PrintBuilder, Printable, PrintType, PrintConfig };
 
+#[cfg(feature = "binary")]
+use crate::binary::{ BinaryError, Decode, Decoder, Encode, Encoder };
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub enum BodyType {
@@ -55,10 +73,11 @@ pub struct Location {
   pub offset: u32,
 }
 
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, PartialEq)]
 pub struct Node<'a> {
   pub node_type: NodeType,
+  #[cfg_attr(feature = "serde", serde(flatten))]
   pub wrapper: NodeWrapper<'a>,
   pub loc: Option<RangeLocation>,
   pub leadings: Option<bumpalo::collections::Vec<'a, Node<'a>>>,
@@ -78,6 +97,47 @@ impl<'a> Node<'a> {
     }
   }
 
+  /// Attribute groups of the declarations that accept them, `None` for every other node
+  pub fn attributes(&self) -> Option<&bumpalo::collections::Vec<'a, Node<'a>>> {
+    match &self.wrapper {
+      NodeWrapper::AnonymousClass(x) => Some(&x.attributes),
+      NodeWrapper::AnonymousFunction(x) => Some(&x.attributes),
+      NodeWrapper::ArrowFunction(x) => Some(&x.attributes),
+      NodeWrapper::Class(x) => Some(&x.attributes),
+      NodeWrapper::ConstProperty(x) => Some(&x.attributes),
+      NodeWrapper::ConstructorParameter(x) => Some(&x.attributes),
+      NodeWrapper::Enum(x) => Some(&x.attributes),
+      NodeWrapper::EnumItem(x) => Some(&x.attributes),
+      NodeWrapper::Function(x) => Some(&x.attributes),
+      NodeWrapper::Interface(x) => Some(&x.attributes),
+      NodeWrapper::Method(x) => Some(&x.attributes),
+      NodeWrapper::Parameter(x) => Some(&x.attributes),
+      NodeWrapper::Property(x) => Some(&x.attributes),
+      NodeWrapper::Trait(x) => Some(&x.attributes),
+      _ => None,
+    }
+  }
+
+  pub fn attributes_mut(&mut self) -> Option<&mut bumpalo::collections::Vec<'a, Node<'a>>> {
+    match &mut self.wrapper {
+      NodeWrapper::AnonymousClass(x) => Some(&mut x.attributes),
+      NodeWrapper::AnonymousFunction(x) => Some(&mut x.attributes),
+      NodeWrapper::ArrowFunction(x) => Some(&mut x.attributes),
+      NodeWrapper::Class(x) => Some(&mut x.attributes),
+      NodeWrapper::ConstProperty(x) => Some(&mut x.attributes),
+      NodeWrapper::ConstructorParameter(x) => Some(&mut x.attributes),
+      NodeWrapper::Enum(x) => Some(&mut x.attributes),
+      NodeWrapper::EnumItem(x) => Some(&mut x.attributes),
+      NodeWrapper::Function(x) => Some(&mut x.attributes),
+      NodeWrapper::Interface(x) => Some(&mut x.attributes),
+      NodeWrapper::Method(x) => Some(&mut x.attributes),
+      NodeWrapper::Parameter(x) => Some(&mut x.attributes),
+      NodeWrapper::Property(x) => Some(&mut x.attributes),
+      NodeWrapper::Trait(x) => Some(&mut x.attributes),
+      _ => None,
+    }
+  }
+
   pub fn leadings_push(&mut self, arena: &'a Bump, node: Node<'a>) {
     if let Some(leadings) = &mut self.leadings {
       leadings.push(node);
@@ -95,8 +155,9 @@ impl<'a> Node<'a> {
   }
 }
 
-#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum NodeWrapper<'a> {
   AnonymousClass(AnonymousClassNode<'a>),
   AnonymousFunction(AnonymousFunctionNode<'a>),
@@ -119,9 +180,10 @@ pub enum NodeWrapper<'a> {
   Class(ClassNode<'a>),
   ClassKeyword(ClassKeywordNode),
   Clone(CloneNode<'a>),
-  CommentBlock(CommentBlockNode),
-  CommentDoc(CommentDocNode),
-  CommentLine(CommentLineNode),
+  CommentBlock(CommentBlockNode<'a>),
+  CommentDoc(CommentDocNode<'a>),
+  CommentDocTag(CommentDocTagNode<'a>),
+  CommentLine(CommentLineNode<'a>),
   Const(ConstNode<'a>),
   ConstProperty(ConstPropertyNode<'a>),
   ConstructorParameter(ConstructorParameterNode<'a>),
@@ -136,6 +198,7 @@ pub enum NodeWrapper<'a> {
   EncapsedPart(EncapsedPartNode<'a>),
   Enum(EnumNode<'a>),
   EnumItem(EnumItemNode<'a>),
+  Error(ErrorNode),
   Eval(EvalNode<'a>),
   Exit(ExitNode<'a>),
   Finally(FinallyNode<'a>),
@@ -146,10 +209,10 @@ pub enum NodeWrapper<'a> {
   Goto(GotoNode<'a>),
   HaltCompiler(HaltCompilerNode),
   HereDoc(HereDocNode<'a>),
-  Identifier(IdentifierNode),
+  Identifier(IdentifierNode<'a>),
   If(IfNode<'a>),
   Include(IncludeNode<'a>),
-  Inline(InlineNode),
+  Inline(InlineNode<'a>),
   Interface(InterfaceNode<'a>),
   IntersectionType(IntersectionTypeNode<'a>),
   Label(LabelNode<'a>),
@@ -162,9 +225,9 @@ pub enum NodeWrapper<'a> {
   Namespace(NamespaceNode<'a>),
   Negate(NegateNode<'a>),
   New(NewNode<'a>),
-  NowDoc(NowDocNode),
+  NowDoc(NowDocNode<'a>),
   Null(NullNode),
-  Number(NumberNode),
+  Number(NumberNode<'a>),
   ObjectAccess(ObjectAccessNode<'a>),
   Parameter(ParameterNode<'a>),
   Parent(ParentNode),
@@ -183,7 +246,7 @@ pub enum NodeWrapper<'a> {
   Static(StaticNode<'a>),
   StaticKeyword(StaticKeywordNode),
   StaticLookup(StaticLookupNode<'a>),
-  String(StringNode),
+  String(StringNode<'a>),
   Switch(SwitchNode<'a>),
   Ternary(TernaryNode<'a>),
   This(ThisNode),
@@ -193,7 +256,7 @@ pub enum NodeWrapper<'a> {
   TraitUsePrecedence(TraitUsePrecedenceNode<'a>),
   Throw(ThrowNode<'a>),
   Try(TryNode<'a>),
-  Type(TypeNode),
+  Type(TypeNode<'a>),
   UnionType(UnionTypeNode<'a>),
   Use(UseNode<'a>),
   UseItem(UseItemNode<'a>),
@@ -231,6 +294,7 @@ pub enum NodeType {
   Clone,
   CommentBlock,
   CommentDoc,
+  CommentDocTag,
   CommentLine,
   Const,
   ConstProperty,
@@ -246,6 +310,7 @@ pub enum NodeType {
   EncapsedPart,
   Enum,
   EnumItem,
+  Error,
   Eval,
   Exit,
   Finally,
@@ -322,13 +387,14 @@ macro_rules! new_node {
       $($blueprint_field_name:ident: $blueprint_field_type:ty),* $(,)?
     }
   ) => {
-    #[cfg_attr(test, derive(Serialize))]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
     #[derive(Debug, PartialEq)]
     pub struct $struct_name<$lt> {
       $(pub $field_name: $field_type),*
     }
 
     impl<$lt> $struct_name<$lt> {
+      #[allow(clippy::too_many_arguments)]
       pub fn loc($($field_name: $field_type,)* loc: Option<RangeLocation>) -> Node<$lt> {
         Node {
           leadings: None,
@@ -353,6 +419,36 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "serde")]
+    impl<'arena> DeserializeFields<'arena> for $struct_name<'arena> {
+      fn deserialize_fields<'de, A>(fields: &mut NodeFields<'arena>, map: &mut A) -> Result<Self, A::Error>
+        where A: MapAccess<'de>
+      {
+        $(let mut $field_name = None;)*
+        while let Some(key) = fields.next_key(map)? {
+          match key.as_str() {
+            $(stringify!($field_name) => { $field_name = Some(fields.next_value(map)?); })*
+            _ => fields.skip_value(map)?,
+          }
+        }
+        Ok(Self { $($field_name: fields.finish($field_name, stringify!($field_name))?),* })
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'a> Encode<'a> for $struct_name<'a> {
+      fn encode(&self, encoder: &mut Encoder<'a>) {
+        $(self.$field_name.encode(encoder);)*
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'arena> Decode<'arena> for $struct_name<'arena> {
+      fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+        Ok(Self { $($field_name: Decode::decode(decoder)?),* })
+      }
+    }
+
     #[cfg(feature = "walker")]
     impl<'arena> Walkable<'arena> for $struct_name<'arena> {
       fn populate_walks<'a>(&'a self, stack: &mut std::collections::VecDeque<WalkerItem<'arena, 'a>>, level: u16) {
@@ -363,6 +459,17 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "visitor")]
+    impl<'arena> Visitable<'arena> for $struct_name<'arena> {
+      fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+        $(self.$field_name.accept(visitor);)*
+      }
+
+      fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+        $(self.$field_name.accept_mut(visitor);)*
+      }
+    }
+
     #[cfg(feature = "builder")]
     #[derive(Debug)]
     pub struct $blueprint_name<$blt> {
@@ -371,7 +478,7 @@ macro_rules! new_node {
 
     #[cfg(feature = "builder")]
     impl Builder {
-      #[allow(non_snake_case)]
+      #[allow(non_snake_case, clippy::too_many_arguments)]
       pub fn $node_type<$blt>(&self, $($blueprint_field_name: $blueprint_field_type,)*) -> std::boxed::Box<Blueprint<$blt>> {
         std::boxed::Box::new(
           Blueprint {
@@ -420,7 +527,7 @@ macro_rules! new_node {
       $($blueprint_field_name:ident: $blueprint_field_type:ty),* $(,)?
     }
   ) => {
-    #[cfg_attr(test, derive(Serialize))]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
     #[derive(Debug, PartialEq)]
     pub struct $struct_name {
       $(pub $field_name: $field_type),*
@@ -449,6 +556,38 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "serde")]
+    impl<'arena> DeserializeFields<'arena> for $struct_name {
+      fn deserialize_fields<'de, A>(fields: &mut NodeFields<'arena>, map: &mut A) -> Result<Self, A::Error>
+        where A: MapAccess<'de>
+      {
+        $(let mut $field_name = None;)*
+        while let Some(key) = fields.next_key(map)? {
+          match key.as_str() {
+            $(stringify!($field_name) => { $field_name = Some(fields.next_value(map)?); })*
+            _ => fields.skip_value(map)?,
+          }
+        }
+        Ok(Self { $($field_name: fields.finish($field_name, stringify!($field_name))?),* })
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'a> Encode<'a> for $struct_name {
+      #[allow(unused_variables)]
+      fn encode(&self, encoder: &mut Encoder<'a>) {
+        $(self.$field_name.encode(encoder);)*
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'arena> Decode<'arena> for $struct_name {
+      #[allow(unused_variables)]
+      fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+        Ok(Self { $($field_name: Decode::decode(decoder)?),* })
+      }
+    }
+
     #[cfg(feature = "walker")]
     impl<'arena> Walkable<'arena> for $struct_name {
       fn populate_walks<'a>(&'a self, stack: &mut std::collections::VecDeque<WalkerItem<'arena, 'a>>, level: u16) {
@@ -459,6 +598,17 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "visitor")]
+    impl<'arena> Visitable<'arena> for $struct_name {
+      fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+        $(self.$field_name.accept(visitor);)*
+      }
+
+      fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+        $(self.$field_name.accept_mut(visitor);)*
+      }
+    }
+
     #[cfg(feature = "builder")]
     #[derive(Debug)]
     pub struct $blueprint_name<$blt> {
@@ -514,7 +664,7 @@ macro_rules! new_node {
     $struct_name:ident { $($field_name:ident: $field_type:ty),* $(,)? },
     $blueprint_name:ident { $($blueprint_field_name:ident: $blueprint_field_type:ty),* $(,)? }
   ) => {
-    #[cfg_attr(test, derive(Serialize))]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
     #[derive(Debug, PartialEq)]
     pub struct $struct_name {
       $(pub $field_name: $field_type),*
@@ -543,6 +693,38 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "serde")]
+    impl<'arena> DeserializeFields<'arena> for $struct_name {
+      fn deserialize_fields<'de, A>(fields: &mut NodeFields<'arena>, map: &mut A) -> Result<Self, A::Error>
+        where A: MapAccess<'de>
+      {
+        $(let mut $field_name = None;)*
+        while let Some(key) = fields.next_key(map)? {
+          match key.as_str() {
+            $(stringify!($field_name) => { $field_name = Some(fields.next_value(map)?); })*
+            _ => fields.skip_value(map)?,
+          }
+        }
+        Ok(Self { $($field_name: fields.finish($field_name, stringify!($field_name))?),* })
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'a> Encode<'a> for $struct_name {
+      #[allow(unused_variables)]
+      fn encode(&self, encoder: &mut Encoder<'a>) {
+        $(self.$field_name.encode(encoder);)*
+      }
+    }
+
+    #[cfg(feature = "binary")]
+    impl<'arena> Decode<'arena> for $struct_name {
+      #[allow(unused_variables)]
+      fn decode(decoder: &mut Decoder<'arena, '_>) -> Result<Self, BinaryError> {
+        Ok(Self { $($field_name: Decode::decode(decoder)?),* })
+      }
+    }
+
     #[cfg(feature = "walker")]
     impl<'arena> Walkable<'arena> for $struct_name {
 
@@ -555,6 +737,19 @@ macro_rules! new_node {
       }
     }
 
+    #[cfg(feature = "visitor")]
+    impl<'arena> Visitable<'arena> for $struct_name {
+      #[allow(unused_variables)]
+      fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+        $(self.$field_name.accept(visitor);)*
+      }
+
+      #[allow(unused_variables)]
+      fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+        $(self.$field_name.accept_mut(visitor);)*
+      }
+    }
+
     #[cfg(feature = "builder")]
     #[derive(Debug)]
     pub struct $blueprint_name {
@@ -609,16 +804,16 @@ macro_rules! new_node {
   };
 }
 
-new_node!(AnonymousClass, AnonymousClassNode<'a> { parameters: bumpalo::collections::Vec<'a, Node<'a>>, extends: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, AnonymousClassBlueprint<'b> { parameters: &'b [Box<Blueprint<'b>>], extends: Option<Box<Blueprint<'b>>>, implements: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, });
-new_node!(AnonymousFunction, AnonymousFunctionNode<'a> { is_ref: bool, parameters: bumpalo::collections::Vec<'a, Node<'a>>, uses: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, AnonymousFunctionBlueprint<'b> { is_ref: bool, parameters: &'b [Box<Blueprint<'b>>], uses: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
+new_node!(AnonymousClass, AnonymousClassNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, parameters: bumpalo::collections::Vec<'a, Node<'a>>, extends: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, AnonymousClassBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], parameters: &'b [Box<Blueprint<'b>>], extends: Option<Box<Blueprint<'b>>>, implements: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, });
+new_node!(AnonymousFunction, AnonymousFunctionNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, is_ref: bool, parameters: bumpalo::collections::Vec<'a, Node<'a>>, uses: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, AnonymousFunctionBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], is_ref: bool, parameters: &'b [Box<Blueprint<'b>>], uses: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
 new_node!(Argument, ArgumentNode<'a> { name: Option<bumpalo::boxed::Box<'a, Node<'a>>>, value: bumpalo::boxed::Box<'a, Node<'a>>, }, ArgumentBlueprint<'b> { name: Option<Box<Blueprint<'b>>>, value: Box<Blueprint<'b>>, });
 new_node!(Array, ArrayNode<'a> { is_short: bool, items: bumpalo::collections::Vec<'a, Node<'a>>, }, ArrayBlueprint<'b> { is_short: bool, items: &'b [Box<Blueprint<'b>>], });
 new_node!(ArrayItem, ArrayItemNode<'a> { key: Option<bumpalo::boxed::Box<'a, Node<'a>>>, value: bumpalo::boxed::Box<'a, Node<'a>>, }, ArrayItemBlueprint<'b> { key: Option<Box<Blueprint<'b>>>, value: Box<Blueprint<'b>>, });
 new_node!(ArrayLookup, ArrayLookupNode<'a> { left: bumpalo::boxed::Box<'a, Node<'a>>, right: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, ArrayLookupBlueprint<'b> { left: Box<Blueprint<'b>>, right: Option<Box<Blueprint<'b>>>, });
-new_node!(ArrowFunction, ArrowFunctionNode<'a> { is_ref: bool, parameters: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, ArrowFunctionBlueprint<'b> { is_ref: bool, parameters: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
+new_node!(ArrowFunction, ArrowFunctionNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, is_ref: bool, parameters: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, ArrowFunctionBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], is_ref: bool, parameters: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
 new_node!(Assignment, AssignmentNode<'a> { left: bumpalo::boxed::Box<'a, Node<'a>>, operator: AssignmentType, right: bumpalo::boxed::Box<'a, Node<'a>>, }, AssignmentBlueprint<'b> { left: Box<Blueprint<'b>>, operator: AssignmentType, right: Box<Blueprint<'b>>, });
 new_node!(Attribute, AttributeNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }, AttributeBlueprint<'b> { items: &'b [Box<Blueprint<'b>>], });
-new_node!(AttributeItem, AttributeItemNode<'a> { name: BString, arguments: bumpalo::collections::Vec<'a, Node<'a>>, }, AttributeItemBlueprint<'b> { name: &'b str, arguments: &'b [Box<Blueprint<'b>>], });
+new_node!(AttributeItem, AttributeItemNode<'a> { name: &'a BStr, arguments: bumpalo::collections::Vec<'a, Node<'a>>, }, AttributeItemBlueprint<'b> { name: &'b str, arguments: &'b [Box<Blueprint<'b>>], });
 new_node!(Bin, BinNode<'a> { left: bumpalo::boxed::Box<'a, Node<'a>>, operator: BinaryType, right: bumpalo::boxed::Box<'a, Node<'a>>, }, BinBlueprint<'b> { left: Box<Blueprint<'b>>, operator: BinaryType, right: Box<Blueprint<'b>>, });
 new_node!(Block, BlockNode<'a> { statements: bumpalo::collections::Vec<'a, Node<'a>>, }, BlockBlueprint<'b> { statements: &'b [Box<Blueprint<'b>>], });
 new_node!(Boolean, BooleanNode { is_true: bool }, BooleanBlueprint { is_true: bool });
@@ -627,15 +822,16 @@ new_node!(Call, CallNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, argument
 new_node!(Case, CaseNode<'a> { condition: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, CaseBlueprint<'b> { condition: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
 new_node!(Cast, CastNode<'a> { cast_type: CastType, expression: bumpalo::boxed::Box<'a, Node<'a>>, }, CastBlueprint<'b> { cast_type: CastType, expression: Box<Blueprint<'b>>, });
 new_node!(Catch, CatchNode<'a> { types: bumpalo::collections::Vec<'a, Node<'a>>, variable: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, CatchBlueprint<'b> { types: &'b [Box<Blueprint<'b>>], variable: Option<Box<Blueprint<'b>>>, body: Box<Blueprint<'b>>, });
-new_node!(Class, ClassNode<'a> { inheritance: Option<Inheritance>, name: Option<bumpalo::boxed::Box<'a, Node<'a>>>, extends: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, is_readonly: bool, }, ClassBlueprint<'b> { inheritance: Option<Inheritance>, name: Option<Box<Blueprint<'b>>>, extends: Option<Box<Blueprint<'b>>>, implements: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, is_readonly: bool, });
+new_node!(Class, ClassNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, inheritance: Option<Inheritance>, name: Option<bumpalo::boxed::Box<'a, Node<'a>>>, extends: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, is_readonly: bool, }, ClassBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], inheritance: Option<Inheritance>, name: Option<Box<Blueprint<'b>>>, extends: Option<Box<Blueprint<'b>>>, implements: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, is_readonly: bool, });
 new_node!(ClassKeyword, ClassKeywordNode {}, ClassKeywordBlueprint {});
 new_node!(Clone, CloneNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, CloneBlueprint<'b> { statement: Box<Blueprint<'b>>, });
-new_node!(CommentBlock, CommentBlockNode { comment: BString, }, CommentBlockBlueprint<'b> { comment: &'b str, });
-new_node!(CommentDoc, CommentDocNode { comment: BString, }, CommentDocBlueprint<'b> { comment: &'b str, });
-new_node!(CommentLine, CommentLineNode { comment: BString, }, CommentLineBlueprint<'b> { comment: &'b str, });
+new_node!(CommentBlock, CommentBlockNode<'a> { comment: &'a BStr, }, CommentBlockBlueprint<'b> { comment: &'b str, });
+new_node!(CommentDoc, CommentDocNode<'a> { comment: &'a BStr, summary: &'a BStr, description: &'a BStr, tags: bumpalo::collections::Vec<'a, Node<'a>>, }, CommentDocBlueprint<'b> { comment: &'b str, summary: &'b str, description: &'b str, tags: &'b [Box<Blueprint<'b>>], });
+new_node!(CommentDocTag, CommentDocTagNode<'a> { name: &'a BStr, tag_type: Option<&'a BStr>, variable: Option<&'a BStr>, description: &'a BStr, }, CommentDocTagBlueprint<'b> { name: &'b str, tag_type: Option<&'b str>, variable: Option<&'b str>, description: &'b str, });
+new_node!(CommentLine, CommentLineNode<'a> { comment: &'a BStr, }, CommentLineBlueprint<'b> { comment: &'b str, });
 new_node!(Const, ConstNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }, ConstBlueprint<'b> { items: &'b [Box<Blueprint<'b>>], });
-new_node!(ConstProperty, ConstPropertyNode<'a> { const_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, visibilities: Vec<Visibility>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, ConstPropertyBlueprint<'b> { const_type: Option<Box<Blueprint<'b>>>, visibilities: Vec<Visibility>, items: &'b [Box<Blueprint<'b>>], });
-new_node!(ConstructorParameter, ConstructorParameterNode<'a> { visibilities: Vec<Visibility>, modifier: Option<Modifier>, parameter: bumpalo::boxed::Box<'a, Node<'a>>, }, ConstructorParameterBlueprint<'b> { visibilities: Vec<Visibility>, modifier: Option<Modifier>, parameter: Box<Blueprint<'b>>, });
+new_node!(ConstProperty, ConstPropertyNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, const_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, visibilities: Vec<Visibility>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, ConstPropertyBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], const_type: Option<Box<Blueprint<'b>>>, visibilities: Vec<Visibility>, items: &'b [Box<Blueprint<'b>>], });
+new_node!(ConstructorParameter, ConstructorParameterNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, visibilities: Vec<Visibility>, modifier: Option<Modifier>, parameter: bumpalo::boxed::Box<'a, Node<'a>>, }, ConstructorParameterBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], visibilities: Vec<Visibility>, modifier: Option<Modifier>, parameter: Box<Blueprint<'b>>, });
 new_node!(Continue, ContinueNode<'a> { statement: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, ContinueBlueprint<'b> { statement: Option<Box<Blueprint<'b>>>, });
 new_node!(Declare, DeclareNode<'a> { arguments: bumpalo::collections::Vec<'a, Node<'a>>, body: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body_type: BodyType, }, DeclareBlueprint<'b> { arguments: &'b [Box<Blueprint<'b>>], body: Option<Box<Blueprint<'b>>>, body_type: BodyType, });
 new_node!(DeclareArgument, DeclareArgumentNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, value: bumpalo::boxed::Box<'a, Node<'a>>, }, DeclareArgumentBlueprint<'b> { name: Box<Blueprint<'b>>, value: Box<Blueprint<'b>>, });
@@ -645,23 +841,24 @@ new_node!(Echo, EchoNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }
 new_node!(Else, ElseNode<'a> { body: bumpalo::boxed::Box<'a, Node<'a>>, is_short: bool, }, ElseBlueprint<'b> { body: Box<Blueprint<'b>>, is_short: bool, });
 new_node!(Encapsed, EncapsedNode<'a> { quote: Quote, values: bumpalo::collections::Vec<'a, Node<'a>>, }, EncapsedBlueprint<'b> { quote: Quote, values: &'b [Box<Blueprint<'b>>], });
 new_node!(EncapsedPart, EncapsedPartNode<'a> { is_advanced: bool, value: bumpalo::boxed::Box<'a, Node<'a>>, }, EncapsedPartBlueprint<'b> { is_advanced: bool, value: Box<Blueprint<'b>>, });
-new_node!(Enum, EnumNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, enum_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::collections::Vec<'a, Node<'a>>, }, EnumBlueprint<'b> { name: Box<Blueprint<'b>>, enum_type: Option<Box<Blueprint<'b>>>, implements: Option<Box<Blueprint<'b>>>, body: &'b [Box<Blueprint<'b>>], });
-new_node!(EnumItem, EnumItemNode<'a> { value: bumpalo::boxed::Box<'a, Node<'a>>, }, EnumItemBlueprint<'b> { value: Box<Blueprint<'b>>, });
+new_node!(Enum, EnumNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, name: bumpalo::boxed::Box<'a, Node<'a>>, enum_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, implements: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: bumpalo::collections::Vec<'a, Node<'a>>, }, EnumBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], name: Box<Blueprint<'b>>, enum_type: Option<Box<Blueprint<'b>>>, implements: Option<Box<Blueprint<'b>>>, body: &'b [Box<Blueprint<'b>>], });
+new_node!(EnumItem, EnumItemNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, value: bumpalo::boxed::Box<'a, Node<'a>>, }, EnumItemBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], value: Box<Blueprint<'b>>, });
+new_node!(Error, ErrorNode {}, ErrorBlueprint {});
 new_node!(Eval, EvalNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, EvalBlueprint<'b> { statement: Box<Blueprint<'b>>, });
 new_node!(Exit, ExitNode<'a> { statement: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, ExitBlueprint<'b> { statement: Option<Box<Blueprint<'b>>>, });
 new_node!(Finally, FinallyNode<'a> { body: bumpalo::boxed::Box<'a, Node<'a>>, }, FinallyBlueprint<'b> { body: Box<Blueprint<'b>>, });
 new_node!(For, ForNode<'a> { inits: bumpalo::collections::Vec<'a, Node<'a>>, tests: bumpalo::collections::Vec<'a, Node<'a>>, increments: bumpalo::collections::Vec<'a, Node<'a>>, body: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body_type: BodyType, }, ForBlueprint<'b> { inits: &'b [Box<Blueprint<'b>>], tests: &'b [Box<Blueprint<'b>>], increments: &'b [Box<Blueprint<'b>>], body: Option<Box<Blueprint<'b>>>, body_type: BodyType, });
 new_node!(Foreach, ForeachNode<'a> { source: bumpalo::boxed::Box<'a, Node<'a>>, key: Option<bumpalo::boxed::Box<'a, Node<'a>>>, value: bumpalo::boxed::Box<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, is_short: bool, }, ForeachBlueprint<'b> { source: Box<Blueprint<'b>>, key: Option<Box<Blueprint<'b>>>, value: Box<Blueprint<'b>>, body: Box<Blueprint<'b>>, is_short: bool, });
-new_node!(Function, FunctionNode<'a> { is_ref: bool, name: bumpalo::boxed::Box<'a, Node<'a>>, parameters: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, FunctionBlueprint<'b> { is_ref: bool, name: Box<Blueprint<'b>>, parameters: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Option<Box<Blueprint<'b>>>, });
+new_node!(Function, FunctionNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, is_ref: bool, name: bumpalo::boxed::Box<'a, Node<'a>>, parameters: bumpalo::collections::Vec<'a, Node<'a>>, return_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, body: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, FunctionBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], is_ref: bool, name: Box<Blueprint<'b>>, parameters: &'b [Box<Blueprint<'b>>], return_type: Option<Box<Blueprint<'b>>>, body: Option<Box<Blueprint<'b>>>, });
 new_node!(Global, GlobalNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }, GlobalBlueprint<'b> { items: &'b [Box<Blueprint<'b>>], });
 new_node!(Goto, GotoNode<'a> { label: bumpalo::boxed::Box<'a, Node<'a>>, }, GotoBlueprint<'b> { label: Box<Blueprint<'b>>, });
 new_node!(HaltCompiler, HaltCompilerNode {}, HaltCompilerBlueprint {});
-new_node!(HereDoc, HereDocNode<'a> { label: BString, values: bumpalo::collections::Vec<'a, Node<'a>>, }, HereDocBlueprint<'b> { label: &'b str, values: &'b [Box<Blueprint<'b>>], });
-new_node!(Identifier, IdentifierNode { name: BString, }, IdentifierBlueprint<'b> { name: &'b str, });
+new_node!(HereDoc, HereDocNode<'a> { label: &'a BStr, values: bumpalo::collections::Vec<'a, Node<'a>>, }, HereDocBlueprint<'b> { label: &'b str, values: &'b [Box<Blueprint<'b>>], });
+new_node!(Identifier, IdentifierNode<'a> { name: &'a BStr, }, IdentifierBlueprint<'b> { name: &'b str, });
 new_node!(If, IfNode<'a> { condition: bumpalo::boxed::Box<'a, Node<'a>>, valid: bumpalo::boxed::Box<'a, Node<'a>>, invalid: Option<bumpalo::boxed::Box<'a, Node<'a>>>, is_short: bool, }, IfBlueprint<'b> { condition: Box<Blueprint<'b>>, valid: Box<Blueprint<'b>>, invalid: Option<Box<Blueprint<'b>>>, is_short: bool, });
 new_node!(Include, IncludeNode<'a> { use_parenthesis: bool, is_require: bool, is_once: bool, argument: bumpalo::boxed::Box<'a, Node<'a>>, }, IncludeBlueprint<'b> { use_parenthesis: bool, is_require: bool, is_once: bool, argument: Box<Blueprint<'b>>, });
-new_node!(Inline, InlineNode { text: BString, }, InlineBlueprint<'b> { text: &'b str, });
-new_node!(Interface, InterfaceNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, extends: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, InterfaceBlueprint<'b> { name: Box<Blueprint<'b>>, extends: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, });
+new_node!(Inline, InlineNode<'a> { text: &'a BStr, }, InlineBlueprint<'b> { text: &'b str, });
+new_node!(Interface, InterfaceNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, name: bumpalo::boxed::Box<'a, Node<'a>>, extends: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, InterfaceBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], name: Box<Blueprint<'b>>, extends: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, });
 new_node!(IntersectionType, IntersectionTypeNode<'a> { types: bumpalo::collections::Vec<'a, Node<'a>>, }, IntersectionTypeBlueprint<'b> { types: &'b [Box<Blueprint<'b>>], });
 new_node!(Label, LabelNode<'a> { label: bumpalo::boxed::Box<'a, Node<'a>>, }, LabelBlueprint<'b> { label: Box<Blueprint<'b>>, });
 new_node!(List, ListNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }, ListBlueprint<'b> { items: &'b [Box<Blueprint<'b>>], });
@@ -673,22 +870,22 @@ new_node!(
 );
 new_node!(Match, MatchNode<'a> { condition: bumpalo::boxed::Box<'a, Node<'a>>, arms: bumpalo::collections::Vec<'a, Node<'a>>, }, MatchBlueprint<'b> { condition: Box<Blueprint<'b>>, arms: &'b [Box<Blueprint<'b>>], });
 new_node!(MatchArm, MatchArmNode<'a> { conditions: bumpalo::collections::Vec<'a, Node<'a>>, expr: bumpalo::boxed::Box<'a, Node<'a>>, }, MatchArmBlueprint<'b> { conditions: &'b [Box<Blueprint<'b>>], expr: Box<Blueprint<'b>>, });
-new_node!(Method, MethodNode<'a> { visibility: Option<Visibility>, inheritance: Option<Inheritance>, is_static: bool, function: bumpalo::boxed::Box<'a, Node<'a>>, }, MethodBlueprint<'b> { visibility: Option<Visibility>, inheritance: Option<Inheritance>, is_static: bool, function: Box<Blueprint<'b>>, });
-new_node!(Namespace, NamespaceNode<'a> { name: BString, body: bumpalo::boxed::Box<'a, Node<'a>>, is_bracket: bool, }, NamespaceBlueprint<'b> { name: &'b str, body: Box<Blueprint<'b>>, is_bracket: bool, });
+new_node!(Method, MethodNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, visibility: Option<Visibility>, inheritance: Option<Inheritance>, is_static: bool, function: bumpalo::boxed::Box<'a, Node<'a>>, }, MethodBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], visibility: Option<Visibility>, inheritance: Option<Inheritance>, is_static: bool, function: Box<Blueprint<'b>>, });
+new_node!(Namespace, NamespaceNode<'a> { name: &'a BStr, body: bumpalo::boxed::Box<'a, Node<'a>>, is_bracket: bool, }, NamespaceBlueprint<'b> { name: &'b str, body: Box<Blueprint<'b>>, is_bracket: bool, });
 new_node!(Negate, NegateNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, NegateBlueprint<'b> { statement: Box<Blueprint<'b>>, });
 new_node!(New, NewNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, NewBlueprint<'b> { statement: Box<Blueprint<'b>>, });
-new_node!(NowDoc, NowDocNode { label: BString, value: BString, }, NowDocBlueprint<'b> { label: &'b str, value: &'b str, });
+new_node!(NowDoc, NowDocNode<'a> { label: &'a BStr, value: &'a BStr, }, NowDocBlueprint<'b> { label: &'b str, value: &'b str, });
 new_node!(Null, NullNode {}, NullBlueprint {});
-new_node!(Number, NumberNode { value: BString, }, NumberBlueprint<'b> { value: &'b str, });
+new_node!(Number, NumberNode<'a> { value: &'a BStr, }, NumberBlueprint<'b> { value: &'b str, });
 new_node!(ObjectAccess, ObjectAccessNode<'a> { object: bumpalo::boxed::Box<'a, Node<'a>>, property: bumpalo::boxed::Box<'a, Node<'a>>, use_bracket: bool, is_nullsafe: bool, }, ObjectAccessBlueprint<'b> { object: Box<Blueprint<'b>>, property: Box<Blueprint<'b>>, use_bracket: bool, is_nullsafe: bool, });
-new_node!(Parameter, ParameterNode<'a> { variable_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, is_ref: bool, is_ellipsis: bool, name: bumpalo::boxed::Box<'a, Node<'a>>, value: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, ParameterBlueprint<'b> { variable_type: Option<Box<Blueprint<'b>>>, is_ref: bool, is_ellipsis: bool, name: Box<Blueprint<'b>>, value: Option<Box<Blueprint<'b>>>, });
+new_node!(Parameter, ParameterNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, variable_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, is_ref: bool, is_ellipsis: bool, name: bumpalo::boxed::Box<'a, Node<'a>>, value: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, ParameterBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], variable_type: Option<Box<Blueprint<'b>>>, is_ref: bool, is_ellipsis: bool, name: Box<Blueprint<'b>>, value: Option<Box<Blueprint<'b>>>, });
 new_node!(Parent, ParentNode {}, ParentBlueprint {});
 new_node!(Parenthesis, ParenthesisNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, ParenthesisBlueprint<'b> { statement: Box<Blueprint<'b>>, });
 new_node!(Post, PostNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, operator: PostType, }, PostBlueprint<'b> { statement: Box<Blueprint<'b>>, operator: PostType, });
 new_node!(Pre, PreNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, operator: PreType, }, PreBlueprint<'b> { statement: Box<Blueprint<'b>>, operator: PreType, });
 new_node!(Print, PrintNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, PrintBlueprint<'b> { statement: Box<Blueprint<'b>>, });
 new_node!(Program, ProgramNode<'a> { children: bumpalo::collections::Vec<'a, Node<'a>>, }, ProgramBlueprint<'b> { children: &'b [Box<Blueprint<'b>>], });
-new_node!(Property, PropertyNode<'a> { visibilities: Vec<Visibility>, modifier: Option<Modifier>, hooks: bumpalo::collections::Vec<'a, Node<'a>>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, PropertyBlueprint<'b> { visibilities: Vec<Visibility>, modifier: Option<Modifier>, hooks: &'b [Box<Blueprint<'b>>], items: &'b [Box<Blueprint<'b>>], });
+new_node!(Property, PropertyNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, visibilities: Vec<Visibility>, modifier: Option<Modifier>, hooks: bumpalo::collections::Vec<'a, Node<'a>>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, PropertyBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], visibilities: Vec<Visibility>, modifier: Option<Modifier>, hooks: &'b [Box<Blueprint<'b>>], items: &'b [Box<Blueprint<'b>>], });
 new_node!(PropertyHook, PropertyHookNode<'a> { is_get: bool, is_ref: bool, parameters: bumpalo::collections::Vec<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, PropertyHookBlueprint<'b> { is_get: bool, is_ref: bool, parameters: &'b [Box<Blueprint<'b>>], body: Box<Blueprint<'b>>, });
 new_node!(PropertyItem, PropertyItemNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, variable_type: Option<bumpalo::boxed::Box<'a, Node<'a>>>, value: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, PropertyItemBlueprint<'b> { name: Box<Blueprint<'b>>, variable_type: Option<Box<Blueprint<'b>>>, value: Option<Box<Blueprint<'b>>>, });
 new_node!(Reference, ReferenceNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, ReferenceBlueprint<'b> { statement: Box<Blueprint<'b>>, });
@@ -698,20 +895,20 @@ new_node!(Silent, SilentNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>,
 new_node!(Static, StaticNode<'a> { items: bumpalo::collections::Vec<'a, Node<'a>>, }, StaticBlueprint<'b> { items: &'b [Box<Blueprint<'b>>], });
 new_node!(StaticKeyword, StaticKeywordNode {}, StaticKeywordBlueprint {});
 new_node!(StaticLookup, StaticLookupNode<'a> { left: bumpalo::boxed::Box<'a, Node<'a>>, right: bumpalo::boxed::Box<'a, Node<'a>>, use_bracket: bool, }, StaticLookupBlueprint<'b> { left: Box<Blueprint<'b>>, right: Box<Blueprint<'b>>, use_bracket: bool, });
-new_node!(String, StringNode { quote: Quote, value: BString, }, StringBlueprint<'b> { quote: Quote, value: &'b str, });
+new_node!(String, StringNode<'a> { quote: Quote, value: &'a BStr, }, StringBlueprint<'b> { quote: Quote, value: &'b str, });
 new_node!(Switch, SwitchNode<'a> { condition: bumpalo::boxed::Box<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, is_short: bool, }, SwitchBlueprint<'b> { condition: Box<Blueprint<'b>>, body: Box<Blueprint<'b>>, is_short: bool, });
 new_node!(Ternary, TernaryNode<'a> { condition: bumpalo::boxed::Box<'a, Node<'a>>, valid: bumpalo::boxed::Box<'a, Node<'a>>, invalid: bumpalo::boxed::Box<'a, Node<'a>>, }, TernaryBlueprint<'b> { condition: Box<Blueprint<'b>>, valid: Box<Blueprint<'b>>, invalid: Box<Blueprint<'b>>, });
 new_node!(This, ThisNode {}, ThisBlueprint {});
 new_node!(Throw, ThrowNode<'a> { statement: bumpalo::boxed::Box<'a, Node<'a>>, }, ThrowBlueprint<'b> { statement: Box<Blueprint<'b>>, });
-new_node!(Trait, TraitNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, TraitBlueprint<'b> { name: Box<Blueprint<'b>>, body: Box<Blueprint<'b>>, });
+new_node!(Trait, TraitNode<'a> { attributes: bumpalo::collections::Vec<'a, Node<'a>>, name: bumpalo::boxed::Box<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, }, TraitBlueprint<'b> { attributes: &'b [Box<Blueprint<'b>>], name: Box<Blueprint<'b>>, body: Box<Blueprint<'b>>, });
 new_node!(TraitUse, TraitUseNode<'a> { traits: bumpalo::collections::Vec<'a, Node<'a>>, adaptations: bumpalo::collections::Vec<'a, Node<'a>>, }, TraitUseBlueprint<'b> { traits: &'b [Box<Blueprint<'b>>], adaptations: &'b [Box<Blueprint<'b>>], });
 new_node!(TraitUseAlias, TraitUseAliasNode<'a> { trait_name: Option<bumpalo::boxed::Box<'a, Node<'a>>>, method: bumpalo::boxed::Box<'a, Node<'a>>, alias: Option<bumpalo::boxed::Box<'a, Node<'a>>>, visibility: Option<Visibility>, }, TraitUseAliasBlueprint<'b> { trait_name: Option<Box<Blueprint<'b>>>, method: Box<Blueprint<'b>>, alias: Option<Box<Blueprint<'b>>>, visibility: Option<Visibility>, });
 new_node!(TraitUsePrecedence, TraitUsePrecedenceNode<'a> { trait_name: Option<bumpalo::boxed::Box<'a, Node<'a>>>, method: bumpalo::boxed::Box<'a, Node<'a>>, instead: bumpalo::boxed::Box<'a, Node<'a>>, }, TraitUsePrecedenceBlueprint<'b> { trait_name: Option<Box<Blueprint<'b>>>, method: Box<Blueprint<'b>>, instead: Box<Blueprint<'b>>, });
 new_node!(Try, TryNode<'a> { body: bumpalo::boxed::Box<'a, Node<'a>>, catches: bumpalo::collections::Vec<'a, Node<'a>>, }, TryBlueprint<'b> { body: Box<Blueprint<'b>>, catches: &'b [Box<Blueprint<'b>>], });
-new_node!(Type, TypeNode { is_nullable: bool, name: BString, }, TypeBlueprint<'b> { is_nullable: bool, name: &'b str, });
+new_node!(Type, TypeNode<'a> { is_nullable: bool, name: &'a BStr, }, TypeBlueprint<'b> { is_nullable: bool, name: &'b str, });
 new_node!(UnionType, UnionTypeNode<'a> { types: bumpalo::collections::Vec<'a, Node<'a>>, }, UnionTypeBlueprint<'b> { types: &'b [Box<Blueprint<'b>>], });
-new_node!(Use, UseNode<'a> { name: Option<BString>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, UseBlueprint<'b> { name: Option<&'b str>, items: &'b [Box<Blueprint<'b>>], });
-new_node!(UseItem, UseItemNode<'a> { modifier: Option<UseItemModifier>, name: BString, alias: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, UseItemBlueprint<'b> { modifier: Option<UseItemModifier>, name: &'b str, alias: Option<Box<Blueprint<'b>>>, });
+new_node!(Use, UseNode<'a> { name: Option<&'a BStr>, items: bumpalo::collections::Vec<'a, Node<'a>>, }, UseBlueprint<'b> { name: Option<&'b str>, items: &'b [Box<Blueprint<'b>>], });
+new_node!(UseItem, UseItemNode<'a> { modifier: Option<UseItemModifier>, name: &'a BStr, alias: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, UseItemBlueprint<'b> { modifier: Option<UseItemModifier>, name: &'b str, alias: Option<Box<Blueprint<'b>>>, });
 new_node!(Variable, VariableNode<'a> { name: bumpalo::boxed::Box<'a, Node<'a>>, }, VariableBlueprint<'b> { name: Box<Blueprint<'b>>, });
 new_node!(Variadic, VariadicNode<'a> { statement: Option<bumpalo::boxed::Box<'a, Node<'a>>>, }, VariadicBlueprint<'b> { statement: Option<Box<Blueprint<'b>>>, });
 new_node!(While, WhileNode<'a> { condition: bumpalo::boxed::Box<'a, Node<'a>>, body: bumpalo::boxed::Box<'a, Node<'a>>, is_short: bool, }, WhileBlueprint<'b> { condition: Box<Blueprint<'b>>, body: Box<Blueprint<'b>>, is_short: bool, });
@@ -740,11 +937,11 @@ pub enum MagicMethodName {
   DebugInfo,
 }
 
-impl TryFrom<&BString> for MagicMethodName {
+impl TryFrom<&BStr> for MagicMethodName {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"__construct" => Ok(MagicMethodName::Construct),
       b"__destruct" => Ok(MagicMethodName::Destruct),
       b"__call" => Ok(MagicMethodName::Call),
@@ -805,11 +1002,11 @@ pub enum MagicName {
   Property,
 }
 
-impl TryFrom<&BString> for MagicName {
+impl TryFrom<&BStr> for MagicName {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"__CLASS__" => Ok(MagicName::Class),
       b"__DIR__" => Ok(MagicName::Dir),
       b"__FILE__" => Ok(MagicName::File),
@@ -847,17 +1044,19 @@ pub enum PreType {
   Decrement,
   Addition,
   Subtraction,
+  BitwiseNot,
 }
 
-impl TryFrom<&BString> for PreType {
+impl TryFrom<&BStr> for PreType {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"++" => Ok(PreType::Increment),
       b"--" => Ok(PreType::Decrement),
       b"+" => Ok(PreType::Addition),
       b"-" => Ok(PreType::Subtraction),
+      b"~" => Ok(PreType::BitwiseNot),
       _ => Err(format!("Invalid pre type: {}", value)),
     }
   }
@@ -870,6 +1069,7 @@ impl Display for PreType {
       PreType::Decrement => "--",
       PreType::Addition => "+",
       PreType::Subtraction => "-",
+      PreType::BitwiseNot => "~",
     })
   }
 }
@@ -881,11 +1081,11 @@ pub enum PostType {
   Decrement,
 }
 
-impl TryFrom<&BString> for PostType {
+impl TryFrom<&BStr> for PostType {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"++" => Ok(PostType::Increment),
       b"--" => Ok(PostType::Decrement),
       _ => Err(format!("Invalid post type: {}", value)),
@@ -919,11 +1119,11 @@ pub enum CastType {
   Unset,
 }
 
-impl TryFrom<&BString> for CastType {
+impl TryFrom<&BStr> for CastType {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"int" => Ok(CastType::Int),
       b"integer" => Ok(CastType::Integer),
       b"bool" => Ok(CastType::Bool),
@@ -987,16 +1187,20 @@ pub enum BinaryType {
   BooleanAnd,
   BooleanOr,
   BooleanXor,
+  /// `and`, binding looser than `&&`
+  LogicalAnd,
+  /// `or`, binding looser than `||`
+  LogicalOr,
   Elvis,
   Coalesce,
   InstanceOf,
 }
 
-impl TryFrom<&BString> for BinaryType {
+impl TryFrom<&BStr> for BinaryType {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"+" => Ok(BinaryType::Addition),
       b"-" => Ok(BinaryType::Subtraction),
       b"*" => Ok(BinaryType::Multiplication),
@@ -1018,8 +1222,10 @@ impl TryFrom<&BString> for BinaryType {
       b">=" => Ok(BinaryType::IsGreaterOrEqual),
       b"<=>" => Ok(BinaryType::Spaceship),
       b"." => Ok(BinaryType::Concatenation),
-      b"&&" | b"and" => Ok(BinaryType::BooleanAnd),
-      b"||" | b"or" => Ok(BinaryType::BooleanOr),
+      b"&&" => Ok(BinaryType::BooleanAnd),
+      b"||" => Ok(BinaryType::BooleanOr),
+      b"and" => Ok(BinaryType::LogicalAnd),
+      b"or" => Ok(BinaryType::LogicalOr),
       b"xor" => Ok(BinaryType::BooleanXor),
       b"?:" => Ok(BinaryType::Elvis),
       b"??" => Ok(BinaryType::Coalesce),
@@ -1056,6 +1262,8 @@ impl Display for BinaryType {
       BinaryType::BooleanAnd => "&&",
       BinaryType::BooleanOr => "||",
       BinaryType::BooleanXor => "xor",
+      BinaryType::LogicalAnd => "and",
+      BinaryType::LogicalOr => "or",
       BinaryType::Elvis => "?:",
       BinaryType::Coalesce => "??",
       BinaryType::InstanceOf => "instanceof",
@@ -1083,11 +1291,11 @@ pub enum AssignmentType {
   BitwiseShiftLeft,
 }
 
-impl TryFrom<&BString> for AssignmentType {
+impl TryFrom<&BStr> for AssignmentType {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"=" => Ok(AssignmentType::Default),
       b"=&" => Ok(AssignmentType::Reference),
       b"??=" => Ok(AssignmentType::Coalesce),
@@ -1137,11 +1345,11 @@ pub enum UseItemModifier {
   Const,
 }
 
-impl TryFrom<&BString> for UseItemModifier {
+impl TryFrom<&BStr> for UseItemModifier {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"function" => Ok(UseItemModifier::Function),
       b"const" => Ok(UseItemModifier::Const),
       _ => Err(format!("Invalid use item modifier: {}", value)),
@@ -1165,11 +1373,11 @@ pub enum Modifier {
   Readonly,
 }
 
-impl TryFrom<&BString> for Modifier {
+impl TryFrom<&BStr> for Modifier {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"static" => Ok(Modifier::Static),
       b"readonly" => Ok(Modifier::Readonly),
       _ => Err(format!("Invalid modifier: {}", value)),
@@ -1194,11 +1402,11 @@ pub enum Quote {
   Backtick,
 }
 
-impl TryFrom<&BString> for Quote {
+impl TryFrom<&BStr> for Quote {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"'" => Ok(Quote::Single),
       b"\"" => Ok(Quote::Double),
       b"`" => Ok(Quote::Backtick),
@@ -1224,11 +1432,11 @@ pub enum Inheritance {
   Final,
 }
 
-impl TryFrom<&BString> for Inheritance {
+impl TryFrom<&BStr> for Inheritance {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"abstract" => Ok(Inheritance::Abstract),
       b"final" => Ok(Inheritance::Final),
       _ => Err(format!("Invalid inheritance: {}", value)),
@@ -1259,11 +1467,11 @@ pub enum Visibility {
   ProtectedSet,
 }
 
-impl TryFrom<&BString> for Visibility {
+impl TryFrom<&BStr> for Visibility {
   type Error = String;
 
-  fn try_from(value: &BString) -> Result<Self, Self::Error> {
-    match value.as_slice() {
+  fn try_from(value: &BStr) -> Result<Self, Self::Error> {
+    match value.as_bytes() {
       b"public" => Ok(Visibility::Public),
       b"public(get)" => Ok(Visibility::PublicGet),
       b"public(set)" => Ok(Visibility::PublicSet),
@@ -1296,7 +1504,7 @@ impl Display for Visibility {
 
 #[cfg(test)]
 mod tests {
-  use bstr::BString;
+  use bstr::BStr;
 
   use crate::{
     AssignmentType,
@@ -1316,42 +1524,42 @@ mod tests {
   #[test]
   fn magic_name() {
     assert_eq!(
-      MagicName::try_from(&BString::new("__CLASS__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__CLASS__")),
       Ok(MagicName::Class)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__DIR__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__DIR__")),
       Ok(MagicName::Dir)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__FILE__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__FILE__")),
       Ok(MagicName::File)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__FUNCTION__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__FUNCTION__")),
       Ok(MagicName::Function)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__LINE__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__LINE__")),
       Ok(MagicName::Line)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__METHOD__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__METHOD__")),
       Ok(MagicName::Method)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__NAMESPACE__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__NAMESPACE__")),
       Ok(MagicName::Namespace)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__TRAIT__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__TRAIT__")),
       Ok(MagicName::Trait)
     );
     assert_eq!(
-      MagicName::try_from(&BString::new("__PROPERTY__".as_bytes().to_vec())),
+      MagicName::try_from(BStr::new("__PROPERTY__")),
       Ok(MagicName::Property)
     );
-    assert!(MagicName::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(MagicName::try_from(BStr::new("none")).is_err());
 
     assert_eq!("__CLASS__", format!("{}", MagicName::Class));
     assert_eq!("__DIR__", format!("{}", MagicName::Dir));
@@ -1367,74 +1575,74 @@ mod tests {
   #[test]
   fn magic_method_name() {
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__construct".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__construct")),
       Ok(MagicMethodName::Construct)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__destruct".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__destruct")),
       Ok(MagicMethodName::Destruct)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__call".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__call")),
       Ok(MagicMethodName::Call)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__callStatic".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__callStatic")),
       Ok(MagicMethodName::CallStatic)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__get".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__get")),
       Ok(MagicMethodName::Get)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__set".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__set")),
       Ok(MagicMethodName::Set)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__isset".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__isset")),
       Ok(MagicMethodName::Isset)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__unset".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__unset")),
       Ok(MagicMethodName::Unset)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__sleep".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__sleep")),
       Ok(MagicMethodName::Sleep)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__wakeup".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__wakeup")),
       Ok(MagicMethodName::Wakeup)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__serialize".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__serialize")),
       Ok(MagicMethodName::Serialize)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__unserialize".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__unserialize")),
       Ok(MagicMethodName::Unserialize)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__toString".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__toString")),
       Ok(MagicMethodName::ToString)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__invoke".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__invoke")),
       Ok(MagicMethodName::Invoke)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__set_state".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__set_state")),
       Ok(MagicMethodName::SetState)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__clone".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__clone")),
       Ok(MagicMethodName::Clone)
     );
     assert_eq!(
-      MagicMethodName::try_from(&BString::new("__debugInfo".as_bytes().to_vec())),
+      MagicMethodName::try_from(BStr::new("__debugInfo")),
       Ok(MagicMethodName::DebugInfo)
     );
-    assert!(MagicMethodName::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(MagicMethodName::try_from(BStr::new("none")).is_err());
 
     assert_eq!("__construct", format!("{}", MagicMethodName::Construct));
     assert_eq!("__destruct", format!("{}", MagicMethodName::Destruct));
@@ -1457,11 +1665,11 @@ mod tests {
 
   #[test]
   fn pre_type() {
-    assert_eq!(PreType::try_from(&BString::new("++".as_bytes().to_vec())), Ok(PreType::Increment));
-    assert_eq!(PreType::try_from(&BString::new("--".as_bytes().to_vec())), Ok(PreType::Decrement));
-    assert_eq!(PreType::try_from(&BString::new("+".as_bytes().to_vec())), Ok(PreType::Addition));
-    assert_eq!(PreType::try_from(&BString::new("-".as_bytes().to_vec())), Ok(PreType::Subtraction));
-    assert!(PreType::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert_eq!(PreType::try_from(BStr::new("++")), Ok(PreType::Increment));
+    assert_eq!(PreType::try_from(BStr::new("--")), Ok(PreType::Decrement));
+    assert_eq!(PreType::try_from(BStr::new("+")), Ok(PreType::Addition));
+    assert_eq!(PreType::try_from(BStr::new("-")), Ok(PreType::Subtraction));
+    assert!(PreType::try_from(BStr::new("none")).is_err());
 
     assert_eq!("++", format!("{}", PreType::Increment));
     assert_eq!("--", format!("{}", PreType::Decrement));
@@ -1472,14 +1680,14 @@ mod tests {
   #[test]
   fn post_type() {
     assert_eq!(
-      PostType::try_from(&BString::new("++".as_bytes().to_vec())),
+      PostType::try_from(BStr::new("++")),
       Ok(PostType::Increment)
     );
     assert_eq!(
-      PostType::try_from(&BString::new("--".as_bytes().to_vec())),
+      PostType::try_from(BStr::new("--")),
       Ok(PostType::Decrement)
     );
-    assert!(PostType::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(PostType::try_from(BStr::new("none")).is_err());
 
     assert_eq!("++", format!("{}", PostType::Increment));
     assert_eq!("--", format!("{}", PostType::Decrement));
@@ -1487,37 +1695,37 @@ mod tests {
 
   #[test]
   fn cast_type() {
-    assert_eq!(CastType::try_from(&BString::new("int".as_bytes().to_vec())), Ok(CastType::Int));
+    assert_eq!(CastType::try_from(BStr::new("int")), Ok(CastType::Int));
     assert_eq!(
-      CastType::try_from(&BString::new("integer".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("integer")),
       Ok(CastType::Integer)
     );
-    assert_eq!(CastType::try_from(&BString::new("bool".as_bytes().to_vec())), Ok(CastType::Bool));
+    assert_eq!(CastType::try_from(BStr::new("bool")), Ok(CastType::Bool));
     assert_eq!(
-      CastType::try_from(&BString::new("boolean".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("boolean")),
       Ok(CastType::Boolean)
     );
-    assert_eq!(CastType::try_from(&BString::new("float".as_bytes().to_vec())), Ok(CastType::Float));
+    assert_eq!(CastType::try_from(BStr::new("float")), Ok(CastType::Float));
     assert_eq!(
-      CastType::try_from(&BString::new("double".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("double")),
       Ok(CastType::Double)
     );
-    assert_eq!(CastType::try_from(&BString::new("real".as_bytes().to_vec())), Ok(CastType::Real));
+    assert_eq!(CastType::try_from(BStr::new("real")), Ok(CastType::Real));
     assert_eq!(
-      CastType::try_from(&BString::new("string".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("string")),
       Ok(CastType::String)
     );
     assert_eq!(
-      CastType::try_from(&BString::new("binary".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("binary")),
       Ok(CastType::Binary)
     );
-    assert_eq!(CastType::try_from(&BString::new("array".as_bytes().to_vec())), Ok(CastType::Array));
+    assert_eq!(CastType::try_from(BStr::new("array")), Ok(CastType::Array));
     assert_eq!(
-      CastType::try_from(&BString::new("object".as_bytes().to_vec())),
+      CastType::try_from(BStr::new("object")),
       Ok(CastType::Object)
     );
-    assert_eq!(CastType::try_from(&BString::new("unset".as_bytes().to_vec())), Ok(CastType::Unset));
-    assert!(CastType::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert_eq!(CastType::try_from(BStr::new("unset")), Ok(CastType::Unset));
+    assert!(CastType::try_from(BStr::new("none")).is_err());
 
     assert_eq!("int", format!("{}", CastType::Int));
     assert_eq!("integer", format!("{}", CastType::Integer));
@@ -1536,122 +1744,122 @@ mod tests {
   #[test]
   fn binary_type() {
     assert_eq!(
-      BinaryType::try_from(&BString::new("+".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("+")),
       Ok(BinaryType::Addition)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("-".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("-")),
       Ok(BinaryType::Subtraction)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("*".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("*")),
       Ok(BinaryType::Multiplication)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("/".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("/")),
       Ok(BinaryType::Division)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("%".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("%")),
       Ok(BinaryType::Modulus)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("**".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("**")),
       Ok(BinaryType::Exponentiation)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("&".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("&")),
       Ok(BinaryType::BitwiseAnd)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("|".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("|")),
       Ok(BinaryType::BitwiseOr)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("^".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("^")),
       Ok(BinaryType::BitwiseXor)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("<<".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("<<")),
       Ok(BinaryType::BitwiseShiftLeft)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new(">>".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new(">>")),
       Ok(BinaryType::BitwiseShiftRight)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("==".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("==")),
       Ok(BinaryType::IsEqual)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("===".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("===")),
       Ok(BinaryType::IsIdentical)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("!=".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("!=")),
       Ok(BinaryType::IsNotEqual)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("!==".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("!==")),
       Ok(BinaryType::IsNotIdentical)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("<".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("<")),
       Ok(BinaryType::IsLesser)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new(">".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new(">")),
       Ok(BinaryType::IsGreater)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("<=".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("<=")),
       Ok(BinaryType::IsLesserOrEqual)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new(">=".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new(">=")),
       Ok(BinaryType::IsGreaterOrEqual)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("<=>".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("<=>")),
       Ok(BinaryType::Spaceship)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new(".".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new(".")),
       Ok(BinaryType::Concatenation)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("&&".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("&&")),
       Ok(BinaryType::BooleanAnd)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("and".as_bytes().to_vec())),
-      Ok(BinaryType::BooleanAnd)
+      BinaryType::try_from(BStr::new("and")),
+      Ok(BinaryType::LogicalAnd)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("||".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("||")),
       Ok(BinaryType::BooleanOr)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("or".as_bytes().to_vec())),
-      Ok(BinaryType::BooleanOr)
+      BinaryType::try_from(BStr::new("or")),
+      Ok(BinaryType::LogicalOr)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("xor".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("xor")),
       Ok(BinaryType::BooleanXor)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("?:".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("?:")),
       Ok(BinaryType::Elvis)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("??".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("??")),
       Ok(BinaryType::Coalesce)
     );
     assert_eq!(
-      BinaryType::try_from(&BString::new("instanceof".as_bytes().to_vec())),
+      BinaryType::try_from(BStr::new("instanceof")),
       Ok(BinaryType::InstanceOf)
     );
-    assert!(BinaryType::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(BinaryType::try_from(BStr::new("none")).is_err());
 
     assert_eq!("+", format!("{}", BinaryType::Addition));
     assert_eq!("-", format!("{}", BinaryType::Subtraction));
@@ -1685,62 +1893,62 @@ mod tests {
   #[test]
   fn assignment_type() {
     assert_eq!(
-      AssignmentType::try_from(&BString::new("=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("=")),
       Ok(AssignmentType::Default)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("=&".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("=&")),
       Ok(AssignmentType::Reference)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("??=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("??=")),
       Ok(AssignmentType::Coalesce)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("**=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("**=")),
       Ok(AssignmentType::Exponentiation)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("*=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("*=")),
       Ok(AssignmentType::Multiplication)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("/=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("/=")),
       Ok(AssignmentType::Division)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("+=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("+=")),
       Ok(AssignmentType::Addition)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("-=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("-=")),
       Ok(AssignmentType::Subtraction)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("%=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("%=")),
       Ok(AssignmentType::Modulus)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("&=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("&=")),
       Ok(AssignmentType::BitwiseAnd)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("|=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("|=")),
       Ok(AssignmentType::BitwiseOr)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("^=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("^=")),
       Ok(AssignmentType::BitwiseXor)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new(">>=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new(">>=")),
       Ok(AssignmentType::BitwiseShiftRight)
     );
     assert_eq!(
-      AssignmentType::try_from(&BString::new("<<=".as_bytes().to_vec())),
+      AssignmentType::try_from(BStr::new("<<=")),
       Ok(AssignmentType::BitwiseShiftLeft)
     );
-    assert!(AssignmentType::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(AssignmentType::try_from(BStr::new("none")).is_err());
 
     assert_eq!("=", format!("{}", AssignmentType::Default));
     assert_eq!("=&", format!("{}", AssignmentType::Reference));
@@ -1761,14 +1969,14 @@ mod tests {
   #[test]
   fn use_item_modifier() {
     assert_eq!(
-      UseItemModifier::try_from(&BString::new("const".as_bytes().to_vec())),
+      UseItemModifier::try_from(BStr::new("const")),
       Ok(UseItemModifier::Const)
     );
     assert_eq!(
-      UseItemModifier::try_from(&BString::new("function".as_bytes().to_vec())),
+      UseItemModifier::try_from(BStr::new("function")),
       Ok(UseItemModifier::Function)
     );
-    assert!(UseItemModifier::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(UseItemModifier::try_from(BStr::new("none")).is_err());
 
     assert_eq!("const", format!("{}", UseItemModifier::Const));
     assert_eq!("function", format!("{}", UseItemModifier::Function));
@@ -1777,14 +1985,14 @@ mod tests {
   #[test]
   fn modifier() {
     assert_eq!(
-      Modifier::try_from(&BString::new("static".as_bytes().to_vec())),
+      Modifier::try_from(BStr::new("static")),
       Ok(Modifier::Static)
     );
     assert_eq!(
-      Modifier::try_from(&BString::new("readonly".as_bytes().to_vec())),
+      Modifier::try_from(BStr::new("readonly")),
       Ok(Modifier::Readonly)
     );
-    assert!(Modifier::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(Modifier::try_from(BStr::new("none")).is_err());
 
     assert_eq!("static", format!("{}", Modifier::Static));
     assert_eq!("readonly", format!("{}", Modifier::Readonly));
@@ -1792,10 +2000,10 @@ mod tests {
 
   #[test]
   fn quote() {
-    assert_eq!(Quote::try_from(&BString::new("'".as_bytes().to_vec())), Ok(Quote::Single));
-    assert_eq!(Quote::try_from(&BString::new("\"".as_bytes().to_vec())), Ok(Quote::Double));
-    assert_eq!(Quote::try_from(&BString::new("`".as_bytes().to_vec())), Ok(Quote::Backtick));
-    assert!(Quote::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert_eq!(Quote::try_from(BStr::new("'")), Ok(Quote::Single));
+    assert_eq!(Quote::try_from(BStr::new("\"")), Ok(Quote::Double));
+    assert_eq!(Quote::try_from(BStr::new("`")), Ok(Quote::Backtick));
+    assert!(Quote::try_from(BStr::new("none")).is_err());
 
     assert_eq!("'", format!("{}", Quote::Single));
     assert_eq!("\"", format!("{}", Quote::Double));
@@ -1805,14 +2013,14 @@ mod tests {
   #[test]
   fn inheritance() {
     assert_eq!(
-      Inheritance::try_from(&BString::new("abstract".as_bytes().to_vec())),
+      Inheritance::try_from(BStr::new("abstract")),
       Ok(Inheritance::Abstract)
     );
     assert_eq!(
-      Inheritance::try_from(&BString::new("final".as_bytes().to_vec())),
+      Inheritance::try_from(BStr::new("final")),
       Ok(Inheritance::Final)
     );
-    assert!(Inheritance::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(Inheritance::try_from(BStr::new("none")).is_err());
 
     assert_eq!("abstract", format!("{}", Inheritance::Abstract));
     assert_eq!("final", format!("{}", Inheritance::Final));
@@ -1821,42 +2029,42 @@ mod tests {
   #[test]
   fn visibility() {
     assert_eq!(
-      Visibility::try_from(&BString::new("private".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("private")),
       Ok(Visibility::Private)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("private(get)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("private(get)")),
       Ok(Visibility::PrivateGet)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("private(set)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("private(set)")),
       Ok(Visibility::PrivateSet)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("protected".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("protected")),
       Ok(Visibility::Protected)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("protected(get)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("protected(get)")),
       Ok(Visibility::ProtectedGet)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("protected(set)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("protected(set)")),
       Ok(Visibility::ProtectedSet)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("public".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("public")),
       Ok(Visibility::Public)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("public(get)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("public(get)")),
       Ok(Visibility::PublicGet)
     );
     assert_eq!(
-      Visibility::try_from(&BString::new("public(set)".as_bytes().to_vec())),
+      Visibility::try_from(BStr::new("public(set)")),
       Ok(Visibility::PublicSet)
     );
-    assert!(Visibility::try_from(&BString::new("none".as_bytes().to_vec())).is_err());
+    assert!(Visibility::try_from(BStr::new("none")).is_err());
 
     assert_eq!("private", format!("{}", Visibility::Private));
     assert_eq!("private(get)", format!("{}", Visibility::PrivateGet));
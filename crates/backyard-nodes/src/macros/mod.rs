@@ -1 +1,3 @@
 pub mod cast;
+#[cfg(any(feature = "binary", feature = "visitor"))]
+pub(crate) mod node_types;
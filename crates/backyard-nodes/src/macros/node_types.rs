@@ -0,0 +1,117 @@
+/// Calls `$callback!` with every node type, listed in the declaration order of `NodeType`.
+/// Per node type dispatches such as the binary tags, the serde seed or the visitor hooks are
+/// generated from it.
+macro_rules! with_node_types {
+  ($callback:ident) => {
+    $callback!(
+      AnonymousClass,
+      AnonymousFunction,
+      Argument,
+      Array,
+      ArrayItem,
+      ArrayLookup,
+      ArrowFunction,
+      Assignment,
+      Attribute,
+      AttributeItem,
+      Bin,
+      Block,
+      Boolean,
+      Break,
+      Call,
+      Case,
+      Cast,
+      Catch,
+      Class,
+      ClassKeyword,
+      Clone,
+      CommentBlock,
+      CommentDoc,
+      CommentDocTag,
+      CommentLine,
+      Const,
+      ConstProperty,
+      ConstructorParameter,
+      Continue,
+      Declare,
+      DeclareArgument,
+      DoWhile,
+      DoWhileCondition,
+      Echo,
+      Else,
+      Encapsed,
+      EncapsedPart,
+      Enum,
+      EnumItem,
+      Error,
+      Eval,
+      Exit,
+      Finally,
+      For,
+      Foreach,
+      Function,
+      Global,
+      Goto,
+      HaltCompiler,
+      HereDoc,
+      Identifier,
+      If,
+      Include,
+      Inline,
+      Interface,
+      IntersectionType,
+      Label,
+      List,
+      Magic,
+      MagicMethod,
+      Match,
+      MatchArm,
+      Method,
+      Namespace,
+      Negate,
+      New,
+      NowDoc,
+      Null,
+      Number,
+      ObjectAccess,
+      Parameter,
+      Parent,
+      Parenthesis,
+      Post,
+      Pre,
+      Print,
+      Program,
+      Property,
+      PropertyHook,
+      PropertyItem,
+      Reference,
+      Return,
+      SelfKeyword,
+      Silent,
+      Static,
+      StaticKeyword,
+      StaticLookup,
+      String,
+      Switch,
+      Ternary,
+      This,
+      Trait,
+      TraitUse,
+      TraitUseAlias,
+      TraitUsePrecedence,
+      Throw,
+      Try,
+      Type,
+      UnionType,
+      Use,
+      UseItem,
+      Variable,
+      Variadic,
+      While,
+      Yield,
+      YieldFrom
+    );
+  };
+}
+
+pub(crate) use with_node_types;
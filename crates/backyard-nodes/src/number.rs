@@ -0,0 +1,128 @@
+//! Decoded value of numeric literals, following the rules of PHP 8.1
+
+use std::fmt::{ self, Display, Formatter };
+
+use bstr::BStr;
+
+use crate::NumberNode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberBase {
+  Binary,
+  Octal,
+  Decimal,
+  Hexadecimal,
+}
+
+impl NumberBase {
+  pub fn radix(&self) -> u32 {
+    match self {
+      NumberBase::Binary => 2,
+      NumberBase::Octal => 8,
+      NumberBase::Decimal => 10,
+      NumberBase::Hexadecimal => 16,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+  Int,
+  Float,
+}
+
+/// Value of a literal as PHP evaluates it, integers that do not fit an `i64` become floats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberValue {
+  Int(i64),
+  Float(f64),
+}
+
+impl NumberValue {
+  pub fn kind(&self) -> NumberKind {
+    match self {
+      NumberValue::Int(_) => NumberKind::Int,
+      NumberValue::Float(_) => NumberKind::Float,
+    }
+  }
+
+  pub fn as_f64(&self) -> f64 {
+    match self {
+      NumberValue::Int(x) => *x as f64,
+      NumberValue::Float(x) => *x,
+    }
+  }
+}
+
+impl Display for NumberValue {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      NumberValue::Int(x) => write!(f, "{}", x),
+      NumberValue::Float(x) => write!(f, "{}", x),
+    }
+  }
+}
+
+/// Reads a literal as written in the source, `None` when it is not a valid PHP number,
+/// e.g. the legacy octal `089`
+pub fn parse_number(raw: &BStr) -> Option<NumberValue> {
+  let digits = raw
+    .iter()
+    .filter(|x| **x != b'_')
+    .map(|x| char::from(*x))
+    .collect::<String>();
+  let base = number_base(raw);
+  let digits = match base {
+    NumberBase::Decimal if is_float(raw) => {
+      return digits.parse::<f64>().ok().map(NumberValue::Float);
+    }
+    NumberBase::Decimal => digits.as_str(),
+    NumberBase::Octal if digits.len() > 1 && digits.as_bytes()[1].is_ascii_digit() => &digits[1..],
+    _ => digits.get(2..)?,
+  };
+  let radix = base.radix();
+  if digits.is_empty() || !digits.chars().all(|x| x.is_digit(radix)) {
+    return None;
+  }
+  if let Ok(value) = i64::from_str_radix(digits, radix) {
+    return Some(NumberValue::Int(value));
+  }
+  if base == NumberBase::Decimal {
+    return digits.parse::<f64>().ok().map(NumberValue::Float);
+  }
+  let value = digits
+    .chars()
+    .fold(0.0, |acc, x| acc * f64::from(radix) + f64::from(x.to_digit(radix).unwrap_or(0)));
+  Some(NumberValue::Float(value))
+}
+
+/// Base of a literal as written in the source, a leading `0` followed by digits is octal
+pub fn number_base(raw: &BStr) -> NumberBase {
+  match raw.get(..2) {
+    Some([b'0', b'x' | b'X']) => NumberBase::Hexadecimal,
+    Some([b'0', b'b' | b'B']) => NumberBase::Binary,
+    Some([b'0', b'o' | b'O']) => NumberBase::Octal,
+    Some([b'0', b'0'..=b'9' | b'_']) if !is_float(raw) => NumberBase::Octal,
+    _ => NumberBase::Decimal,
+  }
+}
+
+fn is_float(raw: &BStr) -> bool {
+  let is_hex = raw.get(..2).is_some_and(|x| x.eq_ignore_ascii_case(b"0x"));
+  !is_hex && raw.iter().any(|x| matches!(x, b'.' | b'e' | b'E'))
+}
+
+impl<'a> NumberNode<'a> {
+  pub fn base(&self) -> NumberBase {
+    number_base(self.value)
+  }
+
+  pub fn kind(&self) -> Option<NumberKind> {
+    self.to_value().map(|x| x.kind())
+  }
+
+  /// Decoded value of the literal, see [`parse_number`]
+  pub fn to_value(&self) -> Option<NumberValue> {
+    parse_number(self.value)
+  }
+}
@@ -0,0 +1,100 @@
+//! Binding power of PHP operators, an operator with a higher value binds tighter
+
+use crate::{ BinaryType, Node, NodeWrapper };
+
+/// `or`
+pub const LOGICAL_OR: u8 = 1;
+/// `xor`
+pub const LOGICAL_XOR: u8 = 2;
+/// `and`
+pub const LOGICAL_AND: u8 = 3;
+/// `print`, `yield`, `yield from` and `throw`
+pub const PRINT: u8 = 4;
+/// `=` and every compound assignment
+pub const ASSIGNMENT: u8 = 5;
+/// `? :` and `?:`
+pub const TERNARY: u8 = 6;
+pub const COALESCE: u8 = 7;
+pub const BOOLEAN_OR: u8 = 8;
+pub const BOOLEAN_AND: u8 = 9;
+pub const BITWISE_OR: u8 = 10;
+pub const BITWISE_XOR: u8 = 11;
+pub const BITWISE_AND: u8 = 12;
+/// `==`, `!=`, `===`, `!==` and `<=>`
+pub const EQUALITY: u8 = 13;
+/// `<`, `<=`, `>` and `>=`
+pub const COMPARISON: u8 = 14;
+pub const CONCATENATION: u8 = 15;
+pub const SHIFT: u8 = 16;
+/// Binary `+` and `-`
+pub const ADDITIVE: u8 = 17;
+/// `*`, `/` and `%`
+pub const MULTIPLICATIVE: u8 = 18;
+/// `!`
+pub const NOT: u8 = 19;
+pub const INSTANCEOF: u8 = 20;
+/// `++`, `--`, `~`, casts, `@` and unary `+` and `-`
+pub const UNARY: u8 = 21;
+pub const EXPONENTIATION: u8 = 22;
+/// `clone` and `new`
+pub const NEW: u8 = 23;
+
+impl BinaryType {
+  pub fn precedence(&self) -> u8 {
+    match self {
+      BinaryType::LogicalOr => LOGICAL_OR,
+      BinaryType::BooleanXor => LOGICAL_XOR,
+      BinaryType::LogicalAnd => LOGICAL_AND,
+      BinaryType::Elvis => TERNARY,
+      BinaryType::Coalesce => COALESCE,
+      BinaryType::BooleanOr => BOOLEAN_OR,
+      BinaryType::BooleanAnd => BOOLEAN_AND,
+      BinaryType::BitwiseOr => BITWISE_OR,
+      BinaryType::BitwiseXor => BITWISE_XOR,
+      BinaryType::BitwiseAnd => BITWISE_AND,
+      | BinaryType::IsEqual
+      | BinaryType::IsNotEqual
+      | BinaryType::IsIdentical
+      | BinaryType::IsNotIdentical
+      | BinaryType::Spaceship => EQUALITY,
+      | BinaryType::IsLesser
+      | BinaryType::IsLesserOrEqual
+      | BinaryType::IsGreater
+      | BinaryType::IsGreaterOrEqual => COMPARISON,
+      BinaryType::Concatenation => CONCATENATION,
+      BinaryType::BitwiseShiftLeft | BinaryType::BitwiseShiftRight => SHIFT,
+      BinaryType::Addition | BinaryType::Subtraction => ADDITIVE,
+      BinaryType::Multiplication | BinaryType::Division | BinaryType::Modulus => MULTIPLICATIVE,
+      BinaryType::InstanceOf => INSTANCEOF,
+      BinaryType::Exponentiation => EXPONENTIATION,
+    }
+  }
+
+  /// `a ** b ** c` is `a ** (b ** c)` and `a ?? b ?? c` is `a ?? (b ?? c)`, every other binary
+  /// operator groups to the left
+  pub fn is_right_associative(&self) -> bool {
+    matches!(self, BinaryType::Exponentiation | BinaryType::Coalesce)
+  }
+
+  /// Comparisons can not be chained, `a == b == c` is a syntax error
+  pub fn is_non_associative(&self) -> bool {
+    matches!(self.precedence(), EQUALITY | COMPARISON)
+  }
+}
+
+/// Precedence of the operation `node` performs, `None` when it is not an operator. Constructs
+/// such as `print`, `yield` or arrow functions that extend to the end of the expression are `None`
+pub fn of(node: &Node) -> Option<u8> {
+  match &node.wrapper {
+    NodeWrapper::Bin(bin) => Some(bin.operator.precedence()),
+    NodeWrapper::Ternary(_) => Some(TERNARY),
+    NodeWrapper::Assignment(_) => Some(ASSIGNMENT),
+    NodeWrapper::Negate(_) => Some(NOT),
+    | NodeWrapper::Cast(_)
+    | NodeWrapper::Pre(_)
+    | NodeWrapper::Post(_)
+    | NodeWrapper::Silent(_) => Some(UNARY),
+    NodeWrapper::New(_) | NodeWrapper::Clone(_) => Some(NEW),
+    _ => None,
+  }
+}
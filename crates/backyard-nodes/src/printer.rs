@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use bstr::BString;
+use bstr::BStr;
 
 use crate::{
   AssignmentType,
@@ -171,6 +171,7 @@ impl<'arena> Printable for Node<'arena> {
       NodeWrapper::Clone(v) => v.build_print(config),
       NodeWrapper::CommentBlock(v) => v.build_print(config),
       NodeWrapper::CommentDoc(v) => v.build_print(config),
+      NodeWrapper::CommentDocTag(v) => v.build_print(config),
       NodeWrapper::CommentLine(v) => v.build_print(config),
       NodeWrapper::Const(v) => v.build_print(config),
       NodeWrapper::ConstProperty(v) => v.build_print(config),
@@ -186,6 +187,7 @@ impl<'arena> Printable for Node<'arena> {
       NodeWrapper::EncapsedPart(v) => v.build_print(config),
       NodeWrapper::Enum(v) => v.build_print(config),
       NodeWrapper::EnumItem(v) => v.build_print(config),
+      NodeWrapper::Error(v) => v.build_print(config),
       NodeWrapper::Eval(v) => v.build_print(config),
       NodeWrapper::Exit(v) => v.build_print(config),
       NodeWrapper::Finally(v) => v.build_print(config),
@@ -375,7 +377,7 @@ macro_rules! impl_build_printableable_enum {
   };
 }
 
-impl_build_printableable!(bool, BString);
+impl_build_printableable!(bool, &BStr);
 
 impl_build_printableable_enum!(
   BodyType,
@@ -395,7 +397,7 @@ impl_build_printableable_enum!(
 
 #[cfg(test)]
 mod tests {
-  use bstr::BString;
+  use bstr::BStr;
 
   use crate::{
     builder::{ BlueprintBuildable, Builder },
@@ -416,7 +418,7 @@ mod tests {
       )
       .build(&arena);
     let comment = CommentLineNode::loc(
-      BString::new(b"Test comment".to_vec()),
+      BStr::new("Test comment"),
       Some(RangeLocation {
         start: Location { line: 1, column: 0, offset: 0 },
         end: Location { line: 1, column: 0, offset: 0 },
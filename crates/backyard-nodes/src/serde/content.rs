@@ -0,0 +1,239 @@
+use std::{ fmt, marker::PhantomData };
+
+use serde::{
+  de::{
+    self,
+    value::{ MapDeserializer, SeqDeserializer },
+    IntoDeserializer,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+  },
+  forward_to_deserialize_any,
+  Deserialize,
+  Deserializer,
+};
+
+/// Any value of a self-describing format, kept to be deserialized once its type is known.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Content {
+  Bool(bool),
+  U64(u64),
+  I64(i64),
+  F64(f64),
+  String(String),
+  Bytes(Vec<u8>),
+  None,
+  Some(Box<Content>),
+  Unit,
+  Seq(Vec<Content>),
+  Map(Vec<(Content, Content)>),
+}
+
+impl<'de> Deserialize<'de> for Content {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+    deserializer.deserialize_any(ContentVisitor)
+  }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+  type Value = Content;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("any value")
+  }
+
+  fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+    Ok(Content::Bool(value))
+  }
+
+  fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+    Ok(Content::I64(value))
+  }
+
+  fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+    Ok(Content::U64(value))
+  }
+
+  fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+    Ok(Content::F64(value))
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+    Ok(Content::String(value.to_owned()))
+  }
+
+  fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+    Ok(Content::String(value))
+  }
+
+  fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+    Ok(Content::Bytes(value.to_vec()))
+  }
+
+  fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Self::Value, E> {
+    Ok(Content::Bytes(value))
+  }
+
+  fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(Content::None)
+  }
+
+  fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where D: Deserializer<'de>
+  {
+    Content::deserialize(deserializer).map(|value| Content::Some(Box::new(value)))
+  }
+
+  fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(Content::Unit)
+  }
+
+  fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where D: Deserializer<'de>
+  {
+    Content::deserialize(deserializer)
+  }
+
+  fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error> where A: SeqAccess<'de> {
+    let mut items = vec![];
+    while let Some(item) = seq.next_element()? {
+      items.push(item);
+    }
+    Ok(Content::Seq(items))
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: MapAccess<'de> {
+    let mut entries = vec![];
+    while let Some(entry) = map.next_entry()? {
+      entries.push(entry);
+    }
+    Ok(Content::Map(entries))
+  }
+}
+
+/// Replays a buffered `Content`, failing with the error type of the outer deserializer.
+pub(crate) struct ContentDeserializer<E> {
+  content: Content,
+  marker: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> IntoDeserializer<'de, E> for Content {
+  type Deserializer = ContentDeserializer<E>;
+
+  fn into_deserializer(self) -> Self::Deserializer {
+    ContentDeserializer { content: self, marker: PhantomData }
+  }
+}
+
+impl<'de, E: de::Error> Deserializer<'de> for ContentDeserializer<E> {
+  type Error = E;
+
+  fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, E> where V: Visitor<'de> {
+    match self.content {
+      Content::Bool(value) => visitor.visit_bool(value),
+      Content::U64(value) => visitor.visit_u64(value),
+      Content::I64(value) => visitor.visit_i64(value),
+      Content::F64(value) => visitor.visit_f64(value),
+      Content::String(value) => visitor.visit_string(value),
+      Content::Bytes(value) => visitor.visit_byte_buf(value),
+      Content::None => visitor.visit_none(),
+      Content::Some(value) => visitor.visit_some(value.into_deserializer()),
+      Content::Unit => visitor.visit_unit(),
+      Content::Seq(items) => {
+        let mut seq = SeqDeserializer::new(items.into_iter());
+        let value = visitor.visit_seq(&mut seq)?;
+        seq.end()?;
+        Ok(value)
+      }
+      Content::Map(mut entries) => {
+        // A buffered node is read back with `node_type` first, so its own fields are typed as
+        // they are read instead of being buffered again at every level of the tree
+        let node_type = entries
+          .iter()
+          .position(|(key, _)| matches!(key, Content::String(key) if key == "node_type"));
+        if let Some(index) = node_type {
+          let entry = entries.remove(index);
+          entries.insert(0, entry);
+        }
+        let mut map = MapDeserializer::new(entries.into_iter());
+        let value = visitor.visit_map(&mut map)?;
+        map.end()?;
+        Ok(value)
+      }
+    }
+  }
+
+  fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, E> where V: Visitor<'de> {
+    match self.content {
+      Content::None | Content::Unit => visitor.visit_none(),
+      Content::Some(value) => visitor.visit_some(value.into_deserializer()),
+      _ => visitor.visit_some(self),
+    }
+  }
+
+  fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, E>
+    where V: Visitor<'de>
+  {
+    visitor.visit_newtype_struct(self)
+  }
+
+  fn deserialize_enum<V>(
+    self,
+    name: &'static str,
+    variants: &'static [&'static str],
+    visitor: V
+  ) -> Result<V::Value, E>
+    where V: Visitor<'de>
+  {
+    match self.content {
+      Content::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+      Content::Map(entries) if entries.len() == 1 => {
+        let map = MapDeserializer::new(entries.into_iter());
+        map.deserialize_enum(name, variants, visitor)
+      }
+      _ => self.deserialize_any(visitor),
+    }
+  }
+
+  forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+    unit_struct seq tuple tuple_struct map struct identifier ignored_any
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde::{ de::{ value::Error, IntoDeserializer }, Deserialize };
+
+  use super::Content;
+
+  #[test]
+  fn node_type_first() {
+    let key = |x: &str| Content::String(x.to_string());
+    let content = Content::Map(
+      vec![
+        (key("leadings"), Content::None),
+        (key("loc"), Content::None),
+        (key("node_type"), key("Null")),
+        (key("trailings"), Content::None)
+      ]
+    );
+    let replayed = Content::deserialize(
+      IntoDeserializer::<Error>::into_deserializer(content)
+    ).unwrap();
+    assert_eq!(
+      replayed,
+      Content::Map(
+        vec![
+          (key("node_type"), key("Null")),
+          (key("leadings"), Content::None),
+          (key("loc"), Content::None),
+          (key("trailings"), Content::None)
+        ]
+      )
+    );
+  }
+}
@@ -1,24 +1,7 @@
-// For now, it's incredibly hard to serde deserialize bumpalo Vec and Box.
-// So we just use this SerializableNode as work around to make deserialize possible.
-pub mod deserialize;
-pub mod serialize;
+// Node implements Serialize directly and is deserialized into an arena with seed::NodeSeed.
+// SerializableNode is an owned copy of the tree, for results that have to outlive the arena
+// such as `parse()`. A `Node` borrows its arena and cannot be returned along with it.
+mod content;
 pub mod node;
-
-use bumpalo::Bump;
-use node::SerializableNode;
-use serialize::Serializable;
-use deserialize::Deserializable;
-
-use crate::Node;
-
-impl<'arena> Node<'arena> {
-  pub fn serializable(&self) -> SerializableNode {
-    self.to_serializable()
-  }
-}
-
-impl SerializableNode {
-  pub fn deserialize_in<'arena>(&self, arena: &'arena Bump) -> Node<'arena> {
-    self.to_deserialize_in(arena)
-  }
-}
+pub mod php_parser;
+pub mod seed;
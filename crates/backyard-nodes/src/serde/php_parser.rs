@@ -0,0 +1,2834 @@
+// Conversion from and to the JSON layout of nikic/PHP-Parser (`JsonSerializer` and `JsonDecoder`).
+// Exports follow PHP-Parser 5 node names, imports accept both 4.x and 5.x names.
+use bstr::{ BStr, ByteSlice };
+use bumpalo::{ Bump, boxed::Box, collections::Vec };
+use serde_json::{ json, Map, Value };
+
+use crate::{
+  visitor::Visitor,
+  AnonymousClassNode,
+  AnonymousFunctionNode,
+  ArgumentNode,
+  ArrayItemNode,
+  ArrayLookupNode,
+  ArrayNode,
+  ArrowFunctionNode,
+  AssignmentNode,
+  AssignmentType,
+  AttributeItemNode,
+  AttributeNode,
+  BinNode,
+  BinaryType,
+  BlockNode,
+  BodyType,
+  BooleanNode,
+  BreakNode,
+  CallNode,
+  CaseNode,
+  CastNode,
+  CastType,
+  CatchNode,
+  ClassKeywordNode,
+  ClassNode,
+  CloneNode,
+  CommentBlockNode,
+  CommentDocNode,
+  CommentLineNode,
+  ConstNode,
+  ConstPropertyNode,
+  ConstructorParameterNode,
+  ContinueNode,
+  DeclareArgumentNode,
+  DeclareNode,
+  DoWhileConditionNode,
+  DoWhileNode,
+  EchoNode,
+  ElseNode,
+  EncapsedNode,
+  EncapsedPartNode,
+  EnumItemNode,
+  EnumNode,
+  ErrorNode,
+  EvalNode,
+  ExitNode,
+  FinallyNode,
+  ForNode,
+  ForeachNode,
+  FunctionNode,
+  GlobalNode,
+  GotoNode,
+  HaltCompilerNode,
+  HereDocNode,
+  IdentifierNode,
+  IfNode,
+  IncludeNode,
+  Inheritance,
+  InlineNode,
+  InterfaceNode,
+  IntersectionTypeNode,
+  LabelNode,
+  ListNode,
+  Location,
+  MagicMethodName,
+  MagicMethodNode,
+  MagicName,
+  MagicNode,
+  MatchArmNode,
+  MatchNode,
+  MethodNode,
+  Modifier,
+  NamespaceNode,
+  NegateNode,
+  NewNode,
+  Node,
+  NodeType,
+  NodeWrapper,
+  NowDocNode,
+  NullNode,
+  NumberNode,
+  ObjectAccessNode,
+  ParameterNode,
+  ParentNode,
+  PostNode,
+  PostType,
+  PreNode,
+  PreType,
+  PrintNode,
+  ProgramNode,
+  PropertyHookNode,
+  PropertyItemNode,
+  PropertyNode,
+  Quote,
+  RangeLocation,
+  ReferenceNode,
+  ReturnNode,
+  SelfNode,
+  SilentNode,
+  StaticKeywordNode,
+  StaticLookupNode,
+  StaticNode,
+  StringNode,
+  SwitchNode,
+  TernaryNode,
+  ThisNode,
+  ThrowNode,
+  TraitNode,
+  TraitUseAliasNode,
+  TraitUseNode,
+  TraitUsePrecedenceNode,
+  TryNode,
+  TypeNode,
+  UnionTypeNode,
+  UseItemModifier,
+  UseItemNode,
+  UseNode,
+  VariableNode,
+  VariadicNode,
+  Visibility,
+  WhileNode,
+  YieldFromNode,
+  YieldNode,
+  number::NumberValue,
+  string::{ escape_double, escape_single, StringPart },
+  utils::IntoBoxedNode,
+};
+
+impl<'arena> Node<'arena> {
+  /// Converts the node into the JSON produced by nikic/PHP-Parser, a program becomes the
+  /// statement array PHP-Parser returns for a whole file.
+  pub fn to_php_parser(&self) -> Value {
+    Exporter::node(self)
+  }
+
+  /// Builds a node from JSON produced by nikic/PHP-Parser, a statement array becomes a program.
+  pub fn from_php_parser_in(arena: &'arena Bump, value: &Value) -> Result<Node<'arena>, String> {
+    Importer { arena }.root(value)
+  }
+}
+
+const BUILTIN_TYPES: &[&[u8]] = &[
+  b"array",
+  b"bool",
+  b"callable",
+  b"false",
+  b"float",
+  b"int",
+  b"iterable",
+  b"mixed",
+  b"never",
+  b"null",
+  b"object",
+  b"string",
+  b"true",
+  b"void",
+];
+
+const MODIFIER_PUBLIC: u64 = 1;
+const MODIFIER_PROTECTED: u64 = 2;
+const MODIFIER_PRIVATE: u64 = 4;
+const MODIFIER_STATIC: u64 = 8;
+const MODIFIER_ABSTRACT: u64 = 16;
+const MODIFIER_FINAL: u64 = 32;
+const MODIFIER_READONLY: u64 = 64;
+const MODIFIER_PUBLIC_SET: u64 = 128;
+const MODIFIER_PROTECTED_SET: u64 = 256;
+const MODIFIER_PRIVATE_SET: u64 = 512;
+
+const STRING_SINGLE: u64 = 1;
+const STRING_DOUBLE: u64 = 2;
+const STRING_HEREDOC: u64 = 3;
+const STRING_NOWDOC: u64 = 4;
+
+struct Exporter;
+
+/// Trailing comments of a statement. The parser attaches a comment following a statement to
+/// the last node before it, which may be nested in the statement, e.g. the body of a method.
+/// Statements inside blocks export their own.
+struct Trailings {
+  blocks: usize,
+  comments: std::vec::Vec<(u32, Value)>,
+}
+
+impl<'arena> Visitor<'arena> for Trailings {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    if self.blocks > 0 {
+      return;
+    }
+    for trailing in node.trailings.iter().flatten() {
+      if let Some(comment) = Exporter::comment(trailing) {
+        let offset = trailing.loc.as_ref().map_or(0, |loc| loc.start.offset);
+        self.comments.push((offset, comment));
+      }
+    }
+  }
+
+  fn enter_block(&mut self, _: &Node<'arena>) {
+    self.blocks += 1;
+  }
+
+  fn leave_block(&mut self, _: &Node<'arena>) {
+    self.blocks -= 1;
+  }
+}
+
+impl Exporter {
+  fn node(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Program(x) => Self::statements(&x.children),
+      _ => Self::try_statement(node).unwrap_or_else(|| Self::expression(node)),
+    }
+  }
+
+  fn object(node_type: &str, node: &Node, fields: Value) -> Value {
+    Self::object_with(node_type, node, fields, Map::new())
+  }
+
+  fn object_with(
+    node_type: &str,
+    node: &Node,
+    fields: Value,
+    mut attributes: Map<String, Value>
+  ) -> Value {
+    let mut object = Map::new();
+    object.insert("nodeType".into(), node_type.into());
+    if let Value::Object(fields) = fields {
+      object.extend(fields);
+    }
+    attributes.extend(Self::attributes(node));
+    object.insert("attributes".into(), Value::Object(attributes));
+    Value::Object(object)
+  }
+
+  fn attributes(node: &Node) -> Map<String, Value> {
+    let mut attributes = Map::new();
+    if let Some(loc) = &node.loc {
+      attributes.insert("startLine".into(), loc.start.line.into());
+      attributes.insert("startFilePos".into(), loc.start.offset.into());
+      attributes.insert("endLine".into(), loc.end.line.into());
+      attributes.insert("endFilePos".into(), (i64::from(loc.end.offset) - 1).into());
+    }
+    let comments = node.leadings
+      .iter()
+      .flatten()
+      .filter_map(Self::comment)
+      .collect::<std::vec::Vec<_>>();
+    if !comments.is_empty() {
+      attributes.insert("comments".into(), Value::Array(comments));
+    }
+    attributes
+  }
+
+  fn comment(node: &Node) -> Option<Value> {
+    let (node_type, text) = match &node.wrapper {
+      NodeWrapper::CommentLine(x) => ("Comment", format!("//{}", x.comment)),
+      NodeWrapper::CommentBlock(x) => ("Comment", format!("/*{}*/", x.comment)),
+      NodeWrapper::CommentDoc(x) => ("Comment_Doc", format!("/**{}*/", x.comment)),
+      _ => {
+        return None;
+      }
+    };
+    let mut comment = Map::new();
+    comment.insert("nodeType".into(), node_type.into());
+    comment.insert("text".into(), text.into());
+    if let Some(loc) = &node.loc {
+      comment.insert("line".into(), loc.start.line.into());
+      comment.insert("filePos".into(), loc.start.offset.into());
+      comment.insert("endLine".into(), loc.end.line.into());
+      comment.insert("endFilePos".into(), (i64::from(loc.end.offset) - 1).into());
+    }
+    Some(Value::Object(comment))
+  }
+
+  fn nop(comments: std::vec::Vec<Value>) -> Value {
+    let first = &comments[0];
+    let last = &comments[comments.len() - 1];
+    json!({
+      "nodeType": "Stmt_Nop",
+      "attributes": {
+        "startLine": first["line"],
+        "startFilePos": first["filePos"],
+        "endLine": last["endLine"],
+        "endFilePos": last["endFilePos"],
+        "comments": comments,
+      },
+    })
+  }
+
+  fn prepend_comments(statement: &mut Value, mut comments: std::vec::Vec<Value>) {
+    if comments.is_empty() {
+      return;
+    }
+    if let Some(attributes) = statement.get_mut("attributes").and_then(Value::as_object_mut) {
+      if let Some(Value::Array(existing)) = attributes.remove("comments") {
+        comments.extend(existing);
+      }
+      attributes.insert("comments".into(), Value::Array(comments));
+    }
+  }
+
+  fn text(value: &[u8]) -> Value {
+    Value::String(value.to_str_lossy().into_owned())
+  }
+
+  fn bare_name(name: &[u8]) -> Value {
+    json!({ "nodeType": "Name", "name": Self::text(name), "attributes": {} })
+  }
+
+  fn list(nodes: &[Node], f: fn(&Node) -> Value) -> Value {
+    Value::Array(nodes.iter().map(f).collect())
+  }
+
+  fn optional(node: &Option<Box<Node>>, f: fn(&Node) -> Value) -> Value {
+    node.as_deref().map_or(Value::Null, f)
+  }
+
+  fn statements(nodes: &[Node]) -> Value {
+    let mut statements = vec![];
+    let mut comments = vec![];
+    for node in nodes {
+      if let Some(comment) = Self::comment(node) {
+        comments.push(comment);
+        continue;
+      }
+      let mut statement = Self::statement(node);
+      Self::prepend_comments(&mut statement, std::mem::take(&mut comments));
+      statements.push(statement);
+      comments.extend(Self::trailings(node));
+    }
+    if !comments.is_empty() {
+      statements.push(Self::nop(comments));
+    }
+    Value::Array(statements)
+  }
+
+  fn trailings(node: &Node) -> std::vec::Vec<Value> {
+    let mut trailings = Trailings { blocks: 0, comments: vec![] };
+    node.visit(&mut trailings);
+    trailings.comments.sort_by_key(|(offset, _)| *offset);
+    trailings.comments
+      .into_iter()
+      .map(|(_, comment)| comment)
+      .collect()
+  }
+
+  fn body(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Block(x) => Self::statements(&x.statements),
+      _ => Self::statements(std::slice::from_ref(node)),
+    }
+  }
+
+  fn optional_body(node: &Option<Box<Node>>) -> Value {
+    node.as_deref().map_or_else(|| json!([]), Self::body)
+  }
+
+  fn statement(node: &Node) -> Value {
+    if let Some(statement) = Self::try_statement(node) {
+      return statement;
+    }
+    let mut expr = Self::expression(node);
+    if let Some(attributes) = expr.get_mut("attributes").and_then(Value::as_object_mut) {
+      attributes.remove("comments");
+    }
+    let mut statement = Self::object("Stmt_Expression", node, json!({ "expr": expr }));
+    // The statement ends at its `;`, which the expression location leaves out
+    if let Some(end) = statement.pointer_mut("/attributes/endFilePos") {
+      *end = (end.as_i64().unwrap_or_default() + 1).into();
+    }
+    statement
+  }
+
+  fn try_statement(node: &Node) -> Option<Value> {
+    let statement = match &node.wrapper {
+      NodeWrapper::Block(x) =>
+        Self::object("Stmt_Block", node, json!({ "stmts": Self::statements(&x.statements) })),
+      NodeWrapper::Break(x) =>
+        Self::object(
+          "Stmt_Break",
+          node,
+          json!({ "num": Self::optional(&x.statement, Self::expression) })
+        ),
+      NodeWrapper::Call(x) if Self::is_function(&x.name, b"unset") =>
+        Self::object("Stmt_Unset", node, json!({ "vars": Self::argument_values(&x.arguments) })),
+      NodeWrapper::Case(x) =>
+        Self::object(
+          "Stmt_Case",
+          node,
+          json!({
+            "cond": Self::optional(&x.condition, Self::expression),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::Catch(x) =>
+        Self::object(
+          "Stmt_Catch",
+          node,
+          json!({
+            "types": Self::list(&x.types, Self::name),
+            "var": Self::optional(&x.variable, Self::expression),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::Class(x) => Self::class(node, x),
+      NodeWrapper::CommentBlock(_) | NodeWrapper::CommentDoc(_) | NodeWrapper::CommentLine(_) =>
+        Self::nop(Self::comment(node).into_iter().collect()),
+      NodeWrapper::Const(x) =>
+        Self::object(
+          "Stmt_Const",
+          node,
+          json!({ "consts": Self::list(&x.items, Self::const_item) })
+        ),
+      NodeWrapper::ConstProperty(x) =>
+        Self::object(
+          "Stmt_ClassConst",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "flags": Self::flags(&x.visibilities, None, None),
+            "consts": Self::list(&x.items, Self::const_item),
+            "type": Self::optional(&x.const_type, Self::type_hint),
+          })
+        ),
+      NodeWrapper::Continue(x) =>
+        Self::object(
+          "Stmt_Continue",
+          node,
+          json!({ "num": Self::optional(&x.statement, Self::expression) })
+        ),
+      NodeWrapper::Declare(x) =>
+        Self::object(
+          "Stmt_Declare",
+          node,
+          json!({
+            "declares": Self::list(&x.arguments, Self::declare_item),
+            "stmts": x.body.as_deref().map_or(Value::Null, Self::body),
+          })
+        ),
+      NodeWrapper::DoWhile(x) => {
+        let condition = match &x.condition.wrapper {
+          NodeWrapper::DoWhileCondition(x) => &x.condition,
+          _ => &x.condition,
+        };
+        Self::object(
+          "Stmt_Do",
+          node,
+          json!({ "stmts": Self::body(&x.body), "cond": Self::expression(condition) })
+        )
+      }
+      NodeWrapper::Echo(x) =>
+        Self::object("Stmt_Echo", node, json!({ "exprs": Self::list(&x.items, Self::expression) })),
+      NodeWrapper::Enum(x) =>
+        Self::object(
+          "Stmt_Enum",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "name": Self::identifier(&x.name),
+            "scalarType": Self::optional(&x.enum_type, Self::type_hint),
+            "implements": x.implements.iter().map(|x| Self::name(x)).collect::<std::vec::Vec<_>>(),
+            "stmts": Self::statements(&x.body),
+          })
+        ),
+      NodeWrapper::EnumItem(x) => {
+        let (name, expr) = match &x.value.wrapper {
+          NodeWrapper::Assignment(x) => (Self::identifier(&x.left), Self::expression(&x.right)),
+          _ => (Self::identifier(&x.value), Value::Null),
+        };
+        Self::object(
+          "Stmt_EnumCase",
+          node,
+          json!({ "attrGroups": Self::attr_groups(node), "name": name, "expr": expr })
+        )
+      }
+      NodeWrapper::For(x) =>
+        Self::object(
+          "Stmt_For",
+          node,
+          json!({
+            "init": Self::list(&x.inits, Self::expression),
+            "cond": Self::list(&x.tests, Self::expression),
+            "loop": Self::list(&x.increments, Self::expression),
+            "stmts": Self::optional_body(&x.body),
+          })
+        ),
+      NodeWrapper::Foreach(x) =>
+        Self::object(
+          "Stmt_Foreach",
+          node,
+          json!({
+            "expr": Self::expression(&x.source),
+            "keyVar": Self::optional(&x.key, Self::expression),
+            "byRef": matches!(x.value.wrapper, NodeWrapper::Reference(_)),
+            "valueVar": Self::destructure(&x.value),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::Function(x) =>
+        Self::object(
+          "Stmt_Function",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "byRef": x.is_ref,
+            "name": Self::identifier(&x.name),
+            "params": Self::list(&x.parameters, Self::param),
+            "returnType": Self::optional(&x.return_type, Self::type_hint),
+            "stmts": Self::optional_body(&x.body),
+          })
+        ),
+      NodeWrapper::Global(x) =>
+        Self::object(
+          "Stmt_Global",
+          node,
+          json!({ "vars": Self::list(&x.items, Self::expression) })
+        ),
+      NodeWrapper::Goto(x) =>
+        Self::object("Stmt_Goto", node, json!({ "name": Self::identifier(&x.label) })),
+      NodeWrapper::HaltCompiler(_) =>
+        Self::object("Stmt_HaltCompiler", node, json!({ "remaining": "" })),
+      NodeWrapper::If(x) => Self::if_statement(node, x),
+      NodeWrapper::Inline(x) =>
+        Self::object("Stmt_InlineHTML", node, json!({ "value": Self::text(x.text) })),
+      NodeWrapper::Interface(x) =>
+        Self::object(
+          "Stmt_Interface",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "name": Self::identifier(&x.name),
+            "extends": Self::list(&x.extends, Self::name),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::Label(x) =>
+        Self::object("Stmt_Label", node, json!({ "name": Self::identifier(&x.label) })),
+      NodeWrapper::Method(x) => {
+        let mut attr_groups = Self::attr_groups(node);
+        attr_groups.extend(Self::attr_groups(&x.function));
+        let flags =
+          Self::flags(x.visibility.as_slice(), None, x.inheritance.as_ref()) |
+          (if x.is_static { MODIFIER_STATIC } else { 0 });
+        let NodeWrapper::Function(function) = &x.function.wrapper else {
+          return None;
+        };
+        Self::object(
+          "Stmt_ClassMethod",
+          node,
+          json!({
+            "attrGroups": attr_groups,
+            "flags": flags,
+            "byRef": function.is_ref,
+            "name": Self::identifier(&function.name),
+            "params": Self::list(&function.parameters, Self::param),
+            "returnType": Self::optional(&function.return_type, Self::type_hint),
+            "stmts": function.body.as_deref().map_or(Value::Null, Self::body),
+          })
+        )
+      }
+      NodeWrapper::Namespace(x) => {
+        let name = if x.name.is_empty() {
+          Value::Null
+        } else {
+          Self::bare_name(x.name)
+        };
+        Self::object_with(
+          "Stmt_Namespace",
+          node,
+          json!({ "name": name, "stmts": Self::body(&x.body) }),
+          Self::kind(if x.is_bracket { 2 } else { 1 })
+        )
+      }
+      NodeWrapper::Property(x) => {
+        let property_type = x.items
+          .first()
+          .and_then(|x| match &x.wrapper {
+            NodeWrapper::PropertyItem(x) => x.variable_type.as_deref(),
+            _ => None,
+          })
+          .map_or(Value::Null, Self::type_hint);
+        Self::object(
+          "Stmt_Property",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "flags": Self::flags(&x.visibilities, x.modifier.as_ref(), None),
+            "type": property_type,
+            "props": Self::list(&x.items, Self::property_item),
+            "hooks": Self::list(&x.hooks, Self::property_hook),
+          })
+        )
+      }
+      NodeWrapper::Return(x) =>
+        Self::object(
+          "Stmt_Return",
+          node,
+          json!({ "expr": Self::optional(&x.statement, Self::expression) })
+        ),
+      NodeWrapper::Static(x) =>
+        Self::object(
+          "Stmt_Static",
+          node,
+          json!({ "vars": Self::list(&x.items, Self::static_var) })
+        ),
+      NodeWrapper::Switch(x) => {
+        let cases = match &x.body.wrapper {
+          NodeWrapper::Block(x) => &x.statements[..],
+          _ => std::slice::from_ref(&*x.body),
+        };
+        Self::object(
+          "Stmt_Switch",
+          node,
+          json!({
+            "cond": Self::expression(&x.condition),
+            "cases": cases.iter().filter_map(Self::try_statement).collect::<std::vec::Vec<_>>(),
+          })
+        )
+      }
+      NodeWrapper::Trait(x) =>
+        Self::object(
+          "Stmt_Trait",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "name": Self::identifier(&x.name),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::TraitUse(x) =>
+        Self::object(
+          "Stmt_TraitUse",
+          node,
+          json!({
+            "traits": Self::list(&x.traits, Self::name),
+            "adaptations": Self::list(&x.adaptations, Self::statement),
+          })
+        ),
+      NodeWrapper::TraitUseAlias(x) =>
+        Self::object(
+          "Stmt_TraitUseAdaptation_Alias",
+          node,
+          json!({
+            "trait": Self::optional(&x.trait_name, Self::name),
+            "method": Self::identifier(&x.method),
+            "newModifier": x.visibility.as_ref().map(Self::visibility_flag),
+            "newName": Self::optional(&x.alias, Self::identifier),
+          })
+        ),
+      NodeWrapper::TraitUsePrecedence(x) =>
+        Self::object(
+          "Stmt_TraitUseAdaptation_Precedence",
+          node,
+          json!({
+            "trait": Self::optional(&x.trait_name, Self::name),
+            "method": Self::identifier(&x.method),
+            "insteadof": [Self::name(&x.instead)],
+          })
+        ),
+      NodeWrapper::Try(x) => {
+        let finally = x.catches
+          .iter()
+          .find_map(|catch| match &catch.wrapper {
+            NodeWrapper::Finally(x) =>
+              Some(Self::object("Stmt_Finally", catch, json!({ "stmts": Self::body(&x.body) }))),
+            _ => None,
+          })
+          .unwrap_or(Value::Null);
+        let catches = x.catches
+          .iter()
+          .filter(|x| x.node_type == NodeType::Catch)
+          .map(Self::statement)
+          .collect::<std::vec::Vec<_>>();
+        Self::object(
+          "Stmt_TryCatch",
+          node,
+          json!({ "stmts": Self::body(&x.body), "catches": catches, "finally": finally })
+        )
+      }
+      NodeWrapper::Use(x) => Self::use_statement(node, x),
+      NodeWrapper::While(x) =>
+        Self::object(
+          "Stmt_While",
+          node,
+          json!({ "cond": Self::expression(&x.condition), "stmts": Self::body(&x.body) })
+        ),
+      _ => {
+        return None;
+      }
+    };
+    Some(statement)
+  }
+
+  fn class(node: &Node, x: &crate::ClassNode) -> Value {
+    let flags =
+      Self::flags(&[], None, x.inheritance.as_ref()) |
+      (if x.is_readonly { MODIFIER_READONLY } else { 0 });
+    Self::object(
+      "Stmt_Class",
+      node,
+      json!({
+        "attrGroups": Self::attr_groups(node),
+        "flags": flags,
+        "name": Self::optional(&x.name, Self::identifier),
+        "extends": Self::optional(&x.extends, Self::name),
+        "implements": Self::list(&x.implements, Self::name),
+        "stmts": Self::body(&x.body),
+      })
+    )
+  }
+
+  fn if_statement(node: &Node, x: &crate::IfNode) -> Value {
+    let mut elseifs = vec![];
+    let mut otherwise = Value::Null;
+    let mut invalid = x.invalid.as_deref();
+    while let Some(node) = invalid {
+      let NodeWrapper::Else(x) = &node.wrapper else {
+        break;
+      };
+      if let NodeWrapper::If(elseif) = &x.body.wrapper {
+        elseifs.push(
+          Self::object(
+            "Stmt_ElseIf",
+            &x.body,
+            json!({
+              "cond": Self::expression(&elseif.condition),
+              "stmts": Self::body(&elseif.valid),
+            })
+          )
+        );
+        invalid = elseif.invalid.as_deref();
+      } else {
+        otherwise = Self::object("Stmt_Else", node, json!({ "stmts": Self::body(&x.body) }));
+        break;
+      }
+    }
+    Self::object(
+      "Stmt_If",
+      node,
+      json!({
+        "cond": Self::expression(&x.condition),
+        "stmts": Self::body(&x.valid),
+        "elseifs": elseifs,
+        "else": otherwise,
+      })
+    )
+  }
+
+  fn use_statement(node: &Node, x: &crate::UseNode) -> Value {
+    let modifiers = x.items
+      .iter()
+      .map(|item| match &item.wrapper {
+        NodeWrapper::UseItem(x) => Self::use_type(x.modifier.as_ref()),
+        _ => 0,
+      })
+      .collect::<std::vec::Vec<_>>();
+    let Some(prefix) = x.name else {
+      return Self::object(
+        "Stmt_Use",
+        node,
+        json!({
+          "type": modifiers.first().copied().unwrap_or(1),
+          "uses": x.items.iter().map(|x| Self::use_item(x, 0)).collect::<std::vec::Vec<_>>(),
+        })
+      );
+    };
+    let is_shared = modifiers.windows(2).all(|x| x[0] == x[1]);
+    let use_type = if is_shared { modifiers.first().copied().unwrap_or(1) } else { 0 };
+    let uses = x.items
+      .iter()
+      .zip(modifiers)
+      .map(|(item, modifier)| Self::use_item(item, if is_shared { 0 } else { modifier }))
+      .collect::<std::vec::Vec<_>>();
+    Self::object(
+      "Stmt_GroupUse",
+      node,
+      json!({
+        "type": use_type,
+        "prefix": Self::bare_name(prefix.trim_end_with(|x| x == '\\').as_bytes()),
+        "uses": uses,
+      })
+    )
+  }
+
+  fn use_type(modifier: Option<&UseItemModifier>) -> u64 {
+    match modifier {
+      None => 1,
+      Some(UseItemModifier::Function) => 2,
+      Some(UseItemModifier::Const) => 3,
+    }
+  }
+
+  fn use_item(node: &Node, use_type: u64) -> Value {
+    let NodeWrapper::UseItem(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    Self::object(
+      "UseItem",
+      node,
+      json!({
+        "type": use_type,
+        "name": Self::bare_name(x.name),
+        "alias": Self::optional(&x.alias, Self::identifier),
+      })
+    )
+  }
+
+  fn const_item(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Assignment(x) =>
+        Self::object(
+          "Const",
+          node,
+          json!({ "name": Self::identifier(&x.left), "value": Self::expression(&x.right) })
+        ),
+      _ => Self::object("Const", node, json!({ "name": Self::identifier(node), "value": null })),
+    }
+  }
+
+  fn declare_item(node: &Node) -> Value {
+    let NodeWrapper::DeclareArgument(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    Self::object(
+      "DeclareItem",
+      node,
+      json!({ "key": Self::identifier(&x.name), "value": Self::expression(&x.value) })
+    )
+  }
+
+  fn property_item(node: &Node) -> Value {
+    let NodeWrapper::PropertyItem(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    Self::object(
+      "PropertyItem",
+      node,
+      json!({
+        "name": Self::var_like_identifier(&x.name),
+        "default": Self::optional(&x.value, Self::expression),
+      })
+    )
+  }
+
+  fn property_hook(node: &Node) -> Value {
+    let NodeWrapper::PropertyHook(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    let body = match &x.body.wrapper {
+      NodeWrapper::Block(x) => Self::statements(&x.statements),
+      _ => Self::expression(&x.body),
+    };
+    Self::object(
+      "PropertyHook",
+      node,
+      json!({
+        "attrGroups": Self::attr_groups(node),
+        "flags": 0,
+        "byRef": x.is_ref,
+        "name": {
+          "nodeType": "Identifier",
+          "name": if x.is_get { "get" } else { "set" },
+          "attributes": {},
+        },
+        "params": Self::list(&x.parameters, Self::param),
+        "body": body,
+      })
+    )
+  }
+
+  fn static_var(node: &Node) -> Value {
+    let NodeWrapper::PropertyItem(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    Self::object(
+      "StaticVar",
+      node,
+      json!({
+        "var": Self::variable(&x.name),
+        "default": Self::optional(&x.value, Self::expression),
+      })
+    )
+  }
+
+  fn param(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::ConstructorParameter(x) => {
+        let mut param = Self::param(&x.parameter);
+        let mut attr_groups = Self::attr_groups(node);
+        if let Some(Value::Array(inner)) = param.get_mut("attrGroups").map(Value::take) {
+          attr_groups.extend(inner);
+        }
+        param["attrGroups"] = Value::Array(attr_groups);
+        param["flags"] = Self::flags(&x.visibilities, x.modifier.as_ref(), None).into();
+        param["attributes"] = Value::Object(Self::attributes(node));
+        param
+      }
+      NodeWrapper::Parameter(x) =>
+        Self::object(
+          "Param",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "flags": 0,
+            "type": Self::optional(&x.variable_type, Self::type_hint),
+            "byRef": x.is_ref,
+            "variadic": x.is_ellipsis,
+            "var": Self::variable(&x.name),
+            "default": Self::optional(&x.value, Self::expression),
+            "hooks": [],
+          })
+        ),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn attr_groups(node: &Node) -> std::vec::Vec<Value> {
+    node
+      .attributes()
+      .into_iter()
+      .flatten()
+      .filter_map(|leading| {
+        let NodeWrapper::Attribute(x) = &leading.wrapper else {
+          return None;
+        };
+        Some(
+          Self::object(
+            "AttributeGroup",
+            leading,
+            json!({ "attrs": Self::list(&x.items, Self::attribute) })
+          )
+        )
+      })
+      .collect()
+  }
+
+  fn attribute(node: &Node) -> Value {
+    let NodeWrapper::AttributeItem(x) = &node.wrapper else {
+      return Self::expression(node);
+    };
+    Self::object(
+      "Attribute",
+      node,
+      json!({
+        "name": Self::name_of(node, x.name),
+        "args": Self::list(&x.arguments, Self::argument),
+      })
+    )
+  }
+
+  fn visibility_flag(visibility: &Visibility) -> u64 {
+    match visibility {
+      Visibility::Public | Visibility::PublicGet => MODIFIER_PUBLIC,
+      Visibility::Protected | Visibility::ProtectedGet => MODIFIER_PROTECTED,
+      Visibility::Private | Visibility::PrivateGet => MODIFIER_PRIVATE,
+      Visibility::PublicSet => MODIFIER_PUBLIC_SET,
+      Visibility::ProtectedSet => MODIFIER_PROTECTED_SET,
+      Visibility::PrivateSet => MODIFIER_PRIVATE_SET,
+    }
+  }
+
+  fn flags(
+    visibilities: &[Visibility],
+    modifier: Option<&Modifier>,
+    inheritance: Option<&Inheritance>
+  ) -> u64 {
+    let mut flags = visibilities.iter().fold(0, |flags, x| flags | Self::visibility_flag(x));
+    flags |= match modifier {
+      Some(Modifier::Static) => MODIFIER_STATIC,
+      Some(Modifier::Readonly) => MODIFIER_READONLY,
+      None => 0,
+    };
+    flags |= match inheritance {
+      Some(Inheritance::Abstract) => MODIFIER_ABSTRACT,
+      Some(Inheritance::Final) => MODIFIER_FINAL,
+      None => 0,
+    };
+    flags
+  }
+
+  fn kind(kind: u64) -> Map<String, Value> {
+    let mut attributes = Map::new();
+    attributes.insert("kind".into(), kind.into());
+    attributes
+  }
+
+  fn identifier(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Identifier(x) =>
+        Self::object("Identifier", node, json!({ "name": Self::text(x.name) })),
+      NodeWrapper::MagicMethod(x) =>
+        Self::object("Identifier", node, json!({ "name": x.name.to_string() })),
+      NodeWrapper::ClassKeyword(_) => Self::object("Identifier", node, json!({ "name": "class" })),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn var_like_identifier(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Identifier(x) =>
+        Self::object("VarLikeIdentifier", node, json!({ "name": Self::text(x.name) })),
+      NodeWrapper::Variable(x) => Self::var_like_identifier(&x.name),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn variable_name(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Identifier(x) => Self::text(x.name),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn variable(node: &Node) -> Value {
+    Self::object("Expr_Variable", node, json!({ "name": Self::variable_name(node) }))
+  }
+
+  fn name(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Identifier(x) => Self::name_of(node, x.name),
+      NodeWrapper::SelfKeyword(_) => Self::name_of(node, "self".into()),
+      NodeWrapper::Parent(_) => Self::name_of(node, "parent".into()),
+      NodeWrapper::StaticKeyword(_) => Self::name_of(node, "static".into()),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn name_of(node: &Node, name: &BStr) -> Value {
+    let (node_type, name) = if let Some(name) = name.strip_prefix(b"\\") {
+      ("Name_FullyQualified", name)
+    } else if let Some(name) = name.strip_prefix(b"namespace\\") {
+      ("Name_Relative", name)
+    } else {
+      ("Name", name.as_bytes())
+    };
+    Self::object(node_type, node, json!({ "name": Self::text(name) }))
+  }
+
+  fn type_hint(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Type(x) => {
+        let lowercase = x.name.to_ascii_lowercase();
+        let hint = if BUILTIN_TYPES.contains(&lowercase.as_slice()) {
+          Self::object("Identifier", node, json!({ "name": Self::text(&lowercase) }))
+        } else {
+          Self::name_of(node, x.name)
+        };
+        if x.is_nullable {
+          Self::object("NullableType", node, json!({ "type": hint }))
+        } else {
+          hint
+        }
+      }
+      NodeWrapper::UnionType(x) =>
+        Self::object("UnionType", node, json!({ "types": Self::list(&x.types, Self::type_hint) })),
+      NodeWrapper::IntersectionType(x) =>
+        Self::object(
+          "IntersectionType",
+          node,
+          json!({ "types": Self::list(&x.types, Self::type_hint) })
+        ),
+      _ => Self::name(node),
+    }
+  }
+
+  fn is_function(node: &Node, name: &[u8]) -> bool {
+    matches!(&node.wrapper, NodeWrapper::Identifier(x) if x.name.eq_ignore_ascii_case(name))
+  }
+
+  fn argument_value<'b, 'a>(node: &'b Node<'a>) -> &'b Node<'a> {
+    match &node.wrapper {
+      NodeWrapper::Argument(x) => &x.value,
+      _ => node,
+    }
+  }
+
+  fn argument_values(nodes: &[Node]) -> Value {
+    Value::Array(
+      nodes
+        .iter()
+        .map(|x| Self::expression(Self::argument_value(x)))
+        .collect()
+    )
+  }
+
+  fn argument(node: &Node) -> Value {
+    let (name, value) = match &node.wrapper {
+      NodeWrapper::Argument(x) => (Self::optional(&x.name, Self::identifier), &*x.value),
+      _ => (Value::Null, node),
+    };
+    match &value.wrapper {
+      NodeWrapper::Variadic(x) if x.statement.is_none() =>
+        Self::object("VariadicPlaceholder", node, json!({})),
+      NodeWrapper::Variadic(x) =>
+        Self::object(
+          "Arg",
+          node,
+          json!({
+            "name": name,
+            "value": Self::optional(&x.statement, Self::expression),
+            "byRef": false,
+            "unpack": true,
+          })
+        ),
+      _ =>
+        Self::object(
+          "Arg",
+          node,
+          json!({ "name": name, "value": Self::expression(value), "byRef": false, "unpack": false })
+        ),
+    }
+  }
+
+  fn array_item(node: &Node) -> Value {
+    let (key, value) = match &node.wrapper {
+      NodeWrapper::ArrayItem(x) => (Self::optional(&x.key, Self::expression), &*x.value),
+      _ => (Value::Null, node),
+    };
+    let (value, by_ref, unpack) = match &value.wrapper {
+      NodeWrapper::Reference(x) => (Self::destructure(&x.statement), true, false),
+      NodeWrapper::Variadic(x) => (Self::optional(&x.statement, Self::expression), false, true),
+      _ => (Self::destructure(value), false, false),
+    };
+    Self::object(
+      "ArrayItem",
+      node,
+      json!({ "key": key, "value": value, "byRef": by_ref, "unpack": unpack })
+    )
+  }
+
+  fn destructure(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::Array(x) =>
+        Self::object_with(
+          "Expr_List",
+          node,
+          json!({ "items": Self::list(&x.items, Self::array_item) }),
+          Self::kind(2)
+        ),
+      NodeWrapper::Reference(x) => Self::destructure(&x.statement),
+      _ => Self::expression(node),
+    }
+  }
+
+  fn call(node: &Node, x: &crate::CallNode) -> Value {
+    let arguments = Self::list(&x.arguments, Self::argument);
+    match &x.name.wrapper {
+      NodeWrapper::ObjectAccess(access) =>
+        Self::object(
+          if access.is_nullsafe { "Expr_NullsafeMethodCall" } else { "Expr_MethodCall" },
+          node,
+          json!({
+            "var": Self::expression(&access.object),
+            "name": Self::identifier(&access.property),
+            "args": arguments,
+          })
+        ),
+      NodeWrapper::StaticLookup(lookup) =>
+        Self::object(
+          "Expr_StaticCall",
+          node,
+          json!({
+            "class": Self::name(&lookup.left),
+            "name": Self::identifier(&lookup.right),
+            "args": arguments,
+          })
+        ),
+      _ if Self::is_function(&x.name, b"isset") => {
+        let vars = x.arguments
+          .iter()
+          .map(|x| Self::expression(Self::argument_value(x)))
+          .collect::<std::vec::Vec<_>>();
+        Self::object("Expr_Isset", node, json!({ "vars": vars }))
+      }
+      _ if Self::is_function(&x.name, b"empty") && x.arguments.len() == 1 =>
+        Self::object(
+          "Expr_Empty",
+          node,
+          json!({ "expr": Self::expression(Self::argument_value(&x.arguments[0])) })
+        ),
+      _ =>
+        Self::object(
+          "Expr_FuncCall",
+          node,
+          json!({ "name": Self::name(&x.name), "args": arguments })
+        ),
+    }
+  }
+
+  fn number(node: &Node, x: &NumberNode) -> Value {
+    let mut attributes = Map::new();
+    attributes.insert("rawValue".into(), Self::text(x.value));
+    match x.to_value() {
+      Some(NumberValue::Int(value)) => {
+        attributes.insert("kind".into(), x.base().radix().into());
+        Self::object_with("Scalar_Int", node, json!({ "value": value }), attributes)
+      }
+      value => {
+        let value = value.map_or(0.0, |x| x.as_f64());
+        Self::object_with("Scalar_Float", node, json!({ "value": value }), attributes)
+      }
+    }
+  }
+
+  fn string(node: &Node, x: &crate::StringNode) -> Value {
+    let (kind, quote) = match x.quote {
+      Quote::Single => (STRING_SINGLE, '\''),
+      Quote::Double => (STRING_DOUBLE, '"'),
+      Quote::Backtick => {
+        let part = Self::object(
+          "InterpolatedStringPart",
+          node,
+          json!({ "value": Self::text(&x.to_bytes()) })
+        );
+        return Self::object("Expr_ShellExec", node, json!({ "parts": [part] }));
+      }
+    };
+    let mut attributes = Self::kind(kind);
+    attributes.insert("rawValue".into(), format!("{}{}{}", quote, x.value, quote).into());
+    Self::object_with(
+      "Scalar_String",
+      node,
+      json!({ "value": Self::text(&x.to_bytes()) }),
+      attributes
+    )
+  }
+
+  fn interpolated_parts(
+    values: &[Node],
+    parts: std::vec::Vec<StringPart>
+  ) -> std::vec::Vec<Value> {
+    values
+      .iter()
+      .zip(parts)
+      .filter_map(|(node, part)| {
+        match part {
+          StringPart::Literal(x) if x.is_empty() => None,
+          StringPart::Literal(x) =>
+            Some(Self::object("InterpolatedStringPart", node, json!({ "value": Self::text(&x) }))),
+          StringPart::Expression(x) => Some(Self::expression(x)),
+        }
+      })
+      .collect()
+  }
+
+  fn expression(node: &Node) -> Value {
+    match &node.wrapper {
+      NodeWrapper::AnonymousClass(x) =>
+        Self::object(
+          "Stmt_Class",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "flags": 0,
+            "name": null,
+            "extends": Self::optional(&x.extends, Self::name),
+            "implements": Self::list(&x.implements, Self::name),
+            "stmts": Self::body(&x.body),
+          })
+        ),
+      NodeWrapper::AnonymousFunction(x) => {
+        let uses = x.uses
+          .iter()
+          .map(|node| {
+            let (var, by_ref) = match &node.wrapper {
+              NodeWrapper::Reference(x) => (&*x.statement, true),
+              _ => (node, false),
+            };
+            Self::object(
+              "ClosureUse",
+              node,
+              json!({ "var": Self::expression(var), "byRef": by_ref })
+            )
+          })
+          .collect::<std::vec::Vec<_>>();
+        Self::object(
+          "Expr_Closure",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "static": false,
+            "byRef": x.is_ref,
+            "params": Self::list(&x.parameters, Self::param),
+            "uses": uses,
+            "returnType": Self::optional(&x.return_type, Self::type_hint),
+            "stmts": Self::body(&x.body),
+          })
+        )
+      }
+      NodeWrapper::Argument(_) => Self::argument(node),
+      NodeWrapper::Array(x) =>
+        Self::object_with(
+          "Expr_Array",
+          node,
+          json!({ "items": Self::list(&x.items, Self::array_item) }),
+          Self::kind(if x.is_short { 2 } else { 1 })
+        ),
+      NodeWrapper::ArrayItem(_) => Self::array_item(node),
+      NodeWrapper::ArrayLookup(x) =>
+        Self::object(
+          "Expr_ArrayDimFetch",
+          node,
+          json!({
+            "var": Self::expression(&x.left),
+            "dim": Self::optional(&x.right, Self::expression),
+          })
+        ),
+      NodeWrapper::ArrowFunction(x) =>
+        Self::object(
+          "Expr_ArrowFunction",
+          node,
+          json!({
+            "attrGroups": Self::attr_groups(node),
+            "static": false,
+            "byRef": x.is_ref,
+            "params": Self::list(&x.parameters, Self::param),
+            "returnType": Self::optional(&x.return_type, Self::type_hint),
+            "expr": Self::expression(&x.body),
+          })
+        ),
+      NodeWrapper::Assignment(x) => {
+        let var = Self::destructure(&x.left);
+        let node_type = match (&x.operator, &x.right.wrapper) {
+          (AssignmentType::Default, NodeWrapper::Reference(reference)) => {
+            return Self::object(
+              "Expr_AssignRef",
+              node,
+              json!({ "var": var, "expr": Self::expression(&reference.statement) })
+            );
+          }
+          (AssignmentType::Default, _) => "Expr_Assign",
+          (AssignmentType::Reference, _) => "Expr_AssignRef",
+          (AssignmentType::Coalesce, _) => "Expr_AssignOp_Coalesce",
+          (AssignmentType::Concatenation, _) => "Expr_AssignOp_Concat",
+          (AssignmentType::Addition, _) => "Expr_AssignOp_Plus",
+          (AssignmentType::Subtraction, _) => "Expr_AssignOp_Minus",
+          (AssignmentType::Multiplication, _) => "Expr_AssignOp_Mul",
+          (AssignmentType::Division, _) => "Expr_AssignOp_Div",
+          (AssignmentType::Exponentiation, _) => "Expr_AssignOp_Pow",
+          (AssignmentType::Modulus, _) => "Expr_AssignOp_Mod",
+          (AssignmentType::BitwiseAnd, _) => "Expr_AssignOp_BitwiseAnd",
+          (AssignmentType::BitwiseOr, _) => "Expr_AssignOp_BitwiseOr",
+          (AssignmentType::BitwiseXor, _) => "Expr_AssignOp_BitwiseXor",
+          (AssignmentType::BitwiseShiftRight, _) => "Expr_AssignOp_ShiftRight",
+          (AssignmentType::BitwiseShiftLeft, _) => "Expr_AssignOp_ShiftLeft",
+        };
+        Self::object(node_type, node, json!({ "var": var, "expr": Self::expression(&x.right) }))
+      }
+      NodeWrapper::Bin(x) => {
+        let node_type = match x.operator {
+          BinaryType::Elvis => {
+            return Self::object(
+              "Expr_Ternary",
+              node,
+              json!({
+                "cond": Self::expression(&x.left),
+                "if": null,
+                "else": Self::expression(&x.right),
+              })
+            );
+          }
+          BinaryType::InstanceOf => {
+            return Self::object(
+              "Expr_Instanceof",
+              node,
+              json!({ "expr": Self::expression(&x.left), "class": Self::name(&x.right) })
+            );
+          }
+          BinaryType::Addition => "Expr_BinaryOp_Plus",
+          BinaryType::Subtraction => "Expr_BinaryOp_Minus",
+          BinaryType::Multiplication => "Expr_BinaryOp_Mul",
+          BinaryType::Division => "Expr_BinaryOp_Div",
+          BinaryType::Modulus => "Expr_BinaryOp_Mod",
+          BinaryType::Exponentiation => "Expr_BinaryOp_Pow",
+          BinaryType::BitwiseAnd => "Expr_BinaryOp_BitwiseAnd",
+          BinaryType::BitwiseOr => "Expr_BinaryOp_BitwiseOr",
+          BinaryType::BitwiseXor => "Expr_BinaryOp_BitwiseXor",
+          BinaryType::BitwiseShiftLeft => "Expr_BinaryOp_ShiftLeft",
+          BinaryType::BitwiseShiftRight => "Expr_BinaryOp_ShiftRight",
+          BinaryType::IsEqual => "Expr_BinaryOp_Equal",
+          BinaryType::IsIdentical => "Expr_BinaryOp_Identical",
+          BinaryType::IsNotEqual => "Expr_BinaryOp_NotEqual",
+          BinaryType::IsNotIdentical => "Expr_BinaryOp_NotIdentical",
+          BinaryType::IsLesser => "Expr_BinaryOp_Smaller",
+          BinaryType::IsGreater => "Expr_BinaryOp_Greater",
+          BinaryType::IsLesserOrEqual => "Expr_BinaryOp_SmallerOrEqual",
+          BinaryType::IsGreaterOrEqual => "Expr_BinaryOp_GreaterOrEqual",
+          BinaryType::Spaceship => "Expr_BinaryOp_Spaceship",
+          BinaryType::Concatenation => "Expr_BinaryOp_Concat",
+          BinaryType::BooleanAnd => "Expr_BinaryOp_BooleanAnd",
+          BinaryType::BooleanOr => "Expr_BinaryOp_BooleanOr",
+          BinaryType::BooleanXor => "Expr_BinaryOp_LogicalXor",
+          BinaryType::LogicalAnd => "Expr_BinaryOp_LogicalAnd",
+          BinaryType::LogicalOr => "Expr_BinaryOp_LogicalOr",
+          BinaryType::Coalesce => "Expr_BinaryOp_Coalesce",
+        };
+        Self::object(
+          node_type,
+          node,
+          json!({ "left": Self::expression(&x.left), "right": Self::expression(&x.right) })
+        )
+      }
+      NodeWrapper::Boolean(x) =>
+        Self::object(
+          "Expr_ConstFetch",
+          node,
+          json!({ "name": Self::name_of(node, if x.is_true { "true" } else { "false" }.into()) })
+        ),
+      NodeWrapper::Call(x) => Self::call(node, x),
+      NodeWrapper::Cast(x) => {
+        let (node_type, kind) = match x.cast_type {
+          CastType::Int => ("Expr_Cast_Int", 1),
+          CastType::Integer => ("Expr_Cast_Int", 2),
+          CastType::Bool => ("Expr_Cast_Bool", 1),
+          CastType::Boolean => ("Expr_Cast_Bool", 2),
+          CastType::Double => ("Expr_Cast_Double", 1),
+          CastType::Float => ("Expr_Cast_Double", 2),
+          CastType::Real => ("Expr_Cast_Double", 3),
+          CastType::String => ("Expr_Cast_String", 1),
+          CastType::Binary => ("Expr_Cast_String", 2),
+          CastType::Array => ("Expr_Cast_Array", 1),
+          CastType::Object => ("Expr_Cast_Object", 1),
+          CastType::Unset => ("Expr_Cast_Unset", 1),
+        };
+        Self::object_with(
+          node_type,
+          node,
+          json!({ "expr": Self::expression(&x.expression) }),
+          Self::kind(kind)
+        )
+      }
+      NodeWrapper::ClassKeyword(_) => Self::identifier(node),
+      NodeWrapper::Clone(x) =>
+        Self::object("Expr_Clone", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::Encapsed(x) => {
+        let parts = Self::interpolated_parts(&x.values, x.decoded_parts());
+        if x.quote == Quote::Backtick {
+          Self::object("Expr_ShellExec", node, json!({ "parts": parts }))
+        } else {
+          Self::object_with(
+            "Scalar_InterpolatedString",
+            node,
+            json!({ "parts": parts }),
+            Self::kind(STRING_DOUBLE)
+          )
+        }
+      }
+      NodeWrapper::Error(_) => Self::object("Expr_Error", node, json!({})),
+      NodeWrapper::Eval(x) =>
+        Self::object("Expr_Eval", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::Exit(x) =>
+        Self::object(
+          "Expr_Exit",
+          node,
+          json!({ "expr": Self::optional(&x.statement, Self::expression) })
+        ),
+      NodeWrapper::HereDoc(x) => {
+        let parts = Self::interpolated_parts(&x.values, x.decoded_parts());
+        let mut attributes = Self::kind(STRING_HEREDOC);
+        attributes.insert("docLabel".into(), Self::text(x.label));
+        if parts.iter().all(|x| x["nodeType"] == "InterpolatedStringPart") {
+          let value = parts
+            .iter()
+            .filter_map(|x| x["value"].as_str())
+            .collect::<String>();
+          Self::object_with("Scalar_String", node, json!({ "value": value }), attributes)
+        } else {
+          Self::object_with(
+            "Scalar_InterpolatedString",
+            node,
+            json!({ "parts": parts }),
+            attributes
+          )
+        }
+      }
+      NodeWrapper::Identifier(_) =>
+        Self::object("Expr_ConstFetch", node, json!({ "name": Self::name(node) })),
+      NodeWrapper::Include(x) => {
+        let include_type = match (x.is_require, x.is_once) {
+          (false, false) => 1,
+          (false, true) => 2,
+          (true, false) => 3,
+          (true, true) => 4,
+        };
+        Self::object(
+          "Expr_Include",
+          node,
+          json!({ "expr": Self::expression(&x.argument), "type": include_type })
+        )
+      }
+      NodeWrapper::IntersectionType(_) | NodeWrapper::Type(_) | NodeWrapper::UnionType(_) =>
+        Self::type_hint(node),
+      NodeWrapper::List(x) =>
+        Self::object_with(
+          "Expr_List",
+          node,
+          json!({ "items": Self::list(&x.items, Self::array_item) }),
+          Self::kind(1)
+        ),
+      NodeWrapper::Magic(x) => {
+        let node_type = match x.name {
+          MagicName::Class => "Scalar_MagicConst_Class",
+          MagicName::Dir => "Scalar_MagicConst_Dir",
+          MagicName::File => "Scalar_MagicConst_File",
+          MagicName::Function => "Scalar_MagicConst_Function",
+          MagicName::Line => "Scalar_MagicConst_Line",
+          MagicName::Method => "Scalar_MagicConst_Method",
+          MagicName::Namespace => "Scalar_MagicConst_Namespace",
+          MagicName::Trait => "Scalar_MagicConst_Trait",
+          MagicName::Property => "Scalar_MagicConst_Property",
+        };
+        Self::object(node_type, node, json!({}))
+      }
+      NodeWrapper::MagicMethod(_) => Self::identifier(node),
+      NodeWrapper::Match(x) => {
+        let arms = x.arms
+          .iter()
+          .map(|arm| {
+            let NodeWrapper::MatchArm(x) = &arm.wrapper else {
+              return Self::expression(arm);
+            };
+            let conditions = if x.conditions.is_empty() {
+              Value::Null
+            } else {
+              Self::list(&x.conditions, Self::expression)
+            };
+            Self::object(
+              "MatchArm",
+              arm,
+              json!({ "conds": conditions, "body": Self::expression(&x.expr) })
+            )
+          })
+          .collect::<std::vec::Vec<_>>();
+        Self::object(
+          "Expr_Match",
+          node,
+          json!({ "cond": Self::expression(&x.condition), "arms": arms })
+        )
+      }
+      NodeWrapper::Negate(x) =>
+        Self::object("Expr_BooleanNot", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::New(x) => {
+        let (class, arguments) = match &x.statement.wrapper {
+          NodeWrapper::AnonymousClass(class) =>
+            (Self::expression(&x.statement), Self::list(&class.parameters, Self::argument)),
+          NodeWrapper::Call(call) =>
+            (Self::name(&call.name), Self::list(&call.arguments, Self::argument)),
+          _ => (Self::name(&x.statement), json!([])),
+        };
+        Self::object("Expr_New", node, json!({ "class": class, "args": arguments }))
+      }
+      NodeWrapper::NowDoc(x) => {
+        let mut attributes = Self::kind(STRING_NOWDOC);
+        attributes.insert("docLabel".into(), Self::text(x.label));
+        Self::object_with(
+          "Scalar_String",
+          node,
+          json!({ "value": Self::text(&x.to_bytes()) }),
+          attributes
+        )
+      }
+      NodeWrapper::Null(_) =>
+        Self::object(
+          "Expr_ConstFetch",
+          node,
+          json!({ "name": Self::name_of(node, "null".into()) })
+        ),
+      NodeWrapper::Number(x) => Self::number(node, x),
+      NodeWrapper::ObjectAccess(x) =>
+        Self::object(
+          if x.is_nullsafe { "Expr_NullsafePropertyFetch" } else { "Expr_PropertyFetch" },
+          node,
+          json!({ "var": Self::expression(&x.object), "name": Self::identifier(&x.property) })
+        ),
+      NodeWrapper::Parent(_) | NodeWrapper::SelfKeyword(_) | NodeWrapper::StaticKeyword(_) =>
+        Self::name(node),
+      NodeWrapper::Parenthesis(x) => Self::expression(&x.statement),
+      NodeWrapper::Post(x) =>
+        Self::object(
+          match x.operator {
+            PostType::Increment => "Expr_PostInc",
+            PostType::Decrement => "Expr_PostDec",
+          },
+          node,
+          json!({ "var": Self::expression(&x.statement) })
+        ),
+      NodeWrapper::Pre(x) => {
+        let expr = Self::expression(&x.statement);
+        match x.operator {
+          PreType::Increment => Self::object("Expr_PreInc", node, json!({ "var": expr })),
+          PreType::Decrement => Self::object("Expr_PreDec", node, json!({ "var": expr })),
+          PreType::Addition => Self::object("Expr_UnaryPlus", node, json!({ "expr": expr })),
+          PreType::Subtraction => Self::object("Expr_UnaryMinus", node, json!({ "expr": expr })),
+          PreType::BitwiseNot => Self::object("Expr_BitwiseNot", node, json!({ "expr": expr })),
+        }
+      }
+      NodeWrapper::Print(x) =>
+        Self::object("Expr_Print", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::Reference(x) => Self::expression(&x.statement),
+      NodeWrapper::Silent(x) =>
+        Self::object("Expr_ErrorSuppress", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::StaticLookup(x) => {
+        let class = Self::name(&x.left);
+        match &x.right.wrapper {
+          NodeWrapper::Variable(variable) =>
+            Self::object(
+              "Expr_StaticPropertyFetch",
+              node,
+              json!({ "class": class, "name": Self::var_like_identifier(&variable.name) })
+            ),
+          _ =>
+            Self::object(
+              "Expr_ClassConstFetch",
+              node,
+              json!({ "class": class, "name": Self::identifier(&x.right) })
+            ),
+        }
+      }
+      NodeWrapper::String(x) => Self::string(node, x),
+      NodeWrapper::Ternary(x) =>
+        Self::object(
+          "Expr_Ternary",
+          node,
+          json!({
+            "cond": Self::expression(&x.condition),
+            "if": Self::expression(&x.valid),
+            "else": Self::expression(&x.invalid),
+          })
+        ),
+      NodeWrapper::This(_) => Self::object("Expr_Variable", node, json!({ "name": "this" })),
+      NodeWrapper::Throw(x) =>
+        Self::object("Expr_Throw", node, json!({ "expr": Self::expression(&x.statement) })),
+      NodeWrapper::Variable(x) =>
+        Self::object("Expr_Variable", node, json!({ "name": Self::variable_name(&x.name) })),
+      NodeWrapper::Variadic(x) =>
+        match &x.statement {
+          Some(statement) => Self::expression(statement),
+          None => Self::object("VariadicPlaceholder", node, json!({})),
+        }
+      NodeWrapper::Yield(x) =>
+        Self::object(
+          "Expr_Yield",
+          node,
+          json!({
+            "key": Self::optional(&x.key, Self::expression),
+            "value": Self::optional(&x.value, Self::expression),
+          })
+        ),
+      NodeWrapper::YieldFrom(x) =>
+        Self::object("Expr_YieldFrom", node, json!({ "expr": Self::expression(&x.statement) })),
+      _ => Self::try_statement(node).unwrap_or(Value::Null),
+    }
+  }
+}
+
+struct Importer<'arena> {
+  arena: &'arena Bump,
+}
+
+impl<'arena> Importer<'arena> {
+  fn root(&self, value: &Value) -> Result<Node<'arena>, String> {
+    if !value.is_array() {
+      return self.node(value);
+    }
+    let children = self.statements(value)?;
+    let loc = children
+      .first()
+      .and_then(|x| x.loc.as_ref())
+      .zip(children.last().and_then(|x| x.loc.as_ref()))
+      .map(|(start, end)| RangeLocation { start: start.start.clone(), end: end.end.clone() });
+    Ok(ProgramNode::loc(children, loc))
+  }
+
+  fn field<'v>(value: &'v Value, key: &str) -> &'v Value {
+    value.get(key).unwrap_or(&Value::Null)
+  }
+
+  fn attribute<'v>(value: &'v Value, key: &str) -> &'v Value {
+    Self::field(Self::field(value, "attributes"), key)
+  }
+
+  fn flag(value: &Value, key: &str) -> bool {
+    Self::field(value, key).as_bool().unwrap_or(false)
+  }
+
+  fn number(value: &Value, key: &str) -> u64 {
+    Self::field(value, key).as_u64().unwrap_or(0)
+  }
+
+  fn node_type(value: &Value) -> Result<&str, String> {
+    value
+      .get("nodeType")
+      .and_then(Value::as_str)
+      .ok_or_else(|| format!("Expected a PHP-Parser node, found: {}", value))
+  }
+
+  fn loc(value: &Value) -> Option<RangeLocation> {
+    let position = |key: &str| Self::attribute(value, key).as_u64().map(|x| x as u32);
+    let end_offset = Self::attribute(value, "endFilePos").as_i64()?;
+    Some(RangeLocation {
+      start: Location {
+        line: position("startLine")?,
+        column: 0,
+        offset: position("startFilePos")?,
+      },
+      end: Location { line: position("endLine")?, column: 0, offset: (end_offset + 1) as u32 },
+    })
+  }
+
+  fn comment_loc(value: &Value) -> Option<RangeLocation> {
+    let position = |key: &str| value.get(key).and_then(Value::as_u64).map(|x| x as u32);
+    let end_offset = value.get("endFilePos").and_then(Value::as_i64)?;
+    Some(RangeLocation {
+      start: Location { line: position("line")?, column: 0, offset: position("filePos")? },
+      end: Location { line: position("endLine")?, column: 0, offset: (end_offset + 1) as u32 },
+    })
+  }
+
+  fn str(&self, value: &Value) -> &'arena BStr {
+    BStr::new(self.arena.alloc_str(value.as_str().unwrap_or_default()))
+  }
+
+  fn bytes(&self, value: &[u8]) -> &'arena BStr {
+    self.arena.alloc_slice_copy(value).as_bstr()
+  }
+
+  fn boxed(&self, value: &Value) -> Result<Box<'arena, Node<'arena>>, String> {
+    self.node(value).map(|x| x.into_boxed(self.arena))
+  }
+
+  fn optional(
+    &self,
+    value: &Value,
+    f: fn(&Self, &Value) -> Result<Node<'arena>, String>
+  ) -> Result<Option<Box<'arena, Node<'arena>>>, String> {
+    if value.is_null() {
+      return Ok(None);
+    }
+    f(self, value).map(|x| Some(x.into_boxed(self.arena)))
+  }
+
+  fn list(
+    &self,
+    value: &Value,
+    f: fn(&Self, &Value) -> Result<Node<'arena>, String>
+  ) -> Result<Vec<'arena, Node<'arena>>, String> {
+    let mut nodes = Vec::new_in(self.arena);
+    for item in value.as_array().into_iter().flatten() {
+      if !item.is_null() {
+        nodes.push(f(self, item)?);
+      }
+    }
+    Ok(nodes)
+  }
+
+  fn statements(&self, value: &Value) -> Result<Vec<'arena, Node<'arena>>, String> {
+    let mut nodes: Vec<'arena, Node<'arena>> = Vec::new_in(self.arena);
+    let mut comments = std::vec::Vec::new();
+    for item in value.as_array().into_iter().flatten() {
+      if Self::node_type(item)? == "Stmt_Nop" {
+        comments.extend(self.comments(item));
+        continue;
+      }
+      let mut node = self.node(item)?;
+      for comment in comments.drain(..).rev() {
+        node.leadings_shift(self.arena, comment);
+      }
+      nodes.push(node);
+    }
+    match nodes.last_mut() {
+      Some(last) => {
+        for comment in comments {
+          last.trailings_push(self.arena, comment);
+        }
+      }
+      None => nodes.extend(comments),
+    }
+    Ok(nodes)
+  }
+
+  fn block(&self, value: &Value) -> Result<Box<'arena, Node<'arena>>, String> {
+    Ok(BlockNode::loc(self.statements(value)?, None).into_boxed(self.arena))
+  }
+
+  fn comments(&self, value: &Value) -> std::vec::Vec<Node<'arena>> {
+    Self::attribute(value, "comments")
+      .as_array()
+      .into_iter()
+      .flatten()
+      .map(|comment| {
+        let loc = Self::comment_loc(comment);
+        let text = Self::field(comment, "text").as_str().unwrap_or_default();
+        if let Some(text) = text.strip_prefix("/**").and_then(|x| x.strip_suffix("*/")) {
+          self.doc_comment(text, loc)
+        } else if let Some(text) = text.strip_prefix("/*").and_then(|x| x.strip_suffix("*/")) {
+          CommentBlockNode::loc(self.bytes(text.as_bytes()), loc)
+        } else {
+          let text = text.strip_prefix("//").or_else(|| text.strip_prefix('#')).unwrap_or(text);
+          CommentLineNode::loc(self.bytes(text.trim_end().as_bytes()), loc)
+        }
+      })
+      .collect()
+  }
+
+  fn doc_comment(&self, text: &str, loc: Option<RangeLocation>) -> Node<'arena> {
+    let comment = text.strip_prefix("/**").unwrap_or(text);
+    let comment = comment.strip_suffix("*/").unwrap_or(comment);
+    let lines = comment
+      .lines()
+      .map(|x| {
+        let x = x.trim();
+        x.strip_prefix('*').map_or(x, |x| x.strip_prefix(' ').unwrap_or(x)).trim_end()
+      })
+      .collect::<std::vec::Vec<_>>();
+    let start = lines
+      .iter()
+      .position(|x| !x.is_empty())
+      .unwrap_or(lines.len());
+    let end = lines
+      .iter()
+      .rposition(|x| !x.is_empty())
+      .map_or(start, |x| x + 1);
+    let lines = &lines[start..end];
+    let texts = lines
+      .iter()
+      .take_while(|x| !x.starts_with('@'))
+      .copied()
+      .collect::<std::vec::Vec<_>>();
+    let mut summary = vec![];
+    let mut index = 0;
+    while let Some(text) = texts.get(index) {
+      index += 1;
+      if text.is_empty() {
+        break;
+      }
+      summary.push(*text);
+      if text.ends_with('.') {
+        break;
+      }
+    }
+    let description = texts[index..].join("\n");
+    CommentDocNode::loc(
+      self.bytes(comment.as_bytes()),
+      self.bytes(summary.join("\n").as_bytes()),
+      self.bytes(description.trim_matches('\n').as_bytes()),
+      Vec::new_in(self.arena),
+      loc
+    )
+  }
+
+  fn attr_groups(&self, value: &Value) -> Result<Vec<'arena, Node<'arena>>, String> {
+    let mut groups = Vec::new_in(self.arena);
+    for group in Self::field(value, "attrGroups").as_array().into_iter().flatten() {
+      let items = self.list(Self::field(group, "attrs"), |this, attr| {
+        Ok(
+          AttributeItemNode::loc(
+            this.bytes(Self::name_text(Self::field(attr, "name")).as_bytes()),
+            this.list(Self::field(attr, "args"), Self::node)?,
+            Self::loc(attr)
+          )
+        )
+      })?;
+      groups.push(AttributeNode::loc(items, Self::loc(group)));
+    }
+    Ok(groups)
+  }
+
+  fn name_text(value: &Value) -> String {
+    let name = match Self::field(value, "name") {
+      Value::String(x) => x.clone(),
+      _ =>
+        Self::field(value, "parts")
+          .as_array()
+          .into_iter()
+          .flatten()
+          .filter_map(Value::as_str)
+          .collect::<std::vec::Vec<_>>()
+          .join("\\"),
+    };
+    match value.get("nodeType").and_then(Value::as_str) {
+      Some("Name_FullyQualified") => format!("\\{}", name),
+      Some("Name_Relative") => format!("namespace\\{}", name),
+      _ => name,
+    }
+  }
+
+  fn is_name(value: &Value) -> bool {
+    matches!(
+      value.get("nodeType").and_then(Value::as_str),
+      Some("Name" | "Name_FullyQualified" | "Name_Relative" | "Identifier" | "VarLikeIdentifier")
+    )
+  }
+
+  fn name(&self, value: &Value) -> Result<Node<'arena>, String> {
+    if !Self::is_name(value) {
+      return self.node(value);
+    }
+    let loc = Self::loc(value);
+    let name = Self::name_text(value);
+    Ok(match name.to_ascii_lowercase().as_str() {
+      "self" => SelfNode::loc(loc),
+      "parent" => ParentNode::loc(loc),
+      "static" => StaticKeywordNode::loc(loc),
+      _ => IdentifierNode::loc(self.bytes(name.as_bytes()), loc),
+    })
+  }
+
+  fn identifier(&self, value: &Value) -> Result<Node<'arena>, String> {
+    if !Self::is_name(value) {
+      return self.node(value);
+    }
+    let name = Self::name_text(value);
+    if let Ok(name) = MagicMethodName::try_from(name.as_bytes().as_bstr()) {
+      return Ok(MagicMethodNode::loc(name, Self::loc(value)));
+    }
+    Ok(IdentifierNode::loc(self.bytes(name.as_bytes()), Self::loc(value)))
+  }
+
+  fn variable_name(
+    &self,
+    value: &Value,
+    loc: Option<RangeLocation>
+  ) -> Result<Node<'arena>, String> {
+    match value {
+      Value::String(x) => Ok(IdentifierNode::loc(self.bytes(x.as_bytes()), loc)),
+      _ => self.node(value),
+    }
+  }
+
+  fn type_hint(&self, value: &Value) -> Result<Node<'arena>, String> {
+    let loc = Self::loc(value);
+    match Self::node_type(value)? {
+      "NullableType" => {
+        let inner = Self::field(value, "type");
+        Ok(TypeNode::loc(true, self.bytes(Self::name_text(inner).as_bytes()), loc))
+      }
+      "UnionType" => {
+        let types = self.list(Self::field(value, "types"), Self::type_hint)?;
+        Ok(UnionTypeNode::loc(types, loc))
+      }
+      "IntersectionType" => {
+        let types = self.list(Self::field(value, "types"), Self::type_hint)?;
+        Ok(IntersectionTypeNode::loc(types, loc))
+      }
+      _ if Self::is_name(value) =>
+        Ok(TypeNode::loc(false, self.bytes(Self::name_text(value).as_bytes()), loc)),
+      _ => self.node(value),
+    }
+  }
+
+  fn visibilities(flags: u64) -> std::vec::Vec<Visibility> {
+    [
+      (MODIFIER_PUBLIC, Visibility::Public),
+      (MODIFIER_PROTECTED, Visibility::Protected),
+      (MODIFIER_PRIVATE, Visibility::Private),
+      (MODIFIER_PUBLIC_SET, Visibility::PublicSet),
+      (MODIFIER_PROTECTED_SET, Visibility::ProtectedSet),
+      (MODIFIER_PRIVATE_SET, Visibility::PrivateSet),
+    ]
+      .into_iter()
+      .filter(|(flag, _)| flags & flag != 0)
+      .map(|(_, visibility)| visibility)
+      .collect()
+  }
+
+  fn modifier(flags: u64) -> Option<Modifier> {
+    if flags & MODIFIER_STATIC != 0 {
+      Some(Modifier::Static)
+    } else if flags & MODIFIER_READONLY != 0 {
+      Some(Modifier::Readonly)
+    } else {
+      None
+    }
+  }
+
+  fn inheritance(flags: u64) -> Option<Inheritance> {
+    if flags & MODIFIER_ABSTRACT != 0 {
+      Some(Inheritance::Abstract)
+    } else if flags & MODIFIER_FINAL != 0 {
+      Some(Inheritance::Final)
+    } else {
+      None
+    }
+  }
+
+  fn use_modifier(use_type: u64) -> Option<UseItemModifier> {
+    match use_type {
+      2 => Some(UseItemModifier::Function),
+      3 => Some(UseItemModifier::Const),
+      _ => None,
+    }
+  }
+
+  fn arguments(&self, value: &Value) -> Result<Vec<'arena, Node<'arena>>, String> {
+    self.list(value, |this, arg| {
+      let node = this.node(arg)?;
+      if node.node_type == NodeType::Argument {
+        return Ok(node);
+      }
+      let loc = node.loc.clone();
+      Ok(ArgumentNode::loc(None, node.into_boxed(this.arena), loc))
+    })
+  }
+
+  /// Arguments of an anonymous class, kept as bare expressions like the parser does
+  fn parameters(&self, value: &Value) -> Result<Vec<'arena, Node<'arena>>, String> {
+    self.list(value, |this, arg| {
+      let node = this.node(arg)?;
+      match node.wrapper {
+        NodeWrapper::Argument(ArgumentNode { name: None, value }) => Ok(Box::into_inner(value)),
+        _ => Ok(node),
+      }
+    })
+  }
+
+  fn string_parts(
+    &self,
+    value: &Value,
+    quote: Option<u8>
+  ) -> Result<Vec<'arena, Node<'arena>>, String> {
+    let mut parts = Vec::new_in(self.arena);
+    for part in Self::field(value, "parts").as_array().into_iter().flatten() {
+      let loc = Self::loc(part);
+      let node = match Self::node_type(part)? {
+        "InterpolatedStringPart" | "Scalar_EncapsedStringPart" => {
+          let text = Self::field(part, "value").as_str().unwrap_or_default();
+          let text = escape_double(text.as_bytes(), quote);
+          EncapsedPartNode::loc(
+            false,
+            StringNode::loc(Quote::Single, self.bytes(&text), loc.clone()).into_boxed(self.arena),
+            loc
+          )
+        }
+        node_type => EncapsedPartNode::loc(node_type != "Expr_Variable", self.boxed(part)?, loc),
+      };
+      parts.push(node);
+    }
+    Ok(parts)
+  }
+
+  fn heredoc_parts(&self, mut parts: Vec<'arena, Node<'arena>>) -> Vec<'arena, Node<'arena>> {
+    let newline = || StringNode::loc(Quote::Single, BStr::new("\n"), None).into_boxed(self.arena);
+    parts.insert(0, EncapsedPartNode::loc(false, newline(), None));
+    parts.push(EncapsedPartNode::loc(false, newline(), None));
+    parts
+  }
+
+  fn node(&self, value: &Value) -> Result<Node<'arena>, String> {
+    let node_type = Self::node_type(value)?;
+    let mut node = self.node_of(node_type, value)?;
+    for leading in self.comments(value).into_iter().rev() {
+      node.leadings_shift(self.arena, leading);
+    }
+    Ok(node)
+  }
+
+  fn node_of(&self, node_type: &str, v: &Value) -> Result<Node<'arena>, String> {
+    let loc = Self::loc(v);
+    let field = |key: &str| Self::field(v, key);
+    let node = match node_type {
+      "Stmt_Expression" => self.node(field("expr"))?,
+      "Stmt_Block" => BlockNode::loc(self.statements(field("stmts"))?, loc),
+      "Stmt_Echo" => EchoNode::loc(self.list(field("exprs"), Self::node)?, loc),
+      "Stmt_InlineHTML" => InlineNode::loc(self.str(field("value")), loc),
+      "Stmt_Function" =>
+        FunctionNode::loc(
+          self.attr_groups(v)?,
+          Self::flag(v, "byRef"),
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          self.list(field("params"), Self::node)?,
+          self.optional(field("returnType"), Self::type_hint)?,
+          Some(self.block(field("stmts"))?),
+          loc
+        ),
+      "Stmt_ClassMethod" => {
+        let flags = Self::number(v, "flags");
+        let body = if field("stmts").is_null() { None } else { Some(self.block(field("stmts"))?) };
+        let function = FunctionNode::loc(
+          Vec::new_in(self.arena),
+          Self::flag(v, "byRef"),
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          self.list(field("params"), Self::node)?,
+          self.optional(field("returnType"), Self::type_hint)?,
+          body,
+          loc.clone()
+        );
+        MethodNode::loc(
+          self.attr_groups(v)?,
+          Self::visibilities(flags).into_iter().next(),
+          Self::inheritance(flags),
+          flags & MODIFIER_STATIC != 0,
+          function.into_boxed(self.arena),
+          loc
+        )
+      }
+      "Stmt_Class" => {
+        let flags = Self::number(v, "flags");
+        ClassNode::loc(
+          self.attr_groups(v)?,
+          Self::inheritance(flags),
+          self.optional(field("name"), Self::identifier)?,
+          self.optional(field("extends"), Self::name)?,
+          self.list(field("implements"), Self::name)?,
+          self.block(field("stmts"))?,
+          flags & MODIFIER_READONLY != 0,
+          loc
+        )
+      }
+      "Stmt_Interface" =>
+        InterfaceNode::loc(
+          self.attr_groups(v)?,
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          self.list(field("extends"), Self::name)?,
+          self.block(field("stmts"))?,
+          loc
+        ),
+      "Stmt_Trait" =>
+        TraitNode::loc(
+          self.attr_groups(v)?,
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          self.block(field("stmts"))?,
+          loc
+        ),
+      "Stmt_Enum" => {
+        let implements = field("implements")
+          .as_array()
+          .and_then(|x| x.first())
+          .unwrap_or(&Value::Null);
+        EnumNode::loc(
+          self.attr_groups(v)?,
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          self.optional(field("scalarType"), Self::type_hint)?,
+          self.optional(implements, Self::name)?,
+          self.statements(field("stmts"))?,
+          loc
+        )
+      }
+      "Stmt_EnumCase" => {
+        let name = self.identifier(field("name"))?;
+        let value = if field("expr").is_null() {
+          name
+        } else {
+          AssignmentNode::loc(
+            name.into_boxed(self.arena),
+            AssignmentType::Default,
+            self.boxed(field("expr"))?,
+            loc.clone()
+          )
+        };
+        EnumItemNode::loc(self.attr_groups(v)?, value.into_boxed(self.arena), loc)
+      }
+      "Stmt_Property" => {
+        let flags = Self::number(v, "flags");
+        let mut items = self.list(field("props"), Self::node)?;
+        if let Some(NodeWrapper::PropertyItem(first)) = items.first_mut().map(|x| &mut x.wrapper) {
+          first.variable_type = self.optional(field("type"), Self::type_hint)?;
+        }
+        PropertyNode::loc(
+          self.attr_groups(v)?,
+          Self::visibilities(flags),
+          Self::modifier(flags),
+          self.list(field("hooks"), Self::node)?,
+          items,
+          loc
+        )
+      }
+      "PropertyItem" | "Stmt_PropertyProperty" =>
+        PropertyItemNode::loc(
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          None,
+          self.optional(field("default"), Self::node)?,
+          loc
+        ),
+      "PropertyHook" => {
+        let body = if field("body").is_array() {
+          self.block(field("body"))?
+        } else {
+          self.boxed(field("body"))?
+        };
+        let is_get = Self::name_text(field("name")).eq_ignore_ascii_case("get");
+        PropertyHookNode::loc(
+          is_get,
+          Self::flag(v, "byRef"),
+          self.list(field("params"), Self::node)?,
+          body,
+          loc
+        )
+      }
+      "Stmt_ClassConst" =>
+        ConstPropertyNode::loc(
+          self.attr_groups(v)?,
+          self.optional(field("type"), Self::type_hint)?,
+          Self::visibilities(Self::number(v, "flags")),
+          self.list(field("consts"), Self::node)?,
+          loc
+        ),
+      "Stmt_Const" => ConstNode::loc(self.list(field("consts"), Self::node)?, loc),
+      "Const" =>
+        AssignmentNode::loc(
+          self.identifier(field("name"))?.into_boxed(self.arena),
+          AssignmentType::Default,
+          self.boxed(field("value"))?,
+          loc
+        ),
+      "Stmt_TraitUse" =>
+        TraitUseNode::loc(
+          self.list(field("traits"), Self::name)?,
+          self.list(field("adaptations"), Self::node)?,
+          loc
+        ),
+      "Stmt_TraitUseAdaptation_Alias" => {
+        let visibility = field("newModifier")
+          .as_u64()
+          .and_then(|x| Self::visibilities(x).into_iter().next());
+        TraitUseAliasNode::loc(
+          self.optional(field("trait"), Self::name)?,
+          self.identifier(field("method"))?.into_boxed(self.arena),
+          self.optional(field("newName"), Self::identifier)?,
+          visibility,
+          loc
+        )
+      }
+      "Stmt_TraitUseAdaptation_Precedence" => {
+        let instead = field("insteadof")
+          .as_array()
+          .and_then(|x| x.first())
+          .ok_or("Stmt_TraitUseAdaptation_Precedence without insteadof")?;
+        TraitUsePrecedenceNode::loc(
+          self.optional(field("trait"), Self::name)?,
+          self.identifier(field("method"))?.into_boxed(self.arena),
+          self.name(instead)?.into_boxed(self.arena),
+          loc
+        )
+      }
+      "Stmt_If" => {
+        let mut invalid = match field("else") {
+          Value::Null => None,
+          otherwise => {
+            let body = self.block(Self::field(otherwise, "stmts"))?;
+            Some(ElseNode::loc(body, false, Self::loc(otherwise)).into_boxed(self.arena))
+          }
+        };
+        for elseif in field("elseifs").as_array().into_iter().flatten().rev() {
+          let elseif_loc = Self::loc(elseif);
+          let body = IfNode::loc(
+            self.boxed(Self::field(elseif, "cond"))?,
+            self.block(Self::field(elseif, "stmts"))?,
+            invalid,
+            false,
+            elseif_loc.clone()
+          );
+          invalid = Some(
+            ElseNode::loc(body.into_boxed(self.arena), false, elseif_loc).into_boxed(self.arena)
+          );
+        }
+        IfNode::loc(self.boxed(field("cond"))?, self.block(field("stmts"))?, invalid, false, loc)
+      }
+      "Stmt_While" =>
+        WhileNode::loc(self.boxed(field("cond"))?, self.block(field("stmts"))?, false, loc),
+      "Stmt_Do" => {
+        let condition = DoWhileConditionNode::loc(self.boxed(field("cond"))?, None);
+        DoWhileNode::loc(condition.into_boxed(self.arena), self.block(field("stmts"))?, loc)
+      }
+      "Stmt_For" =>
+        ForNode::loc(
+          self.list(field("init"), Self::node)?,
+          self.list(field("cond"), Self::node)?,
+          self.list(field("loop"), Self::node)?,
+          Some(self.block(field("stmts"))?),
+          BodyType::Basic,
+          loc
+        ),
+      "Stmt_Foreach" => {
+        let mut value = self.node(field("valueVar"))?;
+        if Self::flag(v, "byRef") {
+          let value_loc = value.loc.clone();
+          value = ReferenceNode::loc(value.into_boxed(self.arena), value_loc);
+        }
+        ForeachNode::loc(
+          self.boxed(field("expr"))?,
+          self.optional(field("keyVar"), Self::node)?,
+          value.into_boxed(self.arena),
+          self.block(field("stmts"))?,
+          false,
+          loc
+        )
+      }
+      "Stmt_Switch" => {
+        let cases = BlockNode::loc(self.list(field("cases"), Self::node)?, None);
+        SwitchNode::loc(self.boxed(field("cond"))?, cases.into_boxed(self.arena), false, loc)
+      }
+      "Stmt_Case" =>
+        CaseNode::loc(self.optional(field("cond"), Self::node)?, self.block(field("stmts"))?, loc),
+      "Stmt_Break" => BreakNode::loc(self.optional(field("num"), Self::node)?, loc),
+      "Stmt_Continue" => ContinueNode::loc(self.optional(field("num"), Self::node)?, loc),
+      "Stmt_Return" => ReturnNode::loc(self.optional(field("expr"), Self::node)?, loc),
+      "Stmt_Global" => GlobalNode::loc(self.list(field("vars"), Self::node)?, loc),
+      "Stmt_Static" => StaticNode::loc(self.list(field("vars"), Self::node)?, loc),
+      "StaticVar" | "Stmt_StaticVar" => {
+        let var = field("var");
+        PropertyItemNode::loc(
+          self.variable_name(Self::field(var, "name"), Self::loc(var))?.into_boxed(self.arena),
+          None,
+          self.optional(field("default"), Self::node)?,
+          loc
+        )
+      }
+      "Stmt_Unset" => {
+        let name = IdentifierNode::loc(BStr::new("unset"), None);
+        CallNode::loc(name.into_boxed(self.arena), self.arguments(field("vars"))?, loc)
+      }
+      "Stmt_Namespace" => {
+        let name = match field("name") {
+          Value::Null => String::new(),
+          name => Self::name_text(name),
+        };
+        NamespaceNode::loc(
+          self.bytes(name.as_bytes()),
+          self.block(field("stmts"))?,
+          Self::attribute(v, "kind").as_u64() == Some(2),
+          loc
+        )
+      }
+      "Stmt_Use" | "Stmt_GroupUse" => {
+        let use_type = Self::number(v, "type");
+        let mut items = Vec::new_in(self.arena);
+        for (index, item) in field("uses").as_array().into_iter().flatten().enumerate() {
+          let item_type = match Self::number(item, "type") {
+            0 if node_type == "Stmt_Use" && index > 0 => 1,
+            0 => use_type,
+            x => x,
+          };
+          items.push(
+            UseItemNode::loc(
+              Self::use_modifier(item_type),
+              self.bytes(Self::name_text(Self::field(item, "name")).as_bytes()),
+              self.optional(Self::field(item, "alias"), Self::identifier)?,
+              Self::loc(item)
+            )
+          );
+        }
+        let prefix = match field("prefix") {
+          Value::Null => None,
+          prefix => Some(self.bytes(format!("{}\\", Self::name_text(prefix)).as_bytes())),
+        };
+        UseNode::loc(prefix, items, loc)
+      }
+      "Stmt_TryCatch" => {
+        let mut catches = self.list(field("catches"), Self::node)?;
+        if let Some(finally) = self.optional(field("finally"), Self::node)? {
+          catches.push(Box::into_inner(finally));
+        }
+        TryNode::loc(self.block(field("stmts"))?, catches, loc)
+      }
+      "Stmt_Catch" =>
+        CatchNode::loc(
+          self.list(field("types"), Self::name)?,
+          self.optional(field("var"), Self::node)?,
+          self.block(field("stmts"))?,
+          loc
+        ),
+      "Stmt_Finally" => FinallyNode::loc(self.block(field("stmts"))?, loc),
+      "Stmt_Declare" => {
+        let (body, body_type) = if field("stmts").is_null() {
+          (None, BodyType::Empty)
+        } else {
+          (Some(self.block(field("stmts"))?), BodyType::Basic)
+        };
+        DeclareNode::loc(self.list(field("declares"), Self::node)?, body, body_type, loc)
+      }
+      "DeclareItem" | "Stmt_DeclareDeclare" =>
+        DeclareArgumentNode::loc(
+          self.identifier(field("key"))?.into_boxed(self.arena),
+          self.boxed(field("value"))?,
+          loc
+        ),
+      "Stmt_Label" => LabelNode::loc(self.identifier(field("name"))?.into_boxed(self.arena), loc),
+      "Stmt_Goto" => GotoNode::loc(self.identifier(field("name"))?.into_boxed(self.arena), loc),
+      "Stmt_HaltCompiler" => HaltCompilerNode::loc(loc),
+      "Stmt_Throw" | "Expr_Throw" => ThrowNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_Variable" =>
+        match field("name") {
+          Value::String(x) if x == "this" => ThisNode::loc(loc),
+          name => {
+            let name = self.variable_name(name, loc.clone())?;
+            VariableNode::loc(name.into_boxed(self.arena), loc)
+          }
+        }
+      "Expr_ConstFetch" => {
+        let name = field("name");
+        match Self::name_text(name).to_ascii_lowercase().as_str() {
+          "true" => BooleanNode::loc(true, loc),
+          "false" => BooleanNode::loc(false, loc),
+          "null" => NullNode::loc(loc),
+          _ => self.name(name)?,
+        }
+      }
+      "Scalar_Int" | "Scalar_LNumber" | "Scalar_Float" | "Scalar_DNumber" => {
+        let value = match Self::attribute(v, "rawValue") {
+          Value::String(x) => x.clone(),
+          _ => field("value").to_string(),
+        };
+        NumberNode::loc(self.bytes(value.as_bytes()), loc)
+      }
+      "Scalar_String" => {
+        let text = field("value").as_str().unwrap_or_default();
+        let label = self.str(Self::attribute(v, "docLabel"));
+        let raw = Self::attribute(v, "rawValue").as_str().filter(|x| x.len() >= 2);
+        match Self::attribute(v, "kind").as_u64().unwrap_or(STRING_SINGLE) {
+          STRING_HEREDOC => {
+            let text = escape_double(text.as_bytes(), None);
+            let part = StringNode::loc(Quote::Single, self.bytes(&text), None);
+            let part = EncapsedPartNode::loc(false, part.into_boxed(self.arena), None);
+            let parts = bumpalo::vec![in self.arena; part];
+            HereDocNode::loc(label, self.heredoc_parts(parts), loc)
+          }
+          STRING_NOWDOC =>
+            NowDocNode::loc(label, self.bytes(format!("\n{}\n", text).as_bytes()), loc),
+          kind => {
+            let quote = if kind == STRING_DOUBLE { Quote::Double } else { Quote::Single };
+            let value = match raw {
+              Some(raw) if raw.starts_with(['\'', '"']) =>
+                self.bytes(&raw.as_bytes()[1..raw.len() - 1]),
+              _ if quote == Quote::Double =>
+                self.bytes(&escape_double(text.as_bytes(), Some(b'"'))),
+              _ => self.bytes(&escape_single(text.as_bytes())),
+            };
+            StringNode::loc(quote, value, loc)
+          }
+        }
+      }
+      "Scalar_InterpolatedString" | "Scalar_Encapsed" => {
+        if Self::attribute(v, "kind").as_u64() == Some(STRING_HEREDOC) {
+          let parts = self.string_parts(v, None)?;
+          HereDocNode::loc(self.str(Self::attribute(v, "docLabel")), self.heredoc_parts(parts), loc)
+        } else {
+          EncapsedNode::loc(Quote::Double, self.string_parts(v, Some(b'"'))?, loc)
+        }
+      }
+      "Expr_ShellExec" => {
+        let parts = self.string_parts(v, Some(b'`'))?;
+        let text = match parts.as_slice() {
+          [Node { wrapper: NodeWrapper::EncapsedPart(part), .. }] =>
+            match &part.value.wrapper {
+              NodeWrapper::String(x) => Some(x.value),
+              _ => None,
+            }
+          _ => None,
+        };
+        match text {
+          Some(text) => StringNode::loc(Quote::Backtick, text, loc),
+          None => EncapsedNode::loc(Quote::Backtick, parts, loc),
+        }
+      }
+      "Expr_Array" => {
+        let is_short = Self::attribute(v, "kind").as_u64() != Some(1);
+        ArrayNode::loc(is_short, self.list(field("items"), Self::node)?, loc)
+      }
+      "Expr_List" => {
+        if Self::attribute(v, "kind").as_u64() == Some(2) {
+          ArrayNode::loc(true, self.list(field("items"), Self::node)?, loc)
+        } else {
+          let items = self.list(field("items"), |this, item| {
+            let node = this.node(item)?;
+            match node.wrapper {
+              NodeWrapper::ArrayItem(x) if x.key.is_none() => Ok(Box::into_inner(x.value)),
+              wrapper => Ok(Node { wrapper, ..node }),
+            }
+          })?;
+          ListNode::loc(items, loc)
+        }
+      }
+      "ArrayItem" | "Expr_ArrayItem" => {
+        let mut value = self.node(field("value"))?;
+        if Self::flag(v, "byRef") {
+          let value_loc = value.loc.clone();
+          value = ReferenceNode::loc(value.into_boxed(self.arena), value_loc);
+        } else if Self::flag(v, "unpack") {
+          let value_loc = value.loc.clone();
+          value = VariadicNode::loc(Some(value.into_boxed(self.arena)), value_loc);
+        }
+        let key = self.optional(field("key"), Self::node)?;
+        ArrayItemNode::loc(key, value.into_boxed(self.arena), loc)
+      }
+      "Expr_ArrayDimFetch" =>
+        ArrayLookupNode::loc(
+          self.boxed(field("var"))?,
+          self.optional(field("dim"), Self::node)?,
+          loc
+        ),
+      "Expr_Assign" =>
+        AssignmentNode::loc(
+          self.boxed(field("var"))?,
+          AssignmentType::Default,
+          self.boxed(field("expr"))?,
+          loc
+        ),
+      "Expr_AssignRef" => {
+        let expr = self.node(field("expr"))?;
+        let expr_loc = expr.loc.clone();
+        let reference = ReferenceNode::loc(expr.into_boxed(self.arena), expr_loc);
+        AssignmentNode::loc(
+          self.boxed(field("var"))?,
+          AssignmentType::Default,
+          reference.into_boxed(self.arena),
+          loc
+        )
+      }
+      "Expr_Instanceof" =>
+        BinNode::loc(
+          self.boxed(field("expr"))?,
+          BinaryType::InstanceOf,
+          self.name(field("class"))?.into_boxed(self.arena),
+          loc
+        ),
+      "Expr_Ternary" =>
+        match field("if") {
+          Value::Null =>
+            BinNode::loc(
+              self.boxed(field("cond"))?,
+              BinaryType::Elvis,
+              self.boxed(field("else"))?,
+              loc
+            ),
+          valid =>
+            TernaryNode::loc(
+              self.boxed(field("cond"))?,
+              self.boxed(valid)?,
+              self.boxed(field("else"))?,
+              loc
+            ),
+        }
+      "Expr_PreInc" => PreNode::loc(self.boxed(field("var"))?, PreType::Increment, loc),
+      "Expr_PreDec" => PreNode::loc(self.boxed(field("var"))?, PreType::Decrement, loc),
+      "Expr_UnaryPlus" => PreNode::loc(self.boxed(field("expr"))?, PreType::Addition, loc),
+      "Expr_UnaryMinus" => PreNode::loc(self.boxed(field("expr"))?, PreType::Subtraction, loc),
+      "Expr_PostInc" => PostNode::loc(self.boxed(field("var"))?, PostType::Increment, loc),
+      "Expr_PostDec" => PostNode::loc(self.boxed(field("var"))?, PostType::Decrement, loc),
+      "Expr_BitwiseNot" => PreNode::loc(self.boxed(field("expr"))?, PreType::BitwiseNot, loc),
+      "Expr_BooleanNot" => NegateNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_FuncCall" =>
+        CallNode::loc(
+          self.name(field("name"))?.into_boxed(self.arena),
+          self.arguments(field("args"))?,
+          loc
+        ),
+      "Expr_Isset" | "Expr_Empty" => {
+        let (name, arguments) = if node_type == "Expr_Isset" {
+          ("isset", self.arguments(field("vars"))?)
+        } else {
+          ("empty", self.arguments(&Value::Array(vec![field("expr").clone()]))?)
+        };
+        let name = IdentifierNode::loc(BStr::new(name), None);
+        CallNode::loc(name.into_boxed(self.arena), arguments, loc)
+      }
+      | "Expr_MethodCall"
+      | "Expr_NullsafeMethodCall"
+      | "Expr_PropertyFetch"
+      | "Expr_NullsafePropertyFetch" => {
+        let name = field("name");
+        let access = ObjectAccessNode::loc(
+          self.boxed(field("var"))?,
+          self.identifier(name)?.into_boxed(self.arena),
+          !Self::is_name(name),
+          node_type.starts_with("Expr_Nullsafe"),
+          loc.clone()
+        );
+        if node_type.ends_with("MethodCall") {
+          CallNode::loc(access.into_boxed(self.arena), self.arguments(field("args"))?, loc)
+        } else {
+          access
+        }
+      }
+      "Expr_StaticCall" => {
+        let name = field("name");
+        let lookup = StaticLookupNode::loc(
+          self.name(field("class"))?.into_boxed(self.arena),
+          self.identifier(name)?.into_boxed(self.arena),
+          !Self::is_name(name),
+          loc.clone()
+        );
+        CallNode::loc(lookup.into_boxed(self.arena), self.arguments(field("args"))?, loc)
+      }
+      "Expr_StaticPropertyFetch" => {
+        let name = field("name");
+        let variable = VariableNode::loc(
+          self.identifier(name)?.into_boxed(self.arena),
+          Self::loc(name)
+        );
+        StaticLookupNode::loc(
+          self.name(field("class"))?.into_boxed(self.arena),
+          variable.into_boxed(self.arena),
+          false,
+          loc
+        )
+      }
+      "Expr_ClassConstFetch" => {
+        let name = field("name");
+        let right = if Self::is_name(name) && Self::name_text(name).eq_ignore_ascii_case("class") {
+          ClassKeywordNode::loc(Self::loc(name))
+        } else {
+          self.identifier(name)?
+        };
+        StaticLookupNode::loc(
+          self.name(field("class"))?.into_boxed(self.arena),
+          right.into_boxed(self.arena),
+          !Self::is_name(name),
+          loc
+        )
+      }
+      "Expr_New" => {
+        let class = field("class");
+        let statement = if Self::node_type(class)? == "Stmt_Class" {
+          AnonymousClassNode::loc(
+            self.attr_groups(class)?,
+            self.parameters(field("args"))?,
+            self.optional(Self::field(class, "extends"), Self::name)?,
+            self.list(Self::field(class, "implements"), Self::name)?,
+            self.block(Self::field(class, "stmts"))?,
+            Self::loc(class)
+          )
+        } else {
+          let arguments = self.arguments(field("args"))?;
+          if arguments.is_empty() {
+            self.name(class)?
+          } else {
+            CallNode::loc(self.name(class)?.into_boxed(self.arena), arguments, loc.clone())
+          }
+        };
+        NewNode::loc(statement.into_boxed(self.arena), loc)
+      }
+      "Expr_Closure" =>
+        AnonymousFunctionNode::loc(
+          self.attr_groups(v)?,
+          Self::flag(v, "byRef"),
+          self.list(field("params"), Self::node)?,
+          self.list(field("uses"), Self::node)?,
+          self.optional(field("returnType"), Self::type_hint)?,
+          self.block(field("stmts"))?,
+          loc
+        ),
+      "ClosureUse" | "Expr_ClosureUse" => {
+        let var = self.node(field("var"))?;
+        if Self::flag(v, "byRef") {
+          ReferenceNode::loc(var.into_boxed(self.arena), loc)
+        } else {
+          var
+        }
+      }
+      "Expr_ArrowFunction" =>
+        ArrowFunctionNode::loc(
+          self.attr_groups(v)?,
+          Self::flag(v, "byRef"),
+          self.list(field("params"), Self::node)?,
+          self.optional(field("returnType"), Self::type_hint)?,
+          self.boxed(field("expr"))?,
+          loc
+        ),
+      "Expr_Match" =>
+        MatchNode::loc(self.boxed(field("cond"))?, self.list(field("arms"), Self::node)?, loc),
+      "MatchArm" =>
+        MatchArmNode::loc(self.list(field("conds"), Self::node)?, self.boxed(field("body"))?, loc),
+      "Expr_Yield" =>
+        YieldNode::loc(
+          self.optional(field("key"), Self::node)?,
+          self.optional(field("value"), Self::node)?,
+          loc
+        ),
+      "Expr_YieldFrom" => YieldFromNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_Clone" => CloneNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_Print" => PrintNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_Eval" => EvalNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_ErrorSuppress" => SilentNode::loc(self.boxed(field("expr"))?, loc),
+      "Expr_Exit" => ExitNode::loc(self.optional(field("expr"), Self::node)?, loc),
+      "Expr_Include" => {
+        let include_type = Self::number(v, "type");
+        IncludeNode::loc(
+          false,
+          include_type >= 3,
+          include_type % 2 == 0,
+          self.boxed(field("expr"))?,
+          loc
+        )
+      }
+      "Expr_Error" => ErrorNode::loc(loc),
+      "Param" => {
+        let var = field("var");
+        let flags = Self::number(v, "flags");
+        let (attributes, parameter_attributes) = if flags == 0 {
+          (Vec::new_in(self.arena), self.attr_groups(v)?)
+        } else {
+          (self.attr_groups(v)?, Vec::new_in(self.arena))
+        };
+        let parameter = ParameterNode::loc(
+          parameter_attributes,
+          self.optional(field("type"), Self::type_hint)?,
+          Self::flag(v, "byRef"),
+          Self::flag(v, "variadic"),
+          self.variable_name(Self::field(var, "name"), Self::loc(var))?.into_boxed(self.arena),
+          self.optional(field("default"), Self::node)?,
+          loc.clone()
+        );
+        if flags == 0 {
+          parameter
+        } else {
+          ConstructorParameterNode::loc(
+            attributes,
+            Self::visibilities(flags),
+            Self::modifier(flags),
+            parameter.into_boxed(self.arena),
+            loc
+          )
+        }
+      }
+      "Arg" => {
+        let mut value = self.node(field("value"))?;
+        if Self::flag(v, "unpack") {
+          let value_loc = value.loc.clone();
+          value = VariadicNode::loc(Some(value.into_boxed(self.arena)), value_loc);
+        }
+        let name = self.optional(field("name"), Self::identifier)?;
+        ArgumentNode::loc(name, value.into_boxed(self.arena), loc)
+      }
+      "VariadicPlaceholder" =>
+        ArgumentNode::loc(None, VariadicNode::loc(None, loc.clone()).into_boxed(self.arena), loc),
+      "Identifier" | "VarLikeIdentifier" | "Name" | "Name_FullyQualified" | "Name_Relative" =>
+        self.identifier(v)?,
+      "NullableType" | "UnionType" | "IntersectionType" => self.type_hint(v)?,
+      _ => {
+        if let Some(operator) = node_type.strip_prefix("Expr_AssignOp_") {
+          let operator = match operator {
+            "Coalesce" => AssignmentType::Coalesce,
+            "Concat" => AssignmentType::Concatenation,
+            "Plus" => AssignmentType::Addition,
+            "Minus" => AssignmentType::Subtraction,
+            "Mul" => AssignmentType::Multiplication,
+            "Div" => AssignmentType::Division,
+            "Pow" => AssignmentType::Exponentiation,
+            "Mod" => AssignmentType::Modulus,
+            "BitwiseAnd" => AssignmentType::BitwiseAnd,
+            "BitwiseOr" => AssignmentType::BitwiseOr,
+            "BitwiseXor" => AssignmentType::BitwiseXor,
+            "ShiftRight" => AssignmentType::BitwiseShiftRight,
+            "ShiftLeft" => AssignmentType::BitwiseShiftLeft,
+            _ => {
+              return Err(format!("Unsupported PHP-Parser node type: {}", node_type));
+            }
+          };
+          AssignmentNode::loc(self.boxed(field("var"))?, operator, self.boxed(field("expr"))?, loc)
+        } else if let Some(operator) = node_type.strip_prefix("Expr_BinaryOp_") {
+          let operator = match operator {
+            "Plus" => BinaryType::Addition,
+            "Minus" => BinaryType::Subtraction,
+            "Mul" => BinaryType::Multiplication,
+            "Div" => BinaryType::Division,
+            "Mod" => BinaryType::Modulus,
+            "Pow" => BinaryType::Exponentiation,
+            "BitwiseAnd" => BinaryType::BitwiseAnd,
+            "BitwiseOr" => BinaryType::BitwiseOr,
+            "BitwiseXor" => BinaryType::BitwiseXor,
+            "ShiftLeft" => BinaryType::BitwiseShiftLeft,
+            "ShiftRight" => BinaryType::BitwiseShiftRight,
+            "Equal" => BinaryType::IsEqual,
+            "Identical" => BinaryType::IsIdentical,
+            "NotEqual" => BinaryType::IsNotEqual,
+            "NotIdentical" => BinaryType::IsNotIdentical,
+            "Smaller" => BinaryType::IsLesser,
+            "Greater" => BinaryType::IsGreater,
+            "SmallerOrEqual" => BinaryType::IsLesserOrEqual,
+            "GreaterOrEqual" => BinaryType::IsGreaterOrEqual,
+            "Spaceship" => BinaryType::Spaceship,
+            "Concat" => BinaryType::Concatenation,
+            "BooleanAnd" => BinaryType::BooleanAnd,
+            "BooleanOr" => BinaryType::BooleanOr,
+            "LogicalAnd" => BinaryType::LogicalAnd,
+            "LogicalOr" => BinaryType::LogicalOr,
+            "LogicalXor" => BinaryType::BooleanXor,
+            "Coalesce" => BinaryType::Coalesce,
+            _ => {
+              return Err(format!("Unsupported PHP-Parser node type: {}", node_type));
+            }
+          };
+          BinNode::loc(self.boxed(field("left"))?, operator, self.boxed(field("right"))?, loc)
+        } else if let Some(cast) = node_type.strip_prefix("Expr_Cast_") {
+          let kind = Self::attribute(v, "kind").as_u64().unwrap_or(1);
+          let cast_type = match (cast, kind) {
+            ("Int", 2) => CastType::Integer,
+            ("Int", _) => CastType::Int,
+            ("Bool", 2) => CastType::Boolean,
+            ("Bool", _) => CastType::Bool,
+            ("Double", 2) => CastType::Float,
+            ("Double", 3) => CastType::Real,
+            ("Double", _) => CastType::Double,
+            ("String", 2) => CastType::Binary,
+            ("String", _) => CastType::String,
+            ("Array", _) => CastType::Array,
+            ("Object", _) => CastType::Object,
+            ("Unset", _) => CastType::Unset,
+            _ => {
+              return Err(format!("Unsupported PHP-Parser node type: {}", node_type));
+            }
+          };
+          CastNode::loc(cast_type, self.boxed(field("expr"))?, loc)
+        } else if let Some(name) = node_type.strip_prefix("Scalar_MagicConst_") {
+          let name = match name {
+            "Class" => MagicName::Class,
+            "Dir" => MagicName::Dir,
+            "File" => MagicName::File,
+            "Function" => MagicName::Function,
+            "Line" => MagicName::Line,
+            "Method" => MagicName::Method,
+            "Namespace" => MagicName::Namespace,
+            "Trait" => MagicName::Trait,
+            "Property" => MagicName::Property,
+            _ => {
+              return Err(format!("Unsupported PHP-Parser node type: {}", node_type));
+            }
+          };
+          MagicNode::loc(name, loc)
+        } else {
+          return Err(format!("Unsupported PHP-Parser node type: {}", node_type));
+        }
+      }
+    };
+    Ok(node)
+  }
+}
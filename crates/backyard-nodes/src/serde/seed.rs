@@ -0,0 +1,416 @@
+use std::{ fmt, marker::PhantomData };
+
+use bstr::{ BStr, BString, ByteSlice };
+use bumpalo::Bump;
+use serde::{
+  de::{
+    self,
+    value::UnitDeserializer,
+    DeserializeSeed,
+    IgnoredAny,
+    IntoDeserializer,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+  },
+  Deserialize,
+  Deserializer,
+};
+
+use crate::{
+  macros::node_types::with_node_types,
+  serde::content::Content,
+  AssignmentType,
+  BinaryType,
+  BodyType,
+  CastType,
+  Inheritance,
+  MagicMethodName,
+  MagicName,
+  Modifier,
+  Node,
+  NodeType,
+  NodeWrapper,
+  PostType,
+  PreType,
+  Quote,
+  RangeLocation,
+  UseItemModifier,
+  Visibility,
+};
+
+/// Deserialize a node straight into `arena`, reading the same format `Node` serializes to.
+pub struct NodeSeed<'arena> {
+  arena: &'arena Bump,
+}
+
+impl<'arena> NodeSeed<'arena> {
+  pub fn new(arena: &'arena Bump) -> Self {
+    Self { arena }
+  }
+}
+
+impl<'arena> Node<'arena> {
+  pub fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    NodeSeed::new(arena).deserialize(deserializer)
+  }
+}
+
+impl<'de, 'arena> DeserializeSeed<'de> for NodeSeed<'arena> {
+  type Value = Node<'arena>;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where D: Deserializer<'de>
+  {
+    deserializer.deserialize_map(self)
+  }
+}
+
+impl<'de, 'arena> Visitor<'de> for NodeSeed<'arena> {
+  type Value = Node<'arena>;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a node map with a node_type field")
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error> where A: MapAccess<'de> {
+    let mut fields = NodeFields::new(self.arena);
+    let node_type = loop {
+      let Some(key) = map.next_key::<String>()? else {
+        return Err(de::Error::missing_field("node_type"));
+      };
+      match key.as_str() {
+        "node_type" => {
+          break map.next_value::<NodeType>()?;
+        }
+        "loc" | "leadings" | "trailings" => fields.common_value(&key, &mut map)?,
+        _ => fields.buffered.push((key, map.next_value()?)),
+      }
+    };
+    fields.buffered.reverse();
+    let wrapper = NodeWrapper::deserialize_fields(&node_type, &mut fields, &mut map)?;
+    Ok(Node {
+      node_type,
+      wrapper,
+      loc: fields.loc,
+      leadings: fields.leadings,
+      trailings: fields.trailings,
+    })
+  }
+}
+
+macro_rules! deserialize_wrapper {
+  ($($node_type:ident),* $(,)?) => {
+    impl<'arena> NodeWrapper<'arena> {
+      fn deserialize_fields<'de, A>(
+        node_type: &NodeType,
+        fields: &mut NodeFields<'arena>,
+        map: &mut A
+      ) -> Result<Self, A::Error>
+        where A: MapAccess<'de>
+      {
+        Ok(match node_type {
+          $(
+            NodeType::$node_type =>
+              NodeWrapper::$node_type(DeserializeFields::deserialize_fields(fields, map)?),
+          )*
+        })
+      }
+    }
+  };
+}
+
+with_node_types!(deserialize_wrapper);
+
+/// The fields of a node map that is being read, shared by every node type.
+pub struct NodeFields<'arena> {
+  arena: &'arena Bump,
+  // Fields that come before `node_type` cannot be typed yet, so they are buffered.
+  // `Node` always serializes `node_type` first, which keeps this empty. With sorted keys only
+  // the outermost node buffers, nodes replayed from the buffer see `node_type` first.
+  buffered: Vec<(String, Content)>,
+  pending: Option<Content>,
+  loc: Option<RangeLocation>,
+  leadings: Option<bumpalo::collections::Vec<'arena, Node<'arena>>>,
+  trailings: Option<bumpalo::collections::Vec<'arena, Node<'arena>>>,
+}
+
+impl<'arena> NodeFields<'arena> {
+  fn new(arena: &'arena Bump) -> Self {
+    Self { arena, buffered: vec![], pending: None, loc: None, leadings: None, trailings: None }
+  }
+
+  fn common_value<'de, A>(&mut self, key: &str, map: &mut A) -> Result<(), A::Error>
+    where A: MapAccess<'de>
+  {
+    match key {
+      "loc" => {
+        self.loc = map.next_value()?;
+      }
+      "leadings" => {
+        self.leadings = self.next_value(map)?;
+      }
+      "trailings" => {
+        self.trailings = self.next_value(map)?;
+      }
+      _ => {
+        self.skip_value(map)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Next key specific to the node type, the shared fields are consumed along the way.
+  pub fn next_key<'de, A>(&mut self, map: &mut A) -> Result<Option<String>, A::Error>
+    where A: MapAccess<'de>
+  {
+    if let Some((key, value)) = self.buffered.pop() {
+      self.pending = Some(value);
+      return Ok(Some(key));
+    }
+    while let Some(key) = map.next_key::<String>()? {
+      match key.as_str() {
+        "node_type" => {
+          return Err(de::Error::duplicate_field("node_type"));
+        }
+        "loc" | "leadings" | "trailings" => self.common_value(&key, map)?,
+        _ => {
+          return Ok(Some(key));
+        }
+      }
+    }
+    Ok(None)
+  }
+
+  pub fn next_value<'de, T, A>(&mut self, map: &mut A) -> Result<T, A::Error>
+    where T: DeserializeIn<'arena>, A: MapAccess<'de>
+  {
+    match self.pending.take() {
+      Some(value) => T::deserialize_in(value.into_deserializer(), self.arena),
+      None => map.next_value_seed(InSeed::new(self.arena)),
+    }
+  }
+
+  pub fn skip_value<'de, A>(&mut self, map: &mut A) -> Result<(), A::Error>
+    where A: MapAccess<'de>
+  {
+    if self.pending.take().is_none() {
+      map.next_value::<IgnoredAny>()?;
+    }
+    Ok(())
+  }
+
+  /// Unwrap a field that was read, a missing field is only allowed when it is optional.
+  pub fn finish<T, E>(&self, value: Option<T>, field: &'static str) -> Result<T, E>
+    where T: DeserializeIn<'arena>, E: de::Error
+  {
+    match value {
+      Some(value) => Ok(value),
+      None =>
+        T::deserialize_in(UnitDeserializer::<E>::new(), self.arena).map_err(|_|
+          E::missing_field(field)
+        ),
+    }
+  }
+}
+
+pub trait DeserializeFields<'arena>: Sized {
+  fn deserialize_fields<'de, A>(
+    fields: &mut NodeFields<'arena>,
+    map: &mut A
+  ) -> Result<Self, A::Error>
+    where A: MapAccess<'de>;
+}
+
+pub trait DeserializeIn<'arena>: Sized {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>;
+}
+
+struct InSeed<'arena, T> {
+  arena: &'arena Bump,
+  marker: PhantomData<T>,
+}
+
+impl<'arena, T> InSeed<'arena, T> {
+  fn new(arena: &'arena Bump) -> Self {
+    Self { arena, marker: PhantomData }
+  }
+}
+
+impl<'de, 'arena, T: DeserializeIn<'arena>> DeserializeSeed<'de> for InSeed<'arena, T> {
+  type Value = T;
+
+  fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where D: Deserializer<'de>
+  {
+    T::deserialize_in(deserializer, self.arena)
+  }
+}
+
+impl<'arena> DeserializeIn<'arena> for Node<'arena> {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    NodeSeed::new(arena).deserialize(deserializer)
+  }
+}
+
+impl<'arena> DeserializeIn<'arena> for &'arena BStr {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    let value = BString::deserialize(deserializer)?;
+    Ok(arena.alloc_slice_copy(&value).as_bstr())
+  }
+}
+
+impl<'arena, T: DeserializeIn<'arena>> DeserializeIn<'arena> for bumpalo::boxed::Box<'arena, T> {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    Ok(bumpalo::boxed::Box::new_in(T::deserialize_in(deserializer, arena)?, arena))
+  }
+}
+
+impl<'arena, T: DeserializeIn<'arena>> DeserializeIn<'arena>
+for bumpalo::collections::Vec<'arena, T> {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    let items = bumpalo::collections::Vec::new_in(arena);
+    deserializer.deserialize_seq(SeqVisitor { arena, items, marker: PhantomData })
+  }
+}
+
+impl<'arena, T: DeserializeIn<'arena>> DeserializeIn<'arena> for Vec<T> {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    deserializer.deserialize_seq(SeqVisitor { arena, items: vec![], marker: PhantomData })
+  }
+}
+
+struct SeqVisitor<'arena, T, C> {
+  arena: &'arena Bump,
+  items: C,
+  marker: PhantomData<T>,
+}
+
+impl<'de, 'arena, T, C> Visitor<'de>
+  for SeqVisitor<'arena, T, C>
+  where T: DeserializeIn<'arena>, C: Extend<T>
+{
+  type Value = C;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a sequence")
+  }
+
+  fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error> where A: SeqAccess<'de> {
+    while let Some(item) = seq.next_element_seed(InSeed::new(self.arena))? {
+      self.items.extend(Some(item));
+    }
+    Ok(self.items)
+  }
+}
+
+impl<'arena, T: DeserializeIn<'arena>> DeserializeIn<'arena> for Option<T> {
+  fn deserialize_in<'de, D>(deserializer: D, arena: &'arena Bump) -> Result<Self, D::Error>
+    where D: Deserializer<'de>
+  {
+    deserializer.deserialize_option(OptionVisitor { arena, marker: PhantomData })
+  }
+}
+
+struct OptionVisitor<'arena, T> {
+  arena: &'arena Bump,
+  marker: PhantomData<T>,
+}
+
+impl<'de, 'arena, T: DeserializeIn<'arena>> Visitor<'de> for OptionVisitor<'arena, T> {
+  type Value = Option<T>;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("an option")
+  }
+
+  fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+    Ok(None)
+  }
+
+  fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where D: Deserializer<'de>
+  {
+    T::deserialize_in(deserializer, self.arena).map(Some)
+  }
+}
+
+macro_rules! impl_deserialize_in {
+  ($($ty:ty),*) => {
+    $(
+      impl<'arena> DeserializeIn<'arena> for $ty {
+        fn deserialize_in<'de, D>(deserializer: D, _: &'arena Bump) -> Result<Self, D::Error>
+          where D: Deserializer<'de>
+        {
+          <$ty>::deserialize(deserializer)
+        }
+      }
+    )*
+  };
+}
+
+impl_deserialize_in!(
+  bool,
+  AssignmentType,
+  BinaryType,
+  BodyType,
+  CastType,
+  Inheritance,
+  MagicMethodName,
+  MagicName,
+  Modifier,
+  PostType,
+  PreType,
+  Quote,
+  UseItemModifier,
+  Visibility
+);
+
+#[cfg(test)]
+mod tests {
+  use crate::{ builder::{ BlueprintBuildable, BoxBlueprint, Builder }, AssignmentType, Node };
+
+  #[test]
+  fn serde() {
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let node = b
+      .Program(
+        &[
+          b
+            .Assignment(b.Variable(b.Identifier("a")), AssignmentType::Default, b.Number("21"))
+            .add_leading(b.CommentLine("Test leading")),
+          b.Echo(&[b.Null(), b.StaticLookup(b.SelfKeyword(), b.ClassKeyword(), false)]),
+        ]
+      )
+      .build(&arena);
+
+    let json = serde_json::to_string(&node).unwrap();
+    assert_eq!(json, serde_json::to_string(&node.serializable()).unwrap());
+
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let deserialized = Node::deserialize_in(&mut deserializer, &arena).unwrap();
+    assert_eq!(node.as_ref(), &deserialized);
+
+    let value = serde_json::to_value(&node).unwrap();
+    let deserialized = Node::deserialize_in(value, &arena).unwrap();
+    assert_eq!(node.as_ref(), &deserialized);
+  }
+}
@@ -0,0 +1,284 @@
+//! Runtime value of string literals, escape sequences and the indentation of flexible
+//! heredocs are removed the same way PHP does
+
+use bstr::ByteSlice;
+
+use crate::{ EncapsedNode, HereDocNode, Node, NodeWrapper, NowDocNode, Quote, StringNode };
+
+/// Decoded piece of an interpolated string
+#[derive(Debug, PartialEq)]
+pub enum StringPart<'n, 'a> {
+  Literal(Vec<u8>),
+  Expression(&'n Node<'a>),
+}
+
+impl<'a> StringNode<'a> {
+  pub fn to_bytes(&self) -> Vec<u8> {
+    match self.quote {
+      Quote::Single => unescape_single(self.value),
+      Quote::Double => unescape_double(self.value, Some(b'"')),
+      Quote::Backtick => unescape_double(self.value, Some(b'`')),
+    }
+  }
+}
+
+impl<'a> EncapsedNode<'a> {
+  /// One part for each of `values`
+  pub fn decoded_parts(&self) -> Vec<StringPart<'_, 'a>> {
+    let quote = match self.quote {
+      Quote::Single => b'\'',
+      Quote::Double => b'"',
+      Quote::Backtick => b'`',
+    };
+    raw_parts(&self.values)
+      .into_iter()
+      .map(|part| match part {
+        RawPart::Literal(x) => StringPart::Literal(unescape_double(x, Some(quote))),
+        RawPart::Expression(x) => StringPart::Expression(x),
+      })
+      .collect()
+  }
+
+  /// Value of the string when it does not interpolate any expression
+  pub fn to_bytes(&self) -> Option<Vec<u8>> {
+    concat_parts(self.decoded_parts())
+  }
+}
+
+impl<'a> HereDocNode<'a> {
+  /// One part for each of `values`, the newlines around the body and the indentation of the
+  /// closing label are removed
+  pub fn decoded_parts(&self) -> Vec<StringPart<'_, 'a>> {
+    doc_parts(raw_parts(&self.values))
+      .into_iter()
+      .map(|part| match part {
+        DocPart::Literal(x) => StringPart::Literal(unescape_double(&x, None)),
+        DocPart::Expression(x) => StringPart::Expression(x),
+      })
+      .collect()
+  }
+
+  /// Value of the heredoc when it does not interpolate any expression
+  pub fn to_bytes(&self) -> Option<Vec<u8>> {
+    concat_parts(self.decoded_parts())
+  }
+}
+
+impl<'a> NowDocNode<'a> {
+  pub fn to_bytes(&self) -> Vec<u8> {
+    match doc_parts(vec![RawPart::Literal(self.value)]).pop() {
+      Some(DocPart::Literal(x)) => x,
+      _ => vec![],
+    }
+  }
+}
+
+enum RawPart<'n, 'a> {
+  Literal(&'n [u8]),
+  Expression(&'n Node<'a>),
+}
+
+enum DocPart<'n, 'a> {
+  Literal(Vec<u8>),
+  Expression(&'n Node<'a>),
+}
+
+fn raw_parts<'n, 'a>(values: &'n [Node<'a>]) -> Vec<RawPart<'n, 'a>> {
+  values
+    .iter()
+    .map(|node| {
+      let value = match &node.wrapper {
+        NodeWrapper::EncapsedPart(x) => &*x.value,
+        _ => node,
+      };
+      match &value.wrapper {
+        NodeWrapper::String(x) => RawPart::Literal(x.value.as_bytes()),
+        _ => RawPart::Expression(value),
+      }
+    })
+    .collect()
+}
+
+/// Removes from the raw body of a heredoc or nowdoc the newline (`\n` or `\r\n`) after the
+/// opening label, the newline before the closing label and the indentation of the closing label on every line
+fn doc_parts<'n, 'a>(mut parts: Vec<RawPart<'n, 'a>>) -> Vec<DocPart<'n, 'a>> {
+  if let Some(RawPart::Literal(first)) = parts.first_mut() {
+    *first = first
+      .strip_prefix(b"\r\n")
+      .or_else(|| first.strip_prefix(b"\n"))
+      .unwrap_or(first);
+  }
+  let mut indent = 0;
+  let count = parts.len();
+  if let Some(RawPart::Literal(last)) = parts.last_mut() {
+    let start = last.rfind_byte(b'\n').map_or(0, |x| x + 1);
+    let is_closing = last[start..].iter().all(|x| matches!(x, b' ' | b'\t'));
+    if is_closing && (start > 0 || count == 1) {
+      indent = last.len() - start;
+      let end = start.saturating_sub(1);
+      *last = last[..end].strip_suffix(b"\r").unwrap_or(&last[..end]);
+    }
+  }
+  let mut is_line_start = true;
+  parts
+    .into_iter()
+    .map(|part| {
+      let value = match part {
+        RawPart::Literal(x) => x,
+        RawPart::Expression(x) => {
+          is_line_start = false;
+          return DocPart::Expression(x);
+        }
+      };
+      let mut result = Vec::with_capacity(value.len());
+      for (index, line) in value.split_inclusive(|x| *x == b'\n').enumerate() {
+        let mut line = line;
+        if index > 0 || is_line_start {
+          let count = line
+            .iter()
+            .take(indent)
+            .take_while(|x| matches!(x, b' ' | b'\t'))
+            .count();
+          line = &line[count..];
+        }
+        result.extend_from_slice(line);
+      }
+      is_line_start = value.ends_with(b"\n");
+      DocPart::Literal(result)
+    })
+    .collect()
+}
+
+fn concat_parts(parts: Vec<StringPart>) -> Option<Vec<u8>> {
+  let mut result = vec![];
+  for part in parts {
+    match part {
+      StringPart::Literal(x) => result.extend(x),
+      StringPart::Expression(_) => {
+        return None;
+      }
+    }
+  }
+  Some(result)
+}
+
+/// Decodes the content of a single quoted string
+pub fn unescape_single(value: &[u8]) -> Vec<u8> {
+  let mut result = Vec::with_capacity(value.len());
+  let mut index = 0;
+  while let Some(&byte) = value.get(index) {
+    if byte == b'\\' && matches!(value.get(index + 1), Some(b'\\' | b'\'')) {
+      result.push(value[index + 1]);
+      index += 2;
+    } else {
+      result.push(byte);
+      index += 1;
+    }
+  }
+  result
+}
+
+/// Decodes the content of a double quoted string, a backtick string when `quote` is the
+/// backtick or a heredoc when it is `None`
+pub fn unescape_double(value: &[u8], quote: Option<u8>) -> Vec<u8> {
+  let mut result = Vec::with_capacity(value.len());
+  let mut index = 0;
+  while let Some(&byte) = value.get(index) {
+    index += 1;
+    let Some(&next) = value.get(index).filter(|_| byte == b'\\') else {
+      result.push(byte);
+      continue;
+    };
+    let digits = |from: usize, max: usize, radix: u32| {
+      value[from..]
+        .iter()
+        .take(max)
+        .take_while(|x| (**x as char).is_digit(radix))
+        .count()
+    };
+    match next {
+      b'n' => result.push(b'\n'),
+      b't' => result.push(b'\t'),
+      b'r' => result.push(b'\r'),
+      b'v' => result.push(0x0b),
+      b'e' => result.push(0x1b),
+      b'f' => result.push(0x0c),
+      b'\\' | b'$' => result.push(next),
+      _ if Some(next) == quote => result.push(next),
+      b'0'..=b'7' => {
+        let count = digits(index, 3, 8);
+        let code = u32::from_str_radix(&value[index..index + count].to_str_lossy(), 8).unwrap_or(0);
+        result.push(code as u8);
+        index += count;
+        continue;
+      }
+      b'x' if digits(index + 1, 2, 16) > 0 => {
+        let count = digits(index + 1, 2, 16);
+        let code = &value[index + 1..index + 1 + count];
+        let code = u8::from_str_radix(&code.to_str_lossy(), 16).unwrap_or(0);
+        result.push(code);
+        index += count + 1;
+        continue;
+      }
+      b'u' if value.get(index + 1) == Some(&b'{') => {
+        let end = value[index..].find_byte(b'}').map(|x| x + index);
+        let code = end
+          .and_then(|end| u32::from_str_radix(&value[index + 2..end].to_str_lossy(), 16).ok())
+          .and_then(char::from_u32);
+        match (code, end) {
+          (Some(code), Some(end)) => {
+            result.extend_from_slice(code.encode_utf8(&mut [0; 4]).as_bytes());
+            index = end + 1;
+          }
+          _ => result.push(byte),
+        }
+        continue;
+      }
+      _ => {
+        result.push(byte);
+        continue;
+      }
+    }
+    index += 1;
+  }
+  result
+}
+
+/// Encodes `value` as the content of a single quoted string
+pub fn escape_single(value: &[u8]) -> Vec<u8> {
+  let mut result = Vec::with_capacity(value.len());
+  for byte in value {
+    if matches!(byte, b'\\' | b'\'') {
+      result.push(b'\\');
+    }
+    result.push(*byte);
+  }
+  result
+}
+
+/// Encodes `value` as the content of a double quoted string, or of a heredoc when `quote` is
+/// `None`, control characters and invalid UTF-8 are written as escape sequences
+pub fn escape_double(value: &[u8], quote: Option<u8>) -> Vec<u8> {
+  let mut result = Vec::with_capacity(value.len());
+  for chunk in value.utf8_chunks() {
+    for byte in chunk.valid().bytes() {
+      match byte {
+        b'\n' if quote.is_none() => result.push(byte),
+        b'\n' => result.extend_from_slice(b"\\n"),
+        b'\t' => result.extend_from_slice(b"\\t"),
+        b'\r' => result.extend_from_slice(b"\\r"),
+        0x0b => result.extend_from_slice(b"\\v"),
+        0x1b => result.extend_from_slice(b"\\e"),
+        0x0c => result.extend_from_slice(b"\\f"),
+        b'\\' | b'$' => result.extend_from_slice(&[b'\\', byte]),
+        _ if Some(byte) == quote => result.extend_from_slice(&[b'\\', byte]),
+        _ if byte.is_ascii_control() => result.extend(format!("\\x{:02X}", byte).bytes()),
+        _ => result.push(byte),
+      }
+    }
+    for byte in chunk.invalid() {
+      result.extend(format!("\\x{:02X}", byte).bytes());
+    }
+  }
+  result
+}
@@ -1,4 +1,4 @@
-use bstr::BString;
+use bstr::{ BStr, ByteSlice };
 use bumpalo::{ Bump, boxed::Box, collections::Vec };
 use crate::{
   AssignmentType,
@@ -92,6 +92,7 @@ impl<'arena> CloneIn<'arena> for NodeWrapper<'_> {
       NodeWrapper::Clone(v) => NodeWrapper::Clone(v.clone_in(arena)),
       NodeWrapper::CommentBlock(v) => NodeWrapper::CommentBlock(v.clone_in(arena)),
       NodeWrapper::CommentDoc(v) => NodeWrapper::CommentDoc(v.clone_in(arena)),
+      NodeWrapper::CommentDocTag(v) => NodeWrapper::CommentDocTag(v.clone_in(arena)),
       NodeWrapper::CommentLine(v) => NodeWrapper::CommentLine(v.clone_in(arena)),
       NodeWrapper::Const(v) => NodeWrapper::Const(v.clone_in(arena)),
       NodeWrapper::ConstProperty(v) => NodeWrapper::ConstProperty(v.clone_in(arena)),
@@ -107,6 +108,7 @@ impl<'arena> CloneIn<'arena> for NodeWrapper<'_> {
       NodeWrapper::EncapsedPart(v) => NodeWrapper::EncapsedPart(v.clone_in(arena)),
       NodeWrapper::Enum(v) => NodeWrapper::Enum(v.clone_in(arena)),
       NodeWrapper::EnumItem(v) => NodeWrapper::EnumItem(v.clone_in(arena)),
+      NodeWrapper::Error(v) => NodeWrapper::Error(v.clone_in(arena)),
       NodeWrapper::Eval(v) => NodeWrapper::Eval(v.clone_in(arena)),
       NodeWrapper::Exit(v) => NodeWrapper::Exit(v.clone_in(arena)),
       NodeWrapper::Finally(v) => NodeWrapper::Finally(v.clone_in(arena)),
@@ -227,10 +229,18 @@ impl_clone_in_clone!(
   Inheritance,
   Visibility,
   BodyType,
-  BString,
   std::vec::Vec<Visibility>
 );
 
+impl<'arena> CloneIn<'arena> for &BStr {
+  type Cloned = &'arena BStr;
+
+  #[inline]
+  fn clone_in(&self, arena: &'arena Bump) -> Self::Cloned {
+    arena.alloc_slice_copy(self).as_bstr()
+  }
+}
+
 macro_rules! impl_clone_in {
   ($($t:ty),*) => {
       $(
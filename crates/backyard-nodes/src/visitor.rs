@@ -0,0 +1,372 @@
+use bstr::BStr;
+
+use crate::{
+  macros::node_types::with_node_types,
+  AssignmentType,
+  BinaryType,
+  BodyType,
+  CastType,
+  Inheritance,
+  MagicMethodName,
+  MagicName,
+  Modifier,
+  Node,
+  NodeType,
+  NodeWrapper,
+  PostType,
+  PreType,
+  Quote,
+  UseItemModifier,
+  Visibility,
+};
+
+/// Edits requested by a `VisitorMut` hook for the node being visited.
+#[derive(Debug, Default)]
+pub struct VisitContext<'arena> {
+  skip_children: bool,
+  remove: bool,
+  before: Vec<Node<'arena>>,
+  after: Vec<Node<'arena>>,
+}
+
+impl<'arena> VisitContext<'arena> {
+  /// Do not descend into the children of the current node, only meaningful in `enter_*` hooks
+  pub fn skip_children(&mut self) {
+    self.skip_children = true;
+  }
+
+  /// Remove the current node from its list or optional field. Nodes in required fields are
+  /// kept, replace them through the `&mut Node` instead.
+  pub fn remove(&mut self) {
+    self.remove = true;
+  }
+
+  /// Insert a sibling before the current node, ignored unless the node is in a list
+  pub fn insert_before(&mut self, node: Node<'arena>) {
+    self.before.push(node);
+  }
+
+  /// Insert a sibling after the current node, ignored unless the node is in a list
+  pub fn insert_after(&mut self, node: Node<'arena>) {
+    self.after.push(node);
+  }
+
+  pub fn is_removed(&self) -> bool {
+    self.remove
+  }
+}
+
+macro_rules! new_visitor {
+  ($($node_type:ident),* $(,)?) => {
+    paste::paste! {
+      /// Read-only traversal. `enter_node` and `leave_node` are called for every node, around
+      /// the hooks of its type.
+      #[allow(unused_variables)]
+      pub trait Visitor<'arena> {
+        fn enter_node(&mut self, node: &Node<'arena>) {}
+        fn leave_node(&mut self, node: &Node<'arena>) {}
+        $(
+          fn [<enter_ $node_type:snake>](&mut self, node: &Node<'arena>) {}
+          fn [<leave_ $node_type:snake>](&mut self, node: &Node<'arena>) {}
+        )*
+      }
+
+      /// Rewriting traversal. Hooks may replace the node in place, or use the context to skip
+      /// its children, remove it or insert siblings. Inserted siblings are not visited.
+      #[allow(unused_variables)]
+      pub trait VisitorMut<'arena> {
+        fn enter_node(&mut self, node: &mut Node<'arena>, context: &mut VisitContext<'arena>) {}
+        fn leave_node(&mut self, node: &mut Node<'arena>, context: &mut VisitContext<'arena>) {}
+        $(
+          fn [<enter_ $node_type:snake>](
+            &mut self,
+            node: &mut Node<'arena>,
+            context: &mut VisitContext<'arena>
+          ) {}
+          fn [<leave_ $node_type:snake>](
+            &mut self,
+            node: &mut Node<'arena>,
+            context: &mut VisitContext<'arena>
+          ) {}
+        )*
+      }
+
+      fn visit_node<'arena, V: Visitor<'arena> + ?Sized>(node: &Node<'arena>, visitor: &mut V) {
+        visitor.enter_node(node);
+        match node.node_type {
+          $(NodeType::$node_type => visitor.[<enter_ $node_type:snake>](node),)*
+        }
+        match &node.wrapper {
+          $(NodeWrapper::$node_type(v) => v.accept(visitor),)*
+        }
+        match node.node_type {
+          $(NodeType::$node_type => visitor.[<leave_ $node_type:snake>](node),)*
+        }
+        visitor.leave_node(node);
+      }
+
+      fn visit_node_mut<'arena, V: VisitorMut<'arena> + ?Sized>(
+        node: &mut Node<'arena>,
+        visitor: &mut V,
+        context: &mut VisitContext<'arena>
+      ) {
+        visitor.enter_node(node, context);
+        if !context.remove {
+          match node.node_type {
+            $(NodeType::$node_type => visitor.[<enter_ $node_type:snake>](node, context),)*
+          }
+        }
+        if context.remove {
+          return;
+        }
+        if !context.skip_children {
+          match &mut node.wrapper {
+            $(NodeWrapper::$node_type(v) => v.accept_mut(visitor),)*
+          }
+        }
+        match node.node_type {
+          $(NodeType::$node_type => visitor.[<leave_ $node_type:snake>](node, context),)*
+        }
+        if !context.remove {
+          visitor.leave_node(node, context);
+        }
+      }
+    }
+  };
+}
+
+with_node_types!(new_visitor);
+
+impl<'arena> Node<'arena> {
+  pub fn visit<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+    visit_node(self, visitor);
+  }
+
+  /// Removing the root or inserting siblings to it has no effect
+  pub fn visit_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+    visit_node_mut(self, visitor, &mut VisitContext::default());
+  }
+}
+
+pub(crate) trait Visitable<'arena> {
+  fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V);
+  fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V);
+}
+
+impl<'arena> Visitable<'arena> for bumpalo::collections::Vec<'arena, Node<'arena>> {
+  fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+    self.iter().for_each(|x| visit_node(x, visitor));
+  }
+
+  fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+    let mut index = 0;
+    while index < self.len() {
+      let mut context = VisitContext::default();
+      visit_node_mut(&mut self[index], visitor, &mut context);
+      for node in context.before {
+        self.insert(index, node);
+        index += 1;
+      }
+      if context.remove {
+        self.remove(index);
+      } else {
+        index += 1;
+      }
+      for node in context.after {
+        self.insert(index, node);
+        index += 1;
+      }
+    }
+  }
+}
+
+impl<'arena> Visitable<'arena> for bumpalo::boxed::Box<'arena, Node<'arena>> {
+  fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+    visit_node(self, visitor);
+  }
+
+  fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+    visit_node_mut(self, visitor, &mut VisitContext::default());
+  }
+}
+
+impl<'arena> Visitable<'arena> for Option<bumpalo::boxed::Box<'arena, Node<'arena>>> {
+  fn accept<V: Visitor<'arena> + ?Sized>(&self, visitor: &mut V) {
+    if let Some(x) = self {
+      visit_node(x, visitor);
+    }
+  }
+
+  fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, visitor: &mut V) {
+    if let Some(x) = self {
+      let mut context = VisitContext::default();
+      visit_node_mut(x, visitor, &mut context);
+      if context.remove {
+        *self = None;
+      }
+    }
+  }
+}
+
+macro_rules! impl_visitable_leaf {
+  ($($t:ty),*) => {
+      $(
+          impl<'arena> Visitable<'arena> for $t {
+            fn accept<V: Visitor<'arena> + ?Sized>(&self, _: &mut V) {}
+            fn accept_mut<V: VisitorMut<'arena> + ?Sized>(&mut self, _: &mut V) {}
+          }
+      )*
+  };
+}
+
+impl_visitable_leaf!(
+  bool,
+  &BStr,
+  Option<&BStr>,
+  BodyType,
+  std::vec::Vec<Visibility>,
+  AssignmentType,
+  BinaryType,
+  CastType,
+  PostType,
+  PreType,
+  MagicName,
+  MagicMethodName,
+  UseItemModifier,
+  Option<UseItemModifier>,
+  Visibility,
+  Option<Visibility>,
+  Inheritance,
+  Option<Inheritance>,
+  Quote,
+  Modifier,
+  Option<Modifier>
+);
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    builder::{ BlueprintBuildable, Builder },
+    cast_node,
+    AssignmentType,
+    Node,
+    NodeType,
+    NodeWrapper,
+  };
+
+  use super::{ VisitContext, Visitor, VisitorMut };
+
+  #[test]
+  fn visitor() {
+    struct Trace(Vec<String>);
+
+    impl<'arena> Visitor<'arena> for Trace {
+      fn enter_node(&mut self, node: &Node<'arena>) {
+        self.0.push(format!("enter {:?}", node.node_type));
+      }
+
+      fn leave_node(&mut self, node: &Node<'arena>) {
+        self.0.push(format!("leave {:?}", node.node_type));
+      }
+
+      fn enter_number(&mut self, _: &Node<'arena>) {
+        self.0.push("number".to_string());
+      }
+    }
+
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let node = b
+      .Program(
+        &[b.Assignment(b.Variable(b.Identifier("a")), AssignmentType::Default, b.Number("21"))]
+      )
+      .build(&arena);
+    let mut trace = Trace(vec![]);
+    node.visit(&mut trace);
+
+    assert_eq!(
+      vec![
+        "enter Program",
+        "enter Assignment",
+        "enter Variable",
+        "enter Identifier",
+        "leave Identifier",
+        "leave Variable",
+        "enter Number",
+        "number",
+        "leave Number",
+        "leave Assignment",
+        "leave Program"
+      ],
+      trace.0
+    );
+  }
+
+  #[test]
+  fn visitor_mut() {
+    struct Codemod<'arena>(&'arena bumpalo::Bump);
+
+    impl<'arena> VisitorMut<'arena> for Codemod<'arena> {
+      fn enter_echo(&mut self, node: &mut Node<'arena>, context: &mut VisitContext<'arena>) {
+        let b = Builder::new();
+        context.insert_before(b.Call(b.Identifier("before"), &[]).as_ref().build(self.0));
+        context.insert_after(b.Call(b.Identifier("after"), &[]).as_ref().build(self.0));
+        context.remove();
+        assert_eq!(NodeType::Echo, node.node_type);
+      }
+
+      fn leave_number(&mut self, node: &mut Node<'arena>, _: &mut VisitContext<'arena>) {
+        let number = cast_node!(Number, &node.wrapper);
+        let value = format!("{}0", number.value);
+        *node = Builder::new().Number(&value).as_ref().build(self.0);
+      }
+
+      fn enter_return(&mut self, _: &mut Node<'arena>, context: &mut VisitContext<'arena>) {
+        context.skip_children();
+      }
+    }
+
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let mut node = b
+      .Program(
+        &[
+          b.Echo(&[b.Number("1")]),
+          b.Call(b.Identifier("f"), &[b.Argument(Some(b.Identifier("a")), b.Number("2"))]),
+          b.Return(Some(b.Number("3"))),
+        ]
+      )
+      .build(&arena);
+    node.visit_mut(&mut Codemod(&arena));
+
+    let expected = b
+      .Program(
+        &[
+          b.Call(b.Identifier("before"), &[]),
+          b.Call(b.Identifier("after"), &[]),
+          b.Call(b.Identifier("f"), &[b.Argument(Some(b.Identifier("a")), b.Number("20"))]),
+          b.Return(Some(b.Number("3"))),
+        ]
+      )
+      .build(&arena);
+    assert_eq!(expected, node);
+  }
+
+  #[test]
+  fn visitor_mut_optional() {
+    struct RemoveName;
+
+    impl<'arena> VisitorMut<'arena> for RemoveName {
+      fn enter_identifier(&mut self, _: &mut Node<'arena>, context: &mut VisitContext<'arena>) {
+        context.remove();
+      }
+    }
+
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let mut node = b.Argument(Some(b.Identifier("a")), b.Number("2")).build(&arena);
+    node.visit_mut(&mut RemoveName);
+
+    assert_eq!(b.Argument(None, b.Number("2")).build(&arena), node);
+  }
+}
@@ -1,6 +1,6 @@
 use std::{ collections::VecDeque, iter::{ Chain, Rev }, slice::Iter };
 
-use bstr::BString;
+use bstr::BStr;
 
 use crate::{
   AssignmentType,
@@ -99,6 +99,17 @@ impl<'arena> Node<'arena> {
   pub fn walk(&self) -> Walker<'arena, '_> {
     Walker::new(self)
   }
+
+  /// Nodes directly under this node, in the order they are walked
+  pub fn children(&self) -> Vec<&Node<'arena>> {
+    let mut walker = self.walk();
+    walker.next();
+    walker.stack
+      .iter()
+      .rev()
+      .map(|x| x.node)
+      .collect()
+  }
 }
 
 pub(crate) trait Walkable<'arena> {
@@ -134,6 +145,7 @@ impl<'arena, 'a> Iterator for Walker<'arena, 'a> {
         NodeWrapper::Clone(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::CommentBlock(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::CommentDoc(v) => v.populate_walks(&mut self.stack, item.level),
+        NodeWrapper::CommentDocTag(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::CommentLine(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::Const(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::ConstProperty(v) => v.populate_walks(&mut self.stack, item.level),
@@ -149,6 +161,7 @@ impl<'arena, 'a> Iterator for Walker<'arena, 'a> {
         NodeWrapper::EncapsedPart(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::Enum(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::EnumItem(v) => v.populate_walks(&mut self.stack, item.level),
+        NodeWrapper::Error(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::Eval(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::Exit(v) => v.populate_walks(&mut self.stack, item.level),
         NodeWrapper::Finally(v) => v.populate_walks(&mut self.stack, item.level),
@@ -263,7 +276,7 @@ macro_rules! impl_map_into_walker_stack {
 
 impl_map_into_walker_stack!(
   bool,
-  BString,
+  &BStr,
   BodyType,
   std::vec::Vec<Visibility>,
   AssignmentType,
@@ -302,4 +315,20 @@ mod tests {
     assert_eq!(NodeType::Number, walker.next().unwrap().1.node_type);
     assert!(walker.next().is_none());
   }
+
+  #[test]
+  fn children() {
+    let arena = bumpalo::Bump::new();
+    let b = Builder::new();
+    let node = b
+      .Assignment(b.Variable(b.Identifier("a")), AssignmentType::Default, b.Number("21"))
+      .build(&arena);
+    let children: Vec<NodeType> = node
+      .children()
+      .iter()
+      .map(|x| x.node_type.clone())
+      .collect();
+
+    assert_eq!(vec![NodeType::Variable, NodeType::Number], children);
+  }
 }
@@ -1,5 +1,6 @@
 use std::hint::black_box;
 use backyard_lexer::lex_in;
+use backyard_nodes::binary::from_binary_in;
 use backyard_parser::parse_tokens_in;
 use bumpalo::Bump;
 use criterion::{ criterion_group, criterion_main, Criterion };
@@ -102,6 +103,14 @@ fn criterion_benchmark(c: &mut Criterion) {
       let _ = parse_tokens_in(&arena, black_box(&tokens));
     });
   });
+  let arena = Bump::new();
+  let bytes = parse_tokens_in(&arena, &tokens).unwrap().to_binary();
+  c.bench_function("binary_decode", |b| {
+    b.iter(|| {
+      let arena = Bump::new();
+      let _ = from_binary_in(&arena, black_box(&bytes));
+    });
+  });
 }
 
 criterion_group! {
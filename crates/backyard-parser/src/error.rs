@@ -1,13 +1,38 @@
 use std::fmt::{ Display, Formatter };
 
-use backyard_lexer::{ error::LexError, token::Token };
+use backyard_lexer::{ error::LexError, token::{ OwnedToken, TokenType }, version::PhpVersion };
+use backyard_nodes::RangeLocation;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParserError {
   LexError(LexError),
   Internal,
   Eof,
-  UnexpectedToken(Token),
+  UnexpectedToken(OwnedToken),
+  Diagnostic(Box<ParserDiagnostic>),
+  Unsupported(Box<UnsupportedSyntax>),
+  InvalidEdit {
+    start: usize,
+    end: usize,
+  },
+}
+
+impl ParserError {
+  pub fn render(&self, input: &str) -> String {
+    match self {
+      ParserError::LexError(LexError::Unrecognized { token, line, column }) =>
+        format!("{}\n{}", self, render_snippet(input, *line, *column, token.len())),
+      ParserError::UnexpectedToken(token) =>
+        format!(
+          "{}\n{}",
+          self,
+          render_snippet(input, token.line as usize, token.column as usize, token.value.len())
+        ),
+      ParserError::Diagnostic(diagnostic) => diagnostic.render(input),
+      ParserError::Unsupported(unsupported) => unsupported.render(input),
+      _ => self.to_string(),
+    }
+  }
 }
 
 impl Display for ParserError {
@@ -19,12 +44,153 @@ impl Display for ParserError {
       ParserError::UnexpectedToken(token) => {
         write!(
           f,
-          "Unexpected character '{}' at line {}, column {}",
+          "Unexpected token '{}' at line {}, column {}",
           token.value,
           token.line,
           token.column
         )
       }
+      ParserError::Diagnostic(diagnostic) => write!(f, "{}", diagnostic),
+      ParserError::Unsupported(unsupported) => write!(f, "{}", unsupported),
+      ParserError::InvalidEdit { start, end } => {
+        write!(f, "Invalid text edit from offset {} to {}", start, end)
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParserDiagnostic {
+  /// Location of the unexpected token, or the end of the last token at the end of the file
+  pub loc: Option<RangeLocation>,
+  pub context: String,
+  pub expected: Vec<Expected>,
+  pub found: Option<OwnedToken>,
+}
+
+/// What the parser could have continued with where it failed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Expected {
+  Token(TokenType),
+  Expression,
+}
+
+impl Display for Expected {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Expected::Token(token_type) => write!(f, "{:?}", token_type),
+      Expected::Expression => write!(f, "expression"),
+    }
+  }
+}
+
+impl ParserDiagnostic {
+  pub fn render(&self, input: &str) -> String {
+    match &self.loc {
+      Some(loc) => format!("{}\n{}", self, render_range(input, loc)),
+      None => self.to_string(),
+    }
+  }
+}
+
+impl Display for ParserDiagnostic {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match &self.found {
+      Some(token) =>
+        write!(
+          f,
+          "Unexpected token '{}' at line {}, column {}",
+          token.value,
+          token.line,
+          token.column
+        )?,
+      None => {
+        write!(f, "Unexpected end of file")?;
+        if let Some(loc) = &self.loc {
+          write!(f, " at line {}, column {}", loc.start.line, loc.start.column)?;
+        }
+      }
     }
+    write!(f, " in {}", self.context)?;
+    if let Some((last, rest)) = self.expected.split_last() {
+      write!(f, ", expected ")?;
+      if !rest.is_empty() {
+        let rest: Vec<String> = rest
+          .iter()
+          .map(|x| x.to_string())
+          .collect();
+        write!(f, "{} or ", rest.join(", "))?;
+      }
+      write!(f, "{}", last)?;
+    }
+    Ok(())
   }
 }
+
+/// Syntax introduced after the PHP version targeted by the parser
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnsupportedSyntax {
+  pub loc: Option<RangeLocation>,
+  /// Description of the syntax, e.g. `match expression`
+  pub construct: String,
+  /// Version that introduced the syntax
+  pub version: PhpVersion,
+}
+
+impl UnsupportedSyntax {
+  pub fn render(&self, input: &str) -> String {
+    match &self.loc {
+      Some(loc) => format!("{}\n{}", self, render_range(input, loc)),
+      None => self.to_string(),
+    }
+  }
+}
+
+impl Display for UnsupportedSyntax {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Unsupported {}", self.construct)?;
+    if let Some(loc) = &self.loc {
+      write!(f, " at line {}, column {}", loc.start.line, loc.start.column)?;
+    }
+    write!(f, ", requires PHP {}", self.version)
+  }
+}
+
+fn render_range(input: &str, loc: &RangeLocation) -> String {
+  let len = if loc.start.line == loc.end.line {
+    loc.end.column.saturating_sub(loc.start.column) as usize
+  } else {
+    1
+  };
+  render_snippet(input, loc.start.line as usize, loc.start.column as usize, len)
+}
+
+fn render_snippet(input: &str, line: usize, column: usize, len: usize) -> String {
+  let source_line = input
+    .split('\n')
+    .nth(line.saturating_sub(1))
+    .unwrap_or_default()
+    .trim_end_matches('\r');
+  let line_number = line.to_string();
+  let padding = " ".repeat(line_number.len());
+  // Columns count bytes, the caret line counts characters to stay under multibyte text
+  let bytes = source_line.as_bytes();
+  let is_char_start = |x: &&u8| (**x & 0xc0) != 0x80;
+  let column = column.min(bytes.len());
+  let end = (column + len).min(bytes.len());
+  let len = bytes[column..end].iter().filter(is_char_start).count();
+  let indent: String = bytes[..column]
+    .iter()
+    .filter(is_char_start)
+    .map(|x| if *x == b'\t' { '\t' } else { ' ' })
+    .collect();
+  format!(
+    "{} |\n{} | {}\n{} | {}{}",
+    padding,
+    line_number,
+    source_line,
+    padding,
+    indent,
+    "^".repeat(len.max(1))
+  )
+}
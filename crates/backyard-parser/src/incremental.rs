@@ -0,0 +1,490 @@
+use backyard_lexer::{ lex_byte_in, token::{ Token, TokenType } };
+use backyard_nodes::{
+  visitor::{ VisitContext, Visitor, VisitorMut },
+  BodyType,
+  Location,
+  Node,
+  NodeType,
+  NodeWrapper,
+  RangeLocation,
+};
+use bstr::BString;
+use bumpalo::Bump;
+
+use crate::{ error::ParserError, parse_byte_in, parser::{ LocationHelper, LoopArgument, Parser } };
+
+/// Replacement of the bytes `start..end` of the source with `text`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+  pub start: usize,
+  pub end: usize,
+  pub text: BString,
+}
+
+impl TextEdit {
+  pub fn new(start: usize, end: usize, text: &str) -> Self {
+    Self { start, end, text: BString::from(text) }
+  }
+}
+
+/// Statements that change how the rest of the file is parsed
+const FULL_REPARSE: [NodeType; 4] = [
+  NodeType::Declare,
+  NodeType::HaltCompiler,
+  NodeType::Inline,
+  NodeType::Namespace,
+];
+
+pub(crate) fn reparse<'arena>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  tree: &mut Node<'arena>,
+  source: &[u8],
+  edits: &[TextEdit]
+) -> Result<Vec<u8>, ParserError> {
+  let input = apply_edits(source, edits)?;
+  let (Some(first), Some(last)) = (edits.first(), edits.last()) else {
+    return Ok(input);
+  };
+  if Reparse::new(source, &input, first.start, last.end).run(arena, tree).is_none() {
+    *tree = parse_byte_in(arena, is_eval, arena.alloc_slice_copy(&input))?;
+  }
+  Ok(input)
+}
+
+fn apply_edits(source: &[u8], edits: &[TextEdit]) -> Result<Vec<u8>, ParserError> {
+  let mut input = Vec::with_capacity(source.len());
+  let mut position = 0;
+  for edit in edits {
+    if edit.start < position || edit.start > edit.end || edit.end > source.len() {
+      return Err(ParserError::InvalidEdit { start: edit.start, end: edit.end });
+    }
+    input.extend_from_slice(&source[position..edit.start]);
+    input.extend_from_slice(&edit.text);
+    position = edit.end;
+  }
+  input.extend_from_slice(&source[position..]);
+  Ok(input)
+}
+
+/// Reparse of the statements around the edited bytes `start..end` of `source`, returns `None`
+/// whenever the result could differ from a full parse of `input`.
+struct Reparse<'s> {
+  source: &'s [u8],
+  input: &'s [u8],
+  start: usize,
+  end: usize,
+}
+
+impl<'s> Reparse<'s> {
+  fn new(source: &'s [u8], input: &'s [u8], start: usize, end: usize) -> Self {
+    Self { source, input, start, end }
+  }
+
+  fn run<'arena>(&self, arena: &'arena Bump, tree: &mut Node<'arena>) -> Option<()> {
+    let NodeWrapper::Program(program) = &mut tree.wrapper else {
+      return None;
+    };
+    let mut target = self.find(&mut program.children, None, true, &mut vec![])?;
+    let mut tokens = self.lex(arena, &target)?;
+    // A statement not closed by a semicolon could be continued by the next one
+    let is_closed = tokens.last().is_some_and(|token| token.token_type == TokenType::Semicolon);
+    if !is_closed && target.extend() {
+      tokens = self.lex(arena, &target)?;
+    }
+    let mut parser = Parser::new(arena, &tokens);
+    let statements = parser
+      .get_children(&mut LoopArgument::with_tokens(arena, "main", &[TokenType::Semicolon], &[]))
+      .ok()?;
+    if
+      parser.position < tokens.len() ||
+      statements.iter().any(|statement| FULL_REPARSE.contains(&statement.node_type))
+    {
+      return None;
+    }
+    // Nodes ending at the last token of the file now end at the last reparsed token
+    let last = match target.end {
+      Some(_) => None,
+      None => Some(tokens.last()?.get_range_location()?.end),
+    };
+    let (start, old_end, new_end) = self.range(&target);
+    let mut shift = Shift {
+      old_end: Self::advance(target.start.clone(), &self.source[start..old_end]),
+      new_end: Self::advance(target.start.clone(), &self.input[start..new_end]),
+      start: target.start.offset,
+      last,
+    };
+    tree.visit_mut(&mut shift);
+    let NodeWrapper::Program(program) = &mut tree.wrapper else {
+      return None;
+    };
+    let container = container(&mut program.children, &target.path)?;
+    container.splice(target.first..=target.last, statements);
+    Some(())
+  }
+
+  /// Innermost list of statements that contains the edit and can be reparsed on its own.
+  /// `path` leads from the statements of the file to `statements`.
+  fn find(
+    &self,
+    statements: &mut [Node],
+    end: Option<usize>,
+    is_reparsable: bool,
+    path: &mut Vec<(usize, usize)>
+  ) -> Option<Target> {
+    if end.is_some_and(|end| self.end > end) {
+      return None;
+    }
+    let starts = statements.iter().map(span_start).collect::<Option<Vec<Location>>>()?;
+    if starts.first().is_none_or(|start| self.start < (start.offset as usize)) {
+      return None;
+    }
+    let end_of = |index: usize| {
+      starts.get(index + 1).map_or(end, |start| Some(start.offset as usize))
+    };
+    let index = starts.iter().rposition(|start| (start.offset as usize) <= self.start)?;
+    if end_of(index).is_none_or(|end| self.end <= end) {
+      let mut bodies = vec![];
+      collect_bodies(&mut statements[index], &mut bodies);
+      for (body_index, body) in bodies.into_iter().enumerate() {
+        let body_end = match body.is_bracketed {
+          // Statements end before the closing bracket
+          true => Some((body.block.loc.as_ref()?.end.offset as usize).checked_sub(1)?),
+          false => end_of(index),
+        };
+        let NodeWrapper::Block(block) = &mut body.block.wrapper else {
+          continue;
+        };
+        path.push((index, body_index));
+        let target = self.find(&mut block.statements, body_end, body.is_reparsable, path);
+        if target.is_some() {
+          return target;
+        }
+        path.pop();
+      }
+    }
+    if !is_reparsable {
+      return None;
+    }
+    let first = (0..starts.len()).find(|index| end_of(*index).is_none_or(|end| self.start <= end))?;
+    let last = starts.iter().rposition(|start| (start.offset as usize) <= self.end)?;
+    let next = (last + 1 < starts.len()).then(|| end_of(last + 1));
+    let checked = first..=(last + 2).min(starts.len() - 1);
+    if
+      statements[first..=(last + 1).min(starts.len() - 1)]
+        .iter()
+        .any(|statement| FULL_REPARSE.contains(&statement.node_type)) ||
+      !checked.into_iter().all(|index| self.starts_statement(statements, &starts, index))
+    {
+      return None;
+    }
+    Some(Target {
+      path: path.clone(),
+      first,
+      last,
+      start: starts[first].clone(),
+      end: end_of(last),
+      next,
+    })
+  }
+
+  /// Whether the source before the statement at `index` ends the previous statement. A
+  /// keyword written before it but not covered by its location, as in `static $a;`, would
+  /// otherwise be cut off when the statement is lexed again.
+  fn starts_statement(&self, statements: &[Node], starts: &[Location], index: usize) -> bool {
+    let start = starts[index].offset as usize;
+    match index.checked_sub(1).map(|previous| span_end(&statements[previous])) {
+      Some(Some(end)) => {
+        self.source
+          .get(end..start)
+          .is_some_and(|gap| gap.iter().all(|ch| ch.is_ascii_whitespace() || *ch == b';'))
+      }
+      Some(None) => false,
+      None => {
+        let before = self.source[..start].trim_ascii_end();
+        before.is_empty() ||
+          before.ends_with(b"{") ||
+          before.ends_with(b":") ||
+          before.ends_with(b";") ||
+          before.len() >= 5 && before[before.len() - 5..].eq_ignore_ascii_case(b"<?php")
+      }
+    }
+  }
+
+  /// Start of `target` and its end in the source and in the edited input
+  fn range(&self, target: &Target) -> (usize, usize, usize) {
+    let old_end = target.end.unwrap_or(self.source.len());
+    let new_end = old_end + self.input.len() - self.source.len();
+    (target.start.offset as usize, old_end, new_end)
+  }
+
+  /// Lex the edited text of `target`. A sentinel statement is appended to make sure that no
+  /// token continues past its end.
+  fn lex<'arena>(
+    &self,
+    arena: &'arena Bump,
+    target: &Target
+  ) -> Option<bumpalo::collections::Vec<'arena, Token<'arena>>> {
+    let (start, _, end) = self.range(target);
+    let mut input = bumpalo::collections::Vec::with_capacity_in(end - start + 2, arena);
+    input.extend_from_slice(&self.input[start..end]);
+    input.extend_from_slice(b"\n;");
+    let mut tokens = lex_byte_in(arena, true, input.into_bump_slice()).ok()?;
+    let sentinel = tokens.pop()?;
+    if sentinel.token_type != TokenType::Semicolon || (sentinel.offset as usize) != end - start + 1 {
+      return None;
+    }
+    // Blocks and lists are accepted unclosed at the end of the tokens
+    let mut depth: usize = 0;
+    for token in tokens.iter() {
+      match token.token_type {
+        | TokenType::LeftCurlyBracket
+        | TokenType::LeftParenthesis
+        | TokenType::LeftSquareBracket
+        | TokenType::VariableBracketOpen
+        | TokenType::AdvanceInterpolationOpen => {
+          depth += 1;
+        }
+        | TokenType::RightCurlyBracket
+        | TokenType::RightParenthesis
+        | TokenType::RightSquareBracket
+        | TokenType::VariableBracketClose
+        | TokenType::AdvanceInterpolationClose => {
+          depth = depth.checked_sub(1)?;
+        }
+        _ => {}
+      }
+    }
+    if depth > 0 {
+      return None;
+    }
+    let location = &target.start;
+    for token in tokens.iter_mut() {
+      if token.line == 1 {
+        token.column += location.column;
+      }
+      if token.end.line == 1 {
+        token.end.column += location.column;
+      }
+      token.line += location.line - 1;
+      token.end.line += location.line - 1;
+      token.offset += location.offset;
+      token.end.offset += location.offset;
+    }
+    Some(tokens)
+  }
+
+  fn advance(mut location: Location, text: &[u8]) -> Location {
+    for ch in text {
+      if *ch == b'\n' {
+        location.line += 1;
+        location.column = 0;
+      } else {
+        location.column += 1;
+      }
+    }
+    location.offset += text.len() as u32;
+    location
+  }
+}
+
+/// Statements `first..=last` of the list found by following `path`, spanning from `start` to
+/// `end` or to the end of the file.
+struct Target {
+  /// Index of the statement and of its body for each list entered from the file
+  path: Vec<(usize, usize)>,
+  first: usize,
+  last: usize,
+  start: Location,
+  end: Option<usize>,
+  /// End of the statement after `last`, if any
+  next: Option<Option<usize>>,
+}
+
+impl Target {
+  fn extend(&mut self) -> bool {
+    match self.next.take() {
+      Some(end) => {
+        self.last += 1;
+        self.end = end;
+        true
+      }
+      None => false,
+    }
+  }
+}
+
+struct Body<'x, 'arena> {
+  block: &'x mut Node<'arena>,
+  is_reparsable: bool,
+  is_bracketed: bool,
+}
+
+fn push_body<'x, 'arena>(
+  bodies: &mut Vec<Body<'x, 'arena>>,
+  block: &'x mut Node<'arena>,
+  is_reparsable: bool,
+  is_bracketed: bool
+) {
+  if block.node_type == NodeType::Block {
+    bodies.push(Body { block, is_reparsable, is_bracketed });
+  }
+}
+
+/// Blocks of statements directly owned by `node`. Class-like bodies can hold a reparsable
+/// method body but are not reparsable themselves.
+fn collect_bodies<'x, 'arena>(node: &'x mut Node<'arena>, bodies: &mut Vec<Body<'x, 'arena>>) {
+  if node.node_type == NodeType::Block {
+    push_body(bodies, node, true, true);
+    return;
+  }
+  match &mut node.wrapper {
+    NodeWrapper::Function(function) => {
+      if let Some(body) = &mut function.body {
+        push_body(bodies, body, true, true);
+      }
+    }
+    NodeWrapper::Class(class) => push_body(bodies, &mut class.body, false, true),
+    NodeWrapper::Interface(interface) => push_body(bodies, &mut interface.body, false, true),
+    NodeWrapper::Trait(declaration) => push_body(bodies, &mut declaration.body, false, true),
+    NodeWrapper::While(node) if !node.is_short => push_body(bodies, &mut node.body, true, true),
+    NodeWrapper::DoWhile(node) => push_body(bodies, &mut node.body, true, true),
+    NodeWrapper::Foreach(node) if !node.is_short => push_body(bodies, &mut node.body, true, true),
+    NodeWrapper::For(node) if node.body_type == BodyType::Basic => {
+      if let Some(body) = &mut node.body {
+        push_body(bodies, body, true, true);
+      }
+    }
+    NodeWrapper::Declare(node) if node.body_type == BodyType::Basic => {
+      if let Some(body) = &mut node.body {
+        push_body(bodies, body, true, true);
+      }
+    }
+    NodeWrapper::Namespace(namespace) => {
+      push_body(bodies, &mut namespace.body, true, namespace.is_bracket);
+    }
+    NodeWrapper::Catch(node) => push_body(bodies, &mut node.body, true, true),
+    NodeWrapper::Finally(node) => push_body(bodies, &mut node.body, true, true),
+    NodeWrapper::Method(method) => collect_bodies(&mut method.function, bodies),
+    NodeWrapper::Else(node) if !node.is_short => collect_bodies(&mut node.body, bodies),
+    NodeWrapper::If(node) if !node.is_short => {
+      push_body(bodies, &mut node.valid, true, true);
+      if let Some(invalid) = &mut node.invalid {
+        collect_bodies(invalid, bodies);
+      }
+    }
+    NodeWrapper::Try(node) => {
+      push_body(bodies, &mut node.body, true, true);
+      node.catches.iter_mut().for_each(|catch| collect_bodies(catch, bodies));
+    }
+    _ => {}
+  }
+}
+
+/// Statements of the list `path` leads to from the statements of the file
+fn container<'x, 'arena>(
+  statements: &'x mut bumpalo::collections::Vec<'arena, Node<'arena>>,
+  path: &[(usize, usize)]
+) -> Option<&'x mut bumpalo::collections::Vec<'arena, Node<'arena>>> {
+  let Some(((index, body), rest)) = path.split_first() else {
+    return Some(statements);
+  };
+  let mut bodies = vec![];
+  collect_bodies(statements.get_mut(*index)?, &mut bodies);
+  let NodeWrapper::Block(block) = &mut bodies.into_iter().nth(*body)?.block.wrapper else {
+    return None;
+  };
+  container(&mut block.statements, rest)
+}
+
+/// Location of the first token of `node`, leading comments included
+fn span_start(node: &Node) -> Option<Location> {
+  let mut span = SpanStart { start: None };
+  node.visit(&mut span);
+  span.start
+}
+
+struct SpanStart {
+  start: Option<Location>,
+}
+
+impl<'arena> Visitor<'arena> for SpanStart {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    for leading in node.leadings.iter().flatten() {
+      leading.visit(self);
+    }
+    if let Some(loc) = &node.loc {
+      if self.start.as_ref().is_none_or(|start| loc.start.offset < start.offset) {
+        self.start = Some(loc.start.clone());
+      }
+    }
+  }
+}
+
+/// End of the last token of `node`, trailing comments included
+fn span_end(node: &Node) -> Option<usize> {
+  let mut span = SpanEnd { end: None };
+  node.visit(&mut span);
+  span.end
+}
+
+struct SpanEnd {
+  end: Option<usize>,
+}
+
+impl<'arena> Visitor<'arena> for SpanEnd {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    for trailing in node.trailings.iter().flatten() {
+      trailing.visit(self);
+    }
+    if let Some(loc) = &node.loc {
+      let end = loc.end.offset as usize;
+      if self.end.is_none_or(|x| end > x) {
+        self.end = Some(end);
+      }
+    }
+  }
+}
+
+/// Moves every location after the edit to its new position, the reparsed statements replace
+/// the target ones afterwards
+struct Shift {
+  start: u32,
+  old_end: Location,
+  new_end: Location,
+  last: Option<Location>,
+}
+
+impl Shift {
+  fn location(&self, location: &mut Location) {
+    if location.offset < self.old_end.offset {
+      return;
+    }
+    if location.line == self.old_end.line {
+      location.column = location.column - self.old_end.column + self.new_end.column;
+    }
+    location.line = location.line - self.old_end.line + self.new_end.line;
+    location.offset = location.offset - self.old_end.offset + self.new_end.offset;
+  }
+
+  fn range(&self, loc: &mut RangeLocation) {
+    self.location(&mut loc.start);
+    match &self.last {
+      Some(last) if loc.start.offset < self.start && loc.end.offset >= self.start => {
+        loc.end = last.clone();
+      }
+      _ => self.location(&mut loc.end),
+    }
+  }
+}
+
+impl<'arena> VisitorMut<'arena> for Shift {
+  fn enter_node(&mut self, node: &mut Node<'arena>, _: &mut VisitContext<'arena>) {
+    if let Some(loc) = &mut node.loc {
+      self.range(loc);
+    }
+    for comment in node.leadings.iter_mut().chain(node.trailings.iter_mut()).flatten() {
+      comment.visit_mut(self);
+    }
+  }
+}
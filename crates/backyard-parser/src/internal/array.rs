@@ -11,7 +11,15 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine, DEFAULT_PARSERS },
+  parser::{
+    LocationHelper,
+    LoopArgument,
+    OptionNodeOrInternal,
+    Parser,
+    Starts,
+    TokenTypeArrayCombine,
+    ARRAY_PARSERS,
+  },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -23,18 +31,16 @@ impl ArrayParser {
     parser: &mut Parser<'arena, 'b>,
     breaker: TokenType
   ) -> Result<Vec<'arena, Node<'arena>>, ParserError> {
-    let mut loop_parsers = DEFAULT_PARSERS.to_vec();
-    loop_parsers.insert(0, (ArrayItemParser::test, ArrayItemParser::parse));
     Ok(
       Vec::from_iter_in(
         parser
-          .get_children(
+          .get_closed_children(
             &mut LoopArgument::new(
               parser.arena,
               "array",
               &[TokenType::Comma],
               &[breaker],
-              &loop_parsers
+              ARRAY_PARSERS.as_slice()
             )
           )?
           .into_iter()
@@ -57,6 +63,8 @@ impl ArrayParser {
     )
   }
 
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::LeftSquareBracket, TokenType::Array]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -109,6 +117,8 @@ impl ArrayParser {
 pub struct ArrayItemParser;
 
 impl ArrayItemParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Arrow]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -134,6 +144,7 @@ impl ArrayItemParser {
         )?
         .ok_internal()?;
       let key = args.last_expr.take();
+      let start_loc = key.as_ref().and_then(|x| x.get_location()).unwrap_or(start_loc);
       return Ok(
         ArrayItemNode::loc(
           key.into_boxed(parser.arena),
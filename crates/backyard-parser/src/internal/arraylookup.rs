@@ -9,7 +9,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -17,6 +17,8 @@ use crate::{
 pub struct ArrayLookupParser;
 
 impl ArrayLookupParser {
+  pub const STARTS: Starts = Starts::postfix(&[TokenType::LeftSquareBracket]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
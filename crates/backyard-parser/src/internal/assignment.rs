@@ -1,9 +1,16 @@
 use backyard_lexer::token::TokenType;
-use backyard_nodes::{ utils::IntoBoxedNode, AssignmentNode, AssignmentType, Location, Node };
+use backyard_nodes::{
+  precedence,
+  utils::IntoBoxedNode,
+  AssignmentNode,
+  AssignmentType,
+  Location,
+  Node,
+};
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser, TokenTypeArrayCombine, DEFAULT_PARSERS },
+  parser::{ LoopArgument, Parser, Starts, TokenTypeArrayCombine, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,35 +18,32 @@ use crate::{
 pub struct AssignmentParser;
 
 impl AssignmentParser {
+  pub const STARTS: Starts = Starts::postfix(
+    &[
+      TokenType::Assignment,
+      TokenType::BitwiseAndAssignment,
+      TokenType::CoalesceAssignment,
+      TokenType::ModulusAssignment,
+      TokenType::BitwiseXorAssignment,
+      TokenType::ExponentiationAssignment,
+      TokenType::MultiplicationAssignment,
+      TokenType::DivisionAssignment,
+      TokenType::ConcatenationAssignment,
+      TokenType::BitwiseOrAssignment,
+      TokenType::SubtractionAssignment,
+      TokenType::BitwiseShiftRightAssignment,
+      TokenType::BitwiseShiftLeftAssignment,
+      TokenType::AdditionAssignment,
+      TokenType::ReferenceAssignment,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
     args.last_expr.as_ref()?;
-    match_pattern(
-      parser,
-      &[
-        Lookup::Equal(
-          &[
-            TokenType::Assignment,
-            TokenType::BitwiseAndAssignment,
-            TokenType::CoalesceAssignment,
-            TokenType::ModulusAssignment,
-            TokenType::BitwiseXorAssignment,
-            TokenType::ExponentiationAssignment,
-            TokenType::MultiplicationAssignment,
-            TokenType::DivisionAssignment,
-            TokenType::ConcatenationAssignment,
-            TokenType::BitwiseOrAssignment,
-            TokenType::SubtractionAssignment,
-            TokenType::BitwiseShiftRightAssignment,
-            TokenType::BitwiseShiftLeftAssignment,
-            TokenType::AdditionAssignment,
-            TokenType::ReferenceAssignment,
-          ]
-        ),
-      ]
-    )
+    match_pattern(parser, &[Lookup::Equal(Self::STARTS.tokens)])
   }
 
   pub fn parse<'arena, 'a, 'b>(
@@ -49,7 +53,7 @@ impl AssignmentParser {
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [operator] = matched.as_slice() {
-      let operator = AssignmentType::try_from(&operator.as_equal(parser)?.value).map_err(
+      let operator = AssignmentType::try_from(operator.as_equal(parser)?.value).map_err(
         |_| ParserError::Internal
       )?;
       let left = args.last_expr.take().unwrap();
@@ -63,7 +67,7 @@ impl AssignmentParser {
               .combine(args.separators)
               .combine(&[TokenType::Semicolon, TokenType::Comma]),
             &DEFAULT_PARSERS
-          )
+          ).with_precedence(precedence::ASSIGNMENT)
         )?
       {
         return Ok(
@@ -4,7 +4,7 @@ use backyard_nodes::{ AttributeItemNode, AttributeNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -14,6 +14,8 @@ use super::call::CallParser;
 pub struct AttributeParser;
 
 impl AttributeParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Attribute]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -34,7 +36,7 @@ impl AttributeParser {
           "attribute",
           &[TokenType::Comma],
           &[TokenType::RightSquareBracket],
-          &[(AttributeItemParser::test, AttributeItemParser::parse)]
+          &[(AttributeItemParser::test, AttributeItemParser::parse, AttributeItemParser::STARTS)]
         )
       )?;
       let expr = parser.get_statement(
@@ -47,7 +49,13 @@ impl AttributeParser {
         )
       )?;
       if let Some(mut expr) = expr {
-        expr.leadings_shift(parser.arena, AttributeNode::loc(items, parser.gen_loc(start_loc)));
+        let attribute = AttributeNode::loc(items, parser.gen_loc(start_loc));
+        // Nodes without an attributes field, e.g. property hooks, keep them as leadings
+        if let Some(attributes) = expr.attributes_mut() {
+          attributes.insert(0, attribute);
+        } else {
+          expr.leadings_shift(parser.arena, attribute);
+        }
         return Ok(expr);
       }
     }
@@ -59,6 +67,17 @@ impl AttributeParser {
 pub struct AttributeItemParser;
 
 impl AttributeItemParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::UnqualifiedName,
+      TokenType::QualifiedName,
+      TokenType::RelativeName,
+      TokenType::FullyQualifiedName,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -88,7 +107,7 @@ impl AttributeItemParser {
     _: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [name, has_argument] = matched.as_slice() {
-      let name = name.as_equal(parser)?.value.to_owned();
+      let name = name.as_equal(parser)?.value;
       let arguments = if !has_argument.is_empty() {
         CallParser::get_arguments(parser)?
       } else {
@@ -1,9 +1,9 @@
-use backyard_lexer::token::TokenType;
-use backyard_nodes::{ utils::IntoBoxedNode, BinNode, BinaryType, Location, Node };
+use backyard_lexer::token::{ Token, TokenType };
+use backyard_nodes::{ utils::IntoBoxedNode, BinNode, BinaryType, Location, Node, NodeWrapper };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser, TokenTypeArrayCombine, DEFAULT_PARSERS },
+  parser::{ LoopArgument, Parser, Starts, TokenTypeArrayCombine, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,49 +11,79 @@ use crate::{
 pub struct BinParser;
 
 impl BinParser {
+  /// Keyword operators are matched by their token, they can be written in any case
+  fn operator(token: &Token) -> Option<BinaryType> {
+    match token.token_type {
+      TokenType::And => Some(BinaryType::LogicalAnd),
+      TokenType::Or => Some(BinaryType::LogicalOr),
+      TokenType::Xor => Some(BinaryType::BooleanXor),
+      _ => BinaryType::try_from(token.value).ok(),
+    }
+  }
+
+  /// Precedence of the operator, and the minimum precedence of its right operand
+  fn binding(token: &Token) -> Option<(u8, u8)> {
+    let operator = Self::operator(token)?;
+    let precedence = operator.precedence();
+    match operator.is_right_associative() {
+      true => Some((precedence, precedence)),
+      false => Some((precedence, precedence + 1)),
+    }
+  }
+
+  pub const STARTS: Starts = Starts::postfix(
+    &[
+      TokenType::Addition,
+      TokenType::Subtraction,
+      TokenType::Multiplication,
+      TokenType::Division,
+      TokenType::Modulus,
+      TokenType::Exponentiation,
+      TokenType::BitwiseAnd,
+      TokenType::BitwiseOr,
+      TokenType::BitwiseXor,
+      TokenType::BitwiseShiftLeft,
+      TokenType::BitwiseShiftRight,
+      TokenType::IsEqual,
+      TokenType::IsIdentical,
+      TokenType::IsNotEqual,
+      TokenType::IsNotIdentical,
+      TokenType::IsLesser,
+      TokenType::IsGreater,
+      TokenType::IsLesserOrEqual,
+      TokenType::IsGreaterOrEqual,
+      TokenType::Spaceship,
+      TokenType::Concatenation,
+      TokenType::Coalesce,
+      TokenType::BooleanAnd,
+      TokenType::BooleanOr,
+      TokenType::And,
+      TokenType::Or,
+      TokenType::Xor,
+      TokenType::Elvis,
+      TokenType::InstanceOf,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
-    args.last_expr.as_ref()?;
-    match_pattern(
-      parser,
-      &[
-        Lookup::Equal(
-          &[
-            TokenType::Addition,
-            TokenType::Subtraction,
-            TokenType::Multiplication,
-            TokenType::Division,
-            TokenType::Modulus,
-            TokenType::Exponentiation,
-            TokenType::BitwiseAnd,
-            TokenType::BitwiseOr,
-            TokenType::BitwiseXor,
-            TokenType::BitwiseShiftLeft,
-            TokenType::BitwiseShiftRight,
-            TokenType::IsEqual,
-            TokenType::IsIdentical,
-            TokenType::IsNotEqual,
-            TokenType::IsNotIdentical,
-            TokenType::IsLesser,
-            TokenType::IsGreater,
-            TokenType::IsLesserOrEqual,
-            TokenType::IsGreaterOrEqual,
-            TokenType::Spaceship,
-            TokenType::Concatenation,
-            TokenType::Coalesce,
-            TokenType::BooleanAnd,
-            TokenType::BooleanOr,
-            TokenType::And,
-            TokenType::Or,
-            TokenType::Xor,
-            TokenType::Elvis,
-            TokenType::InstanceOf,
-          ]
-        ),
-      ]
-    )
+    let left = args.last_expr.as_ref()?;
+    let token = parser.tokens.get(parser.position)?;
+    let operator = Self::operator(token)?;
+    let (precedence, _) = Self::binding(token)?;
+    if precedence < args.precedence {
+      return None;
+    }
+    // Comparisons can not be chained, the operator is left unmatched so parsing fails on it
+    if
+      operator.is_non_associative() &&
+      matches!(&left.wrapper, NodeWrapper::Bin(bin) if bin.operator.precedence() == precedence)
+    {
+      return None;
+    }
+    match_pattern(parser, &[Lookup::Equal(Self::STARTS.tokens)])
   }
 
   pub fn parse<'arena, 'a, 'b>(
@@ -63,9 +93,9 @@ impl BinParser {
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [operator] = matched.as_slice() {
-      let operator = BinaryType::try_from(&operator.as_equal(parser)?.value).map_err(
-        |_| ParserError::Internal
-      )?;
+      let operator = operator.as_equal(parser)?;
+      let (_, right_precedence) = Self::binding(operator).ok_or(ParserError::Internal)?;
+      let operator = Self::operator(operator).ok_or(ParserError::Internal)?;
       let left = args.last_expr.take().unwrap();
       if
         let Some(right) = parser.get_statement(
@@ -75,7 +105,7 @@ impl BinParser {
             &[],
             &args.breakers.combine(args.separators),
             &DEFAULT_PARSERS
-          )
+          ).with_precedence(right_precedence)
         )?
       {
         return Ok(
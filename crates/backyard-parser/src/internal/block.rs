@@ -3,7 +3,7 @@ use backyard_nodes::{ BlockNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, DEFAULT_PARSERS },
+  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, Starts, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -24,8 +24,8 @@ impl BlockParser {
           &[],
           &[],
           &[
-            (CommentParser::test, CommentParser::parse),
-            (BlockParser::test, BlockParser::parse),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+            (BlockParser::test, BlockParser::parse, BlockParser::STARTS),
           ]
         )
       )?
@@ -105,6 +105,8 @@ impl BlockParser {
     Err(ParserError::Internal)
   }
 
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::LeftCurlyBracket]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -10,7 +10,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult, LookupResultWrapper },
 };
 
@@ -23,15 +23,15 @@ impl CallParser {
   pub fn get_arguments<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>
   ) -> Result<Vec<'arena, Node<'arena>>, ParserError> {
-    parser.get_children(
+    parser.get_closed_children(
       &mut LoopArgument::new(
         parser.arena,
         "call",
         &[TokenType::Comma],
         &[TokenType::RightParenthesis],
         &[
-          (CommentParser::test, CommentParser::parse),
-          (ArgumentParser::test, ArgumentParser::parse),
+          (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+          (ArgumentParser::test, ArgumentParser::parse, ArgumentParser::STARTS),
         ]
       )
     )
@@ -39,6 +39,8 @@ impl CallParser {
 }
 
 impl CallParser {
+  pub const STARTS: Starts = Starts::postfix(&[TokenType::LeftParenthesis]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
@@ -70,6 +72,8 @@ impl CallParser {
 pub struct ArgumentParser;
 
 impl ArgumentParser {
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
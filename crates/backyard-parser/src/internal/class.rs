@@ -12,7 +12,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult, ModifierLookup },
 };
 
@@ -30,6 +30,15 @@ use super::{
 pub struct ClassParser;
 
 impl ClassParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Readonly,
+      TokenType::Abstract,
+      TokenType::Final,
+      TokenType::Class,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -120,8 +129,8 @@ impl ClassParser {
                   &[TokenType::Comma],
                   &[TokenType::LeftCurlyBracket],
                   &[
-                    (IdentifierParser::test, IdentifierParser::parse),
-                    (CommentParser::test, CommentParser::parse),
+                    (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+                    (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
                   ]
                 )
               )?
@@ -144,17 +153,18 @@ impl ClassParser {
           &[TokenType::Semicolon],
           &[TokenType::RightCurlyBracket],
           &[
-            (TraitUseParser::test, TraitUseParser::parse),
-            (MethodParser::test, MethodParser::parse),
-            (ConstPropertyParser::test, ConstPropertyParser::parse),
-            (PropertyParser::test, PropertyParser::parse),
-            (AttributeParser::test, AttributeParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (TraitUseParser::test, TraitUseParser::parse, TraitUseParser::STARTS),
+            (MethodParser::test, MethodParser::parse, MethodParser::STARTS),
+            (ConstPropertyParser::test, ConstPropertyParser::parse, ConstPropertyParser::STARTS),
+            (PropertyParser::test, PropertyParser::parse, PropertyParser::STARTS),
+            (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
       return Ok(
         AnonymousClassNode::loc(
+          vec![in parser.arena],
           parameters,
           extends.into_boxed(parser.arena),
           implements,
@@ -183,8 +193,8 @@ impl ClassParser {
             &[TokenType::Comma],
             &[TokenType::LeftCurlyBracket],
             &[
-              (IdentifierParser::test, IdentifierParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?;
@@ -202,12 +212,12 @@ impl ClassParser {
           &[TokenType::Semicolon],
           &[TokenType::RightCurlyBracket],
           &[
-            (TraitUseParser::test, TraitUseParser::parse),
-            (MethodParser::test, MethodParser::parse),
-            (ConstPropertyParser::test, ConstPropertyParser::parse),
-            (PropertyParser::test, PropertyParser::parse),
-            (AttributeParser::test, AttributeParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (TraitUseParser::test, TraitUseParser::parse, TraitUseParser::STARTS),
+            (MethodParser::test, MethodParser::parse, MethodParser::STARTS),
+            (ConstPropertyParser::test, ConstPropertyParser::parse, ConstPropertyParser::STARTS),
+            (PropertyParser::test, PropertyParser::parse, PropertyParser::STARTS),
+            (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -219,6 +229,7 @@ impl ClassParser {
       }
       return Ok(
         ClassNode::loc(
+          vec![in parser.arena],
           inheritance,
           Some(name.into_boxed(parser.arena)),
           extends.into_boxed(parser.arena),
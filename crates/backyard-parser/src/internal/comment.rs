@@ -1,9 +1,20 @@
-use backyard_lexer::token::TokenType;
-use backyard_nodes::{ CommentBlockNode, CommentLineNode, Location, Node, NodeType };
+use backyard_lexer::token::{ Token, TokenType };
+use backyard_nodes::{
+  CommentBlockNode,
+  CommentDocNode,
+  CommentDocTagNode,
+  CommentLineNode,
+  Location,
+  Node,
+  NodeType,
+  RangeLocation,
+};
+use bstr::{ BStr, BString, ByteSlice };
+use bumpalo::Bump;
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,11 +22,26 @@ use crate::{
 pub struct CommentParser;
 
 impl CommentParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::CommentLine,
+      TokenType::CommentBlock,
+      TokenType::CommentDocOpen,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
-    match_pattern(parser, &[Lookup::Equal(&[TokenType::CommentLine, TokenType::CommentBlock])])
+    match_pattern(
+      parser,
+      &[
+        Lookup::Equal(
+          &[TokenType::CommentLine, TokenType::CommentBlock, TokenType::CommentDocOpen]
+        ),
+      ]
+    )
   }
 
   pub fn parse<'arena, 'a, 'b>(
@@ -28,11 +54,10 @@ impl CommentParser {
       let comment = comment.as_equal(parser)?;
       let comment = match comment.token_type {
         TokenType::CommentLine =>
-          CommentLineNode::loc(comment.value.to_owned(), parser.gen_loc(start_loc)),
+          CommentLineNode::loc(comment.value, parser.gen_loc(start_loc)),
         TokenType::CommentBlock =>
-          CommentBlockNode::loc(comment.value.to_owned(), parser.gen_loc(start_loc)),
-        // TokenType::CommentDoc =>
-        //   CommentDocNode::loc(comment.value.to_owned(), parser.gen_loc(start_loc)),
+          CommentBlockNode::loc(comment.value, parser.gen_loc(start_loc)),
+        TokenType::CommentDocOpen => Self::parse_doc(parser, comment.value, start_loc),
         _ => {
           return Err(ParserError::Internal);
         }
@@ -85,18 +110,243 @@ impl CommentParser {
         }
       }
       if let Some(statements) = &mut args.statements {
-        if let Some(expr) = statements.pop() {
+        if let Some(mut expr) = statements.pop() {
           if
             ![NodeType::CommentBlock, NodeType::CommentDoc, NodeType::CommentLine].contains(
               &expr.node_type
             )
           {
+            expr.trailings_push(parser.arena, comment);
             return Ok(expr);
           }
+          statements.push(expr);
         }
       }
       return Ok(comment);
     }
     Err(ParserError::Internal)
   }
+
+  fn parse_doc<'arena, 'a>(
+    parser: &mut Parser<'arena, 'a>,
+    raw: &'arena BStr,
+    start_loc: Location
+  ) -> Node<'arena> {
+    let open_line = start_loc.line;
+    let mut lines: std::vec::Vec<std::vec::Vec<&Token>> = vec![];
+    let mut last_line = open_line;
+    while let Some(token) = parser.tokens.get(parser.position) {
+      parser.position += 1;
+      match token.token_type {
+        TokenType::CommentDocClose => {
+          break;
+        }
+        TokenType::DocHorizontalWhitespace => {
+          continue;
+        }
+        _ => {}
+      }
+      if token.line != last_line || lines.is_empty() {
+        if !lines.is_empty() {
+          for _ in last_line + 1..token.line {
+            lines.push(vec![]);
+          }
+        }
+        lines.push(vec![]);
+        last_line = token.line;
+      }
+      if let Some(line) = lines.last_mut() {
+        line.push(token);
+      }
+    }
+
+    let comment = raw.strip_prefix(b"/**").unwrap_or(raw);
+    let comment = comment.strip_suffix(b"*/").unwrap_or(comment).as_bstr();
+
+    let mut texts: std::vec::Vec<BString> = vec![];
+    let mut sections: std::vec::Vec<&[std::vec::Vec<&Token>]> = vec![];
+    let mut section_start = None;
+    for (index, line) in lines.iter().enumerate() {
+      if line.first().is_some_and(|x| Self::is_doc_tag(x)) {
+        if let Some(start) = section_start {
+          sections.push(&lines[start..index]);
+        }
+        section_start = Some(index);
+      } else if section_start.is_none() {
+        texts.push(Self::join_doc_tokens(line));
+      }
+    }
+    if let Some(start) = section_start {
+      sections.push(&lines[start..]);
+    }
+    let tags = bumpalo::collections::Vec::from_iter_in(
+      sections.into_iter().map(|lines| Self::parse_doc_tag(parser.arena, lines)),
+      parser.arena
+    );
+
+    let mut texts = texts.into_iter().skip_while(|x| x.is_empty());
+    let mut summary: std::vec::Vec<BString> = vec![];
+    for text in texts.by_ref() {
+      if text.is_empty() {
+        break;
+      }
+      let is_end = text.ends_with(b".");
+      summary.push(text);
+      if is_end {
+        break;
+      }
+    }
+    let summary = summary.join(&b"\n"[..]);
+    let description = Self::join_doc_lines(texts.collect());
+    CommentDocNode::loc(
+      comment,
+      parser.arena.alloc_slice_copy(&summary).as_bstr(),
+      parser.arena.alloc_slice_copy(&description).as_bstr(),
+      tags,
+      parser.gen_loc(start_loc)
+    )
+  }
+
+  fn is_doc_tag(token: &Token) -> bool {
+    token.value.len() > 1 && token.value.starts_with(b"@")
+  }
+
+  fn split_doc_tag(tokens: &[&Token]) -> (Option<BString>, Option<BString>, BString) {
+    // The type ends at the first whitespace outside of brackets, e.g. in `array{a: int} $a`,
+    // except after the colon of a callable return type as in `callable(int): void`
+    let mut index = 0;
+    let mut depth = 0usize;
+    while let Some(token) = tokens.get(index) {
+      let is_adjacent = index > 0 && Self::is_doc_adjacent(tokens[index - 1], token);
+      let is_type = match token.token_type {
+        TokenType::DocUnknown => is_adjacent,
+        _ => DOC_TYPE_TOKENS.contains(&token.token_type),
+      };
+      let is_return_type = index > 0 && tokens[index - 1].token_type == TokenType::Colon;
+      if !is_type || (index > 0 && depth == 0 && !is_adjacent && !is_return_type) {
+        break;
+      }
+      match token.token_type {
+        | TokenType::LeftParenthesis
+        | TokenType::LeftAngleBracket
+        | TokenType::LeftCurlyBracket
+        | TokenType::LeftSquareBracket => {
+          depth += 1;
+        }
+        | TokenType::RightParenthesis
+        | TokenType::RightAngleBracket
+        | TokenType::RightCurlyBracket
+        | TokenType::RightSquareBracket => {
+          depth = depth.saturating_sub(1);
+        }
+        _ => {}
+      }
+      index += 1;
+    }
+    let mut type_end = index;
+    let mut variable = None;
+    while
+      tokens.get(index).is_some_and(|x| PREFIXES.contains(&x.token_type)) &&
+      tokens.get(index + 1).is_some_and(|x| Self::is_doc_adjacent(tokens[index], x))
+    {
+      index += 1;
+    }
+    if let Some(token) = tokens.get(index) {
+      if token.token_type == TokenType::Variable {
+        while
+          type_end > 0 &&
+          PREFIXES.contains(&tokens[type_end - 1].token_type)
+        {
+          type_end -= 1;
+        }
+        variable = Some(Self::join_doc_tokens(&tokens[type_end..=index]));
+        index += 1;
+      } else {
+        index = type_end;
+      }
+    }
+    let tag_type = if type_end > 0 { Some(Self::join_doc_tokens(&tokens[..type_end])) } else { None };
+    (tag_type, variable, Self::join_doc_tokens(&tokens[index..]))
+  }
+
+  fn parse_doc_tag<'arena>(
+    arena: &'arena Bump,
+    lines: &[std::vec::Vec<&Token<'arena>>]
+  ) -> Node<'arena> {
+    let name = lines[0][0];
+    let (tag_type, variable, description) = Self::split_doc_tag(&lines[0][1..]);
+    let mut descriptions = vec![description];
+    descriptions.extend(lines[1..].iter().map(|line| Self::join_doc_tokens(line)));
+    let loc = lines
+      .iter()
+      .rev()
+      .find_map(|line| line.last())
+      .and_then(|end| name.get_location().zip(end.get_range_location()))
+      .map(|(start, end)| RangeLocation { start, end: end.end });
+    CommentDocTagNode::loc(
+      name.value,
+      tag_type.map(|x| arena.alloc_slice_copy(&x).as_bstr()),
+      variable.map(|x| arena.alloc_slice_copy(&x).as_bstr()),
+      arena.alloc_slice_copy(&Self::join_doc_lines(descriptions)).as_bstr(),
+      loc
+    )
+  }
+
+  fn is_doc_adjacent(prev: &Token, next: &Token) -> bool {
+    prev.line == next.line && prev.offset + (prev.value.len() as u32) == next.offset
+  }
+
+  fn join_doc_tokens(tokens: &[&Token]) -> BString {
+    let mut text = BString::new(vec![]);
+    for (index, token) in tokens.iter().enumerate() {
+      if index > 0 && !Self::is_doc_adjacent(tokens[index - 1], token) {
+        text.push(b' ');
+      }
+      text.extend_from_slice(token.value);
+    }
+    text
+  }
+
+  fn join_doc_lines(lines: std::vec::Vec<BString>) -> BString {
+    let start = lines
+      .iter()
+      .position(|x| !x.is_empty())
+      .unwrap_or(lines.len());
+    let end = lines
+      .iter()
+      .rposition(|x| !x.is_empty())
+      .map_or(start, |x| x + 1);
+    lines[start..end].join(&b"\n"[..]).into()
+  }
 }
+
+/// Written before a parameter name for by-reference and variadic parameters, `&...$a`
+const PREFIXES: &[TokenType] = &[TokenType::BitwiseAnd, TokenType::Ellipsis];
+
+const DOC_TYPE_TOKENS: &[TokenType] = &[
+  TokenType::Type,
+  TokenType::UnqualifiedName,
+  TokenType::QualifiedName,
+  TokenType::FullyQualifiedName,
+  TokenType::RelativeName,
+  TokenType::LeftParenthesis,
+  TokenType::RightParenthesis,
+  TokenType::LeftAngleBracket,
+  TokenType::RightAngleBracket,
+  TokenType::LeftCurlyBracket,
+  TokenType::RightCurlyBracket,
+  TokenType::LeftSquareBracket,
+  TokenType::RightSquareBracket,
+  TokenType::Colon,
+  TokenType::Comma,
+  TokenType::BitwiseOr,
+  TokenType::BitwiseAnd,
+  TokenType::QuestionMark,
+  TokenType::Ellipsis,
+  TokenType::Multiplication,
+  TokenType::String,
+  TokenType::Number,
+  TokenType::NumberHex,
+  TokenType::NumberOctal,
+  TokenType::NumberBinary,
+];
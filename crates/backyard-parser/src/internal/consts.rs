@@ -4,7 +4,7 @@ use backyard_nodes::{ ConstNode, ConstPropertyNode, Location, Node, utils::IntoB
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult, ModifierLookup },
 };
 
@@ -24,9 +24,9 @@ impl ConstParser {
         &[TokenType::Comma],
         &[TokenType::Semicolon],
         &[
-          (IdentifierParser::test, IdentifierParser::parse),
-          (AssignmentParser::test, AssignmentParser::parse),
-          (CommentParser::test, CommentParser::parse),
+          (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+          (AssignmentParser::test, AssignmentParser::parse, AssignmentParser::STARTS),
+          (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
         ]
       )
     );
@@ -36,6 +36,8 @@ impl ConstParser {
 }
 
 impl ConstParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Const]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -60,6 +62,21 @@ impl ConstParser {
 pub struct ConstPropertyParser;
 
 impl ConstPropertyParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Private,
+      TokenType::PrivateGet,
+      TokenType::PrivateSet,
+      TokenType::Protected,
+      TokenType::ProtectedGet,
+      TokenType::ProtectedSet,
+      TokenType::Public,
+      TokenType::PublicGet,
+      TokenType::PublicSet,
+      TokenType::Const,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -88,6 +105,7 @@ impl ConstPropertyParser {
       };
       return Ok(
         ConstPropertyNode::loc(
+          bumpalo::vec![in parser.arena],
           const_type.as_optional_type().into_boxed(parser.arena),
           visibilities,
           ConstParser::get_consts(parser)?,
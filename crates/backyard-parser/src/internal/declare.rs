@@ -10,7 +10,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -20,6 +20,8 @@ use super::{ block::BlockParser, comment::CommentParser, identifier::IdentifierP
 pub struct DeclareParser;
 
 impl DeclareParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Declare]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -44,8 +46,12 @@ impl DeclareParser {
           &[TokenType::Comma],
           &[TokenType::RightParenthesis],
           &[
-            (DeclareArgumentParser::test, DeclareArgumentParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (
+              DeclareArgumentParser::test,
+              DeclareArgumentParser::parse,
+              DeclareArgumentParser::STARTS,
+            ),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -80,6 +86,8 @@ impl DeclareParser {
 pub struct DeclareArgumentParser;
 
 impl DeclareArgumentParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::UnqualifiedName]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
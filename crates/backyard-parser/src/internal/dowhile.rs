@@ -3,7 +3,7 @@ use backyard_nodes::{ DoWhileConditionNode, DoWhileNode, Location, Node, utils::
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,8 @@ use super::{ block::BlockParser, comment::CommentParser };
 pub struct DoWhileParser;
 
 impl DoWhileParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Do]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -36,8 +38,12 @@ impl DoWhileParser {
             &[],
             &[TokenType::RightParenthesis],
             &[
-              (DoWhileConditionParser::test, DoWhileConditionParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (
+                DoWhileConditionParser::test,
+                DoWhileConditionParser::parse,
+                DoWhileConditionParser::STARTS,
+              ),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?
@@ -59,6 +65,8 @@ impl DoWhileParser {
 pub struct DoWhileConditionParser;
 
 impl DoWhileConditionParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::While]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
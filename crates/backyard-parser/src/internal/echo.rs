@@ -3,7 +3,7 @@ use backyard_nodes::{ EchoNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser, TokenTypeArrayCombine },
+  parser::{ LoopArgument, Parser, Starts, TokenTypeArrayCombine },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct EchoParser;
 
 impl EchoParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Echo]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -9,7 +9,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -25,6 +25,8 @@ use super::{
 pub struct EnumParser;
 
 impl EnumParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Enum]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -69,16 +71,17 @@ impl EnumParser {
           &[TokenType::Semicolon],
           &[TokenType::RightCurlyBracket],
           &[
-            (MethodParser::test, MethodParser::parse),
-            (ConstPropertyParser::test, ConstPropertyParser::parse),
-            (EnumItemParser::test, EnumItemParser::parse),
-            (AttributeParser::test, AttributeParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (MethodParser::test, MethodParser::parse, MethodParser::STARTS),
+            (ConstPropertyParser::test, ConstPropertyParser::parse, ConstPropertyParser::STARTS),
+            (EnumItemParser::test, EnumItemParser::parse, EnumItemParser::STARTS),
+            (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
       return Ok(
         EnumNode::loc(
+          bumpalo::vec![in parser.arena],
           name.into_boxed(parser.arena),
           enum_type.as_optional_type().into_boxed(parser.arena),
           implements.into_boxed(parser.arena),
@@ -95,6 +98,8 @@ impl EnumParser {
 pub struct EnumItemParser;
 
 impl EnumItemParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Case]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -119,7 +124,13 @@ impl EnumItemParser {
           )
         )?
       {
-        return Ok(EnumItemNode::loc(value.into_boxed(parser.arena), parser.gen_loc(start_loc)));
+        return Ok(
+          EnumItemNode::loc(
+            bumpalo::vec![in parser.arena],
+            value.into_boxed(parser.arena),
+            parser.gen_loc(start_loc)
+          )
+        );
       }
     }
     Err(ParserError::Internal)
@@ -3,7 +3,7 @@ use backyard_nodes::{ ForeachNode, Location, Node, utils::{ IntoBoxedNode, IntoB
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -49,6 +49,8 @@ impl ForeachParser {
 }
 
 impl ForeachParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Foreach]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ BodyType, ForNode, Location, Node, utils::IntoBoxedOptionN
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,8 @@ use super::block::BlockParser;
 pub struct ForParser;
 
 impl ForParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::For]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
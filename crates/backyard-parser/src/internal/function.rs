@@ -17,7 +17,14 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine },
+  parser::{
+    LocationHelper,
+    LoopArgument,
+    OptionNodeOrInternal,
+    Parser,
+    Starts,
+    TokenTypeArrayCombine,
+  },
   utils::{ match_pattern, Lookup, LookupResult, LookupResultWrapper, ModifierLookup },
 };
 
@@ -33,6 +40,8 @@ use super::{
 pub struct FunctionParser;
 
 impl FunctionParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Function, TokenType::Fn]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -119,6 +128,7 @@ impl FunctionParser {
         .ok_internal()?;
       return Ok(
         ArrowFunctionNode::loc(
+          vec![in parser.arena],
           !is_ref.is_empty(),
           arguments,
           return_type,
@@ -155,6 +165,7 @@ impl FunctionParser {
       let body = BlockParser::new_block(parser)?;
       return Ok(
         AnonymousFunctionNode::loc(
+          vec![in parser.arena],
           !is_ref.is_empty(),
           arguments,
           uses,
@@ -177,10 +188,9 @@ impl FunctionParser {
       let name = if let LookupResultWrapper::Any(name) = &name.wrapper {
         let name = parser.get_token(*name)?;
         if name.token_type == TokenType::MagicMethod {
-          if name.value == "__construct" {
-            is_contructor = true;
-          }
-          MagicMethodNode::loc(MagicMethodName::Construct, name.get_range_location())
+          let method = MagicMethodName::try_from(name.value).map_err(|_| ParserError::Internal)?;
+          is_contructor = method == MagicMethodName::Construct;
+          MagicMethodNode::loc(method, name.get_range_location())
         } else {
           IdentifierParser::from_token(name)
         }
@@ -195,10 +205,14 @@ impl FunctionParser {
             &[TokenType::Comma],
             &[TokenType::RightParenthesis],
             &[
-              (ConstructorParameterParser::test, ConstructorParameterParser::parse),
-              (TypesParser::test, TypesParser::parse),
-              (AttributeParser::test, AttributeParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (
+                ConstructorParameterParser::test,
+                ConstructorParameterParser::parse,
+                ConstructorParameterParser::STARTS,
+              ),
+              (TypesParser::test, TypesParser::parse, TypesParser::STARTS),
+              (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?
@@ -213,6 +227,7 @@ impl FunctionParser {
       };
       return Ok(
         FunctionNode::loc(
+          vec![in parser.arena],
           !is_ref.is_empty(),
           name.into_boxed(parser.arena),
           arguments,
@@ -235,9 +250,9 @@ impl FunctionParser {
         &[TokenType::Comma],
         &[TokenType::RightParenthesis],
         &[
-          (AttributeParser::test, AttributeParser::parse),
-          (CommentParser::test, CommentParser::parse),
-          (ParameterParser::test, ParameterParser::parse),
+          (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+          (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+          (ParameterParser::test, ParameterParser::parse, ParameterParser::STARTS),
         ]
       )
     )
@@ -257,8 +272,8 @@ impl FunctionParser {
               &[TokenType::LeftCurlyBracket, TokenType::Arrow, TokenType::Semicolon],
               &[],
               &[
-                (TypesParser::test, TypesParser::parse),
-                (CommentParser::test, CommentParser::parse),
+                (TypesParser::test, TypesParser::parse, TypesParser::STARTS),
+                (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
               ]
             )
           )?
@@ -275,6 +290,8 @@ impl FunctionParser {
 pub struct ConstructorParameterParser;
 
 impl ConstructorParameterParser {
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -321,6 +338,7 @@ impl ConstructorParameterParser {
         None
       };
       let item = ParameterNode::loc(
+        vec![in parser.arena],
         prop_type.as_optional_type().into_boxed(parser.arena),
         !is_ref.is_empty(),
         !is_variadic.is_empty(),
@@ -339,6 +357,7 @@ impl ConstructorParameterParser {
       }
       return Ok(
         ConstructorParameterNode::loc(
+          vec![in parser.arena],
           visibilities,
           modifier,
           item.into_boxed(parser.arena),
@@ -354,6 +373,8 @@ impl ConstructorParameterParser {
 pub struct ParameterParser;
 
 impl ParameterParser {
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -392,6 +413,7 @@ impl ParameterParser {
       };
       return Ok(
         ParameterNode::loc(
+          vec![in parser.arena],
           prop_type.as_optional_type().into_boxed(parser.arena),
           !is_ref.is_empty(),
           !is_ellipsis.is_empty(),
@@ -3,7 +3,7 @@ use backyard_nodes::{ GlobalNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,8 @@ use super::{ comment::CommentParser, variable::VariableParser };
 pub struct GlobalParser;
 
 impl GlobalParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Global]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -34,11 +36,16 @@ impl GlobalParser {
           &[TokenType::Comma],
           &[TokenType::Semicolon],
           &[
-            (CommentParser::test, CommentParser::parse),
-            (VariableParser::test, VariableParser::parse),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+            (VariableParser::test, VariableParser::parse, VariableParser::STARTS),
           ]
         )
       )?;
+      if let Ok(last_token) = parser.get_token(parser.position - 1) {
+        if last_token.token_type == TokenType::Semicolon {
+          parser.position -= 1;
+        }
+      }
       return Ok(GlobalNode::loc(items, parser.gen_loc(start_loc)));
     }
     Err(ParserError::Internal)
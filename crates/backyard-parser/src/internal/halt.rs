@@ -3,7 +3,7 @@ use backyard_nodes::{ HaltCompilerNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct HaltParser;
 
 impl HaltParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::HaltCompiler]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ IdentifierNode, Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,11 +11,22 @@ use crate::{
 pub struct IdentifierParser;
 
 impl IdentifierParser {
-  pub fn from_token<'arena>(id: &Token) -> Node<'arena> {
+  pub fn from_token<'arena>(id: &Token<'arena>) -> Node<'arena> {
     let loc = id.get_range_location();
-    IdentifierNode::loc(id.value.to_owned(), loc)
+    IdentifierNode::loc(id.value, loc)
   }
 
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::UnqualifiedName,
+      TokenType::QualifiedName,
+      TokenType::RelativeName,
+      TokenType::FullyQualifiedName,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
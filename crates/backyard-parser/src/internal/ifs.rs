@@ -9,7 +9,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult, LookupResultWrapper },
 };
 
@@ -19,6 +19,8 @@ use super::{ block::BlockParser, comment::CommentParser };
 pub struct IfParser;
 
 impl IfParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::If]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -57,8 +59,8 @@ impl IfParser {
           &[],
           &[TokenType::RightCurlyBracket, TokenType::EndIf],
           &[
-            (ElseParser::test, ElseParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (ElseParser::test, ElseParser::parse, ElseParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -87,6 +89,8 @@ impl IfParser {
 pub struct ElseParser;
 
 impl ElseParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Else, TokenType::ElseIf]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ IncludeNode, Location, Node, utils::IntoBoxedNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts, TokenTypeArrayCombine },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,15 @@ use crate::{
 pub struct IncludeParser;
 
 impl IncludeParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Require,
+      TokenType::RequireOnce,
+      TokenType::Include,
+      TokenType::IncludeOnce,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -4,7 +4,7 @@ use backyard_nodes::{ BlockNode, InterfaceNode, Location, Node, utils::IntoBoxed
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -20,6 +20,8 @@ use super::{
 pub struct InterfaceParser;
 
 impl InterfaceParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Interface]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -51,8 +53,8 @@ impl InterfaceParser {
                   &[TokenType::Comma],
                   &[TokenType::LeftCurlyBracket],
                   &[
-                    (IdentifierParser::test, IdentifierParser::parse),
-                    (CommentParser::test, CommentParser::parse),
+                    (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+                    (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
                   ]
                 )
               )?
@@ -75,15 +77,16 @@ impl InterfaceParser {
           &[TokenType::Semicolon],
           &[TokenType::RightCurlyBracket],
           &[
-            (MethodParser::test, MethodParser::parse),
-            (ConstPropertyParser::test, ConstPropertyParser::parse),
-            (AttributeParser::test, AttributeParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (MethodParser::test, MethodParser::parse, MethodParser::STARTS),
+            (ConstPropertyParser::test, ConstPropertyParser::parse, ConstPropertyParser::STARTS),
+            (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
       return Ok(
         InterfaceNode::loc(
+          vec![in parser.arena],
           name.into_boxed(parser.arena),
           extends,
           BlockNode::loc(body, parser.gen_loc(block_loc)).into_boxed(parser.arena),
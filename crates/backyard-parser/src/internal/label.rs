@@ -3,7 +3,7 @@ use backyard_nodes::{ LabelNode, Location, Node, NodeType, utils::IntoBoxedNode
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,14 +11,14 @@ use crate::{
 pub struct LabelParser;
 
 impl LabelParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Colon]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
-    if let Some(last) = &args.last_expr {
-      if last.node_type != NodeType::Identifier {
-        return None;
-      }
+    if args.last_expr.as_ref().is_none_or(|last| last.node_type != NodeType::Identifier) {
+      return None;
     }
     match_pattern(parser, &[Lookup::Equal(&[TokenType::Colon])])
   }
@@ -30,7 +30,7 @@ impl LabelParser {
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [_] = matched.as_slice() {
-      let name = args.last_expr.take().unwrap();
+      let name = args.last_expr.take().ok_or(ParserError::Internal)?;
       let name_loc = if let Some(loc) = &name.loc.as_ref().map(|x| x.start.clone()) {
         loc.to_owned()
       } else {
@@ -3,7 +3,7 @@ use backyard_nodes::{ Location, Node, ListNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct ListParser;
 
 impl ListParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::List]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ Location, MagicMethodName, MagicMethodNode, MagicName, Mag
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct MagicParser;
 
 impl MagicParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Magic, TokenType::MagicMethod]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -29,14 +31,14 @@ impl MagicParser {
       if name.token_type == TokenType::MagicMethod {
         return Ok(
           MagicMethodNode::loc(
-            MagicMethodName::try_from(&name.value).map_err(|_| ParserError::Internal)?,
+            MagicMethodName::try_from(name.value).map_err(|_| ParserError::Internal)?,
             parser.gen_loc(start_loc)
           )
         );
       }
       return Ok(
         MagicNode::loc(
-          MagicName::try_from(&name.value).map_err(|_| ParserError::Internal)?,
+          MagicName::try_from(name.value).map_err(|_| ParserError::Internal)?,
           parser.gen_loc(start_loc)
         )
       );
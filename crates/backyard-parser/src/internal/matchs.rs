@@ -4,7 +4,7 @@ use backyard_nodes::{ Location, MatchArmNode, MatchNode, Node, utils::IntoBoxedN
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -14,6 +14,8 @@ use super::comment::CommentParser;
 pub struct MatchParser;
 
 impl MatchParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Match]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -44,8 +46,8 @@ impl MatchParser {
           &[TokenType::Comma],
           &[TokenType::RightCurlyBracket],
           &[
-            (CommentParser::test, CommentParser::parse),
-            (MatchArmParser::test, MatchArmParser::parse),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+            (MatchArmParser::test, MatchArmParser::parse, MatchArmParser::STARTS),
           ]
         )
       )?;
@@ -61,6 +63,8 @@ impl MatchParser {
 pub struct MatchArmParser;
 
 impl MatchArmParser {
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     _: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ Inheritance, Location, MethodNode, Node, Visibility, utils
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult, ModifierLookup },
 };
 
@@ -14,6 +14,18 @@ pub struct MethodParser;
 
 impl MethodParser {
   #[allow(unused_variables)]
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Private,
+      TokenType::Protected,
+      TokenType::Public,
+      TokenType::Abstract,
+      TokenType::Final,
+      TokenType::Static,
+      TokenType::Function,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -49,8 +61,8 @@ impl MethodParser {
             &[TokenType::RightCurlyBracket],
             &[],
             &[
-              (FunctionParser::test, FunctionParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (FunctionParser::test, FunctionParser::parse, FunctionParser::STARTS),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?
@@ -65,6 +77,7 @@ impl MethodParser {
       }
       return Ok(
         MethodNode::loc(
+          bumpalo::vec![in parser.arena],
           visibility,
           inheritance,
           is_static,
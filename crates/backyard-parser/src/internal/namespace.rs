@@ -3,7 +3,7 @@ use backyard_nodes::{ BlockNode, Location, NamespaceNode, Node, utils::IntoBoxed
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct NamespaceParser;
 
 impl NamespaceParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Namespace]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -38,7 +40,7 @@ impl NamespaceParser {
     _: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [_, name] = matched.as_slice() {
-      let name = name.as_equal(parser)?.value.to_owned();
+      let name = name.as_equal(parser)?.value;
       let is_bracket = parser.get_token(parser.position)?.token_type == TokenType::LeftCurlyBracket;
       let block_loc = parser.get_token(parser.position)?.get_location().unwrap();
       if is_bracket {
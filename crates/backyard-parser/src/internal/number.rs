@@ -3,7 +3,7 @@ use backyard_nodes::{ Location, Node, NumberNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,13 +11,31 @@ use crate::{
 pub struct NumberParser;
 
 impl NumberParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Number,
+      TokenType::NumberHex,
+      TokenType::NumberOctal,
+      TokenType::NumberBinary,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
     match_pattern(
       parser,
-      &[Lookup::Equal(&[TokenType::Number, TokenType::NumberHex, TokenType::NumberBinary])]
+      &[
+        Lookup::Equal(
+          &[
+            TokenType::Number,
+            TokenType::NumberHex,
+            TokenType::NumberOctal,
+            TokenType::NumberBinary,
+          ]
+        ),
+      ]
     )
   }
 
@@ -29,7 +47,7 @@ impl NumberParser {
   ) -> Result<Node<'arena>, ParserError> {
     if let [number] = matched.as_slice() {
       return Ok(
-        NumberNode::loc(number.as_equal(parser)?.value.to_owned(), parser.gen_loc(start_loc))
+        NumberNode::loc(number.as_equal(parser)?.value, parser.gen_loc(start_loc))
       );
     }
     Err(ParserError::Internal)
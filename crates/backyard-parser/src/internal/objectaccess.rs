@@ -4,7 +4,7 @@ use backyard_nodes::{ Location, Node, ObjectAccessNode, utils::IntoBoxedNode };
 use crate::{
   error::ParserError,
   internal::{ identifier::IdentifierParser, variable::VariableParser },
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -12,6 +12,13 @@ use crate::{
 pub struct ObjectAccessParser;
 
 impl ObjectAccessParser {
+  pub const STARTS: Starts = Starts::postfix(
+    &[
+      TokenType::ObjectAccess,
+      TokenType::NullsafeObjectAccess,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
@@ -1,9 +1,18 @@
 use backyard_lexer::token::TokenType;
-use backyard_nodes::{ utils::IntoBoxedNode, CastNode, CastType, Location, Node, ParenthesisNode };
+use backyard_nodes::{
+  precedence,
+  utils::IntoBoxedNode,
+  CastNode,
+  CastType,
+  Location,
+  Node,
+  ParenthesisNode,
+};
+use bstr::ByteSlice;
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser, DEFAULT_PARSERS },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -26,6 +35,8 @@ const CAST_TYPES: &[&[u8]] = &[
 ];
 
 impl ParenthesisParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::LeftParenthesis]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -41,10 +52,10 @@ impl ParenthesisParser {
   ) -> Result<Node<'arena>, ParserError> {
     if let [_] = matched.as_slice() {
       if let Ok(token) = parser.get_token(parser.position) {
-        if CAST_TYPES.contains(&token.value.as_slice()) {
+        if CAST_TYPES.contains(&token.value.as_bytes()) {
           if let Ok(next_token) = parser.get_token(parser.position + 1) {
             if next_token.token_type == TokenType::RightParenthesis {
-              let cast_type = CastType::try_from(&token.value).map_err(|_| ParserError::Internal)?;
+              let cast_type = CastType::try_from(token.value).map_err(|_| ParserError::Internal)?;
               parser.position += 2;
               let expression = parser
                 .get_statement(
@@ -54,7 +65,7 @@ impl ParenthesisParser {
                     args.separators,
                     args.breakers,
                     &DEFAULT_PARSERS
-                  )
+                  ).with_precedence(precedence::UNARY)
                 )?
                 .ok_internal()?;
               return Ok(
@@ -78,7 +89,7 @@ impl ParenthesisParser {
           )
         )?
         .ok_internal()?;
-      parser.position += 1;
+      parser.expect_closer("parenthesis", TokenType::RightParenthesis)?;
       return Ok(
         ParenthesisNode::loc(statement.into_boxed(parser.arena), parser.gen_loc(start_loc))
       );
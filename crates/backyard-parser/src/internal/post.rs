@@ -3,7 +3,7 @@ use backyard_nodes::{ utils::IntoBoxedNode, Location, Node, PostNode, PostType }
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ use crate::{
 pub struct PostParser;
 
 impl PostParser {
+  pub const STARTS: Starts = Starts::postfix(&[TokenType::PostIncrement, TokenType::PostDecrement]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
@@ -26,7 +28,7 @@ impl PostParser {
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [operator] = matched.as_slice() {
-      let operator = PostType::try_from(&operator.as_equal(parser)?.value).map_err(
+      let operator = PostType::try_from(operator.as_equal(parser)?.value).map_err(
         |_| ParserError::Internal
       )?;
       return Ok(
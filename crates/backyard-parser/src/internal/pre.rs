@@ -1,5 +1,6 @@
 use backyard_lexer::token::TokenType;
 use backyard_nodes::{
+  precedence,
   utils::IntoBoxedOptionNode,
   Location,
   NegateNode,
@@ -13,7 +14,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser, DEFAULT_PARSERS },
+  parser::{ LoopArgument, Parser, Starts, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -21,27 +22,25 @@ use crate::{
 pub struct PreParser;
 
 impl PreParser {
+  pub const STARTS: Starts = Starts::prefix(
+    &[
+      TokenType::PreIncrement,
+      TokenType::PreDecrement,
+      TokenType::BooleanNegate,
+      TokenType::BitwiseNot,
+      TokenType::AtSign,
+      TokenType::Addition,
+      TokenType::Subtraction,
+      TokenType::Ellipsis,
+      TokenType::BitwiseAnd,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
-    match_pattern(
-      parser,
-      &[
-        Lookup::Equal(
-          &[
-            TokenType::PreIncrement,
-            TokenType::PreDecrement,
-            TokenType::BooleanNegate,
-            TokenType::AtSign,
-            TokenType::Addition,
-            TokenType::Subtraction,
-            TokenType::Ellipsis,
-            TokenType::BitwiseAnd,
-          ]
-        ),
-      ]
-    )
+    match_pattern(parser, &[Lookup::Equal(Self::STARTS.tokens)])
   }
 
   pub fn parse<'arena, 'a, 'b>(
@@ -51,6 +50,11 @@ impl PreParser {
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Node<'arena>, ParserError> {
     if let [operator] = matched.as_slice() {
+      let operand_precedence = match operator.as_equal(parser)?.token_type {
+        TokenType::BooleanNegate => precedence::NOT,
+        TokenType::Ellipsis | TokenType::BitwiseAnd => 0,
+        _ => precedence::UNARY,
+      };
       let argument = parser
         .get_statement(
           &mut LoopArgument::safe(
@@ -59,7 +63,7 @@ impl PreParser {
             args.separators,
             args.breakers,
             &DEFAULT_PARSERS
-          )
+          ).with_precedence(operand_precedence)
         )?
         .into_boxed(parser.arena);
       let operator = operator.as_equal(parser)?;
@@ -71,8 +75,9 @@ impl PreParser {
         | TokenType::PreIncrement
         | TokenType::PreDecrement
         | TokenType::Addition
-        | TokenType::Subtraction => {
-          let operator = PreType::try_from(&operator.value).map_err(|_| ParserError::Internal)?;
+        | TokenType::Subtraction
+        | TokenType::BitwiseNot => {
+          let operator = PreType::try_from(operator.value).map_err(|_| ParserError::Internal)?;
           Ok(PreNode::loc(argument, operator, parser.gen_loc(start_loc)))
         }
         TokenType::BooleanNegate => Ok(NegateNode::loc(argument, parser.gen_loc(start_loc))),
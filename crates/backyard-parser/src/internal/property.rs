@@ -13,7 +13,14 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine },
+  parser::{
+    LocationHelper,
+    LoopArgument,
+    OptionNodeOrInternal,
+    Parser,
+    Starts,
+    TokenTypeArrayCombine,
+  },
   utils::{ match_pattern, Lookup, LookupResult, ModifierLookup },
 };
 
@@ -29,6 +36,8 @@ use super::{
 pub struct PropertyParser;
 
 impl PropertyParser {
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -102,9 +111,9 @@ impl PropertyParser {
             &[TokenType::Comma],
             &[TokenType::Semicolon, TokenType::LeftCurlyBracket],
             &[
-              (CommentParser::test, CommentParser::parse),
-              (TypesParser::test, TypesParser::parse),
-              (PropertyItemParser::test, PropertyItemParser::parse),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+              (TypesParser::test, TypesParser::parse, TypesParser::STARTS),
+              (PropertyItemParser::test, PropertyItemParser::parse, PropertyItemParser::STARTS),
             ]
           )
         )?;
@@ -118,8 +127,8 @@ impl PropertyParser {
             &[],
             &[TokenType::RightCurlyBracket],
             &[
-              (CommentParser::test, CommentParser::parse),
-              (HookParser::test, HookParser::parse),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+              (HookParser::test, HookParser::parse, HookParser::STARTS),
             ]
           )
         )?;
@@ -133,7 +142,16 @@ impl PropertyParser {
       if visibilities.is_empty() && !has_var.is_empty() {
         visibilities.push(Visibility::Public);
       }
-      return Ok(PropertyNode::loc(visibilities, modifier, hooks, items, parser.gen_loc(start_loc)));
+      return Ok(
+        PropertyNode::loc(
+          vec![in parser.arena],
+          visibilities,
+          modifier,
+          hooks,
+          items,
+          parser.gen_loc(start_loc)
+        )
+      );
     }
     Err(ParserError::Internal)
   }
@@ -143,6 +161,8 @@ impl PropertyParser {
 pub struct PropertyItemParser;
 
 impl PropertyItemParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Variable]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -190,6 +210,14 @@ impl PropertyItemParser {
 pub struct HookParser;
 
 impl HookParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::BitwiseAnd,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
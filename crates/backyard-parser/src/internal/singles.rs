@@ -17,12 +17,13 @@ use backyard_nodes::{
   StaticKeywordNode,
   ThisNode,
   ThrowNode,
+  precedence,
   utils::IntoBoxedOptionNode,
 };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser, TokenTypeArrayCombine },
+  parser::{ LoopArgument, Parser, Starts, TokenTypeArrayCombine, DEFAULT_PARSERS },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -30,35 +31,32 @@ use crate::{
 pub struct SinglesParser;
 
 impl SinglesParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Break,
+      TokenType::Continue,
+      TokenType::Goto,
+      TokenType::New,
+      TokenType::Print,
+      TokenType::Return,
+      TokenType::Throw,
+      TokenType::Parent,
+      TokenType::Static,
+      TokenType::Clone,
+      TokenType::This,
+      TokenType::True,
+      TokenType::False,
+      TokenType::Null,
+      TokenType::SelfKeyword,
+      TokenType::Inline,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
-    match_pattern(
-      parser,
-      &[
-        Lookup::Equal(
-          &[
-            TokenType::Break,
-            TokenType::Continue,
-            TokenType::Goto,
-            TokenType::New,
-            TokenType::Print,
-            TokenType::Return,
-            TokenType::Throw,
-            TokenType::Parent,
-            TokenType::Static,
-            TokenType::Clone,
-            TokenType::This,
-            TokenType::True,
-            TokenType::False,
-            TokenType::Null,
-            TokenType::SelfKeyword,
-            TokenType::Inline,
-          ]
-        ),
-      ]
-    )
+    match_pattern(parser, &[Lookup::Equal(Self::STARTS.tokens)])
   }
 
   pub fn parse<'arena, 'a, 'b>(
@@ -89,19 +87,25 @@ impl SinglesParser {
           TokenType::True => Ok(BooleanNode::loc(true, parser.gen_loc(start_loc))),
           TokenType::False => Ok(BooleanNode::loc(false, parser.gen_loc(start_loc))),
           TokenType::Null => Ok(NullNode::loc(parser.gen_loc(start_loc))),
-          TokenType::Inline => Ok(InlineNode::loc(key.value.to_owned(), parser.gen_loc(start_loc))),
+          TokenType::Inline => Ok(InlineNode::loc(key.value, parser.gen_loc(start_loc))),
           _ => Err(ParserError::Internal),
         };
       }
       let key_type = key.token_type;
+      let operand_precedence = match key_type {
+        TokenType::New | TokenType::Clone => precedence::NEW,
+        TokenType::Print => precedence::PRINT,
+        _ => 0,
+      };
       let argument = parser
         .get_statement(
-          &mut LoopArgument::with_tokens(
+          &mut LoopArgument::safe(
             parser.arena,
             "singles",
             &args.separators.combine(&[TokenType::Semicolon]),
-            &args.breakers.combine(&[TokenType::RightCurlyBracket])
-          )
+            &args.breakers.combine(&[TokenType::RightCurlyBracket]),
+            &DEFAULT_PARSERS
+          ).with_precedence(operand_precedence)
         )?
         .into_boxed(parser.arena);
       match key_type {
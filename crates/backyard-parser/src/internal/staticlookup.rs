@@ -3,7 +3,7 @@ use backyard_nodes::{ ClassKeywordNode, Location, Node, StaticLookupNode, utils:
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,8 @@ use super::{ identifier::IdentifierParser, variable::VariableParser };
 pub struct StaticLookupParser;
 
 impl StaticLookupParser {
+  pub const STARTS: Starts = Starts::postfix(&[TokenType::DoubleColon]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
@@ -3,7 +3,7 @@ use backyard_nodes::{ Location, Node, NodeType, StaticNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,13 @@ use super::{ comment::CommentParser, property::PropertyItemParser };
 pub struct StaticsParser;
 
 impl StaticsParser {
+  pub const STARTS: Starts = Starts::postfix(
+    &[
+      TokenType::Variable,
+      TokenType::VariableBracketOpen,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
@@ -43,11 +50,16 @@ impl StaticsParser {
           &[TokenType::Comma],
           &[TokenType::Semicolon],
           &[
-            (CommentParser::test, CommentParser::parse),
-            (PropertyItemParser::test, PropertyItemParser::parse),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+            (PropertyItemParser::test, PropertyItemParser::parse, PropertyItemParser::STARTS),
           ]
         )
       )?;
+      if let Ok(last_token) = parser.get_token(parser.position - 1) {
+        if last_token.token_type == TokenType::Semicolon {
+          parser.position -= 1;
+        }
+      }
       return Ok(StaticNode::loc(items, parser.gen_loc(start_loc)));
     }
     Err(ParserError::Internal)
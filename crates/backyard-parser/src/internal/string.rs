@@ -1,4 +1,4 @@
-use bstr::BString;
+use bstr::ByteSlice;
 use bumpalo::{ collections::Vec, vec };
 use backyard_lexer::token::TokenType;
 use backyard_nodes::{
@@ -15,7 +15,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -25,6 +25,15 @@ use super::variable::VariableParser;
 pub struct StringParser;
 
 impl StringParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::EncapsedStringOpen,
+      TokenType::String,
+      TokenType::HeredocOpen,
+      TokenType::NowDocOpen,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -53,36 +62,34 @@ impl StringParser {
     if let [string_type] = matched.as_slice() {
       let string_type = string_type.as_equal(parser)?;
       if string_type.token_type == TokenType::NowDocOpen {
-        let label = string_type.value.to_owned();
+        let label = string_type.value;
         if let Ok(next) = parser.get_token(parser.position + 1) {
           if next.token_type == TokenType::NowDocClose {
-            let text = parser.get_token(parser.position)?.value.to_owned();
+            let text = parser.get_token(parser.position)?.value;
             parser.position += 2;
             return Ok(NowDocNode::loc(label, text, parser.gen_loc(start_loc)));
           }
         }
       } else if string_type.token_type == TokenType::HeredocOpen {
-        let label = string_type.value.to_owned();
+        let label = string_type.value;
         let values = StringParser::parse_encapsed(parser, args, TokenType::HeredocClose)?;
         return Ok(HereDocNode::loc(label, values, parser.gen_loc(start_loc)));
       } else if string_type.token_type == TokenType::EncapsedStringOpen {
-        let quote = string_type.value.to_owned();
+        let quote = string_type.value;
         let values = StringParser::parse_encapsed(parser, args, TokenType::EncapsedStringClose)?;
         return Ok(
           EncapsedNode::loc(
-            Quote::try_from(&quote).map_err(|_| ParserError::Internal)?,
+            Quote::try_from(quote).map_err(|_| ParserError::Internal)?,
             values,
             parser.gen_loc(start_loc)
           )
         );
       } else if string_type.token_type == TokenType::String {
-        let mut value = string_type.value.to_owned();
-        let quote = BString::new(std::vec![value.remove(0)]);
-        value.pop();
+        let value = string_type.value;
         return Ok(
           StringNode::loc(
-            Quote::try_from(&quote).map_err(|_| ParserError::Internal)?,
-            value,
+            Quote::try_from(value[..1].as_bstr()).map_err(|_| ParserError::Internal)?,
+            value[1..value.len() - 1].as_bstr(),
             parser.gen_loc(start_loc)
           )
         );
@@ -107,7 +114,7 @@ impl StringParser {
         }
         TokenType::EncapsedString => {
           let loc = i.get_range_location();
-          let value = i.value.to_owned();
+          let value = i.value;
           parser.position += 1;
           values.push(
             EncapsedPartNode::loc(
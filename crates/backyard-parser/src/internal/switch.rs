@@ -10,7 +10,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LocationHelper, LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -20,6 +20,8 @@ use super::{ block::BlockParser, comment::CommentParser };
 pub struct SwitchParser;
 
 impl SwitchParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Switch]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -58,8 +60,8 @@ impl SwitchParser {
           &[],
           &[TokenType::RightCurlyBracket, TokenType::EndSwitch],
           &[
-            (CaseParser::test, CaseParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (CaseParser::test, CaseParser::parse, CaseParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -80,6 +82,8 @@ impl SwitchParser {
 pub struct CaseParser;
 
 impl CaseParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Case, TokenType::Default]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -1,9 +1,16 @@
 use backyard_lexer::token::TokenType;
-use backyard_nodes::{ Location, Node, TernaryNode, utils::IntoBoxedNode };
+use backyard_nodes::{ precedence, Location, Node, TernaryNode, utils::IntoBoxedNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine, DEFAULT_PARSERS },
+  parser::{
+    LoopArgument,
+    OptionNodeOrInternal,
+    Parser,
+    Starts,
+    TokenTypeArrayCombine,
+    DEFAULT_PARSERS,
+  },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,11 +18,16 @@ use crate::{
 pub struct TernaryParser;
 
 impl TernaryParser {
+  pub const STARTS: Starts = Starts::postfix(&[TokenType::QuestionMark]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     args: &mut LoopArgument
   ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
     args.last_expr.as_ref()?;
+    if precedence::TERNARY < args.precedence {
+      return None;
+    }
     match_pattern(parser, &[Lookup::Equal(&[TokenType::QuestionMark])])
   }
 
@@ -41,7 +53,7 @@ impl TernaryParser {
             &[],
             &args.breakers.combine(args.separators).combine(&[TokenType::Semicolon]),
             &DEFAULT_PARSERS
-          )
+          ).with_precedence(precedence::TERNARY + 1)
         )?
         .ok_internal()?;
       return Ok(
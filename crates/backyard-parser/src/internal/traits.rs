@@ -3,7 +3,7 @@ use backyard_nodes::{ BlockNode, Location, Node, TraitNode, utils::IntoBoxedNode
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LocationHelper, LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -21,6 +21,8 @@ use super::{
 pub struct TraitParser;
 
 impl TraitParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Trait]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -48,17 +50,18 @@ impl TraitParser {
           &[TokenType::Semicolon],
           &[TokenType::RightCurlyBracket],
           &[
-            (TraitUseParser::test, TraitUseParser::parse),
-            (MethodParser::test, MethodParser::parse),
-            (ConstPropertyParser::test, ConstPropertyParser::parse),
-            (PropertyParser::test, PropertyParser::parse),
-            (AttributeParser::test, AttributeParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (TraitUseParser::test, TraitUseParser::parse, TraitUseParser::STARTS),
+            (MethodParser::test, MethodParser::parse, MethodParser::STARTS),
+            (ConstPropertyParser::test, ConstPropertyParser::parse, ConstPropertyParser::STARTS),
+            (PropertyParser::test, PropertyParser::parse, PropertyParser::STARTS),
+            (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
       return Ok(
         TraitNode::loc(
+          bumpalo::vec![in parser.arena],
           name.into_boxed(parser.arena),
           BlockNode::loc(body, parser.gen_loc(block_loc)).into_boxed(parser.arena),
           parser.gen_loc(start_loc)
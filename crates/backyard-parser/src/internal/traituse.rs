@@ -12,7 +12,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -22,6 +22,8 @@ use super::{ comment::CommentParser, identifier::IdentifierParser };
 pub struct TraitUseParser;
 
 impl TraitUseParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Use]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -43,8 +45,8 @@ impl TraitUseParser {
           &[TokenType::Comma],
           &[TokenType::Semicolon, TokenType::LeftCurlyBracket],
           &[
-            (IdentifierParser::test, IdentifierParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -59,9 +61,13 @@ impl TraitUseParser {
             &[TokenType::Semicolon],
             &[TokenType::RightCurlyBracket],
             &[
-              (TraitUseAliasParser::test, TraitUseAliasParser::parse),
-              (TraitUsePrecedenceParser::test, TraitUsePrecedenceParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (TraitUseAliasParser::test, TraitUseAliasParser::parse, TraitUseAliasParser::STARTS),
+              (
+                TraitUsePrecedenceParser::test,
+                TraitUsePrecedenceParser::parse,
+                TraitUsePrecedenceParser::STARTS,
+              ),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?;
@@ -76,6 +82,17 @@ impl TraitUseParser {
 pub struct TraitUseAliasParser;
 
 impl TraitUseAliasParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::UnqualifiedName,
+      TokenType::QualifiedName,
+      TokenType::RelativeName,
+      TokenType::FullyQualifiedName,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -137,7 +154,7 @@ impl TraitUseAliasParser {
       let alias = alias.as_optional(parser).map(IdentifierParser::from_token);
       let visibility = visibility
         .as_optional(parser)
-        .and_then(|x| Visibility::try_from(&x.value).ok());
+        .and_then(|x| Visibility::try_from(x.value).ok());
       return Ok(
         TraitUseAliasNode::loc(
           trait_name_parsed.into_boxed(parser.arena),
@@ -156,6 +173,17 @@ impl TraitUseAliasParser {
 pub struct TraitUsePrecedenceParser;
 
 impl TraitUsePrecedenceParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::UnqualifiedName,
+      TokenType::QualifiedName,
+      TokenType::RelativeName,
+      TokenType::FullyQualifiedName,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -10,7 +10,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -25,6 +25,8 @@ use super::{
 pub struct TryParser;
 
 impl TryParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Try]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -47,9 +49,9 @@ impl TryParser {
           &[],
           &[],
           &[
-            (FinallyParser::test, FinallyParser::parse),
-            (CatchParser::test, CatchParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (FinallyParser::test, FinallyParser::parse, FinallyParser::STARTS),
+            (CatchParser::test, CatchParser::parse, CatchParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -63,6 +65,8 @@ impl TryParser {
 pub struct CatchParser;
 
 impl CatchParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Catch]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -87,8 +91,8 @@ impl CatchParser {
           &[TokenType::BitwiseOr],
           &[TokenType::Variable, TokenType::VariableBracketOpen, TokenType::RightParenthesis],
           &[
-            (IdentifierParser::test, IdentifierParser::parse),
-            (CommentParser::test, CommentParser::parse),
+            (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+            (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
           ]
         )
       )?;
@@ -103,8 +107,8 @@ impl CatchParser {
               &[],
               &[TokenType::RightParenthesis],
               &[
-                (VariableParser::test, VariableParser::parse),
-                (CommentParser::test, CommentParser::parse),
+                (VariableParser::test, VariableParser::parse, VariableParser::STARTS),
+                (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
               ]
             )
           )?;
@@ -129,6 +133,8 @@ impl CatchParser {
 pub struct FinallyParser;
 
 impl FinallyParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Finally]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
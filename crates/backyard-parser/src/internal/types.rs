@@ -2,7 +2,7 @@ use backyard_nodes::{ Location, Node };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -11,6 +11,8 @@ pub struct TypesParser;
 
 impl TypesParser {
   #[allow(unused_assignments)]
+  pub const STARTS: Starts = Starts::ANY;
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -11,7 +11,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -21,6 +21,8 @@ use super::{ comment::CommentParser, identifier::IdentifierParser };
 pub struct UseParser;
 
 impl UseParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Use]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -47,7 +49,7 @@ impl UseParser {
         p += 1;
       }
       if has_bracket {
-        let name = parser.get_token(parser.position)?.value.to_owned();
+        let name = parser.get_token(parser.position)?.value;
         parser.position += 1;
 
         let items = {
@@ -62,8 +64,8 @@ impl UseParser {
                   &[TokenType::Comma],
                   &[TokenType::RightCurlyBracket],
                   &[
-                    (UseItemParser::test, UseItemParser::parse),
-                    (CommentParser::test, CommentParser::parse),
+                    (UseItemParser::test, UseItemParser::parse, UseItemParser::STARTS),
+                    (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
                   ]
                 )
               )?;
@@ -80,11 +82,16 @@ impl UseParser {
             &[TokenType::Comma],
             &[TokenType::Semicolon],
             &[
-              (UseItemParser::test, UseItemParser::parse),
-              (CommentParser::test, CommentParser::parse),
+              (UseItemParser::test, UseItemParser::parse, UseItemParser::STARTS),
+              (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
             ]
           )
         )?;
+        if let Ok(last_token) = parser.get_token(parser.position - 1) {
+          if last_token.token_type == TokenType::Semicolon {
+            parser.position -= 1;
+          }
+        }
         return Ok(UseNode::loc(None, items, parser.gen_loc(start_loc)));
       }
     }
@@ -96,6 +103,19 @@ impl UseParser {
 pub struct UseItemParser;
 
 impl UseItemParser {
+  pub const STARTS: Starts = Starts::tokens(
+    &[
+      TokenType::Function,
+      TokenType::Const,
+      TokenType::UnqualifiedName,
+      TokenType::QualifiedName,
+      TokenType::RelativeName,
+      TokenType::FullyQualifiedName,
+      TokenType::Get,
+      TokenType::Set,
+    ]
+  );
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -127,8 +147,8 @@ impl UseItemParser {
     if let [modifier, name] = matched.as_slice() {
       let modifier = modifier
         .as_optional(parser)
-        .and_then(|x| UseItemModifier::try_from(&x.value).ok());
-      let name = name.as_equal(parser)?.value.to_owned();
+        .and_then(|x| UseItemModifier::try_from(x.value).ok());
+      let name = name.as_equal(parser)?.value;
       let mut alias = None;
       if let Ok(last) = parser.get_token(parser.position) {
         if last.token_type == TokenType::As {
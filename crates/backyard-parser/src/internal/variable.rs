@@ -4,7 +4,7 @@ use bumpalo::Bump;
 
 use crate::{
   error::ParserError,
-  parser::{ LocationHelper, LoopArgument, Parser },
+  parser::{ LoopArgument, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -14,7 +14,7 @@ use super::identifier::IdentifierParser;
 pub struct VariableParser;
 
 impl VariableParser {
-  pub fn from_token<'arena>(arena: &'arena Bump, name: &Token) -> Node<'arena> {
+  pub fn from_token<'arena>(arena: &'arena Bump, name: &Token<'arena>) -> Node<'arena> {
     let id = IdentifierParser::from_token(name);
     let loc = id.loc.clone();
     VariableParser::new_bracked(arena, id, loc)
@@ -30,6 +30,8 @@ impl VariableParser {
 }
 
 impl VariableParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Variable, TokenType::VariableBracketOpen]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -56,14 +58,7 @@ impl VariableParser {
         )?;
         parser.position += 1;
         if let Some(expr) = expr {
-          let end_loc = parser.get_token(parser.position)?.get_location().unwrap();
-          return Ok(
-            VariableParser::new_bracked(
-              parser.arena,
-              expr,
-              Some(RangeLocation { start: start_loc, end: end_loc })
-            )
-          );
+          return Ok(VariableParser::new_bracked(parser.arena, expr, parser.gen_loc(start_loc)));
         }
       } else {
         return Ok(VariableParser::from_token(parser.arena, name));
@@ -3,7 +3,7 @@ use backyard_nodes::{ Location, Node, WhileNode, utils::IntoBoxedNode };
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -13,6 +13,8 @@ use super::block::BlockParser;
 pub struct WhileParser;
 
 impl WhileParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::While]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
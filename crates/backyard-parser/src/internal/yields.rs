@@ -2,6 +2,7 @@ use backyard_lexer::token::TokenType;
 use backyard_nodes::{
   Location,
   Node,
+  precedence,
   YieldFromNode,
   YieldNode,
   utils::{ IntoBoxedNode, IntoBoxedOptionNode },
@@ -9,7 +10,7 @@ use backyard_nodes::{
 
 use crate::{
   error::ParserError,
-  parser::{ LoopArgument, OptionNodeOrInternal, Parser, TokenTypeArrayCombine },
+  parser::{ LoopArgument, OptionNodeOrInternal, Parser, Starts, TokenTypeArrayCombine },
   utils::{ match_pattern, Lookup, LookupResult },
 };
 
@@ -17,6 +18,8 @@ use crate::{
 pub struct YieldParser;
 
 impl YieldParser {
+  pub const STARTS: Starts = Starts::tokens(&[TokenType::Yield]);
+
   pub fn test<'arena, 'a>(
     parser: &mut Parser<'arena, 'a>,
     _: &mut LoopArgument
@@ -42,7 +45,7 @@ impl YieldParser {
               "yield_from",
               &[],
               &args.breakers.combine(args.separators)
-            )
+            ).with_precedence(precedence::PRINT)
           )?
           .ok_internal()?;
         return Ok(YieldFromNode::loc(expr.into_boxed(parser.arena), parser.gen_loc(start_loc)));
@@ -53,7 +56,7 @@ impl YieldParser {
           "yield",
           &[],
           &args.breakers.combine(args.separators).combine(&[TokenType::Arrow])
-        )
+        ).with_precedence(precedence::PRINT)
       )?;
       if value.is_none() {
         return Ok(YieldNode::loc(None, None, parser.gen_loc(start_loc)));
@@ -70,7 +73,7 @@ impl YieldParser {
                 "singles",
                 &args.separators.combine(&[]),
                 &args.breakers.combine(&[TokenType::Semicolon])
-              )
+              ).with_precedence(precedence::PRINT)
             )?
             .ok_internal()?
         );
@@ -1,14 +1,20 @@
+mod incremental;
 mod internal;
 mod parser;
 mod utils;
+mod version;
 pub mod error;
 
-use backyard_lexer::{ lex_byte_in, token::Token };
+use backyard_lexer::{ lex_byte_in, lex_version_in, token::{ Token, TokenType } };
 use backyard_nodes::{ serde::node::SerializableNode, Location, Node, ProgramNode, RangeLocation };
 use bumpalo::Bump;
 use error::ParserError;
 use parser::{ LocationHelper, LoopArgument, Parser };
 
+pub use backyard_lexer::version::PhpVersion;
+pub use incremental::TextEdit;
+
+/// Parse `input` into an owned copy of the tree, `parse_in` keeps the nodes in `arena` instead
 pub fn parse(is_eval: bool, input: &str) -> Result<SerializableNode, ParserError> {
   let arena = Bump::new();
   let result = parse_byte_in(&arena, is_eval, input.as_bytes())?;
@@ -18,7 +24,7 @@ pub fn parse(is_eval: bool, input: &str) -> Result<SerializableNode, ParserError
 pub fn parse_in<'arena>(
   arena: &'arena Bump,
   is_eval: bool,
-  input: &str
+  input: &'arena str
 ) -> Result<Node<'arena>, ParserError> {
   parse_byte_in(arena, is_eval, input.as_bytes())
 }
@@ -32,14 +38,29 @@ pub fn parse_byte(is_eval: bool, input: &[u8]) -> Result<SerializableNode, Parse
 pub fn parse_byte_in<'arena>(
   arena: &'arena Bump,
   is_eval: bool,
-  input: &[u8]
+  input: &'arena [u8]
 ) -> Result<Node<'arena>, ParserError> {
   let tokens = lex_byte_in(arena, is_eval, input).map_err(|x| ParserError::LexError(x.to_owned()))?;
   parse_tokens_in(arena, &tokens)
 }
 
-pub fn parse_tokens<'arena>(
-  tokens: &bumpalo::collections::Vec<'arena, Token>
+/// Apply `edits` to `input`, the source `tree` was parsed from, and update `tree` to match the
+/// edited source, which is returned. Edits are byte ranges of `input`, sorted and not
+/// overlapping. Only the statements around the edits are lexed and parsed again, the rest of
+/// the tree is kept and its locations are shifted, falling back to a full parse when the edits
+/// can change how the surrounding code is parsed.
+pub fn reparse_byte_in<'arena>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  tree: &mut Node<'arena>,
+  input: &[u8],
+  edits: &[TextEdit]
+) -> Result<Vec<u8>, ParserError> {
+  incremental::reparse(arena, is_eval, tree, input, edits)
+}
+
+pub fn parse_tokens(
+  tokens: &bumpalo::collections::Vec<'_, Token<'_>>
 ) -> Result<SerializableNode, ParserError> {
   let arena = Bump::new();
   let result = parse_tokens_in(&arena, tokens)?;
@@ -48,19 +69,110 @@ pub fn parse_tokens<'arena>(
 
 pub fn parse_tokens_in<'arena>(
   arena: &'arena Bump,
-  tokens: &bumpalo::collections::Vec<'arena, Token>
+  tokens: &bumpalo::collections::Vec<'_, Token<'arena>>
+) -> Result<Node<'arena>, ParserError> {
+  parse_tokens_version_in(arena, tokens, PhpVersion::latest())
+}
+
+/// Parse `input`, rejecting syntax introduced after `version`
+pub fn parse_version(
+  is_eval: bool,
+  input: &str,
+  version: PhpVersion
+) -> Result<SerializableNode, ParserError> {
+  let arena = Bump::new();
+  let result = parse_version_in(&arena, is_eval, input, version)?;
+  Ok(result.serializable())
+}
+
+pub fn parse_version_in<'arena>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  input: &'arena str,
+  version: PhpVersion
+) -> Result<Node<'arena>, ParserError> {
+  let tokens = lex_version_in(arena, is_eval, input.as_bytes(), version).map_err(
+    ParserError::LexError
+  )?;
+  parse_tokens_version_in(arena, &tokens, version)
+}
+
+fn parse_tokens_version_in<'arena>(
+  arena: &'arena Bump,
+  tokens: &bumpalo::collections::Vec<'_, Token<'arena>>,
+  version: PhpVersion
 ) -> Result<Node<'arena>, ParserError> {
   if tokens.is_empty() {
     return Err(ParserError::Eof);
   }
   let mut parser = Parser::new(arena, tokens);
-  Ok(
-    ProgramNode::loc(
-      parser.get_children(&mut LoopArgument::default(arena, "main"))?,
-      Some(RangeLocation {
-        start: Location { line: 1, column: 0, offset: 0 },
-        end: tokens.last().unwrap().get_location().unwrap(),
-      })
-    )
-  )
+  let program = ProgramNode::loc(
+    parser.get_children(&mut LoopArgument::default(arena, "main"))?,
+    Some(RangeLocation {
+      start: Location { line: 1, column: 0, offset: 0 },
+      end: tokens.last().unwrap().get_range_location().unwrap().end,
+    })
+  );
+  match version::check(&program, tokens, version).into_iter().next() {
+    Some(error) => Err(error),
+    None => Ok(program),
+  }
+}
+
+pub fn parse_recovering(is_eval: bool, input: &str) -> (SerializableNode, Vec<ParserError>) {
+  let arena = Bump::new();
+  let (result, errors) = parse_recovering_in(&arena, is_eval, input);
+  (result.serializable(), errors)
+}
+
+pub fn parse_recovering_in<'arena>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  input: &'arena str
+) -> (Node<'arena>, Vec<ParserError>) {
+  parse_recovering_version_in(arena, is_eval, input, PhpVersion::latest())
+}
+
+/// Like `parse_recovering_in`, syntax introduced after `version` is reported with the errors
+pub fn parse_recovering_version_in<'arena>(
+  arena: &'arena Bump,
+  is_eval: bool,
+  input: &'arena str,
+  version: PhpVersion
+) -> (Node<'arena>, Vec<ParserError>) {
+  let tokens = match lex_version_in(arena, is_eval, input.as_bytes(), version) {
+    Ok(tokens) => tokens,
+    Err(err) => {
+      return (ProgramNode::loc(bumpalo::vec![in arena], None), vec![ParserError::LexError(err)]);
+    }
+  };
+  let (program, mut errors) = parse_tokens_recovering_in(arena, &tokens);
+  errors.extend(version::check(&program, &tokens, version));
+  (program, errors)
+}
+
+/// Parse already lexed `tokens`, skipping statements that fail to parse like
+/// `parse_recovering_in` does
+pub fn parse_tokens_recovering_in<'arena>(
+  arena: &'arena Bump,
+  tokens: &bumpalo::collections::Vec<'_, Token<'arena>>
+) -> (Node<'arena>, Vec<ParserError>) {
+  if tokens.is_empty() {
+    return (ProgramNode::loc(bumpalo::vec![in arena], None), vec![ParserError::Eof]);
+  }
+  let mut parser = Parser::new_recovering(arena, tokens);
+  let children = parser
+    .get_children(&mut LoopArgument::with_tokens(arena, "main", &[TokenType::Semicolon], &[]))
+    .unwrap_or_else(|err| {
+      parser.errors.push(err);
+      bumpalo::vec![in arena]
+    });
+  let program = ProgramNode::loc(
+    children,
+    Some(RangeLocation {
+      start: Location { line: 1, column: 0, offset: 0 },
+      end: tokens.last().unwrap().get_range_location().unwrap().end,
+    })
+  );
+  (program, parser.errors)
 }
@@ -1,10 +1,10 @@
-use std::{ collections::HashSet, fmt::Debug };
+use std::{ collections::HashSet, fmt::Debug, sync::LazyLock };
 
 use bumpalo::{ vec, collections::Vec, Bump };
-use backyard_lexer::token::{ Token, TokenType };
-use backyard_nodes::{ Location, Node, NodeType, RangeLocation };
+use backyard_lexer::token::{ OwnedToken, Token, TokenType };
+use backyard_nodes::{ ErrorNode, Location, Node, NodeType, RangeLocation };
 use crate::{
-  error::ParserError,
+  error::{ Expected, ParserDiagnostic, ParserError },
   internal::{
     attribute::AttributeParser,
     block::BlockParser,
@@ -17,7 +17,7 @@ use crate::{
 
 use super::internal::{
   halt::HaltParser,
-  array::ArrayParser,
+  array::{ ArrayItemParser, ArrayParser },
   arraylookup::ArrayLookupParser,
   assignment::AssignmentParser,
   bin::BinParser,
@@ -70,60 +70,144 @@ type InternalParserParse = for<'arena, 'a, 'b> fn(
   start_loc: Location,
   _: &mut LoopArgument<'arena, 'b>
 ) -> Result<Node<'arena>, ParserError>;
-type InternalParser = (InternalParserTest, InternalParserParse);
+type InternalParser = (InternalParserTest, InternalParserParse, Starts);
+
+/// Tokens a parser can start at, and whether it continues `last_expr`. `find_match` only runs the
+/// `test` of parsers accepting the current token
+#[derive(Debug, Clone, Copy)]
+pub struct Starts {
+  /// Empty when the parser can start at any token
+  pub(crate) tokens: &'static [TokenType],
+  /// `Some(true)` when the parser needs `last_expr`, `Some(false)` when it must be empty
+  last_expr: Option<bool>,
+}
+
+impl Starts {
+  pub const ANY: Starts = Starts { tokens: &[], last_expr: None };
+
+  pub const fn tokens(tokens: &'static [TokenType]) -> Self {
+    Starts { tokens, last_expr: None }
+  }
+
+  /// Operators and accessors that continue `last_expr`
+  pub const fn postfix(tokens: &'static [TokenType]) -> Self {
+    Starts { tokens, last_expr: Some(true) }
+  }
+
+  /// Operators that start an expression, the same tokens after an expression belong to others
+  pub const fn prefix(tokens: &'static [TokenType]) -> Self {
+    Starts { tokens, last_expr: Some(false) }
+  }
+
+  fn accepts_token(&self, token_type: TokenType) -> bool {
+    self.tokens.is_empty() || self.tokens.contains(&token_type)
+  }
+
+  fn accepts(&self, token_type: TokenType, has_last_expr: bool) -> bool {
+    self.last_expr.is_none_or(|x| x == has_last_expr) && self.accepts_token(token_type)
+  }
+}
+
+/// Candidates of a parser set for each token type, in their original order
+struct Dispatch {
+  by_token: std::vec::Vec<std::vec::Vec<InternalParser>>,
+  any: std::vec::Vec<InternalParser>,
+}
+
+impl Dispatch {
+  fn new(parsers: &[InternalParser]) -> Self {
+    let size = parsers
+      .iter()
+      .flat_map(|(_, _, starts)| starts.tokens)
+      .map(|x| (*x as usize) + 1)
+      .max()
+      .unwrap_or(0);
+    let by_token = (0..size)
+      .map(|index| {
+        parsers
+          .iter()
+          .filter(|(_, _, starts)| {
+            starts.tokens.is_empty() || starts.tokens.iter().any(|x| (*x as usize) == index)
+          })
+          .copied()
+          .collect()
+      })
+      .collect();
+    let any = parsers
+      .iter()
+      .filter(|(_, _, starts)| starts.tokens.is_empty())
+      .copied()
+      .collect();
+    Dispatch { by_token, any }
+  }
+
+  fn candidates(&self, token_type: TokenType) -> &[InternalParser] {
+    self.by_token.get(token_type as usize).unwrap_or(&self.any)
+  }
+}
+
+static DEFAULT_DISPATCH: LazyLock<Dispatch> = LazyLock::new(|| Dispatch::new(&DEFAULT_PARSERS));
 pub static DEFAULT_PARSERS: [InternalParser; 47] = [
-  (HaltParser::test, HaltParser::parse),
-  (CommentParser::test, CommentParser::parse),
-  (ListParser::test, ListParser::parse),
-  (ArrayLookupParser::test, ArrayLookupParser::parse),
-  (ArrayParser::test, ArrayParser::parse),
-  (AssignmentParser::test, AssignmentParser::parse),
-  (BinParser::test, BinParser::parse),
-  (ObjectAccessParser::test, ObjectAccessParser::parse),
-  (DeclareParser::test, DeclareParser::parse),
-  (DoWhileParser::test, DoWhileParser::parse),
-  (NamespaceParser::test, NamespaceParser::parse),
-  (IncludeParser::test, IncludeParser::parse),
-  (TryParser::test, TryParser::parse),
-  (IfParser::test, IfParser::parse),
-  (MatchParser::test, MatchParser::parse),
-  (UseParser::test, UseParser::parse),
-  (ForParser::test, ForParser::parse),
-  (ForeachParser::test, ForeachParser::parse),
-  (FunctionParser::test, FunctionParser::parse),
-  (CallParser::test, CallParser::parse),
-  (ParenthesisParser::test, ParenthesisParser::parse),
-  (ClassParser::test, ClassParser::parse),
-  (InterfaceParser::test, InterfaceParser::parse),
-  (TraitParser::test, TraitParser::parse),
-  (EnumParser::test, EnumParser::parse),
-  (ConstParser::test, ConstParser::parse),
-  (EvalParser::test, EvalParser::parse),
-  (ExitParser::test, ExitParser::parse),
-  (MagicParser::test, MagicParser::parse),
-  (NumberParser::test, NumberParser::parse),
-  (PostParser::test, PostParser::parse),
-  (PreParser::test, PreParser::parse),
-  (StaticLookupParser::test, StaticLookupParser::parse),
-  (YieldParser::test, YieldParser::parse),
-  (StringParser::test, StringParser::parse),
-  (SwitchParser::test, SwitchParser::parse),
-  (StaticsParser::test, StaticsParser::parse),
-  (GlobalParser::test, GlobalParser::parse),
-  (VariableParser::test, VariableParser::parse),
-  (TernaryParser::test, TernaryParser::parse),
-  (WhileParser::test, WhileParser::parse),
-  (LabelParser::test, LabelParser::parse),
-  (IdentifierParser::test, IdentifierParser::parse),
-  (SinglesParser::test, SinglesParser::parse),
-  (EchoParser::test, EchoParser::parse),
-  (AttributeParser::test, AttributeParser::parse),
-  (BlockParser::test, BlockParser::parse),
+  (HaltParser::test, HaltParser::parse, HaltParser::STARTS),
+  (CommentParser::test, CommentParser::parse, CommentParser::STARTS),
+  (ListParser::test, ListParser::parse, ListParser::STARTS),
+  (ArrayLookupParser::test, ArrayLookupParser::parse, ArrayLookupParser::STARTS),
+  (ArrayParser::test, ArrayParser::parse, ArrayParser::STARTS),
+  (AssignmentParser::test, AssignmentParser::parse, AssignmentParser::STARTS),
+  (BinParser::test, BinParser::parse, BinParser::STARTS),
+  (ObjectAccessParser::test, ObjectAccessParser::parse, ObjectAccessParser::STARTS),
+  (DeclareParser::test, DeclareParser::parse, DeclareParser::STARTS),
+  (DoWhileParser::test, DoWhileParser::parse, DoWhileParser::STARTS),
+  (NamespaceParser::test, NamespaceParser::parse, NamespaceParser::STARTS),
+  (IncludeParser::test, IncludeParser::parse, IncludeParser::STARTS),
+  (TryParser::test, TryParser::parse, TryParser::STARTS),
+  (IfParser::test, IfParser::parse, IfParser::STARTS),
+  (MatchParser::test, MatchParser::parse, MatchParser::STARTS),
+  (UseParser::test, UseParser::parse, UseParser::STARTS),
+  (ForParser::test, ForParser::parse, ForParser::STARTS),
+  (ForeachParser::test, ForeachParser::parse, ForeachParser::STARTS),
+  (FunctionParser::test, FunctionParser::parse, FunctionParser::STARTS),
+  (CallParser::test, CallParser::parse, CallParser::STARTS),
+  (ParenthesisParser::test, ParenthesisParser::parse, ParenthesisParser::STARTS),
+  (ClassParser::test, ClassParser::parse, ClassParser::STARTS),
+  (InterfaceParser::test, InterfaceParser::parse, InterfaceParser::STARTS),
+  (TraitParser::test, TraitParser::parse, TraitParser::STARTS),
+  (EnumParser::test, EnumParser::parse, EnumParser::STARTS),
+  (ConstParser::test, ConstParser::parse, ConstParser::STARTS),
+  (EvalParser::test, EvalParser::parse, EvalParser::STARTS),
+  (ExitParser::test, ExitParser::parse, ExitParser::STARTS),
+  (MagicParser::test, MagicParser::parse, MagicParser::STARTS),
+  (NumberParser::test, NumberParser::parse, NumberParser::STARTS),
+  (PostParser::test, PostParser::parse, PostParser::STARTS),
+  (PreParser::test, PreParser::parse, PreParser::STARTS),
+  (StaticLookupParser::test, StaticLookupParser::parse, StaticLookupParser::STARTS),
+  (YieldParser::test, YieldParser::parse, YieldParser::STARTS),
+  (StringParser::test, StringParser::parse, StringParser::STARTS),
+  (SwitchParser::test, SwitchParser::parse, SwitchParser::STARTS),
+  (StaticsParser::test, StaticsParser::parse, StaticsParser::STARTS),
+  (GlobalParser::test, GlobalParser::parse, GlobalParser::STARTS),
+  (VariableParser::test, VariableParser::parse, VariableParser::STARTS),
+  (TernaryParser::test, TernaryParser::parse, TernaryParser::STARTS),
+  (WhileParser::test, WhileParser::parse, WhileParser::STARTS),
+  (LabelParser::test, LabelParser::parse, LabelParser::STARTS),
+  (IdentifierParser::test, IdentifierParser::parse, IdentifierParser::STARTS),
+  (SinglesParser::test, SinglesParser::parse, SinglesParser::STARTS),
+  (EchoParser::test, EchoParser::parse, EchoParser::STARTS),
+  (AttributeParser::test, AttributeParser::parse, AttributeParser::STARTS),
+  (BlockParser::test, BlockParser::parse, BlockParser::STARTS),
 ];
 
+static ARRAY_DISPATCH: LazyLock<Dispatch> = LazyLock::new(|| Dispatch::new(&*ARRAY_PARSERS));
+/// `DEFAULT_PARSERS` with array items first, for the values of an array
+pub static ARRAY_PARSERS: LazyLock<[InternalParser; 48]> = LazyLock::new(|| {
+  let item: InternalParser = (ArrayItemParser::test, ArrayItemParser::parse, ArrayItemParser::STARTS);
+  let mut parsers = [item; 48];
+  parsers[1..].copy_from_slice(&DEFAULT_PARSERS);
+  parsers
+});
+
 #[derive(Debug)]
 pub struct LoopArgument<'arena, 'a> {
-  #[allow(dead_code)]
   pub context: &'a str,
   pub parsers: &'a [InternalParser],
   pub separators: &'a [TokenType],
@@ -131,6 +215,8 @@ pub struct LoopArgument<'arena, 'a> {
   pub last_expr: Option<Node<'arena>>,
   pub statements: Option<Vec<'arena, Node<'arena>>>,
   pub should_fail: bool,
+  /// Operators binding looser than this end the expression, see `backyard_nodes::precedence`
+  pub precedence: u8,
 }
 
 impl<'arena, 'a> LoopArgument<'arena, 'a> {
@@ -149,6 +235,7 @@ impl<'arena, 'a> LoopArgument<'arena, 'a> {
       last_expr: None,
       statements: Some(vec![in arena]),
       should_fail: true,
+      precedence: 0,
     }
   }
 
@@ -167,6 +254,7 @@ impl<'arena, 'a> LoopArgument<'arena, 'a> {
       last_expr: None,
       statements: Some(vec![in arena]),
       should_fail: false,
+      precedence: 0,
     }
   }
 
@@ -179,6 +267,7 @@ impl<'arena, 'a> LoopArgument<'arena, 'a> {
       last_expr: None,
       statements: Some(vec![in arena]),
       should_fail: true,
+      precedence: 0,
     }
   }
 
@@ -196,27 +285,57 @@ impl<'arena, 'a> LoopArgument<'arena, 'a> {
       last_expr: None,
       statements: Some(vec![in arena]),
       should_fail: true,
+      precedence: 0,
     }
   }
+
+  pub fn with_precedence(mut self, precedence: u8) -> Self {
+    self.precedence = precedence;
+    self
+  }
 }
 
 pub struct Parser<'arena, 'a> {
   pub arena: &'arena Bump,
-  pub tokens: &'a [Token],
+  pub tokens: &'a [Token<'arena>],
+  pub position: usize,
+  pub is_recovering: bool,
+  pub errors: std::vec::Vec<ParserError>,
+  pub furthest_failure: Option<ParserFailure>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParserFailure {
   pub position: usize,
+  pub context: Option<String>,
+  pub expected: std::vec::Vec<Expected>,
 }
 
 impl<'arena, 'a> Parser<'arena, 'a> {
-  pub fn new(arena: &'arena Bump, tokens: &'a [Token]) -> Self {
+  pub fn new(arena: &'arena Bump, tokens: &'a [Token<'arena>]) -> Self {
+    Parser {
+      arena,
+      tokens,
+      position: 0,
+      is_recovering: false,
+      errors: std::vec::Vec::new(),
+      furthest_failure: None,
+    }
+  }
+
+  pub fn new_recovering(arena: &'arena Bump, tokens: &'a [Token<'arena>]) -> Self {
     Parser {
       arena,
       tokens,
       position: 0,
+      is_recovering: true,
+      errors: std::vec::Vec::new(),
+      furthest_failure: None,
     }
   }
 
   #[inline]
-  pub fn get_token(&self, index: usize) -> Result<&Token, ParserError> {
+  pub fn get_token(&self, index: usize) -> Result<&'a Token<'arena>, ParserError> {
     self.tokens.get(index).ok_or(ParserError::Eof)
   }
 
@@ -224,18 +343,64 @@ impl<'arena, 'a> Parser<'arena, 'a> {
     &mut self,
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Vec<'arena, Node<'arena>>, ParserError> {
+    self.get_children_until(args).map(|(children, _)| children)
+  }
+
+  /// Like `get_children`, for lists that must end with one of `args.breakers`, e.g. the `]` of
+  /// `[1, 2]`. Fails when the file ends first.
+  pub fn get_closed_children<'b>(
+    &mut self,
+    args: &mut LoopArgument<'arena, 'b>
+  ) -> Result<Vec<'arena, Node<'arena>>, ParserError> {
+    let (children, is_closed) = self.get_children_until(args)?;
+    if !is_closed {
+      return Err(self.expected(args.context, args.breakers));
+    }
+    Ok(children)
+  }
+
+  /// Consume `closer`, which ends what `context` parsed, e.g. the `)` of `($a)`
+  pub fn expect_closer(&mut self, context: &str, closer: TokenType) -> Result<(), ParserError> {
+    match self.tokens.get(self.position) {
+      Some(token) if token.token_type == closer => {
+        self.position += 1;
+        Ok(())
+      }
+      _ => Err(self.expected(context, &[closer])),
+    }
+  }
+
+  fn expected(&mut self, context: &str, expected: &[TokenType]) -> ParserError {
+    self.fail_at(self.position, Some(context), expected);
+    self.diagnose(context, self.position, ParserError::Internal)
+  }
+
+  /// Children and whether they ended at one of `args.breakers`
+  fn get_children_until<'b>(
+    &mut self,
+    args: &mut LoopArgument<'arena, 'b>
+  ) -> Result<(Vec<'arena, Node<'arena>>, bool), ParserError> {
+    let mut is_closed = false;
     while let Some(token) = self.tokens.get(self.position) {
       if args.breakers.contains(&token.token_type) {
         self.position += 1;
+        is_closed = true;
         break;
       }
       if args.separators.contains(&token.token_type) {
         self.position += 1;
         continue;
       }
+      let statement_start = self.position;
       let statement = self.get_statement(args);
       if statement.is_err() {
-        return Err(statement.err().unwrap());
+        let err = statement.err().unwrap();
+        if self.is_recovering {
+          let node = self.recover(args, statement_start, err);
+          args.statements.as_mut().unwrap().push(node);
+          continue;
+        }
+        return Err(err);
       }
       if let Some(statement) = statement.unwrap() {
         args.statements.as_mut().unwrap().push(statement);
@@ -243,7 +408,7 @@ impl<'arena, 'a> Parser<'arena, 'a> {
         break;
       }
     }
-    Ok(args.statements.take().unwrap())
+    Ok((args.statements.take().unwrap(), is_closed))
   }
 
   pub fn get_statement<'b>(
@@ -297,6 +462,7 @@ impl<'arena, 'a> Parser<'arena, 'a> {
             NodeType::Finally,
             NodeType::CommentLine,
             NodeType::CommentBlock,
+            NodeType::CommentDoc,
             NodeType::Inline,
             NodeType::Program,
             NodeType::PropertyHook,
@@ -313,6 +479,9 @@ impl<'arena, 'a> Parser<'arena, 'a> {
       }
     }
 
+    if args.last_expr.is_none() {
+      self.fail_with(self.position, Some(args.context), &[Expected::Expression]);
+    }
     Ok(args.last_expr.take())
   }
 
@@ -321,26 +490,71 @@ impl<'arena, 'a> Parser<'arena, 'a> {
     &mut self,
     args: &mut LoopArgument<'arena, 'b>
   ) -> Result<Option<Node<'arena>>, ParserError> {
-    for (test, parse) in args.parsers {
+    let start_position = self.position;
+    let mut failure = None;
+    let token_type = self.tokens.get(self.position).map(|x| x.token_type);
+    let parsers = match token_type {
+      Some(token_type) if std::ptr::eq(args.parsers, &DEFAULT_PARSERS) => {
+        DEFAULT_DISPATCH.candidates(token_type)
+      }
+      Some(token_type) if std::ptr::eq(args.parsers, ARRAY_PARSERS.as_slice()) => {
+        ARRAY_DISPATCH.candidates(token_type)
+      }
+      _ => args.parsers,
+    };
+    let has_last_expr = args.last_expr.is_some();
+    for (test, parse, starts) in parsers {
+      if let Some(token_type) = token_type {
+        if !starts.accepts(token_type, has_last_expr) {
+          continue;
+        }
+      }
       if let Some(matched) = test(self, args) {
-        if let Some(start_loc) = self.tokens.get(self.position).map(|x| x.get_location().unwrap()) {
+        // Operators and accessors continuing `last_expr` start where it starts
+        let operand_loc = match starts.last_expr {
+          Some(true) => args.last_expr.as_ref().and_then(|x| x.get_location()),
+          _ => None,
+        };
+        let start_loc = operand_loc.or_else(||
+          self.tokens.get(self.position).map(|x| x.get_location().unwrap())
+        );
+        if let Some(start_loc) = start_loc {
           self.position += matched
             .iter()
             .map(|x| x.size)
             .sum::<usize>();
-          if let Ok(parsed) = parse(self, matched, start_loc, args) {
-            return Ok(Some(parsed));
-          } else {
-            break;
+          match parse(self, matched, start_loc, args) {
+            Ok(parsed) => {
+              return Ok(Some(parsed));
+            }
+            Err(err) => {
+              let is_internal = err == ParserError::Internal;
+              let err = self.diagnose(args.context, start_position, err);
+              if self.is_recovering && !is_internal {
+                return Err(err);
+              }
+              failure = Some(err);
+              break;
+            }
           }
         } else {
           return Err(ParserError::Eof);
         }
       }
     }
+    if self.tokens.get(self.position).is_some() && failure.is_none() {
+      let mut expected = std::vec::Vec::new();
+      if args.last_expr.is_some() {
+        expected.extend(args.separators.iter().chain(args.breakers));
+      }
+      self.fail_at(self.position, Some(args.context), &expected);
+    }
     if args.should_fail {
-      if let Some(token) = self.tokens.get(self.position) {
-        Err(ParserError::UnexpectedToken(token.to_owned()))
+      if let Some(failure) = failure {
+        return Err(failure);
+      }
+      if self.tokens.get(self.position).is_some() {
+        Err(self.diagnose(args.context, self.position, ParserError::Internal))
       } else {
         Err(ParserError::Eof)
       }
@@ -349,9 +563,123 @@ impl<'arena, 'a> Parser<'arena, 'a> {
     }
   }
 
+  pub fn fail_at(&mut self, position: usize, context: Option<&str>, expected: &[TokenType]) {
+    let expected: std::vec::Vec<Expected> = expected
+      .iter()
+      .map(|x| Expected::Token(*x))
+      .collect();
+    self.fail_with(position, context, &expected);
+  }
+
+  fn fail_with(&mut self, position: usize, context: Option<&str>, expected: &[Expected]) {
+    if let Some(failure) = &mut self.furthest_failure {
+      if failure.position > position {
+        return;
+      }
+      if failure.position == position {
+        if failure.context.is_none() {
+          failure.context = context.map(|x| x.to_string());
+        }
+        for token in expected {
+          if !failure.expected.contains(token) {
+            failure.expected.push(*token);
+          }
+        }
+        return;
+      }
+    }
+    self.furthest_failure = Some(ParserFailure {
+      position,
+      context: context.map(|x| x.to_string()),
+      expected: expected.to_vec(),
+    });
+  }
+
+  fn diagnose(&self, context: &str, start_position: usize, err: ParserError) -> ParserError {
+    match err {
+      ParserError::Internal | ParserError::UnexpectedToken(_) => {
+        let mut position = self.position;
+        let mut context = context.to_string();
+        let mut expected = std::vec::Vec::new();
+        if let Some(failure) = &self.furthest_failure {
+          if failure.position >= start_position {
+            position = failure.position;
+            expected = failure.expected.to_owned();
+            if let Some(failure_context) = &failure.context {
+              context = failure_context.to_owned();
+            }
+          }
+        }
+        let found = self.tokens.get(position);
+        // At the end of the file, point right after the last token
+        let loc = match found {
+          Some(found) => found.get_range_location(),
+          None =>
+            self.tokens
+              .last()
+              .and_then(|x| x.get_range_location())
+              .map(|x| RangeLocation { start: x.end.clone(), end: x.end }),
+        };
+        ParserError::Diagnostic(
+          Box::new(ParserDiagnostic {
+            loc,
+            context,
+            expected,
+            found: found.map(OwnedToken::from),
+          })
+        )
+      }
+      _ => err,
+    }
+  }
+
+  /// Skips the statement starting at `start` after it failed to parse. Resumes after its `;`,
+  /// after the block it opens, or before the `}` or breaker ending the enclosing block, so the
+  /// statements that follow are still parsed.
+  fn recover<'b>(
+    &mut self,
+    args: &LoopArgument<'arena, 'b>,
+    start: usize,
+    error: ParserError
+  ) -> Node<'arena> {
+    self.errors.push(error);
+    self.position = (start + 1).min(self.tokens.len());
+    let first = self.tokens.get(start).map(|x| x.token_type);
+    let is_synced = matches!(first, Some(TokenType::Semicolon | TokenType::RightCurlyBracket));
+    let mut depth = usize::from(first == Some(TokenType::LeftCurlyBracket));
+    while let Some(token) = self.tokens.get(self.position).filter(|_| !is_synced) {
+      let token_type = token.token_type;
+      if
+        depth == 0 &&
+        (token_type == TokenType::RightCurlyBracket || args.breakers.contains(&token_type))
+      {
+        break;
+      }
+      self.position += 1;
+      match token_type {
+        TokenType::Semicolon if depth == 0 => {
+          break;
+        }
+        TokenType::LeftCurlyBracket => {
+          depth += 1;
+        }
+        TokenType::RightCurlyBracket => {
+          depth -= 1;
+          if depth == 0 {
+            break;
+          }
+        }
+        _ => {}
+      }
+    }
+    let start_loc = self.tokens.get(start).and_then(|x| x.get_location());
+    ErrorNode::loc(start_loc.and_then(|x| self.gen_loc(x)))
+  }
+
+  /// Range from `start` to the end of the last consumed token
   pub fn gen_loc(&self, start: Location) -> Option<RangeLocation> {
     let end = self.tokens.get(self.position - 1);
-    end.and_then(|x| x.get_location()).map(|end| RangeLocation { start, end })
+    end.and_then(|x| x.get_range_location()).map(|x| RangeLocation { start, end: x.end })
   }
 }
 
@@ -372,27 +700,17 @@ impl<'a> LocationHelper for &Node<'a> {
   }
 }
 
-impl LocationHelper for &Token {
+impl LocationHelper for &Token<'_> {
   #[inline]
   fn get_location(&self) -> Option<Location> {
     Some(Location { line: self.line, column: self.column, offset: self.offset })
   }
 
   fn get_range_location(&self) -> Option<RangeLocation> {
-    if let Some(start_loc) = self.get_location() {
-      let len = self.value.len() as u32;
-      let end_loc = Location {
-        line: start_loc.line,
-        column: start_loc.column + len,
-        offset: start_loc.offset + len,
-      };
-      Some(RangeLocation {
-        start: start_loc,
-        end: end_loc,
-      })
-    } else {
-      None
-    }
+    self.get_location().map(|start| RangeLocation {
+      start,
+      end: Location { line: self.end.line, column: self.end.column, offset: self.end.offset },
+    })
   }
 }
 
@@ -1,4 +1,4 @@
-use bstr::BString;
+use bstr::BStr;
 use bumpalo::collections::Vec;
 use backyard_lexer::token::{ Token, TokenType };
 use backyard_nodes::{
@@ -39,7 +39,10 @@ impl<'arena> LookupResult<'arena> {
     self.size == 0
   }
 
-  pub fn as_equal<'a>(&self, parser: &'a Parser<'arena, '_>) -> Result<&'a Token, ParserError> {
+  pub fn as_equal<'a>(
+    &self,
+    parser: &Parser<'arena, 'a>
+  ) -> Result<&'a Token<'arena>, ParserError> {
     if let LookupResultWrapper::Equal(v) = &self.wrapper {
       parser.tokens.get(*v).ok_or(ParserError::Internal)
     } else {
@@ -47,7 +50,7 @@ impl<'arena> LookupResult<'arena> {
     }
   }
 
-  pub fn as_optional<'a>(&self, parser: &'a Parser<'arena, '_>) -> Option<&'a Token> {
+  pub fn as_optional<'a>(&self, parser: &Parser<'arena, 'a>) -> Option<&'a Token<'arena>> {
     if let LookupResultWrapper::Optional(Some(v)) = &self.wrapper {
       parser.tokens.get(*v)
     } else {
@@ -89,7 +92,7 @@ impl ModifierResult {
       v.iter()
         .filter_map(|x| {
           if let Some(x) = parser.tokens.get(*x) {
-            Visibility::try_from(&x.value).ok()
+            Visibility::try_from(x.value).ok()
           } else {
             None
           }
@@ -101,10 +104,10 @@ impl ModifierResult {
   }
 
   pub fn as_custom<T, C>(&self, parser: &Parser, callback: C) -> Option<T>
-    where C: FnOnce(&BString) -> Result<T, String>
+    where C: FnOnce(&BStr) -> Result<T, String>
   {
     if let ModifierResult::Custom(Some(x)) = self {
-      if let Some(x) = parser.tokens.get(*x) { callback(&x.value).ok() } else { None }
+      if let Some(x) = parser.tokens.get(*x) { callback(x.value).ok() } else { None }
     } else {
       None
     }
@@ -128,7 +131,7 @@ const TYPES: [TokenType; 13] = [
 ];
 
 pub fn match_pattern<'arena, 'a>(
-  parser: &Parser<'arena, 'a>,
+  parser: &mut Parser<'arena, 'a>,
   pattern: &[Lookup]
 ) -> Option<std::vec::Vec<LookupResult<'arena>>> {
   let mut result = std::vec::Vec::with_capacity(pattern.len());
@@ -139,6 +142,9 @@ pub fn match_pattern<'arena, 'a>(
       Lookup::Equal(contains_tokens) => {
         let current_token = parser.tokens.get(check_position)?;
         if !contains_tokens.contains(&current_token.token_type) {
+          if check_position > parser.position {
+            parser.fail_at(check_position, None, contains_tokens);
+          }
           return None;
         }
         result.push(LookupResult {
@@ -200,7 +206,7 @@ pub fn match_pattern<'arena, 'a>(
                   Some(
                     TypeNode::loc(
                       true,
-                      next.value.to_owned(),
+                      next.value,
                       Some(RangeLocation {
                         start: start_loc,
                         end: end_loc,
@@ -365,7 +371,7 @@ fn parse_type<'arena, 'a>(parser: &Parser<'arena, 'a>, index: &mut usize) -> Opt
     }
     *index += 1;
     let loc = token.get_range_location();
-    return Some(TypeNode::loc(false, token.value.to_owned(), loc));
+    return Some(TypeNode::loc(false, token.value, loc));
   }
   None
 }
@@ -390,7 +396,7 @@ fn parse_union_or_intersection_type<'arena, 'a>(
         if TYPES.contains(&token.token_type) {
           last_token_type = Some(token.token_type);
           let loc = token.get_range_location();
-          result.push(TypeNode::loc(false, token.value.to_owned(), loc));
+          result.push(TypeNode::loc(false, token.value, loc));
           continue;
         } else if token.token_type == TokenType::LeftParenthesis {
           result.push(parse_type(parser, index)?);
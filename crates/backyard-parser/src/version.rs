@@ -0,0 +1,323 @@
+use backyard_lexer::{ token::{ Token, TokenType }, version::PhpVersion };
+use backyard_nodes::{ visitor::Visitor, Modifier, Node, NodeType, NodeWrapper };
+
+use crate::error::{ ParserError, UnsupportedSyntax };
+
+/// Syntax of `node`, parsed from `tokens`, introduced after `version`, in source order
+pub(crate) fn check(node: &Node, tokens: &[Token], version: PhpVersion) -> Vec<ParserError> {
+  if version == PhpVersion::latest() {
+    return vec![];
+  }
+  let mut checker = VersionChecker { version, tokens, errors: vec![], ancestors: vec![] };
+  node.visit(&mut checker);
+  checker.errors
+}
+
+struct VersionChecker<'a, 'b> {
+  version: PhpVersion,
+  /// Tokens of the checked tree, for syntax the tree does not keep such as trailing commas
+  tokens: &'a [Token<'b>],
+  errors: Vec<ParserError>,
+  /// Types of the nodes being visited, the current one last
+  ancestors: Vec<NodeType>,
+}
+
+/// Nodes whose children are evaluated at compile time
+const INITIALIZERS: [NodeType; 5] = [
+  NodeType::Parameter,
+  NodeType::ConstructorParameter,
+  NodeType::Static,
+  NodeType::Const,
+  NodeType::Attribute,
+];
+
+/// Nodes where `throw` is a statement. Conditions of control structures are not told apart
+/// from their bodies.
+const STATEMENT_PARENTS: [NodeType; 9] = [
+  NodeType::Program,
+  NodeType::Block,
+  NodeType::If,
+  NodeType::Else,
+  NodeType::While,
+  NodeType::DoWhile,
+  NodeType::For,
+  NodeType::Foreach,
+  NodeType::Declare,
+];
+
+impl VersionChecker<'_, '_> {
+  fn parent(&self) -> Option<NodeType> {
+    self.ancestors.iter().rev().nth(1).cloned()
+  }
+
+  fn require(&mut self, version: PhpVersion, construct: &str, node: &Node) {
+    if self.version < version {
+      self.errors.push(
+        ParserError::Unsupported(
+          Box::new(UnsupportedSyntax {
+            loc: node.loc.clone(),
+            construct: construct.to_string(),
+            version,
+          })
+        )
+      );
+    }
+  }
+
+  /// Tokens from `offset` on, without comments
+  fn tokens_after(&self, offset: u32) -> impl Iterator<Item = &Token<'_>> {
+    let start = self.tokens.partition_point(|token| token.offset < offset);
+    let mut in_doc = false;
+    self.tokens[start..].iter().filter(move |token| {
+      match token.token_type {
+        TokenType::CommentDocOpen => {
+          in_doc = true;
+        }
+        TokenType::CommentDocClose => {
+          in_doc = false;
+          return false;
+        }
+        TokenType::CommentLine | TokenType::CommentBlock => {
+          return false;
+        }
+        _ => {}
+      }
+      !in_doc
+    })
+  }
+
+  /// Whether `node` is followed by a comma that closes its parenthesized list, `f($a,)`
+  fn has_trailing_comma(&self, node: &Node) -> bool {
+    let Some(loc) = &node.loc else {
+      return false;
+    };
+    let mut tokens = self.tokens_after(loc.end.offset).map(|token| token.token_type);
+    tokens.next() == Some(TokenType::Comma) &&
+      tokens.next() == Some(TokenType::RightParenthesis)
+  }
+
+  /// Heredoc and nowdoc closing labels written after whitespace
+  fn require_flexible_doc(&mut self, label: &[u8], node: &Node) {
+    let Some(loc) = &node.loc else {
+      return;
+    };
+    // The location ends right after the closing label
+    let label = label
+      .strip_prefix(b"\"")
+      .and_then(|x| x.strip_suffix(b"\""))
+      .unwrap_or(label);
+    if (loc.end.column as usize) > label.len() {
+      self.require(PhpVersion::Php73, "indented heredoc closing label", node);
+    }
+  }
+
+  /// `[$a, $b] = $c` destructuring, `list($a, $b)` is parsed as a list node
+  fn require_short_list(&mut self, node: &Node) {
+    if let NodeWrapper::Array(array) = &node.wrapper {
+      if array.is_short {
+        self.require(PhpVersion::Php71, "short list destructuring", node);
+      }
+    }
+  }
+}
+
+impl<'arena> Visitor<'arena> for VersionChecker<'_, '_> {
+  fn enter_node(&mut self, node: &Node<'arena>) {
+    self.ancestors.push(node.node_type.clone());
+  }
+
+  fn leave_node(&mut self, _: &Node<'arena>) {
+    self.ancestors.pop();
+  }
+
+  fn enter_type(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Type(declared) = &node.wrapper else {
+      return;
+    };
+    if declared.is_nullable {
+      self.require(PhpVersion::Php71, "nullable type", node);
+    }
+    match declared.name.to_ascii_lowercase().as_slice() {
+      b"void" => self.require(PhpVersion::Php71, "void type", node),
+      b"iterable" => self.require(PhpVersion::Php71, "iterable type", node),
+      b"object" => self.require(PhpVersion::Php72, "object type", node),
+      b"mixed" => self.require(PhpVersion::Php80, "mixed type", node),
+      b"static" => self.require(PhpVersion::Php80, "static return type", node),
+      b"never" => self.require(PhpVersion::Php81, "never type", node),
+      b"true" => self.require(PhpVersion::Php82, "true type", node),
+      b"null" | b"false" if self.parent() != Some(NodeType::UnionType) => {
+        self.require(PhpVersion::Php82, "standalone null and false types", node);
+      }
+      _ => {}
+    }
+  }
+
+  fn enter_assignment(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Assignment(assignment) = &node.wrapper {
+      self.require_short_list(&assignment.left);
+    }
+  }
+
+  fn enter_foreach(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Foreach(foreach) = &node.wrapper {
+      self.require_short_list(&foreach.value);
+    }
+  }
+
+  fn enter_arrow_function(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php74, "arrow function", node);
+  }
+
+  fn enter_property_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::PropertyItem(item) = &node.wrapper {
+      if item.variable_type.is_some() {
+        self.require(PhpVersion::Php74, "typed property", node);
+      }
+    }
+  }
+
+  fn enter_throw(&mut self, node: &Node<'arena>) {
+    if self.parent().is_none_or(|parent| !STATEMENT_PARENTS.contains(&parent)) {
+      self.require(PhpVersion::Php80, "throw expression", node);
+    }
+  }
+
+  fn enter_static_lookup(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::StaticLookup(lookup) = &node.wrapper else {
+      return;
+    };
+    let is_class_name = matches!(
+      lookup.left.node_type,
+      NodeType::Identifier | NodeType::StaticKeyword | NodeType::SelfKeyword | NodeType::Parent
+    );
+    if lookup.right.node_type == NodeType::ClassKeyword && !is_class_name {
+      self.require(PhpVersion::Php80, "::class on an object", node);
+    }
+  }
+
+  fn enter_match(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php80, "match expression", node);
+  }
+
+  fn enter_union_type(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php80, "union type", node);
+  }
+
+  fn enter_argument(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Argument(argument) = &node.wrapper {
+      if argument.name.is_some() {
+        self.require(PhpVersion::Php80, "named argument", node);
+      }
+    }
+    if self.has_trailing_comma(node) {
+      self.require(PhpVersion::Php73, "trailing comma in argument list", node);
+    }
+  }
+
+  fn enter_parameter(&mut self, node: &Node<'arena>) {
+    if self.has_trailing_comma(node) {
+      self.require(PhpVersion::Php80, "trailing comma in parameter list", node);
+    }
+  }
+
+  fn enter_array_item(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ArrayItem(item) = &node.wrapper {
+      if item.value.node_type == NodeType::Variadic {
+        self.require(PhpVersion::Php74, "array unpacking", node);
+      }
+    }
+  }
+
+  fn enter_here_doc(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::HereDoc(doc) = &node.wrapper {
+      self.require_flexible_doc(doc.label, node);
+    }
+  }
+
+  fn enter_now_doc(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::NowDoc(doc) = &node.wrapper {
+      self.require_flexible_doc(doc.label, node);
+    }
+  }
+
+  fn enter_catch(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Catch(catch) = &node.wrapper {
+      if catch.variable.is_none() {
+        self.require(PhpVersion::Php80, "catch without variable", node);
+      }
+    }
+  }
+
+  fn enter_constructor_parameter(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ConstructorParameter(parameter) = &node.wrapper {
+      self.require(PhpVersion::Php80, "constructor property promotion", node);
+      if parameter.modifier == Some(Modifier::Readonly) {
+        self.require(PhpVersion::Php81, "readonly property", node);
+      }
+    }
+  }
+
+  fn enter_call(&mut self, node: &Node<'arena>) {
+    let NodeWrapper::Call(call) = &node.wrapper else {
+      return;
+    };
+    // `strlen(...)` is parsed as a single variadic argument without a value
+    if let [argument] = call.arguments.as_slice() {
+      if let NodeWrapper::Argument(argument) = &argument.wrapper {
+        if let NodeWrapper::Variadic(variadic) = &argument.value.wrapper {
+          if variadic.statement.is_none() {
+            self.require(PhpVersion::Php81, "first-class callable syntax", node);
+          }
+        }
+      }
+    }
+  }
+
+  fn enter_enum(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php81, "enum", node);
+  }
+
+  fn enter_intersection_type(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php81, "intersection type", node);
+    if self.parent() == Some(NodeType::UnionType) {
+      self.require(PhpVersion::Php82, "DNF type", node);
+    }
+  }
+
+  fn enter_new(&mut self, node: &Node<'arena>) {
+    if self.ancestors.iter().any(|ancestor| INITIALIZERS.contains(ancestor)) {
+      self.require(PhpVersion::Php81, "new in initializer", node);
+    }
+  }
+
+  fn enter_property(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Property(property) = &node.wrapper {
+      if property.modifier == Some(Modifier::Readonly) {
+        self.require(PhpVersion::Php81, "readonly property", node);
+      }
+    }
+  }
+
+  fn enter_class(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::Class(class) = &node.wrapper {
+      if class.is_readonly {
+        self.require(PhpVersion::Php82, "readonly class", node);
+      }
+    }
+  }
+
+  fn enter_const_property(&mut self, node: &Node<'arena>) {
+    if let NodeWrapper::ConstProperty(constant) = &node.wrapper {
+      if !constant.visibilities.is_empty() {
+        self.require(PhpVersion::Php71, "class constant visibility", node);
+      }
+      if constant.const_type.is_some() {
+        self.require(PhpVersion::Php83, "typed class constant", node);
+      }
+    }
+  }
+
+  fn enter_property_hook(&mut self, node: &Node<'arena>) {
+    self.require(PhpVersion::Php84, "property hook", node);
+  }
+}
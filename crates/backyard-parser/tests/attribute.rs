@@ -1,4 +1,6 @@
-use backyard_parser::parse;
+use backyard_nodes::{ NodeType, NodeWrapper };
+use backyard_parser::{ parse, parse_in };
+use bumpalo::Bump;
 
 #[test]
 fn basic() {
@@ -40,3 +42,57 @@ class A {
 }").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn targets() {
+  let arena = Bump::new();
+  let input = "#[C]
+class A {
+  #[K] const K = 1;
+  #[P] public int $p;
+  /** doc */
+  #[M] public function __construct(#[R] private int $a) {}
+}
+#[E] enum E { #[I] case A; }
+#[F] function f(#[Q] $b) {}
+$a = #[G] fn() => 1;
+$b = #[H] function() {};
+$c = new #[N] class {};";
+  let program = parse_in(&arena, true, input).unwrap();
+  let mut names = vec![];
+  for (_, node) in program.walk() {
+    assert!(
+      node.leadings.iter().flatten().all(|x| x.node_type != NodeType::Attribute),
+      "{:?} has an attribute in its leadings",
+      node.node_type
+    );
+    for attribute in node.attributes().into_iter().flatten() {
+      let NodeWrapper::Attribute(attribute) = &attribute.wrapper else {
+        panic!("{:?} is not an attribute", attribute.node_type);
+      };
+      for item in attribute.items.iter() {
+        if let NodeWrapper::AttributeItem(item) = &item.wrapper {
+          names.push((item.name.to_string(), node.node_type.clone()));
+        }
+      }
+    }
+  }
+  names.sort_by(|a, b| a.0.cmp(&b.0));
+  assert_eq!(
+    vec![
+      ("C".to_string(), NodeType::Class),
+      ("E".to_string(), NodeType::Enum),
+      ("F".to_string(), NodeType::Function),
+      ("G".to_string(), NodeType::ArrowFunction),
+      ("H".to_string(), NodeType::AnonymousFunction),
+      ("I".to_string(), NodeType::EnumItem),
+      ("K".to_string(), NodeType::ConstProperty),
+      ("M".to_string(), NodeType::Method),
+      ("N".to_string(), NodeType::AnonymousClass),
+      ("P".to_string(), NodeType::Property),
+      ("Q".to_string(), NodeType::Parameter),
+      ("R".to_string(), NodeType::ConstructorParameter),
+    ],
+    names
+  );
+}
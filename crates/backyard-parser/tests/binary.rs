@@ -0,0 +1,79 @@
+use backyard_nodes::binary::{ from_binary_in, BinaryError };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+const INPUT: &str =
+  "<?php
+namespace App;
+use Foo\\{Bar, function baz};
+
+#[Attr(1)]
+abstract class A extends B implements C {
+  use T { a as protected b; }
+  public const int X = 1;
+  private static ?string $y = null;
+  public private(set) int $z { get => $this->z; }
+
+  /**
+   * Summary.
+   * @param int $a
+   */
+  public function __construct(private readonly int $a, &...$rest) {
+    parent::__construct();
+    $this?->b[0] ??= static fn($x): int => $x <=> self::X;
+  }
+}
+
+enum Suit: string { case Hearts = 'H'; }
+
+function &gen(): iterable {
+  yield 1 => 2;
+  yield from [1, 2];
+}
+
+// line
+/* block */
+$s = \"a {$b->c} \\$d\";
+$h = <<<EOT
+  x {$y}
+  EOT;
+$n = <<<'EOT'
+  raw
+  EOT;
+try { throw new E(); } catch (E | F $e) { echo __LINE__; } finally { exit(1); }
+foreach ($a as $k => &$v): endforeach;
+for ($i = 0; $i < 10; $i++) { --$i; }
+while (true) { break; }
+do { continue; } while (false);
+switch ($a) { case 1: break; default: continue 2; }
+$m = match (true) { 1, 2 => (int) $a, default => @$b ?? print 'x' };
+list($x, $y) = [1, 2];
+declare(strict_types=1);
+goto end;
+end:
+?>
+tail";
+
+#[test]
+fn round_trip() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, INPUT).unwrap();
+  let bytes = node.to_binary();
+  let decoded = from_binary_in(&Bump::new(), &bytes).map(|x| x == node);
+  assert_eq!(decoded, Ok(true));
+  assert!(bytes.len() < INPUT.len() * 3);
+  assert!(bytes.len() * 10 < serde_json::to_string(&node).unwrap().len());
+}
+
+#[test]
+fn corrupted() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, INPUT).unwrap();
+  let bytes = node.to_binary();
+  for len in 0..bytes.len() {
+    assert!(from_binary_in(&arena, &bytes[..len]).is_err());
+  }
+  let mut extended = bytes.clone();
+  extended.push(0);
+  assert_eq!(from_binary_in(&arena, &extended).map(|_| ()), Err(BinaryError::TrailingBytes));
+}
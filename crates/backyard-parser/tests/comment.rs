@@ -1,4 +1,6 @@
-use backyard_parser::parse;
+use backyard_nodes::NodeWrapper;
+use backyard_parser::{ parse, parse_in };
+use bumpalo::Bump;
 
 #[test]
 fn line_basic() {
@@ -18,11 +20,85 @@ fn block() {
   insta::assert_yaml_snapshot!(asts);
 }
 
-// #[test]
-// fn doc() {
-//   let asts = parse(true, "/**\n * test\n */").unwrap();
-//   insta::assert_yaml_snapshot!(asts);
-// }
+#[test]
+fn doc() {
+  let asts = parse(true, "/**\n * test\n */").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
+
+#[test]
+fn doc_tags() {
+  let asts = parse(
+    true,
+    "/**
+ * Find a user by name.
+ *
+ * Looks up the repository and
+ * falls back to the cache.
+ *
+ * @param non-empty-string $name User name.
+ * @param array<string, int> ...$options
+ * @return ?User the user,
+ *   or null when missing
+ * @deprecated
+ */
+function find($name, ...$options) {}"
+  ).unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
+
+#[test]
+fn doc_tag_types() {
+  let arena = Bump::new();
+  let node = parse_in(
+    &arena,
+    true,
+    "/**
+ * @return array{a: int} map
+ * @param callable(int): void $f called
+ * @param int &...$rest
+ * @throws \\Exception
+ */
+function f($f, &...$rest) {}"
+  ).unwrap();
+  let NodeWrapper::Program(program) = &node.wrapper else {
+    panic!("not a program");
+  };
+  let leadings = program.children[0].leadings.as_ref().unwrap();
+  let NodeWrapper::CommentDoc(doc) = &leadings[0].wrapper else {
+    panic!("not a doc comment");
+  };
+  let tags: Vec<(String, Option<String>, Option<String>, String)> = doc.tags
+    .iter()
+    .map(|tag| {
+      let NodeWrapper::CommentDocTag(tag) = &tag.wrapper else {
+        panic!("not a doc tag");
+      };
+      (
+        tag.name.to_string(),
+        tag.tag_type.map(|x| x.to_string()),
+        tag.variable.map(|x| x.to_string()),
+        tag.description.to_string(),
+      )
+    })
+    .collect();
+  let tag = |name: &str, tag_type: &str, variable: Option<&str>, description: &str| (
+    name.to_string(),
+    Some(tag_type.to_string()),
+    variable.map(|x| x.to_string()),
+    description.to_string(),
+  );
+  assert_eq!(
+    vec![
+      tag("@return", "array{a: int}", None, "map"),
+      tag("@param", "callable(int): void", Some("$f"), "called"),
+      tag("@param", "int", Some("&...$rest"), ""),
+      tag("@throws", "\\Exception", None, "")
+    ],
+    tags
+  );
+  assert!(doc.comment.starts_with(b"\n * @return array{a: int} map\n"));
+}
 
 #[test]
 fn before_block() {
@@ -54,3 +130,11 @@ fn at_statement_end() {
   let asts = parse(true, "$a = 5 /* test */;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn at_body_end() {
+  let asts = parse(true, "class A {
+  public $a; // test
+}").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
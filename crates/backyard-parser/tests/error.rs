@@ -1,5 +1,5 @@
-use backyard_lexer::{ error::LexError, token::{ Token, TokenType } };
-use backyard_parser::error::ParserError;
+use backyard_lexer::{ error::LexError, token::{ OwnedToken, TokenType } };
+use backyard_parser::{ error::ParserError, parse };
 
 #[test]
 fn basic() {
@@ -7,10 +7,10 @@ fn basic() {
   assert_eq!("Internal parser error", format!("{}", ParserError::Internal));
   assert_eq!("End of file", format!("{}", ParserError::Eof));
   assert_eq!(
-    "Unexpected character 'and' at line 0, column 0",
+    "Unexpected token 'and' at line 0, column 0",
     format!(
       "{}",
-      ParserError::UnexpectedToken(Token {
+      ParserError::UnexpectedToken(OwnedToken {
         token_type: TokenType::And,
         value: "and".into(),
         line: 0,
@@ -20,3 +20,63 @@ fn basic() {
     )
   );
 }
+
+#[test]
+fn diagnostic() {
+  let input = "function a() {\n  $a = ) 1;\n}";
+  let err = parse(true, input).unwrap_err();
+  insta::assert_snapshot!(err.render(input));
+}
+
+#[test]
+fn diagnostic_expected() {
+  let input = "foo(1, 2;";
+  let err = parse(true, input).unwrap_err();
+  insta::assert_snapshot!(err.render(input));
+}
+
+#[test]
+fn diagnostic_pattern() {
+  let input = "function a a() {}";
+  let err = parse(true, input).unwrap_err();
+  insta::assert_snapshot!(err.render(input));
+}
+
+#[test]
+fn diagnostic_tab() {
+  let input = "if (true) {\n\t$a = );\n}";
+  let err = parse(true, input).unwrap_err();
+  insta::assert_snapshot!(err.render(input));
+}
+
+#[test]
+fn diagnostic_missing_operand() {
+  let err = parse(false, "<?php $a = ;").unwrap_err();
+  assert_eq!(
+    "Unexpected token ';' at line 1, column 11 in assignment, expected expression",
+    err.to_string()
+  );
+  let err = parse(false, "<?php $x = 1 +;").unwrap_err();
+  assert_eq!("Unexpected token ';' at line 1, column 14 in bin, expected expression", err.to_string());
+}
+
+#[test]
+fn diagnostic_eof() {
+  let input = "<?php\n$a = [1, 2];\nfunction (";
+  let err = parse(false, input).unwrap_err();
+  let ParserError::Diagnostic(diagnostic) = &err else {
+    panic!("unexpected error {:?}", err);
+  };
+  assert!(diagnostic.found.is_none());
+  let loc = diagnostic.loc.as_ref().unwrap();
+  assert_eq!((3, 10), (loc.start.line, loc.start.column));
+  assert!(err.to_string().starts_with("Unexpected end of file at line 3, column 10 in "));
+  assert!(err.render(input).ends_with("3 | function (\n  |           ^"));
+}
+
+#[test]
+fn diagnostic_multibyte() {
+  let input = "<?php $b = 'éé'; $a = ;";
+  let err = parse(false, input).unwrap_err();
+  assert!(err.render(input).ends_with("1 | <?php $b = 'éé'; $a = ;\n  |                       ^"));
+}
@@ -5,3 +5,9 @@ fn basic() {
   let asts = parse(true, "global $title, $hook_suffix, $current_screen;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn followed() {
+  let asts = parse(true, "global $title;\n$title = 1;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
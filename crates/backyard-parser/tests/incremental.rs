@@ -0,0 +1,184 @@
+use backyard_nodes::{ Node, NodeWrapper };
+use backyard_parser::{ error::ParserError, parse_byte_in, parse_in, reparse_byte_in, TextEdit };
+use bumpalo::Bump;
+
+fn edit(source: &str, find: &str, text: &str) -> TextEdit {
+  let start = source.find(find).unwrap();
+  TextEdit::new(start, start + find.len(), text)
+}
+
+fn reparse<'arena>(
+  arena: &'arena Bump,
+  source: &'arena str,
+  edits: &[TextEdit]
+) -> (Node<'arena>, Node<'arena>) {
+  let mut tree = parse_in(arena, false, source).unwrap();
+  let input = reparse_byte_in(arena, false, &mut tree, source.as_bytes(), edits).unwrap();
+  (tree, parse_byte_in(arena, false, arena.alloc_slice_copy(&input)).unwrap())
+}
+
+fn assert_reparse(source: &str, edits: &[TextEdit]) {
+  let arena = Bump::new();
+  let (tree, expected) = reparse(&arena, source, edits);
+  assert_eq!(expected, tree);
+}
+
+fn function_body<'b, 'arena>(program: &'b Node<'arena>, index: usize) -> &'b Node<'arena> {
+  let NodeWrapper::Program(program) = &program.wrapper else {
+    panic!("not a program");
+  };
+  let NodeWrapper::Function(function) = &program.children[index].wrapper else {
+    panic!("not a function");
+  };
+  function.body.as_ref().unwrap()
+}
+
+const SOURCE: &str =
+  "<?php
+// leading
+$a = 1;
+function a($x) {
+  $b = $x + 1;
+  return $b;
+}
+class A {
+  public function b() {
+    echo 'b';
+  }
+}
+$c = a(2); $d = 3;
+";
+
+#[test]
+fn statement() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "$a = 1;", "$a = [\n  1,\n  2,\n];")]);
+}
+
+#[test]
+fn same_line() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "a(2)", "a(200)")]);
+}
+
+#[test]
+fn function() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "$x + 1", "$x\n    + 10")]);
+}
+
+#[test]
+fn method() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "'b'", "'bb', PHP_EOL")]);
+}
+
+#[test]
+fn insert() {
+  let offset = SOURCE.find("return").unwrap();
+  assert_reparse(SOURCE, &[TextEdit::new(offset, offset, "$b *= 2;\n  ")]);
+}
+
+#[test]
+fn delete() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "$b = $x + 1;\n  ", "")]);
+}
+
+#[test]
+fn comment() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "// leading", "/** doc */")]);
+}
+
+#[test]
+fn end_of_file() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "$d = 3;\n", "$d = 4;\n$e = 5;")]);
+}
+
+#[test]
+fn multiple() {
+  assert_reparse(SOURCE, &[edit(SOURCE, "$x + 1", "$x + 2"), edit(SOURCE, "'b'", "'c'")]);
+}
+
+#[test]
+fn namespace() {
+  let source = "<?php\nnamespace A;\nfunction a() {\n  return 1;\n}\na();\n";
+  assert_reparse(source, &[edit(source, "return 1;", "return\n  2;")]);
+  assert_reparse(source, &[edit(source, "a();", "a(1);")]);
+  assert_reparse(source, &[edit(source, "namespace A;", "namespace B;")]);
+}
+
+#[test]
+fn control_structures() {
+  let source =
+    "<?php\nif ($a) {\n  a();\n} else {\n  b();\n}\nforeach ($a as $b) {\n  c();\n}\ntry {\n  d();\n} catch (E $e) {\n  e();\n} finally {\n  f();\n}\n";
+  for (find, text) in [
+    ("a();", "a(1);"),
+    ("b();", "b(\n1\n);"),
+    ("c();", ""),
+    ("d();", "d(); d();"),
+    ("e();", "e(2);"),
+    ("f();", "f();\n  g();"),
+  ] {
+    assert_reparse(source, &[edit(source, find, text)]);
+  }
+}
+
+#[test]
+fn keywords() {
+  for source in [
+    "<?php\nstatic $m = 'ab';\n",
+    "<?php\n$a = 1;\nstatic $m = 'ab';\n",
+    "<?php\nfunction a() {\n  static $m = 'ab';\n}\n",
+    "<?php\nfinal class A {\n  const B = 'ab';\n}\n",
+    "<?php\nabstract class A {}\n$m = 'ab';\nreadonly class B {}\n",
+    "<?php\n$f = static fn() => 'ab';\n",
+    "<?php\n#[A('ab')]\nfunction a() {}\n",
+  ] {
+    assert_reparse(source, &[edit(source, "'ab'", "'Xb'")]);
+  }
+}
+
+#[test]
+fn fallback() {
+  for text in ["/*", "}", "if ($x) {", "?>", "\"", "function", "namespace B;"] {
+    let arena = Bump::new();
+    let mut tree = parse_in(&arena, false, SOURCE).unwrap();
+    let result = reparse_byte_in(
+      &arena,
+      false,
+      &mut tree,
+      SOURCE.as_bytes(),
+      &[edit(SOURCE, "return $b;", text)]
+    );
+    let input = apply(SOURCE, "return $b;", text);
+    let expected = parse_byte_in(&arena, false, input.as_bytes());
+    match expected {
+      Ok(expected) => {
+        assert_eq!(input.as_bytes(), result.unwrap().as_slice());
+        assert_eq!(expected, tree);
+      }
+      Err(err) => assert_eq!(Err(err), result),
+    }
+  }
+}
+
+fn apply(source: &str, find: &str, text: &str) -> String {
+  source.replacen(find, text, 1)
+}
+
+#[test]
+fn reuse() {
+  let arena = Bump::new();
+  let mut tree = parse_in(&arena, false, SOURCE).unwrap();
+  let body = function_body(&tree, 1) as *const Node;
+  reparse_byte_in(&arena, false, &mut tree, SOURCE.as_bytes(), &[edit(SOURCE, "$a = 1;", "$a = 2;")])
+    .unwrap();
+  assert_eq!(body, function_body(&tree, 1) as *const Node);
+}
+
+#[test]
+fn invalid_edit() {
+  let arena = Bump::new();
+  let mut tree = parse_in(&arena, false, SOURCE).unwrap();
+  let edits = [TextEdit::new(10, 20, ""), TextEdit::new(15, 16, "")];
+  assert_eq!(
+    Err(ParserError::InvalidEdit { start: 15, end: 16 }),
+    reparse_byte_in(&arena, false, &mut tree, SOURCE.as_bytes(), &edits)
+  );
+}
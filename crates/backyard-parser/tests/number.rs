@@ -1,4 +1,6 @@
-use backyard_parser::parse;
+use backyard_nodes::{ number::{ NumberBase, NumberValue }, NodeWrapper };
+use backyard_parser::{ parse, parse_in };
+use bumpalo::Bump;
 
 #[test]
 fn basic() {
@@ -29,3 +31,53 @@ fn binary() {
   let asts = parse(true, "$a = 0b1101_1010;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn octal() {
+  let asts = parse(true, "$a = 0o17;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
+
+#[test]
+fn exponent() {
+  let asts = parse(true, "$a = 1_000.5e-3;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
+
+#[test]
+fn value() {
+  let cases = [
+    ("1_000_000", NumberBase::Decimal, Some(NumberValue::Int(1_000_000))),
+    ("0x1A", NumberBase::Hexadecimal, Some(NumberValue::Int(26))),
+    ("0b101", NumberBase::Binary, Some(NumberValue::Int(5))),
+    ("0o17", NumberBase::Octal, Some(NumberValue::Int(15))),
+    ("017", NumberBase::Octal, Some(NumberValue::Int(15))),
+    ("0", NumberBase::Decimal, Some(NumberValue::Int(0))),
+    ("089", NumberBase::Octal, None),
+    ("1.5e-3", NumberBase::Decimal, Some(NumberValue::Float(0.0015))),
+    (".5", NumberBase::Decimal, Some(NumberValue::Float(0.5))),
+    ("0.5", NumberBase::Decimal, Some(NumberValue::Float(0.5))),
+    ("9223372036854775807", NumberBase::Decimal, Some(NumberValue::Int(i64::MAX))),
+    ("9223372036854775808", NumberBase::Decimal, Some(NumberValue::Float(9.223372036854776e18))),
+    (
+      "0xFFFFFFFFFFFFFFFF",
+      NumberBase::Hexadecimal,
+      Some(NumberValue::Float(1.8446744073709552e19)),
+    ),
+  ];
+  for (code, base, value) in cases {
+    let arena = Bump::new();
+    let input = arena.alloc_str(&format!("{};", code));
+    let program = parse_in(&arena, true, input).unwrap();
+    let number = match &program.wrapper {
+      NodeWrapper::Program(program) =>
+        match &program.children[0].wrapper {
+          NodeWrapper::Number(number) => number,
+          _ => panic!("{} is not a number", code),
+        }
+      _ => panic!("{} is not a program", code),
+    };
+    assert_eq!(base, number.base(), "{}", code);
+    assert_eq!(value, number.to_value(), "{}", code);
+  }
+}
@@ -0,0 +1,223 @@
+use backyard_nodes::{ Node, NodeWrapper };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+use serde_json::{ json, Value };
+
+fn export(input: &str) -> Value {
+  let arena = Bump::new();
+  let input = arena.alloc_str(input);
+  let node = parse_in(&arena, false, input).unwrap();
+  node.to_php_parser()
+}
+
+fn assert_round_trip(input: &str) {
+  let exported = export(input);
+  let arena = Bump::new();
+  let imported = Node::from_php_parser_in(&arena, &exported).unwrap();
+  assert_eq!(exported, imported.to_php_parser());
+}
+
+#[test]
+fn statements() {
+  insta::assert_yaml_snapshot!(export("<?php
+// greeting
+echo 'a\\'b', \"c\\n$d\";
+unset($a[0]);
+"));
+}
+
+#[test]
+fn class() {
+  insta::assert_yaml_snapshot!(export("<?php
+#[Entity]
+final class A extends B implements C {
+  /** The id. */
+  public const int ID = 1;
+  private static ?int $count = 0;
+
+  public function __construct(protected readonly string $name) {}
+
+  abstract protected function run(int ...$args): static;
+}
+"));
+}
+
+#[test]
+fn expressions() {
+  insta::assert_yaml_snapshot!(export("<?php
+$a = 0x1F + 0b11 * 1.5;
+$b = $c ?: $d ?? new \\Foo\\Bar($e, ...$f);
+[$g, $h] = $i->j?->k(named: 1)::L;
+$m = fn(&$n) => isset($n[1]) && !empty($o);
+"));
+}
+
+/// `nodeType`, `startFilePos` and `endFilePos` of `node`
+fn position(node: &Value) -> (&str, &Value, &Value) {
+  let attributes = &node["attributes"];
+  (node["nodeType"].as_str().unwrap(), &attributes["startFilePos"], &attributes["endFilePos"])
+}
+
+#[test]
+fn positions() {
+  let exported = export("<?php\n$a = 1 + 2;\nfoo($b);\n");
+  let assign = &exported[0]["expr"];
+  assert_eq!(("Stmt_Expression", &json!(6), &json!(16)), position(&exported[0]));
+  assert_eq!(("Expr_Assign", &json!(6), &json!(15)), position(assign));
+  assert_eq!(("Expr_BinaryOp_Plus", &json!(11), &json!(15)), position(&assign["expr"]));
+  assert_eq!(("Expr_FuncCall", &json!(18), &json!(24)), position(&exported[1]["expr"]));
+}
+
+#[test]
+fn trailing_comments() {
+  let exported = export(
+    "<?php
+class A {
+  public function f() {
+  }
+  /** g */
+  public function g() {}
+  public $a; // a
+}
+"
+  );
+  let statements = &exported[0]["stmts"];
+  assert_eq!("/** g */", statements[1]["attributes"]["comments"][0]["text"]);
+  assert_eq!("// a", statements[3]["attributes"]["comments"][0]["text"]);
+  assert_eq!("Stmt_Nop", statements[3]["nodeType"]);
+}
+
+#[test]
+fn import_anonymous_class() {
+  let arena = Bump::new();
+  let exported = export("<?php new class(1, ...$a) extends X {};");
+  let imported = Node::from_php_parser_in(&arena, &exported).unwrap();
+  let parsed = parse_in(&arena, false, "<?php new class(1, ...$a) extends X {};").unwrap();
+  let parameters = |node: &Node| {
+    let NodeWrapper::Program(program) = &node.wrapper else { panic!() };
+    let NodeWrapper::New(new) = &program.children[0].wrapper else { panic!() };
+    let NodeWrapper::AnonymousClass(class) = &new.statement.wrapper else { panic!() };
+    class.parameters
+      .iter()
+      .map(|x| x.node_type.clone())
+      .collect::<Vec<_>>()
+  };
+  assert_eq!(parameters(&parsed), parameters(&imported));
+}
+
+#[test]
+fn control_flow() {
+  insta::assert_yaml_snapshot!(export("<?php
+if ($a) {
+  b();
+} elseif ($c) {
+} else {
+  d();
+}
+foreach ($e as $k => &$v) {}
+try {} catch (A | B $f) {} finally {}
+"));
+}
+
+#[test]
+fn round_trip() {
+  assert_round_trip(
+    "<?php
+namespace App;
+use Foo\\{Bar, function baz};
+use const QUX;
+
+/**
+ * Summary.
+ */
+#[Attribute]
+abstract class A extends B implements C, D {
+  use T { T::a as protected b; }
+  public const X = 1, Y = 'y';
+  public ?int $a = null;
+  final public static function &c(int|string $x = 1, ...$y): ?self {
+    return $this->d?->e(...) + static::f(x: 1) + parent::$g + self::class;
+  }
+}
+
+interface E extends F {}
+trait G {}
+function counter() { static $count = 0, $step; }
+function config() { global $config; }
+enum H: string implements I { case J = 'j'; case K = 'k'; }
+
+function l(array $m = [1, 'a' => 2], callable ...$n): void {
+  for ($i = 0; $i < 10; $i++) { continue 1; }
+  while (true) { break; }
+  do { $m[] = $i--; } while (--$i > 0);
+  switch ($i) { case 1: echo 1; break; default: echo 2; }
+  $o = match ($i) { 1, 2 => 'a', default => 'b' };
+  $p = function ($q) use (&$m, $n): int { yield $q => 1; yield from $q; };
+  $r = new class(1) extends S {};
+  $s = (int) $t . (string) $u . -$v . +$w . ~$x;
+  $y = include_once 'z.php';
+  $z = @clone $a instanceof B ? `ls` : print \"{$a->b} $c[0]\";
+  list($d, $e) = $f;
+  $g ??= $h <=> $i;
+  throw new Exception(__FILE__ . __LINE__);
+}
+"
+  );
+}
+
+#[test]
+fn import_legacy_names() {
+  let arena = Bump::new();
+  let value =
+    json!([
+    {
+      "nodeType": "Stmt_Expression",
+      "expr": {
+        "nodeType": "Expr_Assign",
+        "var": { "nodeType": "Expr_Variable", "name": "a", "attributes": {} },
+        "expr": {
+          "nodeType": "Expr_FuncCall",
+          "name": { "nodeType": "Name_FullyQualified", "parts": ["Foo", "bar"], "attributes": {} },
+          "args": [
+            {
+              "nodeType": "Arg",
+              "name": null,
+              "value": { "nodeType": "Scalar_LNumber", "value": 1, "attributes": {} },
+              "byRef": false,
+              "unpack": false,
+              "attributes": {},
+            },
+          ],
+          "attributes": {},
+        },
+        "attributes": {},
+      },
+      "attributes": {},
+    },
+  ]);
+  let node = Node::from_php_parser_in(&arena, &value).unwrap();
+  insta::assert_yaml_snapshot!(node.serializable());
+}
+
+#[test]
+fn import_unsupported() {
+  let arena = Bump::new();
+  let value = json!({ "nodeType": "Expr_Unknown", "attributes": {} });
+  assert_eq!(
+    Node::from_php_parser_in(&arena, &value).unwrap_err(),
+    "Unsupported PHP-Parser node type: Expr_Unknown"
+  );
+}
+
+#[test]
+fn bitwise_not() {
+  let exported = export("<?php ~$a; !$b;");
+  let types: Vec<&Value> = exported
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|x| &x["expr"]["nodeType"])
+    .collect();
+  assert_eq!(vec!["Expr_BitwiseNot", "Expr_BooleanNot"], types);
+  assert_round_trip("<?php ~$a; !$b;");
+}
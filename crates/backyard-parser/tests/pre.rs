@@ -29,3 +29,9 @@ fn ambersand() {
   let asts = parse(true, "$a = &$b;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn bitwise_not() {
+  let asts = parse(true, "~$a;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
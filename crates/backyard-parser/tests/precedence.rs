@@ -0,0 +1,118 @@
+use backyard_nodes::{ Node, NodeWrapper };
+use backyard_parser::parse;
+use bumpalo::Bump;
+
+/// Expression with every operation wrapped in parentheses
+fn group(node: &Node) -> String {
+  match &node.wrapper {
+    NodeWrapper::Program(program) => group(&program.children[0]),
+    NodeWrapper::Bin(bin) =>
+      format!("({} {} {})", group(&bin.left), bin.operator, group(&bin.right)),
+    NodeWrapper::Assignment(assignment) =>
+      format!("({} {} {})", group(&assignment.left), assignment.operator, group(&assignment.right)),
+    NodeWrapper::Ternary(ternary) =>
+      format!(
+        "({} ? {} : {})",
+        group(&ternary.condition),
+        group(&ternary.valid),
+        group(&ternary.invalid)
+      ),
+    NodeWrapper::Pre(pre) => format!("({}{})", pre.operator, group(&pre.statement)),
+    NodeWrapper::Negate(negate) => format!("(!{})", group(&negate.statement)),
+    NodeWrapper::Silent(silent) => format!("(@{})", group(&silent.statement)),
+    NodeWrapper::Cast(cast) => format!("(({}) {})", cast.cast_type, group(&cast.expression)),
+    NodeWrapper::New(new) => format!("(new {})", group(&new.statement)),
+    NodeWrapper::Clone(clone) => format!("(clone {})", group(&clone.statement)),
+    NodeWrapper::Print(print) => format!("(print {})", group(&print.statement)),
+    NodeWrapper::Parenthesis(parenthesis) => group(&parenthesis.statement),
+    NodeWrapper::Variable(variable) => format!("${}", group(&variable.name)),
+    NodeWrapper::Identifier(identifier) => identifier.name.to_string(),
+    NodeWrapper::Number(number) => number.value.to_string(),
+    _ => panic!("unexpected node {:?}", node.node_type),
+  }
+}
+
+fn assert_grouping(input: &str, expected: &str) {
+  let arena = Bump::new();
+  let program = backyard_parser::parse_in(&arena, true, input).unwrap();
+  assert_eq!(expected, group(&program), "{}", input);
+}
+
+#[test]
+fn arithmetic() {
+  assert_grouping("1 * 2 + 3;", "((1 * 2) + 3)");
+  assert_grouping("1 + 2 * 3;", "(1 + (2 * 3))");
+  assert_grouping("1 - 2 - 3;", "((1 - 2) - 3)");
+  assert_grouping("1 + 2 << 3 . 4;", "(((1 + 2) << 3) . 4)");
+  assert_grouping("2 ** 3 ** 2;", "(2 ** (3 ** 2))");
+  assert_grouping("-$a ** 2;", "(-($a ** 2))");
+  assert_grouping("2 ** -1;", "(2 ** (-1))");
+}
+
+#[test]
+fn logical() {
+  assert_grouping("$a && $b || $c;", "(($a && $b) || $c)");
+  assert_grouping("$a || $b && $c;", "($a || ($b && $c))");
+  assert_grouping("$a | $b ^ $c & $d;", "($a | ($b ^ ($c & $d)))");
+  assert_grouping("$a == 1 && $b < 2;", "(($a == 1) && ($b < 2))");
+  assert_grouping("$a or $b xor $c and $d;", "($a or ($b xor ($c and $d)))");
+}
+
+#[test]
+fn coalesce() {
+  assert_grouping("$a ?? $b ?? $c;", "($a ?? ($b ?? $c))");
+  assert_grouping("$a ?? $b || $c;", "($a ?? ($b || $c))");
+}
+
+#[test]
+fn unary() {
+  assert_grouping("!$a instanceof B;", "(!($a instanceof B))");
+  assert_grouping("!$a && $b;", "((!$a) && $b)");
+  assert_grouping("-$a * 2;", "((-$a) * 2)");
+  assert_grouping("(int) $a + 1;", "(((int) $a) + 1)");
+  assert_grouping("@$a . $b;", "((@$a) . $b)");
+  assert_grouping("!$a = 1;", "(!($a = 1))");
+}
+
+#[test]
+fn ternary() {
+  assert_grouping("$a instanceof B ? 1 : 2;", "(($a instanceof B) ? 1 : 2)");
+  assert_grouping("$a || $b ? 1 : 2;", "(($a || $b) ? 1 : 2)");
+  assert_grouping("$a ? 1 : 2 + 3;", "($a ? 1 : (2 + 3))");
+  assert_grouping("$a ? 1 : $b ? 2 : 3;", "(($a ? 1 : $b) ? 2 : 3)");
+  assert_grouping("$a ?: $b ?: $c;", "(($a ?: $b) ?: $c)");
+}
+
+#[test]
+fn non_associative() {
+  assert_grouping("$a < $b == $c;", "(($a < $b) == $c)");
+  assert_grouping("($a == $b) == $c;", "(($a == $b) == $c)");
+  assert_grouping("$a == ($b == $c);", "($a == ($b == $c))");
+  for input in ["$a == $b == $c;", "$a === $b <=> $c;", "$a < $b < $c;", "$a <= $b > $c;"] {
+    assert!(parse(true, input).is_err(), "{}", input);
+  }
+}
+
+#[test]
+fn assignment() {
+  assert_grouping("$a = 1 and $b;", "(($a = 1) and $b)");
+  assert_grouping("$a = $b || $c;", "($a = ($b || $c))");
+  assert_grouping("$a = $b = 1 + 2;", "($a = ($b = (1 + 2)))");
+  assert_grouping("$a = $b ? 1 : 2;", "($a = ($b ? 1 : 2))");
+  assert_grouping("1 + $a = 2;", "(1 + ($a = 2))");
+}
+
+#[test]
+fn keyword() {
+  assert_grouping("new A + 1;", "((new A) + 1)");
+  assert_grouping("clone $a . $b;", "((clone $a) . $b)");
+  assert_grouping("print $a and $b;", "((print $a) and $b)");
+  assert_grouping("print $a = 1 + 2;", "(print ($a = (1 + 2)))");
+  assert_grouping("print $a || $b;", "(print ($a || $b))");
+}
+
+#[test]
+fn snapshot() {
+  let asts = parse(true, "$a = 1 * 2 + 3 and $b;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
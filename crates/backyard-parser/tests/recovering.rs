@@ -0,0 +1,102 @@
+use backyard_nodes::{ Node, NodeType, NodeWrapper };
+use backyard_parser::{ parse, parse_recovering, parse_recovering_in };
+use bumpalo::Bump;
+
+fn node_types(nodes: &[Node]) -> Vec<NodeType> {
+  nodes
+    .iter()
+    .map(|x| x.node_type.clone())
+    .collect()
+}
+
+#[test]
+fn no_error() {
+  let (asts, errors) = parse_recovering(true, "$a = 1;\n$b = 2;");
+  assert!(errors.is_empty());
+  assert_eq!(parse(true, "$a = 1;\n$b = 2;").unwrap(), asts);
+}
+
+#[test]
+fn statement() {
+  let (asts, errors) = parse_recovering(true, "$a = 1;\n$b = );\n$c = 3;");
+  let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+  insta::assert_yaml_snapshot!((asts, errors));
+}
+
+#[test]
+fn block() {
+  let (asts, errors) = parse_recovering(
+    true,
+    "function a() {\n  $a = ) 1;\n  return $a;\n}\n$b = 2;"
+  );
+  let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+  insta::assert_yaml_snapshot!((asts, errors));
+}
+
+#[test]
+fn stray_bracket() {
+  let (asts, errors) = parse_recovering(true, "$a = 1;\n}\n$b = 2;");
+  let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+  insta::assert_yaml_snapshot!((asts, errors));
+}
+
+#[test]
+fn stray_colon() {
+  let (asts, errors) = parse_recovering(false, "<?php :");
+  let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+  insta::assert_yaml_snapshot!((asts, errors));
+}
+
+#[test]
+fn unclosed_condition() {
+  let arena = Bump::new();
+  let (node, errors) = parse_recovering_in(&arena, false, "<?php if ($a { foo(); } bar();");
+  assert_eq!(1, errors.len());
+  let NodeWrapper::Program(program) = &node.wrapper else {
+    panic!("not a program");
+  };
+  assert_eq!(vec![NodeType::Error, NodeType::Call], node_types(&program.children));
+}
+
+#[test]
+fn unclosed_condition_in_block() {
+  let arena = Bump::new();
+  let (node, errors) = parse_recovering_in(
+    &arena,
+    false,
+    "<?php function a() {\n  if ($a { foo(); }\n  bar();\n}\nbaz();"
+  );
+  assert_eq!(1, errors.len());
+  let NodeWrapper::Program(program) = &node.wrapper else {
+    panic!("not a program");
+  };
+  assert_eq!(vec![NodeType::Function, NodeType::Call], node_types(&program.children));
+  let NodeWrapper::Function(function) = &program.children[0].wrapper else {
+    panic!("not a function");
+  };
+  let body = function.body.as_ref().unwrap();
+  let NodeWrapper::Block(block) = &body.wrapper else {
+    panic!("not a block");
+  };
+  assert_eq!(vec![NodeType::Error, NodeType::Call], node_types(&block.statements));
+}
+
+#[test]
+fn unclosed_at_end() {
+  for (input, error) in [
+    ("<?php $a = [1, 2", "line 1, column 16 in array, expected RightSquareBracket"),
+    ("<?php foo(1, 2", "line 1, column 14 in call, expected RightParenthesis"),
+    ("<?php $a = (1", "line 1, column 13 in parenthesis, expected RightParenthesis"),
+  ] {
+    let error = format!("Unexpected end of file at {}", error);
+    let arena = Bump::new();
+    let (node, errors) = parse_recovering_in(&arena, false, input);
+    let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+    assert_eq!(vec![error], errors, "{}", input);
+    let NodeWrapper::Program(program) = &node.wrapper else {
+      panic!("not a program");
+    };
+    assert_eq!(vec![NodeType::Error], node_types(&program.children), "{}", input);
+    assert!(parse(false, input).is_err(), "{}", input);
+  }
+}
@@ -0,0 +1,80 @@
+use backyard_nodes::{ serde::seed::NodeSeed, Node };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+use serde::de::DeserializeSeed;
+
+const INPUT: &str =
+  "<?php
+namespace App;
+use Foo\\{Bar, function baz};
+
+#[Attr(1)]
+abstract class A extends B implements C {
+  use T { a as protected b; }
+  public const int X = 1;
+  private static ?string $y = null;
+
+  /**
+   * Summary.
+   * @param int $a
+   */
+  public function __construct(private readonly int $a, &...$rest) {
+    parent::__construct();
+    $this?->b[0] = static fn($x): int => $x <=> self::X;
+  }
+}
+
+enum Suit: string { case Hearts = 'H'; }
+
+function &gen(): iterable {
+  yield 1 => 2;
+  yield from [1, 2];
+}
+
+// line
+/* block */
+$s = \"a {$b->c} \\$d\";
+$h = <<<EOT
+  x {$y}
+  EOT;
+try { throw new E(); } catch (E | F $e) { echo __LINE__; } finally { exit(1); }
+foreach ($a as $k => &$v): endforeach;
+switch ($a) { case 1: break; default: continue 2; }
+$m = match (true) { 1, 2 => (int) $a, default => @$b ?? print 'x' };
+list($x, $y) = [1, 2];
+?>
+tail";
+
+#[test]
+fn node_matches_serializable_node() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, INPUT).unwrap();
+  assert_eq!(
+    serde_json::to_value(&node).unwrap(),
+    serde_json::to_value(node.serializable()).unwrap()
+  );
+}
+
+#[test]
+fn deserialize_in_arena() {
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, INPUT).unwrap();
+  let json = serde_json::to_string(&node).unwrap();
+
+  let mut deserializer = serde_json::Deserializer::from_str(&json);
+  let deserialized = NodeSeed::new(&arena).deserialize(&mut deserializer).unwrap();
+  assert_eq!(node, deserialized);
+
+  let serializable: backyard_nodes::serde::node::SerializableNode = serde_json
+    ::from_str(&json)
+    .unwrap();
+  assert_eq!(node, serializable.deserialize_in(&arena).unwrap());
+}
+
+#[test]
+fn deserialize_missing_node_type() {
+  let arena = Bump::new();
+  let value = serde_json::json!({ "name": "a", "loc": null });
+  let error = Node::deserialize_in(value, &arena).unwrap_err();
+  assert_eq!(error.to_string(), "missing field `node_type`");
+}
@@ -0,0 +1,83 @@
+use backyard_nodes::{ visitor::Visitor, Node, NodeType };
+use backyard_parser::parse_in;
+use bumpalo::Bump;
+
+/// Source of every `node_type` node of `input`, sliced by its location
+fn slices(input: &str, node_type: NodeType) -> Vec<&str> {
+  struct Slices<'s> {
+    input: &'s str,
+    node_type: NodeType,
+    slices: Vec<&'s str>,
+  }
+
+  impl<'arena> Visitor<'arena> for Slices<'_> {
+    fn enter_node(&mut self, node: &Node<'arena>) {
+      if node.node_type == self.node_type {
+        let loc = node.loc.as_ref().unwrap();
+        self.slices.push(&self.input[loc.start.offset as usize..loc.end.offset as usize]);
+      }
+    }
+  }
+
+  let arena = Bump::new();
+  let node = parse_in(&arena, false, input).unwrap();
+  let mut visitor = Slices { input, node_type, slices: vec![] };
+  node.visit(&mut visitor);
+  visitor.slices
+}
+
+#[test]
+fn call() {
+  assert_eq!(vec!["foo($bar)", "$a->b()"], slices("<?php foo($bar); $a->b();", NodeType::Call));
+}
+
+#[test]
+fn assignment() {
+  assert_eq!(
+    vec!["$a = 1 + 2", "$b .= 'c'"],
+    slices("<?php $a = 1 + 2; $b .= 'c';", NodeType::Assignment)
+  );
+}
+
+#[test]
+fn bin() {
+  assert_eq!(
+    vec!["1 + 2 * 3", "2 * 3", "($a + 1) * 2", "$a + 1"],
+    slices("<?php 1 + 2 * 3; ($a + 1) * 2;", NodeType::Bin)
+  );
+}
+
+#[test]
+fn object_access() {
+  assert_eq!(vec!["$a->b->c", "$a->b"], slices("<?php $a->b->c;", NodeType::ObjectAccess));
+}
+
+#[test]
+fn array_lookup() {
+  assert_eq!(vec!["$a[0]['b']", "$a[0]"], slices("<?php $a[0]['b'];", NodeType::ArrayLookup));
+}
+
+#[test]
+fn static_lookup() {
+  assert_eq!(vec!["A::$b", "$a::class"], slices("<?php A::$b; $a::class;", NodeType::StaticLookup));
+}
+
+#[test]
+fn ternary() {
+  assert_eq!(vec!["$a ? 1 : 2"], slices("<?php $a ? 1 : 2;", NodeType::Ternary));
+}
+
+#[test]
+fn post() {
+  assert_eq!(vec!["$a++"], slices("<?php $a++;", NodeType::Post));
+}
+
+#[test]
+fn array_item() {
+  assert_eq!(vec!["'a' => 1", "2"], slices("<?php ['a' => 1, 2];", NodeType::ArrayItem));
+}
+
+#[test]
+fn statics() {
+  assert_eq!(vec!["static $a = 1"], slices("<?php static $a = 1;", NodeType::Static));
+}
@@ -17,3 +17,9 @@ fn multiple() {
   let asts = parse(true, "static $title = 1, $hook_suffix, $current_screen;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn followed() {
+  let asts = parse(true, "static $count = 0;\n$count++;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
@@ -1,4 +1,6 @@
-use backyard_parser::parse;
+use backyard_nodes::{ string::StringPart, Node, NodeType, NodeWrapper };
+use backyard_parser::{ parse, parse_in };
+use bumpalo::Bump;
 
 #[test]
 fn basic() {
@@ -27,3 +29,91 @@ a {$a}
 START;;").unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+fn with_value(code: &str, f: impl FnOnce(&Node)) {
+  let arena = Bump::new();
+  let program = parse_in(&arena, true, arena.alloc_str(code)).unwrap();
+  let NodeWrapper::Program(program) = &program.wrapper else {
+    panic!("{} is not a program", code);
+  };
+  let NodeWrapper::Assignment(assignment) = &program.children[0].wrapper else {
+    panic!("{} is not an assignment", code);
+  };
+  f(&assignment.right);
+}
+
+#[test]
+fn decoded() {
+  for (code, expected) in [
+    ("$a = 'it\\'s \\n \\\\';", b"it's \\n \\".as_slice()),
+    (
+      "$a = \"a\\tb \\x41\\101 \\u{1F600} \\$ \\\" \\q\";",
+      "a\tb AA \u{1F600} $ \" \\q".as_bytes(),
+    ),
+    ("$a = \"\\xff\\0\";", b"\xff\0"),
+  ] {
+    with_value(code, |node| {
+      let NodeWrapper::String(string) = &node.wrapper else {
+        panic!("{} is not a string", code);
+      };
+      assert_eq!(expected, string.to_bytes(), "{}", code);
+    });
+  }
+}
+
+#[test]
+fn decoded_parts() {
+  with_value("$a = \"a\\n$b c\";", |node| {
+    let NodeWrapper::Encapsed(encapsed) = &node.wrapper else {
+      panic!("not an encapsed string");
+    };
+    let parts = encapsed.decoded_parts();
+    assert_eq!(StringPart::Literal(b"a\n".to_vec()), parts[0]);
+    assert!(matches!(parts[1], StringPart::Expression(x) if x.node_type == NodeType::Variable));
+    assert_eq!(StringPart::Literal(b" c".to_vec()), parts[2]);
+    assert_eq!(None, encapsed.to_bytes());
+  });
+}
+
+#[test]
+fn decoded_doc() {
+  let heredoc = "$a = <<<EOT\n    a\\tb\n      c $d\n     e\n    EOT;";
+  with_value(heredoc, |node| {
+    let NodeWrapper::HereDoc(heredoc) = &node.wrapper else {
+      panic!("not a heredoc");
+    };
+    let parts = heredoc.decoded_parts();
+    assert_eq!(StringPart::Literal(b"a\tb\n  c ".to_vec()), parts[0]);
+    assert_eq!(StringPart::Literal(b"\n e".to_vec()), parts[2]);
+  });
+  with_value("$a = <<<EOT\nplain\nEOT;", |node| {
+    let NodeWrapper::HereDoc(heredoc) = &node.wrapper else {
+      panic!("not a heredoc");
+    };
+    assert_eq!(Some(b"plain".to_vec()), heredoc.to_bytes());
+  });
+  with_value("$a = <<<'EOT'\n  a\\n\n    b\n  EOT;", |node| {
+    let NodeWrapper::NowDoc(nowdoc) = &node.wrapper else {
+      panic!("not a nowdoc");
+    };
+    assert_eq!(b"a\\n\n  b".to_vec(), nowdoc.to_bytes());
+  });
+}
+
+#[test]
+fn decoded_doc_crlf() {
+  with_value("$a = <<<EOT\r\n  a\r\n  b $c\r\n  EOT;", |node| {
+    let NodeWrapper::HereDoc(heredoc) = &node.wrapper else {
+      panic!("not a heredoc");
+    };
+    let parts = heredoc.decoded_parts();
+    assert_eq!(StringPart::Literal(b"a\r\nb ".to_vec()), parts[0]);
+    assert_eq!(StringPart::Literal(vec![]), parts[2]);
+  });
+  with_value("$a = <<<'EOT'\r\n  a\r\n    b\r\n  EOT;", |node| {
+    let NodeWrapper::NowDoc(nowdoc) = &node.wrapper else {
+      panic!("not a nowdoc");
+    };
+    assert_eq!(b"a\r\n  b".to_vec(), nowdoc.to_bytes());
+  });
+}
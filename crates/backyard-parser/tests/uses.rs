@@ -30,3 +30,9 @@ fn items() {
   ).unwrap();
   insta::assert_yaml_snapshot!(asts);
 }
+
+#[test]
+fn followed_by_statement() {
+  let asts = parse(true, "use A\\B;\n$a;").unwrap();
+  insta::assert_yaml_snapshot!(asts);
+}
@@ -0,0 +1,189 @@
+use backyard_parser::{
+  error::ParserError,
+  parse,
+  parse_recovering_version_in,
+  parse_version,
+  PhpVersion,
+};
+use bumpalo::Bump;
+
+const INPUT: &str =
+  "<?php
+$f = strlen(...);
+$a = fn(?int $x): mixed => $x;
+$r = match ($a) { 1 => foo(name: 2), default => 3 };
+try { a(); } catch (E) {}
+enum Suit: string { case Hearts = 'H'; }
+readonly class P {
+  const string X = 'x';
+  public readonly int $a;
+  public function __construct(private readonly A&B $b, public int|string $c) {}
+  public string $full { get => 'x'; }
+}";
+
+#[test]
+fn latest() {
+  assert_eq!(parse(false, INPUT).unwrap(), parse_version(false, INPUT, PhpVersion::Php84).unwrap());
+}
+
+#[test]
+fn unsupported() {
+  let arena = Bump::new();
+  let (_, errors) = parse_recovering_version_in(&arena, false, INPUT, PhpVersion::Php70);
+  let errors: Vec<String> = errors.iter().map(|x| x.to_string()).collect();
+  insta::assert_yaml_snapshot!(errors);
+}
+
+#[test]
+fn first_error() {
+  let input = "<?php\nfunction a(): ?int {}\n$a = fn() => 1;";
+  assert!(parse_version(false, input, PhpVersion::Php74).is_ok());
+  let error = parse_version(false, input, PhpVersion::Php73).unwrap_err();
+  assert_eq!("Unsupported arrow function at line 3, column 5, requires PHP 7.4", error.to_string());
+  assert_eq!(
+    "Unsupported arrow function at line 3, column 5, requires PHP 7.4\n  |\n3 | $a = fn() => 1;\n  |      ^^^^^^^^^",
+    error.render(input)
+  );
+  let error = parse_version(false, input, PhpVersion::Php70).unwrap_err();
+  assert_eq!("Unsupported nullable type at line 2, column 14, requires PHP 7.1", error.to_string());
+}
+
+#[test]
+fn lex_error() {
+  let error = parse_version(false, "<?php\n$a?->b();", PhpVersion::Php74).unwrap_err();
+  assert!(matches!(error, ParserError::LexError(_)));
+}
+
+/// Errors of `input` parsed as `version`, after checking it parses as the version above
+fn gated(input: &str, version: PhpVersion, supported: PhpVersion) -> Vec<String> {
+  assert!(parse_version(false, input, supported).is_ok());
+  let arena = Bump::new();
+  let (_, errors) = parse_recovering_version_in(&arena, false, input, version);
+  errors.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn throw_expression() {
+  let input = "<?php\nif ($a) throw $e;\n$a = $b ?? throw $e;";
+  assert_eq!(
+    vec!["Unsupported throw expression at line 3, column 11, requires PHP 8.0"],
+    gated(input, PhpVersion::Php74, PhpVersion::Php80)
+  );
+}
+
+#[test]
+fn static_return_type() {
+  let input = "<?php\nclass A { function a(): static {} }";
+  assert_eq!(
+    vec!["Unsupported static return type at line 2, column 24, requires PHP 8.0"],
+    gated(input, PhpVersion::Php74, PhpVersion::Php80)
+  );
+}
+
+#[test]
+fn class_on_object() {
+  let input = "<?php\nA::class;\nstatic::class;\n$a::class;";
+  assert_eq!(
+    vec!["Unsupported ::class on an object at line 4, column 0, requires PHP 8.0"],
+    gated(input, PhpVersion::Php74, PhpVersion::Php80)
+  );
+}
+
+#[test]
+fn new_in_initializer() {
+  let input = "<?php\n$a = new A;\nfunction a($a = new A) {}";
+  assert_eq!(
+    vec!["Unsupported new in initializer at line 3, column 16, requires PHP 8.1"],
+    gated(input, PhpVersion::Php80, PhpVersion::Php81)
+  );
+}
+
+#[test]
+fn dnf_type() {
+  let input = "<?php\nfunction a((A&B)|null $a): true {}";
+  assert_eq!(
+    vec![
+      "Unsupported DNF type at line 2, column 12, requires PHP 8.2",
+      "Unsupported true type at line 2, column 27, requires PHP 8.2"
+    ],
+    gated(input, PhpVersion::Php81, PhpVersion::Php82)
+  );
+}
+
+#[test]
+fn short_list() {
+  let input = "<?php\nlist($a) = $c;\n[$a, $b] = $c;\nforeach ($a as [$b]) {}";
+  assert_eq!(
+    vec![
+      "Unsupported short list destructuring at line 3, column 0, requires PHP 7.1",
+      "Unsupported short list destructuring at line 4, column 15, requires PHP 7.1"
+    ],
+    gated(input, PhpVersion::Php70, PhpVersion::Php71)
+  );
+}
+
+#[test]
+fn class_constant_visibility() {
+  let input = "<?php\nclass A {\n  const X = 1;\n  private const Y = 2;\n}";
+  assert_eq!(
+    vec!["Unsupported class constant visibility at line 4, column 2, requires PHP 7.1"],
+    gated(input, PhpVersion::Php70, PhpVersion::Php71)
+  );
+}
+
+#[test]
+fn parameter_trailing_comma() {
+  let input = "<?php\nfunction a($a, $b) {}\nfunction b($a, $b,) {}\n$c = fn($a,) => 1;";
+  assert_eq!(
+    vec![
+      "Unsupported trailing comma in parameter list at line 3, column 15, requires PHP 8.0",
+      "Unsupported trailing comma in parameter list at line 4, column 8, requires PHP 8.0"
+    ],
+    gated(input, PhpVersion::Php74, PhpVersion::Php80)
+  );
+}
+
+#[test]
+fn argument_trailing_comma() {
+  let input = "<?php\nf(1, 2);\nf(1, /* b */ 2 /* c */,);\nnew A(1,);";
+  assert_eq!(
+    vec![
+      "Unsupported trailing comma in argument list at line 3, column 13, requires PHP 7.3",
+      "Unsupported trailing comma in argument list at line 4, column 6, requires PHP 7.3"
+    ],
+    gated(input, PhpVersion::Php72, PhpVersion::Php73)
+  );
+}
+
+#[test]
+fn array_unpacking() {
+  let input = "<?php\nf(...$b);\n$a = [1, ...$b];";
+  assert_eq!(
+    vec!["Unsupported array unpacking at line 3, column 9, requires PHP 7.4"],
+    gated(input, PhpVersion::Php73, PhpVersion::Php74)
+  );
+}
+
+#[test]
+fn standalone_null_false() {
+  let input = "<?php\nfunction a(int|false $a): null {}\nfunction b(): false {}";
+  assert_eq!(
+    vec![
+      "Unsupported standalone null and false types at line 2, column 26, requires PHP 8.2",
+      "Unsupported standalone null and false types at line 3, column 14, requires PHP 8.2"
+    ],
+    gated(input, PhpVersion::Php81, PhpVersion::Php82)
+  );
+}
+
+#[test]
+fn indented_heredoc() {
+  let input = "<?php\n$a = <<<A\nx\nA;\n$b = <<<B\n  x\n  B;\n$c = <<<'C'\n  x\n  C;";
+  assert_eq!(
+    vec![
+      "Unsupported indented heredoc closing label at line 5, column 5, requires PHP 7.3",
+      "Unsupported indented heredoc closing label at line 8, column 5, requires PHP 7.3"
+    ],
+    gated(input, PhpVersion::Php72, PhpVersion::Php73)
+  );
+}